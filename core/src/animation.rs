@@ -0,0 +1,316 @@
+//! Animate values over time with easing curves and springs.
+mod easing;
+mod interpolate;
+mod spring;
+
+pub use easing::Easing;
+pub use interpolate::Interpolate;
+pub use spring::Spring;
+
+use crate::time::{Duration, Instant};
+
+/// The way an animated value progresses over time.
+#[derive(Debug, Clone, Copy)]
+pub enum Motion {
+    /// The value progresses over a fixed [`Duration`] following an [`Easing`]
+    /// curve.
+    Tween {
+        /// The [`Duration`] of the motion.
+        duration: Duration,
+        /// The [`Easing`] curve of the motion.
+        easing: Easing,
+    },
+
+    /// The value is pulled towards its target by a [`Spring`].
+    Spring(Spring),
+}
+
+impl Motion {
+    /// Returns the progress of the [`Motion`] after the given amount of time.
+    ///
+    /// The progress starts at `0.0` and ends at `1.0`, but it may overshoot
+    /// in between, like an underdamped [`Spring`] does.
+    pub fn progress(&self, elapsed: Duration) -> f32 {
+        match self {
+            Motion::Tween { duration, easing } => {
+                if duration.is_zero() {
+                    return 1.0;
+                }
+
+                let t = elapsed.as_secs_f32() / duration.as_secs_f32();
+
+                easing.apply(t.min(1.0))
+            }
+            Motion::Spring(spring) => spring.progress(elapsed),
+        }
+    }
+
+    /// Returns the [`Duration`] of the [`Motion`].
+    ///
+    /// For a [`Spring`], this is the time it takes to settle.
+    pub fn duration(&self) -> Duration {
+        match self {
+            Motion::Tween { duration, .. } => *duration,
+            Motion::Spring(spring) => spring.settling_time(),
+        }
+    }
+}
+
+impl Default for Motion {
+    fn default() -> Self {
+        Motion::Tween {
+            duration: Duration::from_millis(150),
+            easing: Easing::EaseOut,
+        }
+    }
+}
+
+impl From<Spring> for Motion {
+    fn from(spring: Spring) -> Self {
+        Motion::Spring(spring)
+    }
+}
+
+/// An animated transition between discrete states, like the status of a
+/// widget.
+///
+/// An [`Animation`] does not hold the values being animated. Instead, they
+/// are produced on demand for every state with [`Animation::interpolate_with`],
+/// which makes it useful to animate between appearances of a style sheet.
+///
+/// # Example
+/// ```
+/// use iced_core::animation::{Animation, Interpolate};
+/// use iced_core::time::{Duration, Instant};
+/// use iced_core::Color;
+///
+/// let mut is_hovered = Animation::new(false);
+/// let start = Instant::now();
+///
+/// is_hovered.go(true, start);
+///
+/// let color = |is_hovered| if is_hovered { Color::WHITE } else { Color::BLACK };
+///
+/// assert_eq!(is_hovered.interpolate_with(color, start), Color::BLACK);
+/// assert_eq!(
+///     is_hovered.interpolate_with(color, start + Duration::from_secs(1)),
+///     Color::WHITE
+/// );
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Animation<T> {
+    origin: Origin<T>,
+    target: T,
+    started_at: Option<Instant>,
+    motion: Motion,
+}
+
+/// The starting point of an [`Animation`], which may be in between states if
+/// a previous transition was interrupted.
+#[derive(Debug, Clone, Copy)]
+struct Origin<T> {
+    from: T,
+    to: T,
+    progress: f32,
+}
+
+impl<T> Animation<T>
+where
+    T: Copy + PartialEq,
+{
+    /// Creates a new [`Animation`] settled in the given state.
+    pub fn new(state: T) -> Self {
+        Self {
+            origin: Origin {
+                from: state,
+                to: state,
+                progress: 1.0,
+            },
+            target: state,
+            started_at: None,
+            motion: Motion::default(),
+        }
+    }
+
+    /// Sets the [`Motion`] of the [`Animation`].
+    pub fn motion(mut self, motion: impl Into<Motion>) -> Self {
+        self.motion = motion.into();
+        self
+    }
+
+    /// Changes the [`Motion`] of the [`Animation`].
+    ///
+    /// The change also applies to the current transition, if any.
+    pub fn set_motion(&mut self, motion: impl Into<Motion>) {
+        self.motion = motion.into();
+    }
+
+    /// Returns the current target state of the [`Animation`].
+    pub fn state(&self) -> T {
+        self.target
+    }
+
+    /// Starts a transition towards the given state at the given time.
+    ///
+    /// Nothing happens if the [`Animation`] is already headed to that state.
+    pub fn go(&mut self, state: T, now: Instant) {
+        if state == self.target {
+            return;
+        }
+
+        let dominant = if self.origin.progress < 0.5 {
+            self.origin.from
+        } else {
+            self.origin.to
+        };
+
+        self.origin = Origin {
+            from: dominant,
+            to: self.target,
+            progress: self.progress(now),
+        };
+        self.target = state;
+        self.started_at = Some(now);
+    }
+
+    /// Returns the progress of the current transition at the given time.
+    pub fn progress(&self, now: Instant) -> f32 {
+        match self.started_at {
+            Some(started_at) => self.motion.progress(elapsed(started_at, now)),
+            None => 1.0,
+        }
+    }
+
+    /// Returns whether the [`Animation`] is still in transition at the given
+    /// time.
+    pub fn is_animating(&self, now: Instant) -> bool {
+        match self.started_at {
+            Some(started_at) => {
+                elapsed(started_at, now) < self.motion.duration()
+            }
+            None => false,
+        }
+    }
+
+    /// Produces the value of the [`Animation`] at the given time, using the
+    /// provided function to obtain the value of each state.
+    pub fn interpolate_with<V>(&self, f: impl Fn(T) -> V, now: Instant) -> V
+    where
+        V: Interpolate,
+    {
+        let target = f(self.target);
+
+        if !self.is_animating(now) {
+            return target;
+        }
+
+        let to = f(self.origin.to);
+        let origin = if self.origin.progress >= 1.0 {
+            to
+        } else {
+            f(self.origin.from).interpolate(&to, self.origin.progress)
+        };
+
+        origin.interpolate(&target, self.progress(now))
+    }
+}
+
+/// A value that moves smoothly towards its target.
+///
+/// # Example
+/// ```
+/// use iced_core::animation::Tween;
+/// use iced_core::time::{Duration, Instant};
+///
+/// let mut opacity = Tween::new(0.0);
+/// let start = Instant::now();
+///
+/// opacity.go(1.0, start);
+///
+/// assert_eq!(opacity.value(start), 0.0);
+/// assert_eq!(opacity.value(start + Duration::from_secs(1)), 1.0);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Tween<T> {
+    from: T,
+    to: T,
+    started_at: Option<Instant>,
+    motion: Motion,
+}
+
+impl<T> Tween<T>
+where
+    T: Interpolate + Clone + PartialEq,
+{
+    /// Creates a new [`Tween`] resting at the given value.
+    pub fn new(value: T) -> Self {
+        Self {
+            from: value.clone(),
+            to: value,
+            started_at: None,
+            motion: Motion::default(),
+        }
+    }
+
+    /// Sets the [`Motion`] of the [`Tween`].
+    pub fn motion(mut self, motion: impl Into<Motion>) -> Self {
+        self.motion = motion.into();
+        self
+    }
+
+    /// Changes the [`Motion`] of the [`Tween`].
+    ///
+    /// The change also applies to the current transition, if any.
+    pub fn set_motion(&mut self, motion: impl Into<Motion>) {
+        self.motion = motion.into();
+    }
+
+    /// Returns the target value of the [`Tween`].
+    pub fn target(&self) -> &T {
+        &self.to
+    }
+
+    /// Starts moving towards the given value at the given time, starting from
+    /// the current value.
+    ///
+    /// Nothing happens if the [`Tween`] is already headed to that value.
+    pub fn go(&mut self, value: T, now: Instant) {
+        if value == self.to {
+            return;
+        }
+
+        self.from = self.value(now);
+        self.to = value;
+        self.started_at = Some(now);
+    }
+
+    /// Returns the value of the [`Tween`] at the given time.
+    pub fn value(&self, now: Instant) -> T {
+        match self.started_at {
+            Some(started_at) if self.is_animating(now) => {
+                let progress = self.motion.progress(elapsed(started_at, now));
+
+                self.from.interpolate(&self.to, progress)
+            }
+            _ => self.to.clone(),
+        }
+    }
+
+    /// Returns whether the [`Tween`] is still moving at the given time.
+    pub fn is_animating(&self, now: Instant) -> bool {
+        match self.started_at {
+            Some(started_at) => {
+                elapsed(started_at, now) < self.motion.duration()
+            }
+            None => false,
+        }
+    }
+}
+
+fn elapsed(started_at: Instant, now: Instant) -> Duration {
+    if now > started_at {
+        now.duration_since(started_at)
+    } else {
+        Duration::ZERO
+    }
+}
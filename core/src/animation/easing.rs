@@ -0,0 +1,66 @@
+/// A curve that maps the normalized time of an animation to its progress.
+#[derive(Debug, Clone, Copy)]
+pub enum Easing {
+    /// Constant speed.
+    Linear,
+
+    /// Starts slow and accelerates (quadratic).
+    EaseIn,
+
+    /// Starts fast and decelerates (quadratic).
+    EaseOut,
+
+    /// Accelerates until halfway, then decelerates (quadratic).
+    EaseInOut,
+
+    /// Starts slow and accelerates (cubic).
+    EaseInCubic,
+
+    /// Starts fast and decelerates (cubic).
+    EaseOutCubic,
+
+    /// Accelerates until halfway, then decelerates (cubic).
+    EaseInOutCubic,
+
+    /// A custom curve.
+    ///
+    /// It should map `0.0` to `0.0` and `1.0` to `1.0`.
+    Custom(fn(f32) -> f32),
+}
+
+impl Easing {
+    /// Applies the [`Easing`] curve to the given normalized time, clamped
+    /// between `0.0` and `1.0`.
+    pub fn apply(self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+
+        match self {
+            Easing::Linear => t,
+            Easing::EaseIn => t * t,
+            Easing::EaseOut => t * (2.0 - t),
+            Easing::EaseInOut => {
+                if t < 0.5 {
+                    2.0 * t * t
+                } else {
+                    -1.0 + (4.0 - 2.0 * t) * t
+                }
+            }
+            Easing::EaseInCubic => t * t * t,
+            Easing::EaseOutCubic => {
+                let t = t - 1.0;
+
+                t * t * t + 1.0
+            }
+            Easing::EaseInOutCubic => {
+                if t < 0.5 {
+                    4.0 * t * t * t
+                } else {
+                    let t = 2.0 * t - 2.0;
+
+                    0.5 * t * t * t + 1.0
+                }
+            }
+            Easing::Custom(f) => f(t),
+        }
+    }
+}
@@ -0,0 +1,114 @@
+use crate::{Background, Color, Padding, Point, Size, Vector};
+
+/// A value that can be interpolated.
+pub trait Interpolate {
+    /// Returns the value between `self` and `other` at `t`, where `0.0` is
+    /// `self` and `1.0` is `other`.
+    ///
+    /// `t` may fall outside of that range when a motion overshoots.
+    fn interpolate(&self, other: &Self, t: f32) -> Self;
+}
+
+impl Interpolate for f32 {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        self + (other - self) * t
+    }
+}
+
+impl Interpolate for Vector {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        Vector::new(
+            self.x.interpolate(&other.x, t),
+            self.y.interpolate(&other.y, t),
+        )
+    }
+}
+
+impl Interpolate for Point {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        Point::new(
+            self.x.interpolate(&other.x, t),
+            self.y.interpolate(&other.y, t),
+        )
+    }
+}
+
+impl Interpolate for Size {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        Size::new(
+            self.width.interpolate(&other.width, t).max(0.0),
+            self.height.interpolate(&other.height, t).max(0.0),
+        )
+    }
+}
+
+impl Interpolate for Color {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        let channel = |a: f32, b: f32| a.interpolate(&b, t).clamp(0.0, 1.0);
+
+        Color {
+            r: channel(self.r, other.r),
+            g: channel(self.g, other.g),
+            b: channel(self.b, other.b),
+            a: channel(self.a, other.a),
+        }
+    }
+}
+
+impl Interpolate for Padding {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        let side = |a: u16, b: u16| {
+            f32::from(a).interpolate(&f32::from(b), t).round() as u16
+        };
+
+        Padding {
+            top: side(self.top, other.top),
+            right: side(self.right, other.right),
+            bottom: side(self.bottom, other.bottom),
+            left: side(self.left, other.left),
+        }
+    }
+}
+
+impl Interpolate for Background {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        match (self, other) {
+            (Background::Color(a), Background::Color(b)) => {
+                Background::Color(a.interpolate(b, t))
+            }
+        }
+    }
+}
+
+impl Interpolate for Option<Color> {
+    /// A missing [`Color`] fades from or into a transparent version of the
+    /// other one.
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        match (self, other) {
+            (Some(a), Some(b)) => Some(a.interpolate(b, t)),
+            (Some(a), None) => Some(a.interpolate(&transparent(*a), t)),
+            (None, Some(b)) => Some(transparent(*b).interpolate(b, t)),
+            (None, None) => None,
+        }
+    }
+}
+
+impl Interpolate for Option<Background> {
+    /// A missing [`Background`] fades from or into a transparent version of
+    /// the other one.
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        let color = |background: &Option<Background>| {
+            background.map(|background| match background {
+                Background::Color(color) => color,
+            })
+        };
+
+        color(self)
+            .interpolate(&color(other), t)
+            .map(Background::Color)
+    }
+}
+
+fn transparent(color: Color) -> Color {
+    Color { a: 0.0, ..color }
+}
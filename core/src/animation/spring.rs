@@ -0,0 +1,164 @@
+use crate::time::Duration;
+
+/// The parameters of a damped spring.
+///
+/// The progress of a [`Spring`] is the position of a mass attached to it,
+/// released at rest from the start towards the end of an animation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Spring {
+    /// The stiffness of the spring. Stiffer springs are faster.
+    pub stiffness: f32,
+
+    /// The damping of the spring. Springs with less damping oscillate more.
+    pub damping: f32,
+
+    /// The mass attached to the spring. Heavier masses are slower.
+    pub mass: f32,
+}
+
+impl Spring {
+    /// A gentle [`Spring`] that barely overshoots.
+    pub const GENTLE: Spring = Spring::new(120.0, 14.0);
+
+    /// A wobbly [`Spring`] that visibly bounces.
+    pub const WOBBLY: Spring = Spring::new(180.0, 12.0);
+
+    /// A stiff [`Spring`] that settles quickly.
+    pub const STIFF: Spring = Spring::new(210.0, 20.0);
+
+    /// The distance to the target under which a [`Spring`] is considered
+    /// settled, relative to the total distance.
+    const PRECISION: f32 = 0.001;
+
+    /// Creates a new [`Spring`] with the given stiffness and damping, and a
+    /// mass of `1.0`.
+    pub const fn new(stiffness: f32, damping: f32) -> Self {
+        Self {
+            stiffness,
+            damping,
+            mass: 1.0,
+        }
+    }
+
+    /// Returns the progress of the [`Spring`] after the given amount of time.
+    pub fn progress(&self, elapsed: Duration) -> f32 {
+        1.0 - self.displacement(elapsed.as_secs_f32())
+    }
+
+    /// Returns the time the [`Spring`] takes to settle.
+    pub fn settling_time(&self) -> Duration {
+        let (omega, zeta) = self.parameters();
+
+        if omega <= 0.0 || zeta <= 0.0 {
+            return Duration::ZERO;
+        }
+
+        let decay = if zeta < 1.0 {
+            zeta * omega
+        } else {
+            omega * (zeta - (zeta * zeta - 1.0).sqrt())
+        };
+
+        // Start at the time where the decay envelope reaches the precision
+        // and step forward until the displacement stays under it
+        let mut time = (1.0 / Self::PRECISION).ln() / decay;
+
+        while self.displacement(time).abs() > Self::PRECISION
+            || self.envelope(time) > Self::PRECISION
+        {
+            time *= 1.1;
+        }
+
+        Duration::from_secs_f32(time)
+    }
+
+    /// Returns the natural frequency and the damping ratio of the
+    /// [`Spring`].
+    fn parameters(&self) -> (f32, f32) {
+        let omega = (self.stiffness / self.mass).sqrt();
+        let zeta = self.damping / (2.0 * (self.stiffness * self.mass).sqrt());
+
+        (omega, zeta)
+    }
+
+    /// Returns the distance to the target at the given time, relative to the
+    /// total distance.
+    fn displacement(&self, time: f32) -> f32 {
+        let (omega, zeta) = self.parameters();
+
+        if !omega.is_finite() || omega <= 0.0 {
+            return 0.0;
+        }
+
+        if (zeta - 1.0).abs() < f32::EPSILON {
+            (-omega * time).exp() * (1.0 + omega * time)
+        } else if zeta < 1.0 {
+            let omega_d = omega * (1.0 - zeta * zeta).sqrt();
+
+            (-zeta * omega * time).exp()
+                * ((omega_d * time).cos()
+                    + zeta * omega / omega_d * (omega_d * time).sin())
+        } else {
+            let root = (zeta * zeta - 1.0).sqrt();
+            let r1 = -omega * (zeta - root);
+            let r2 = -omega * (zeta + root);
+
+            (r2 * (r1 * time).exp() - r1 * (r2 * time).exp()) / (r2 - r1)
+        }
+    }
+
+    /// Returns an upper bound of the displacement of an underdamped
+    /// [`Spring`] at the given time.
+    fn envelope(&self, time: f32) -> f32 {
+        let (omega, zeta) = self.parameters();
+
+        if zeta >= 1.0 {
+            return 0.0;
+        }
+
+        let omega_d = omega * (1.0 - zeta * zeta).sqrt();
+        let amplitude = (1.0 + (zeta * omega / omega_d).powi(2)).sqrt();
+
+        amplitude * (-zeta * omega * time).exp()
+    }
+}
+
+impl Default for Spring {
+    fn default() -> Self {
+        Spring::GENTLE
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn settles_at_the_target() {
+        for spring in [
+            Spring::GENTLE,
+            Spring::WOBBLY,
+            Spring::STIFF,
+            Spring::new(100.0, 20.0),
+            Spring::new(100.0, 40.0),
+        ] {
+            assert_eq!(spring.progress(Duration::ZERO), 0.0);
+
+            let settled = spring.progress(spring.settling_time());
+
+            assert!((settled - 1.0).abs() <= Spring::PRECISION, "{:?}", spring);
+        }
+    }
+
+    #[test]
+    fn underdamped_springs_overshoot() {
+        let spring = Spring::WOBBLY;
+        let steps = 100;
+
+        let max = (0..steps)
+            .map(|step| spring.progress(spring.settling_time() * step / steps))
+            .fold(0.0, f32::max);
+
+        assert!(max > 1.0);
+    }
+}
@@ -25,6 +25,8 @@
 #![forbid(unsafe_code, rust_2018_idioms)]
 #![allow(clippy::inherent_to_string, clippy::type_complexity)]
 pub mod alignment;
+pub mod animation;
+pub mod font;
 pub mod keyboard;
 pub mod mouse;
 pub mod time;
@@ -32,7 +34,6 @@ pub mod time;
 mod background;
 mod color;
 mod content_fit;
-mod font;
 mod length;
 mod padding;
 mod point;
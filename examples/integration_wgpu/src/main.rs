@@ -169,6 +169,7 @@ pub fn main() {
 
                 // Map window event to iced event
                 if let Some(event) = iced_winit::conversion::window_event(
+                    iced_winit::window::Id::MAIN,
                     &event,
                     window.scale_factor(),
                     modifiers,
@@ -0,0 +1,83 @@
+use iced::executor;
+use iced::multi_window::Application;
+use iced::widget::{button, column, container, text};
+use iced::window;
+use iced::{Alignment, Command, Element, Length, Settings, Theme};
+
+pub fn main() -> iced::Result {
+    Windows::run(Settings::default())
+}
+
+#[derive(Debug, Default)]
+struct Windows {
+    opened: Vec<window::Id>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Message {
+    Open,
+    Close(window::Id),
+}
+
+impl Application for Windows {
+    type Message = Message;
+    type Theme = Theme;
+    type Executor = executor::Default;
+    type Flags = ();
+
+    fn new(_flags: ()) -> (Windows, Command<Message>) {
+        (Windows::default(), Command::none())
+    }
+
+    fn title(&self, window: window::Id) -> String {
+        if window == window::Id::MAIN {
+            String::from("Multi-window - Iced")
+        } else {
+            format!("Window {:?} - Iced", window)
+        }
+    }
+
+    fn update(&mut self, message: Message) -> Command<Message> {
+        match message {
+            Message::Open => {
+                let id = window::Id::unique();
+
+                self.opened.push(id);
+
+                window::spawn(
+                    id,
+                    window::Settings {
+                        size: (400, 300),
+                        ..window::Settings::default()
+                    },
+                )
+            }
+            Message::Close(id) => {
+                self.opened.retain(|opened| *opened != id);
+
+                window::close_window(id)
+            }
+        }
+    }
+
+    fn view(&self, window: window::Id) -> Element<Message> {
+        let content = if window == window::Id::MAIN {
+            column![
+                text(format!("{} windows opened", self.opened.len())),
+                button("Open window").on_press(Message::Open),
+            ]
+        } else {
+            column![
+                text(format!("This is window {:?}", window)),
+                button("Close").on_press(Message::Close(window)),
+            ]
+        };
+
+        container(content.spacing(20).align_items(Alignment::Center))
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .center_x()
+            .center_y()
+            .into()
+    }
+}
@@ -25,7 +25,7 @@ enum Message {
     ThemeChanged(ThemeType),
     ScrollToTop(usize),
     ScrollToBottom(usize),
-    Scrolled(usize, f32),
+    Scrolled(usize, scrollable::RelativeOffset),
 }
 
 impl Application for ScrollableDemo {
@@ -62,7 +62,10 @@ impl Application for ScrollableDemo {
                 if let Some(variant) = self.variants.get_mut(i) {
                     variant.latest_offset = 0.0;
 
-                    scrollable::snap_to(Variant::id(i), 0.0)
+                    scrollable::snap_to(
+                        Variant::id(i),
+                        scrollable::RelativeOffset::START,
+                    )
                 } else {
                     Command::none()
                 }
@@ -71,14 +74,17 @@ impl Application for ScrollableDemo {
                 if let Some(variant) = self.variants.get_mut(i) {
                     variant.latest_offset = 1.0;
 
-                    scrollable::snap_to(Variant::id(i), 1.0)
+                    scrollable::snap_to(
+                        Variant::id(i),
+                        scrollable::RelativeOffset::END,
+                    )
                 } else {
                     Command::none()
                 }
             }
             Message::Scrolled(i, offset) => {
                 if let Some(variant) = self.variants.get_mut(i) {
-                    variant.latest_offset = offset;
+                    variant.latest_offset = offset.y;
                 }
 
                 Command::none()
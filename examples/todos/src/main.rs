@@ -22,6 +22,7 @@ pub fn main() -> iced::Result {
             size: (500, 800),
             ..window::Settings::default()
         },
+        fonts: &[include_bytes!("../../todos/fonts/icons.ttf")],
         ..Settings::default()
     })
 }
@@ -466,10 +467,7 @@ fn empty_message(message: &str) -> Element<'_, Message> {
 }
 
 // Fonts
-const ICONS: Font = Font::External {
-    name: "Icons",
-    bytes: include_bytes!("../../todos/fonts/icons.ttf"),
-};
+const ICONS: Font = Font::with_name("fontello");
 
 fn icon(unicode: char) -> Text<'static> {
     text(unicode.to_string())
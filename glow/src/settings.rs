@@ -11,6 +11,20 @@ pub struct Settings {
     /// If `None` is provided, a default system font will be chosen.
     pub default_font: Option<&'static [u8]>,
 
+    /// The bytes of the fonts that will be used, in order, for the characters
+    /// missing in the default font.
+    ///
+    /// By default, it is empty.
+    pub fallback_fonts: &'static [&'static [u8]],
+
+    /// The bytes of the fonts that will be loaded on boot, which can be
+    /// selected by the family and properties of a [`Font`].
+    ///
+    /// By default, it is empty.
+    ///
+    /// [`Font`]: iced_native::Font
+    pub fonts: &'static [&'static [u8]],
+
     /// The default size of text.
     ///
     /// By default, it will be set to 20.
@@ -32,6 +46,8 @@ impl Default for Settings {
     fn default() -> Settings {
         Settings {
             default_font: None,
+            fallback_fonts: &[],
+            fonts: &[],
             default_text_size: 20,
             text_multithreading: false,
             antialiasing: None,
@@ -44,6 +60,8 @@ impl std::fmt::Debug for Settings {
         f.debug_struct("Settings")
             // Instead of printing the font bytes, we simply show a `bool` indicating if using a default font or not.
             .field("default_font", &self.default_font.is_none())
+            .field("fallback_fonts", &self.fallback_fonts.len())
+            .field("fonts", &self.fonts.len())
             .field("default_text_size", &self.default_text_size)
             .field("text_multithreading", &self.text_multithreading)
             .field("antialiasing", &self.antialiasing)
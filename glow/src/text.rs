@@ -1,23 +1,28 @@
 use crate::Transformation;
 
 use iced_graphics::font;
+use iced_graphics::text::{Database, Layout};
+use iced_native::alignment;
 
 use glow_glyph::ab_glyph;
-use std::{cell::RefCell, collections::HashMap};
+use std::cell::RefCell;
 
-pub use iced_native::text::Hit;
+pub use iced_native::text::{Hit, Span, Wrapping};
 
 #[derive(Debug)]
 pub struct Pipeline {
     draw_brush: RefCell<glow_glyph::GlyphBrush>,
-    draw_font_map: RefCell<HashMap<String, glow_glyph::FontId>>,
     measure_brush: RefCell<glyph_brush::GlyphBrush<()>>,
+    database: RefCell<Database>,
+    fallbacks: usize,
 }
 
 impl Pipeline {
     pub fn new(
         gl: &glow::Context,
         default_font: Option<&[u8]>,
+        fallback_fonts: &[&'static [u8]],
+        fonts: &[&'static [u8]],
         multithreading: bool,
     ) -> Self {
         let default_font = default_font.map(|slice| slice.to_vec());
@@ -46,8 +51,29 @@ impl Pipeline {
                     .expect("Load fallback font")
             });
 
+        let mut loaded: Vec<_> = std::iter::once(font)
+            .chain(iced_graphics::text::load_fallbacks(fallback_fonts))
+            .collect();
+
+        let fallbacks = loaded.len() - 1;
+
+        loaded.extend(fonts.iter().filter_map(|bytes| {
+            ab_glyph::FontArc::try_from_slice(bytes)
+                .map_err(|_| log::warn!("Font failed to load"))
+                .ok()
+        }));
+
+        let mut database = Database::new(&loaded);
+
+        loaded.push(
+            ab_glyph::FontArc::try_from_slice(font::ICONS_BYTES)
+                .expect("Load icons font"),
+        );
+
+        database.alias(font::ICONS, glyph_brush::FontId(loaded.len() - 1));
+
         let draw_brush_builder =
-            glow_glyph::GlyphBrushBuilder::using_font(font.clone())
+            glow_glyph::GlyphBrushBuilder::using_fonts(loaded.clone())
                 .initial_cache_size((2048, 2048))
                 .draw_cache_multithread(multithreading);
 
@@ -57,17 +83,28 @@ impl Pipeline {
         let draw_brush = draw_brush_builder.build(gl);
 
         let measure_brush =
-            glyph_brush::GlyphBrushBuilder::using_font(font).build();
+            glyph_brush::GlyphBrushBuilder::using_fonts(loaded).build();
 
         Pipeline {
             draw_brush: RefCell::new(draw_brush),
-            draw_font_map: RefCell::new(HashMap::new()),
             measure_brush: RefCell::new(measure_brush),
+            database: RefCell::new(database),
+            fallbacks,
         }
     }
 
-    pub fn queue(&mut self, section: glow_glyph::Section<'_>) {
-        self.draw_brush.borrow_mut().queue(section);
+    pub fn layout(
+        &self,
+        horizontal_alignment: alignment::Horizontal,
+        vertical_alignment: alignment::Vertical,
+    ) -> Layout {
+        Layout::new(horizontal_alignment, vertical_alignment, self.fallbacks)
+    }
+
+    pub fn queue(&mut self, section: glow_glyph::Section<'_>, layout: Layout) {
+        self.draw_brush
+            .borrow_mut()
+            .queue_custom_layout(section, &layout);
     }
 
     pub fn draw_queued(
@@ -92,6 +129,7 @@ impl Pipeline {
         size: f32,
         font: iced_native::Font,
         bounds: iced_native::Size,
+        wrapping: Wrapping,
     ) -> (f32, f32) {
         use glow_glyph::GlyphCruncher;
 
@@ -108,8 +146,14 @@ impl Pipeline {
             ..Default::default()
         };
 
-        if let Some(bounds) =
-            self.measure_brush.borrow_mut().glyph_bounds(section)
+        let layout = self
+            .layout(alignment::Horizontal::Left, alignment::Vertical::Top)
+            .wrapping(wrapping);
+
+        if let Some(bounds) = self
+            .measure_brush
+            .borrow_mut()
+            .glyph_bounds_custom_layout(section, &layout)
         {
             (bounds.width().ceil(), bounds.height().ceil())
         } else {
@@ -141,69 +185,87 @@ impl Pipeline {
             ..Default::default()
         };
 
+        let layout =
+            self.layout(alignment::Horizontal::Left, alignment::Vertical::Top);
+
         let mut mb = self.measure_brush.borrow_mut();
 
         // The underlying type is FontArc, so clones are cheap.
-        use ab_glyph::{Font, ScaleFont};
-        let font = mb.fonts()[font_id].clone().into_scaled(size);
-
-        // Implements an iterator over the glyph bounding boxes.
-        let bounds = mb.glyphs(section).map(
-            |glow_glyph::SectionGlyph {
-                 byte_index, glyph, ..
-             }| {
-                (
-                    *byte_index,
-                    iced_native::Rectangle::new(
-                        iced_native::Point::new(
-                            glyph.position.x - font.h_side_bearing(glyph.id),
-                            glyph.position.y - font.ascent(),
-                        ),
-                        iced_native::Size::new(
-                            font.h_advance(glyph.id),
-                            font.ascent() - font.descent(),
-                        ),
-                    ),
-                )
-            },
-        );
+        let fonts = mb.fonts().to_vec();
+
+        iced_graphics::text::hit_test(
+            &fonts,
+            mb.glyphs_custom_layout(section, &layout),
+            content,
+            point,
+            nearest_only,
+        )
+    }
 
-        // Implements computation of the character index based on the byte index
-        // within the input string.
-        let char_index = |byte_index| {
-            let mut b_count = 0;
-            for (i, utf8_len) in
-                content.chars().map(|c| c.len_utf8()).enumerate()
-            {
-                if byte_index < (b_count + utf8_len) {
-                    return i;
-                }
-                b_count += utf8_len;
-            }
+    pub fn measure_spans(
+        &self,
+        spans: &[Span<'_, iced_native::Font>],
+        bounds: iced_native::Size,
+    ) -> (f32, f32) {
+        use glow_glyph::GlyphCruncher;
 
-            byte_index
-        };
+        let section = self.span_section(spans, bounds);
+        let layout =
+            self.layout(alignment::Horizontal::Left, alignment::Vertical::Top);
 
-        if !nearest_only {
-            for (idx, bounds) in bounds.clone() {
-                if bounds.contains(point) {
-                    return Some(Hit::CharOffset(char_index(idx)));
-                }
-            }
+        if let Some(bounds) = self
+            .measure_brush
+            .borrow_mut()
+            .glyph_bounds_custom_layout(section, &layout)
+        {
+            (bounds.width().ceil(), bounds.height().ceil())
+        } else {
+            (0.0, 0.0)
         }
+    }
 
-        let nearest = bounds
-            .map(|(index, bounds)| (index, bounds.center()))
-            .min_by(|(_, center_a), (_, center_b)| {
-                center_a
-                    .distance(point)
-                    .partial_cmp(&center_b.distance(point))
-                    .unwrap_or(std::cmp::Ordering::Greater)
-            });
+    pub fn hit_span(
+        &self,
+        spans: &[Span<'_, iced_native::Font>],
+        bounds: iced_native::Size,
+        point: iced_native::Point,
+    ) -> Option<usize> {
+        use glow_glyph::GlyphCruncher;
 
-        nearest.map(|(idx, center)| {
-            Hit::NearestCharOffset(char_index(idx), point - center)
-        })
+        let section = self.span_section(spans, bounds);
+        let layout =
+            self.layout(alignment::Horizontal::Left, alignment::Vertical::Top);
+
+        let mut mb = self.measure_brush.borrow_mut();
+
+        // The underlying type is FontArc, so clones are cheap.
+        let fonts = mb.fonts().to_vec();
+
+        iced_graphics::text::hit_section(
+            &fonts,
+            mb.glyphs_custom_layout(section, &layout),
+            point,
+        )
+    }
+
+    fn span_section<'a>(
+        &self,
+        spans: &[Span<'a, iced_native::Font>],
+        bounds: iced_native::Size,
+    ) -> glow_glyph::Section<'a> {
+        glow_glyph::Section {
+            bounds: (bounds.width, bounds.height),
+            text: spans
+                .iter()
+                .map(|span| glow_glyph::Text {
+                    text: span.content,
+                    scale: span.size.into(),
+                    font_id: self.find_font(span.font),
+                    extra: glow_glyph::Extra::default(),
+                })
+                .collect(),
+            ..Default::default()
+        }
     }
 
     pub fn trim_measurement_cache(&mut self) {
@@ -230,28 +292,30 @@ impl Pipeline {
         }
     }
 
-    pub fn find_font(&self, font: iced_native::Font) -> glow_glyph::FontId {
-        match font {
-            iced_native::Font::Default => glow_glyph::FontId(0),
-            iced_native::Font::External { name, bytes } => {
-                if let Some(font_id) = self.draw_font_map.borrow().get(name) {
-                    return *font_id;
-                }
+    pub fn load_font(
+        &mut self,
+        bytes: Vec<u8>,
+    ) -> Result<(), iced_native::font::Error> {
+        let font = ab_glyph::FontArc::try_from_vec(bytes)
+            .map_err(|_| iced_native::font::Error::Invalid)?;
 
-                let font = ab_glyph::FontArc::try_from_slice(bytes)
-                    .expect("Load font");
+        let _ = self.measure_brush.get_mut().add_font(font.clone());
+        let id = self.draw_brush.get_mut().add_font(font.clone());
 
-                let _ = self.measure_brush.borrow_mut().add_font(font.clone());
+        self.database.get_mut().insert(id, &font);
 
-                let font_id = self.draw_brush.borrow_mut().add_font(font);
+        // Text measured before may now resolve to the new font
+        let measure_brush = self.measure_brush.get_mut();
+        *measure_brush = measure_brush.to_builder().build();
 
-                let _ = self
-                    .draw_font_map
-                    .borrow_mut()
-                    .insert(String::from(name), font_id);
+        Ok(())
+    }
 
-                font_id
-            }
-        }
+    pub fn find_font(&self, font: iced_native::Font) -> glow_glyph::FontId {
+        self.database.borrow_mut().resolve(font, |font| {
+            let _ = self.measure_brush.borrow_mut().add_font(font.clone());
+
+            self.draw_brush.borrow_mut().add_font(font)
+        })
     }
 }
@@ -1,6 +1,7 @@
 use crate::{Backend, Color, Error, Renderer, Settings, Viewport};
 
 use glow::HasContext;
+use iced_graphics::window::GLCompositor;
 use iced_graphics::{compositor, Antialiasing, Size};
 
 use core::ffi::c_void;
@@ -13,7 +14,7 @@ pub struct Compositor<Theme> {
     theme: PhantomData<Theme>,
 }
 
-impl<Theme> iced_graphics::window::GLCompositor for Compositor<Theme> {
+impl<Theme> GLCompositor for Compositor<Theme> {
     type Settings = Settings;
     type Renderer = Renderer<Theme>;
 
@@ -108,4 +109,88 @@ impl<Theme> iced_graphics::window::GLCompositor for Compositor<Theme> {
             backend.present(gl, primitive, viewport, overlay);
         });
     }
+
+    fn screenshot<T: AsRef<str>>(
+        &mut self,
+        renderer: &mut Self::Renderer,
+        viewport: &Viewport,
+        background_color: Color,
+        overlay: &[T],
+    ) -> Vec<u8> {
+        let Size { width, height } = viewport.physical_size();
+        let mut pixels = vec![0; width as usize * height as usize * 4];
+
+        if pixels.is_empty() {
+            return pixels;
+        }
+
+        unsafe {
+            let mut previous_viewport = [0; 4];
+            self.gl.get_parameter_i32_slice(
+                glow::VIEWPORT,
+                &mut previous_viewport,
+            );
+
+            let renderbuffer = self
+                .gl
+                .create_renderbuffer()
+                .expect("Create screenshot renderbuffer");
+
+            self.gl
+                .bind_renderbuffer(glow::RENDERBUFFER, Some(renderbuffer));
+            self.gl.renderbuffer_storage(
+                glow::RENDERBUFFER,
+                glow::SRGB8_ALPHA8,
+                width as i32,
+                height as i32,
+            );
+
+            let framebuffer = self
+                .gl
+                .create_framebuffer()
+                .expect("Create screenshot framebuffer");
+
+            self.gl
+                .bind_framebuffer(glow::FRAMEBUFFER, Some(framebuffer));
+            self.gl.framebuffer_renderbuffer(
+                glow::FRAMEBUFFER,
+                glow::COLOR_ATTACHMENT0,
+                glow::RENDERBUFFER,
+                Some(renderbuffer),
+            );
+
+            self.gl.viewport(0, 0, width as i32, height as i32);
+
+            self.present(renderer, viewport, background_color, overlay);
+
+            self.gl.pixel_store_i32(glow::PACK_ALIGNMENT, 1);
+            self.gl.read_pixels(
+                0,
+                0,
+                width as i32,
+                height as i32,
+                glow::RGBA,
+                glow::UNSIGNED_BYTE,
+                glow::PixelPackData::Slice(&mut pixels),
+            );
+
+            self.gl.bind_framebuffer(glow::FRAMEBUFFER, None);
+            self.gl.bind_renderbuffer(glow::RENDERBUFFER, None);
+            self.gl.delete_framebuffer(framebuffer);
+            self.gl.delete_renderbuffer(renderbuffer);
+
+            let [x, y, width, height] = previous_viewport;
+            self.gl.viewport(x, y, width, height);
+        }
+
+        // OpenGL reads rows starting from the bottom left corner
+        let row_length = width as usize * 4;
+
+        pixels
+            .chunks_exact(row_length)
+            .rev()
+            .flatten()
+            .copied()
+            .collect()
+    }
 }
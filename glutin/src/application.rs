@@ -12,6 +12,7 @@ use iced_winit::futures;
 use iced_winit::futures::channel::mpsc;
 use iced_winit::renderer;
 use iced_winit::user_interface;
+use iced_winit::window::Screenshot;
 use iced_winit::{Clipboard, Command, Debug, Proxy, Settings};
 
 use glutin::window::Window;
@@ -28,6 +29,7 @@ where
     E: Executor + 'static,
     C: window::GLCompositor<Renderer = A::Renderer> + 'static,
     <A::Renderer as iced_native::Renderer>::Theme: StyleSheet,
+    A::Renderer: iced_native::text::Renderer,
 {
     use futures::task;
     use futures::Future;
@@ -123,6 +125,7 @@ where
     };
 
     let (mut sender, receiver) = mpsc::unbounded();
+    let (control_sender, control_receiver) = std::sync::mpsc::channel();
 
     let mut instance = Box::pin(run_instance::<A, E, C>(
         application,
@@ -132,12 +135,14 @@ where
         proxy,
         debug,
         receiver,
+        control_sender,
         context,
         init_command,
         settings.exit_on_close_request,
     ));
 
     let mut context = task::Context::from_waker(task::noop_waker_ref());
+    let mut requested_flow = glutin::event_loop::ControlFlow::Wait;
 
     let _ = event_loop.run_return(move |event, _, control_flow| {
         use glutin::event_loop::ControlFlow;
@@ -167,7 +172,13 @@ where
             let poll = instance.as_mut().poll(&mut context);
 
             *control_flow = match poll {
-                task::Poll::Pending => ControlFlow::Wait,
+                task::Poll::Pending => {
+                    if let Some(flow) = control_receiver.try_iter().last() {
+                        requested_flow = flow;
+                    }
+
+                    requested_flow
+                }
                 task::Poll::Ready(_) => ControlFlow::Exit,
             };
         }
@@ -184,6 +195,7 @@ async fn run_instance<A, E, C>(
     mut proxy: glutin::event_loop::EventLoopProxy<A::Message>,
     mut debug: Debug,
     mut receiver: mpsc::UnboundedReceiver<glutin::event::Event<'_, A::Message>>,
+    control_sender: std::sync::mpsc::Sender<glutin::event_loop::ControlFlow>,
     mut context: glutin::ContextWrapper<glutin::PossiblyCurrent, Window>,
     init_command: Command<A::Message>,
     exit_on_close_request: bool,
@@ -192,6 +204,7 @@ async fn run_instance<A, E, C>(
     E: Executor + 'static,
     C: window::GLCompositor<Renderer = A::Renderer> + 'static,
     <A::Renderer as iced_native::Renderer>::Theme: StyleSheet,
+    A::Renderer: iced_native::text::Renderer,
 {
     use glutin::event;
     use iced_winit::futures::stream::StreamExt;
@@ -201,6 +214,7 @@ async fn run_instance<A, E, C>(
     let mut state = application::State::new(&application, context.window());
     let mut viewport_version = state.viewport_version();
     let mut should_exit = false;
+    let mut screenshots = Vec::new();
 
     application::run_command(
         &application,
@@ -213,6 +227,7 @@ async fn run_instance<A, E, C>(
         &mut should_exit,
         &mut proxy,
         &mut debug,
+        &mut screenshots,
         context.window(),
         || compositor.fetch_information(),
     );
@@ -230,13 +245,22 @@ async fn run_instance<A, E, C>(
     let mut mouse_interaction = mouse::Interaction::default();
     let mut events = Vec::new();
     let mut messages = Vec::new();
+    let mut redraw_pending = false;
 
     debug.startup_finished();
 
     while let Some(event) = receiver.next().await {
         match event {
+            event::Event::NewEvents(start_cause) => {
+                redraw_pending = matches!(
+                    start_cause,
+                    event::StartCause::Init
+                        | event::StartCause::Poll
+                        | event::StartCause::ResumeTimeReached { .. }
+                );
+            }
             event::Event::MainEventsCleared => {
-                if events.is_empty() && messages.is_empty() {
+                if !redraw_pending && events.is_empty() && messages.is_empty() {
                     continue;
                 }
 
@@ -256,6 +280,14 @@ async fn run_instance<A, E, C>(
                     runtime.broadcast(event);
                 }
 
+                // Widgets may request a redraw while handling the events too
+                let events_redraw_request = match interface_state {
+                    user_interface::State::Updated {
+                        redraw_request, ..
+                    } => redraw_request,
+                    user_interface::State::Outdated => None,
+                };
+
                 if !messages.is_empty()
                     || matches!(
                         interface_state,
@@ -277,6 +309,7 @@ async fn run_instance<A, E, C>(
                         &mut proxy,
                         &mut debug,
                         &mut messages,
+                        &mut screenshots,
                         context.window(),
                         || compositor.fetch_information(),
                     );
@@ -298,6 +331,73 @@ async fn run_instance<A, E, C>(
                     }
                 }
 
+                // Let widgets advance their animations and request the
+                // next redraw
+                let redraw_event = iced_native::Event::Window(
+                    iced_winit::window::Id::MAIN,
+                    iced_native::window::Event::RedrawRequested(
+                        iced_native::time::Instant::now(),
+                    ),
+                );
+
+                let (interface_state, _) = user_interface.update(
+                    std::slice::from_ref(&redraw_event),
+                    state.cursor_position(),
+                    &mut renderer,
+                    &mut clipboard,
+                    &mut messages,
+                );
+
+                runtime.broadcast((
+                    redraw_event,
+                    iced_native::event::Status::Ignored,
+                ));
+
+                let redraw_request = match interface_state {
+                    user_interface::State::Updated {
+                        redraw_request,
+                        input_method,
+                    } => {
+                        state.update_input_method(
+                            context.window(),
+                            input_method,
+                        );
+
+                        events_redraw_request
+                            .into_iter()
+                            .chain(redraw_request)
+                            .min()
+                    }
+                    user_interface::State::Outdated => {
+                        // The widgets changed while redrawing, so the
+                        // interface is rebuilt before drawing it
+                        let cache = ManuallyDrop::into_inner(user_interface)
+                            .into_cache();
+
+                        user_interface = ManuallyDrop::new(
+                            application::build_user_interface(
+                                &application,
+                                cache,
+                                &mut renderer,
+                                state.logical_size(),
+                                &mut debug,
+                            ),
+                        );
+
+                        Some(iced_native::window::RedrawRequest::NextFrame)
+                    }
+                };
+
+                let _ = control_sender.send(if messages.is_empty() {
+                    conversion::control_flow(redraw_request)
+                } else {
+                    // Process any messages produced by the redraw as soon as
+                    // possible
+                    glutin::event_loop::ControlFlow::Poll
+                });
+
+                redraw_pending = false;
+
                 debug.draw_started();
                 let new_mouse_interaction = user_interface.draw(
                     &mut renderer,
@@ -399,8 +499,23 @@ async fn run_instance<A, E, C>(
 
                 debug.render_finished();
 
-                // TODO: Handle animations!
-                // Maybe we can use `ControlFlow::WaitUntil` for this.
+                if !screenshots.is_empty() {
+                    let screenshot = Screenshot::new(
+                        compositor.screenshot(
+                            &mut renderer,
+                            state.viewport(),
+                            state.background_color(),
+                            &debug.overlay(),
+                        ),
+                        state.physical_size(),
+                    );
+
+                    for tag in screenshots.drain(..) {
+                        proxy
+                            .send_event(tag(screenshot.clone()))
+                            .expect("Send message to event loop");
+                    }
+                }
             }
             event::Event::WindowEvent {
                 event: window_event,
@@ -415,6 +530,7 @@ async fn run_instance<A, E, C>(
                 state.update(context.window(), &window_event, &mut debug);
 
                 if let Some(event) = conversion::window_event(
+                    iced_winit::window::Id::MAIN,
                     &window_event,
                     state.scale_factor(),
                     state.modifiers(),
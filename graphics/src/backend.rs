@@ -1,4 +1,5 @@
 //! Write a graphics backend.
+use iced_native::font;
 use iced_native::image;
 use iced_native::svg;
 use iced_native::text;
@@ -34,7 +35,7 @@ pub trait Text {
     /// Returns the default size of text.
     fn default_size(&self) -> u16;
 
-    /// Measures the text contents with the given size and font,
+    /// Measures the text contents with the given size, font and wrapping,
     /// returning the size of a laid out paragraph that fits in the provided
     /// bounds.
     fn measure(
@@ -43,6 +44,7 @@ pub trait Text {
         size: f32,
         font: Font,
         bounds: Size,
+        wrapping: text::Wrapping,
     ) -> (f32, f32);
 
     /// Tests whether the provided point is within the boundaries of [`Text`]
@@ -61,6 +63,26 @@ pub trait Text {
         point: Point,
         nearest_only: bool,
     ) -> Option<text::Hit>;
+
+    /// Measures the given spans laid out as a single paragraph, returning
+    /// the size of the paragraph that fits in the provided bounds.
+    fn measure_spans(
+        &self,
+        spans: &[text::Span<'_, Font>],
+        bounds: Size,
+    ) -> (f32, f32);
+
+    /// Returns the index of the span with a glyph under the given point, if
+    /// any, when the spans are laid out as a single paragraph.
+    fn hit_span(
+        &self,
+        spans: &[text::Span<'_, Font>],
+        bounds: Size,
+        point: Point,
+    ) -> Option<usize>;
+
+    /// Loads a font from its bytes into the text pipeline.
+    fn load_font(&mut self, bytes: Vec<u8>) -> Result<(), font::Error>;
 }
 
 /// A graphics backend that supports image rendering.
@@ -20,10 +20,14 @@ pub const FALLBACK: &[u8] = include_bytes!("../fonts/Lato-Regular.ttf");
 /// A built-in icon font, for convenience.
 #[cfg(feature = "font-icons")]
 #[cfg_attr(docsrs, doc(cfg(feature = "font-icons")))]
-pub const ICONS: iced_native::Font = iced_native::Font::External {
-    name: "iced_wgpu icons",
-    bytes: include_bytes!("../fonts/Icons.ttf"),
-};
+pub const ICONS: iced_native::Font = iced_native::Font::with_name("Iced-Icons");
+
+/// The bytes of the built-in [`ICONS`] font.
+///
+/// Renderers load it on boot and resolve the [`ICONS`] font into it.
+#[cfg(feature = "font-icons")]
+#[cfg_attr(docsrs, doc(cfg(feature = "font-icons")))]
+pub const ICONS_BYTES: &[u8] = include_bytes!("../fonts/Icons.ttf");
 
 /// The `char` representing a ✔ icon in the built-in [`ICONS`] font.
 #[cfg(feature = "font-icons")]
@@ -16,10 +16,15 @@ impl Source {
 
     /// Finds and loads a font matching the set of provided family priorities.
     pub fn load(&self, families: &[Family]) -> Result<Vec<u8>, LoadError> {
-        let font = self.raw.select_best_match(
-            families,
-            &font_kit::properties::Properties::default(),
-        )?;
+        self.load_with(families, font_kit::properties::Properties::default())
+    }
+
+    fn load_with(
+        &self,
+        families: &[Family],
+        properties: font_kit::properties::Properties,
+    ) -> Result<Vec<u8>, LoadError> {
+        let font = self.raw.select_best_match(families, &properties)?;
 
         match font {
             font_kit::handle::Handle::Path { path, .. } => {
@@ -36,8 +41,91 @@ impl Source {
             }
         }
     }
+
+    /// Finds and loads the installed font that best matches the given
+    /// [`Font`](iced_native::Font).
+    pub fn load_font(
+        &self,
+        font: iced_native::Font,
+    ) -> Result<Vec<u8>, LoadError> {
+        use font_kit::properties::{Properties, Stretch, Style, Weight};
+        use iced_native::font;
+
+        let family = match font.family {
+            font::Family::Name(name) => Family::Title(String::from(name)),
+            font::Family::Serif => Family::Serif,
+            font::Family::SansSerif => Family::SansSerif,
+            font::Family::Cursive => Family::Cursive,
+            font::Family::Fantasy => Family::Fantasy,
+            font::Family::Monospace => Family::Monospace,
+        };
+
+        let weight = match font.weight {
+            font::Weight::Thin => Weight::THIN,
+            font::Weight::ExtraLight => Weight::EXTRA_LIGHT,
+            font::Weight::Light => Weight::LIGHT,
+            font::Weight::Normal => Weight::NORMAL,
+            font::Weight::Medium => Weight::MEDIUM,
+            font::Weight::Semibold => Weight::SEMIBOLD,
+            font::Weight::Bold => Weight::BOLD,
+            font::Weight::ExtraBold => Weight::EXTRA_BOLD,
+            font::Weight::Black => Weight::BLACK,
+        };
+
+        let stretch = match font.stretch {
+            font::Stretch::UltraCondensed => Stretch::ULTRA_CONDENSED,
+            font::Stretch::ExtraCondensed => Stretch::EXTRA_CONDENSED,
+            font::Stretch::Condensed => Stretch::CONDENSED,
+            font::Stretch::SemiCondensed => Stretch::SEMI_CONDENSED,
+            font::Stretch::Normal => Stretch::NORMAL,
+            font::Stretch::SemiExpanded => Stretch::SEMI_EXPANDED,
+            font::Stretch::Expanded => Stretch::EXPANDED,
+            font::Stretch::ExtraExpanded => Stretch::EXTRA_EXPANDED,
+            font::Stretch::UltraExpanded => Stretch::ULTRA_EXPANDED,
+        };
+
+        let style = match font.style {
+            font::Style::Normal => Style::Normal,
+            font::Style::Italic => Style::Italic,
+            font::Style::Oblique => Style::Oblique,
+        };
+
+        self.load_with(
+            &[family],
+            Properties {
+                weight,
+                stretch,
+                style,
+            },
+        )
+    }
+
+    /// Loads the installed fonts that cover the most common scripts missing
+    /// in Latin fonts, in order of priority.
+    pub fn load_fallbacks(&self) -> Vec<Vec<u8>> {
+        FALLBACKS
+            .iter()
+            .filter_map(|name| {
+                self.load(&[Family::Title(String::from(*name))]).ok()
+            })
+            .collect()
+    }
 }
 
+const FALLBACKS: &[&str] = &[
+    "Noto Sans",
+    "Noto Sans Arabic",
+    "Noto Sans Hebrew",
+    "Noto Sans Devanagari",
+    "Noto Sans Bengali",
+    "Noto Sans Thai",
+    "Noto Sans CJK SC",
+    "Segoe UI",
+    "Microsoft YaHei",
+    "Arial Unicode MS",
+    "DejaVu Sans",
+];
+
 impl Default for Source {
     fn default() -> Self {
         Self::new()
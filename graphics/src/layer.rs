@@ -8,13 +8,15 @@ pub mod mesh;
 pub use image::Image;
 pub use mesh::Mesh;
 pub use quad::Quad;
-pub use text::Text;
+pub use text::{RichText, Text};
 
 use crate::alignment;
 use crate::{
     Background, Font, Point, Primitive, Rectangle, Size, Vector, Viewport,
 };
 
+use iced_native::text::Wrapping;
+
 /// A group of primitives that should be clipped together.
 #[derive(Debug)]
 pub struct Layer<'a> {
@@ -30,6 +32,9 @@ pub struct Layer<'a> {
     /// The text of the [`Layer`].
     pub text: Vec<Text<'a>>,
 
+    /// The rich text of the [`Layer`].
+    pub rich_text: Vec<RichText<'a>>,
+
     /// The images of the [`Layer`].
     pub images: Vec<Image>,
 }
@@ -42,6 +47,7 @@ impl<'a> Layer<'a> {
             quads: Vec::new(),
             meshes: Vec::new(),
             text: Vec::new(),
+            rich_text: Vec::new(),
             images: Vec::new(),
         }
     }
@@ -62,9 +68,10 @@ impl<'a> Layer<'a> {
                 ),
                 color: [0.9, 0.9, 0.9, 1.0],
                 size: 20.0,
-                font: Font::Default,
+                font: Font::DEFAULT,
                 horizontal_alignment: alignment::Horizontal::Left,
                 vertical_alignment: alignment::Vertical::Top,
+                wrapping: Wrapping::None,
             };
 
             overlay.text.push(text);
@@ -129,6 +136,7 @@ impl<'a> Layer<'a> {
                 font,
                 horizontal_alignment,
                 vertical_alignment,
+                wrapping,
             } => {
                 let layer = &mut layers[current_layer];
 
@@ -140,6 +148,22 @@ impl<'a> Layer<'a> {
                     font: *font,
                     horizontal_alignment: *horizontal_alignment,
                     vertical_alignment: *vertical_alignment,
+                    wrapping: *wrapping,
+                });
+            }
+            Primitive::RichText {
+                spans,
+                bounds,
+                horizontal_alignment,
+                vertical_alignment,
+            } => {
+                let layer = &mut layers[current_layer];
+
+                layer.rich_text.push(RichText {
+                    spans,
+                    bounds: *bounds + translation,
+                    horizontal_alignment: *horizontal_alignment,
+                    vertical_alignment: *vertical_alignment,
                 });
             }
             Primitive::Quad {
@@ -1,5 +1,8 @@
+use crate::primitive::Span;
 use crate::{alignment, Font, Rectangle};
 
+use iced_native::text::Wrapping;
+
 /// A paragraph of text.
 #[derive(Debug, Clone, Copy)]
 pub struct Text<'a> {
@@ -23,4 +26,23 @@ pub struct Text<'a> {
 
     /// The vertical alignment of the [`Text`].
     pub vertical_alignment: alignment::Vertical,
+
+    /// The wrapping strategy of the [`Text`].
+    pub wrapping: Wrapping,
+}
+
+/// A paragraph of rich text.
+#[derive(Debug, Clone, Copy)]
+pub struct RichText<'a> {
+    /// The spans of the [`RichText`].
+    pub spans: &'a [Span],
+
+    /// The layout bounds of the [`RichText`].
+    pub bounds: Rectangle,
+
+    /// The horizontal alignment of the [`RichText`].
+    pub horizontal_alignment: alignment::Horizontal,
+
+    /// The vertical alignment of the [`RichText`].
+    pub vertical_alignment: alignment::Vertical,
 }
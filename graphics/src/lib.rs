@@ -23,7 +23,6 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 mod antialiasing;
 mod error;
-mod primitive;
 mod transformation;
 mod viewport;
 
@@ -33,7 +32,9 @@ pub mod gradient;
 pub mod image;
 pub mod layer;
 pub mod overlay;
+pub mod primitive;
 pub mod renderer;
+pub mod text;
 pub mod triangle;
 pub mod widget;
 pub mod window;
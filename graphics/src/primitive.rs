@@ -1,5 +1,7 @@
+//! Describe what to draw with rendering primitives.
 use iced_native::image;
 use iced_native::svg;
+use iced_native::text;
 use iced_native::{Background, Color, Font, Rectangle, Size, Vector};
 
 use crate::alignment;
@@ -34,6 +36,19 @@ pub enum Primitive {
         horizontal_alignment: alignment::Horizontal,
         /// The vertical alignment of the text
         vertical_alignment: alignment::Vertical,
+        /// The wrapping strategy of the text
+        wrapping: text::Wrapping,
+    },
+    /// A paragraph of rich text
+    RichText {
+        /// The spans of the text
+        spans: Vec<Span>,
+        /// The bounds of the text
+        bounds: Rectangle,
+        /// The horizontal alignment of the text
+        horizontal_alignment: alignment::Horizontal,
+        /// The vertical alignment of the text
+        vertical_alignment: alignment::Vertical,
     },
     /// A quad primitive
     Quad {
@@ -118,6 +133,19 @@ pub enum Primitive {
     },
 }
 
+/// A span of a [`Primitive::RichText`].
+#[derive(Debug, Clone)]
+pub struct Span {
+    /// The contents of the span
+    pub content: String,
+    /// The size of the span
+    pub size: f32,
+    /// The color of the span
+    pub color: Color,
+    /// The font of the span
+    pub font: Font,
+}
+
 impl Default for Primitive {
     fn default() -> Primitive {
         Primitive::None
@@ -1,11 +1,13 @@
 //! Create a renderer from a [`Backend`].
 use crate::backend::{self, Backend};
-use crate::{Primitive, Vector};
+use crate::primitive::{self, Primitive};
+use crate::Vector;
+use iced_native::font;
 use iced_native::image;
 use iced_native::layout;
 use iced_native::renderer;
 use iced_native::svg;
-use iced_native::text::{self, Text};
+use iced_native::text::{self, RichText, Span, Text};
 use iced_native::{Background, Color, Element, Font, Point, Rectangle, Size};
 
 pub use iced_native::renderer::Style;
@@ -140,9 +142,10 @@ where
         size: u16,
         font: Font,
         bounds: Size,
+        wrapping: text::Wrapping,
     ) -> (f32, f32) {
         self.backend()
-            .measure(content, f32::from(size), font, bounds)
+            .measure(content, f32::from(size), font, bounds, wrapping)
     }
 
     fn hit_test(
@@ -164,6 +167,23 @@ where
         )
     }
 
+    fn measure_spans(
+        &self,
+        spans: &[Span<'_, Self::Font>],
+        bounds: Size,
+    ) -> (f32, f32) {
+        self.backend().measure_spans(spans, bounds)
+    }
+
+    fn hit_span(
+        &self,
+        spans: &[Span<'_, Self::Font>],
+        bounds: Size,
+        point: Point,
+    ) -> Option<usize> {
+        self.backend().hit_span(spans, bounds, point)
+    }
+
     fn fill_text(&mut self, text: Text<'_, Self::Font>) {
         self.primitives.push(Primitive::Text {
             content: text.content.to_string(),
@@ -173,8 +193,31 @@ where
             font: text.font,
             horizontal_alignment: text.horizontal_alignment,
             vertical_alignment: text.vertical_alignment,
+            wrapping: text.wrapping,
         });
     }
+
+    fn fill_rich_text(&mut self, text: RichText<'_, '_, Self::Font>) {
+        self.primitives.push(Primitive::RichText {
+            spans: text
+                .spans
+                .iter()
+                .map(|span| primitive::Span {
+                    content: span.content.to_string(),
+                    size: span.size,
+                    color: span.color.unwrap_or(text.color),
+                    font: span.font,
+                })
+                .collect(),
+            bounds: text.bounds,
+            horizontal_alignment: text.horizontal_alignment,
+            vertical_alignment: text.vertical_alignment,
+        });
+    }
+
+    fn load_font(&mut self, bytes: Vec<u8>) -> Result<(), font::Error> {
+        self.backend.load_font(bytes)
+    }
 }
 
 impl<B, T> image::Renderer for Renderer<B, T>
@@ -0,0 +1,1115 @@
+//! Shape and lay out text with font fallback and bidirectional reordering.
+//!
+//! The [`Layout`] of this module replaces the built-in layout of
+//! [`glyph_brush`], which maps every character to a glyph of a single font
+//! and always places them from left to right. Instead, it:
+//!
+//! - resolves the embedding levels of mixed left-to-right and right-to-left
+//!   text with [`unicode_bidi`],
+//! - looks up every missing glyph in a chain of fallback fonts,
+//! - shapes every run of text with [`rustybuzz`], which joins Arabic letters,
+//!   reorders Indic clusters and applies the OpenType features of the font,
+//!   and
+//! - fits overflowing lines with the [`Wrapping`] strategy of the text.
+//!
+//! The [`Database`] of this module resolves the [`Font`](iced_native::Font)
+//! of some text into the best matching font loaded in a glyph brush.
+mod database;
+
+pub use database::Database;
+pub use glyph_brush::ab_glyph;
+
+use crate::alignment;
+
+use glyph_brush::ab_glyph::{Font, GlyphId, PxScale, Rect, ScaleFont};
+use glyph_brush::{
+    BuiltInLineBreaker, FontId, GlyphPositioner, HorizontalAlign, LineBreaker,
+    SectionGeometry, SectionGlyph, ToSectionText, VerticalAlign,
+};
+use iced_native::text::{Hit, Wrapping};
+use iced_native::{Point, Rectangle, Size};
+use unicode_bidi::{BidiClass, Level, ParagraphBidiInfo};
+use unicode_script::{Script, UnicodeScript};
+
+/// Loads the given fallback fonts, skipping the invalid ones.
+///
+/// If the `font-source` feature is enabled, the fallback fonts installed in
+/// the system are appended to the list.
+pub fn load_fallbacks(fonts: &[&'static [u8]]) -> Vec<ab_glyph::FontArc> {
+    let fonts = fonts.iter().filter_map(|bytes| {
+        ab_glyph::FontArc::try_from_slice(bytes)
+            .map_err(|_| log::warn!("Fallback font failed to load"))
+            .ok()
+    });
+
+    #[cfg(feature = "font-source")]
+    let fonts = fonts.chain(
+        crate::font::Source::new()
+            .load_fallbacks()
+            .into_iter()
+            .filter_map(|bytes| ab_glyph::FontArc::try_from_vec(bytes).ok()),
+    );
+
+    fonts.collect()
+}
+
+/// The layout of a section of text.
+///
+/// It can be used with the `_custom_layout` methods of a
+/// [`glyph_brush::GlyphBrush`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Layout {
+    horizontal_alignment: HorizontalAlign,
+    vertical_alignment: VerticalAlign,
+    fallbacks: usize,
+    wrapping: Wrapping,
+}
+
+impl Layout {
+    /// Creates a new [`Layout`] with the given alignment.
+    ///
+    /// The fonts of the glyph brush in the range `1..=fallbacks` will be
+    /// used, after the default font, for the characters that are missing in
+    /// the font of a section.
+    pub fn new(
+        horizontal_alignment: alignment::Horizontal,
+        vertical_alignment: alignment::Vertical,
+        fallbacks: usize,
+    ) -> Self {
+        Self {
+            horizontal_alignment: match horizontal_alignment {
+                alignment::Horizontal::Left => HorizontalAlign::Left,
+                alignment::Horizontal::Center => HorizontalAlign::Center,
+                alignment::Horizontal::Right => HorizontalAlign::Right,
+            },
+            vertical_alignment: match vertical_alignment {
+                alignment::Vertical::Top => VerticalAlign::Top,
+                alignment::Vertical::Center => VerticalAlign::Center,
+                alignment::Vertical::Bottom => VerticalAlign::Bottom,
+            },
+            fallbacks,
+            wrapping: Wrapping::default(),
+        }
+    }
+
+    /// Sets the [`Wrapping`] strategy of the [`Layout`].
+    pub fn wrapping(mut self, wrapping: Wrapping) -> Self {
+        self.wrapping = wrapping;
+        self
+    }
+
+    /// Returns the font of the chain starting at `font_id` that has a glyph
+    /// for the given character, if any.
+    fn find_glyph<F: Font>(
+        &self,
+        fonts: &[F],
+        font_id: FontId,
+        c: char,
+    ) -> Option<(FontId, GlyphId)> {
+        let fallbacks = (0..=self.fallbacks.min(fonts.len() - 1))
+            .map(FontId)
+            .filter(|fallback| *fallback != font_id);
+
+        std::iter::once(font_id)
+            .chain(fallbacks)
+            .find_map(|font_id| {
+                let id = fonts[font_id.0].glyph_id(c);
+
+                (id.0 != 0).then_some((font_id, id))
+            })
+    }
+}
+
+impl Default for Layout {
+    fn default() -> Self {
+        Self::new(alignment::Horizontal::Left, alignment::Vertical::Top, 0)
+    }
+}
+
+/// A character of a section of text.
+#[derive(Debug, Clone, Copy)]
+struct Character {
+    c: char,
+    section_index: usize,
+    byte_index: usize,
+    scale: PxScale,
+    font_id: FontId,
+}
+
+/// A shaped glyph, ready to be positioned.
+#[derive(Debug, Clone, Copy)]
+struct Shaped {
+    glyph: GlyphId,
+    font_id: FontId,
+    advance: f32,
+    offset: (f32, f32),
+    character: usize,
+}
+
+impl GlyphPositioner for Layout {
+    fn calculate_glyphs<F, S>(
+        &self,
+        fonts: &[F],
+        geometry: &SectionGeometry,
+        sections: &[S],
+    ) -> Vec<SectionGlyph>
+    where
+        F: Font,
+        S: ToSectionText,
+    {
+        let mut characters = Vec::new();
+
+        for (section_index, section) in sections.iter().enumerate() {
+            let section = section.to_section_text();
+
+            if section.scale.x <= 0.0 || section.scale.y <= 0.0 {
+                continue;
+            }
+
+            characters.extend(section.text.char_indices().map(
+                |(byte_index, c)| Character {
+                    c,
+                    section_index,
+                    byte_index,
+                    scale: section.scale,
+                    font_id: section.font_id,
+                },
+            ));
+        }
+
+        let mut faces = Faces::new(fonts);
+        let mut glyphs = Vec::new();
+        let mut lines = Vec::new();
+        let mut y = 0.0;
+
+        let mut start = 0;
+
+        while start < characters.len() {
+            let end = characters[start..]
+                .iter()
+                .position(|character| character.c == '\n')
+                .map_or(characters.len(), |i| start + i + 1);
+
+            for line in self.lay_out_paragraph(
+                fonts,
+                &mut faces,
+                &characters,
+                start..end,
+                geometry.bounds.0,
+            ) {
+                let start = glyphs.len();
+
+                for mut glyph in line.glyphs {
+                    glyph.glyph.position.y += y + line.ascent;
+                    glyphs.push(glyph);
+                }
+
+                lines.push((start..glyphs.len(), line.width));
+
+                y += line.height;
+            }
+
+            start = end;
+        }
+
+        let (screen_x, screen_y) = geometry.screen_position;
+
+        let shift_y = match self.vertical_alignment {
+            VerticalAlign::Top => 0.0,
+            VerticalAlign::Center => y / 2.0,
+            VerticalAlign::Bottom => y,
+        };
+
+        for (range, width) in lines {
+            let shift_x = match self.horizontal_alignment {
+                HorizontalAlign::Left => 0.0,
+                HorizontalAlign::Center => width / 2.0,
+                HorizontalAlign::Right => width,
+            };
+
+            for glyph in &mut glyphs[range] {
+                glyph.glyph.position.x += screen_x - shift_x;
+                glyph.glyph.position.y += screen_y - shift_y;
+            }
+        }
+
+        let Rect { min, max } = self.bounds_rect(geometry);
+
+        glyphs.retain(|glyph| {
+            let font = fonts[glyph.font_id.0].as_scaled(glyph.glyph.scale);
+            let position = glyph.glyph.position;
+            let height = font.height();
+
+            position.x - font.h_side_bearing(glyph.glyph.id) <= max.x
+                && position.x + font.h_advance(glyph.glyph.id) >= min.x
+                && position.y - height <= max.y
+                && position.y + height >= min.y
+        });
+
+        glyphs
+    }
+
+    fn bounds_rect(&self, geometry: &SectionGeometry) -> Rect {
+        let SectionGeometry {
+            screen_position: (x, y),
+            bounds: (width, height),
+        } = *geometry;
+
+        let (min_x, max_x) = match self.horizontal_alignment {
+            HorizontalAlign::Left => (x, x + width),
+            HorizontalAlign::Center => (x - width / 2.0, x + width / 2.0),
+            HorizontalAlign::Right => (x - width, x),
+        };
+
+        let (min_y, max_y) = match self.vertical_alignment {
+            VerticalAlign::Top => (y, y + height),
+            VerticalAlign::Center => (y - height / 2.0, y + height / 2.0),
+            VerticalAlign::Bottom => (y - height, y),
+        };
+
+        Rect {
+            min: ab_glyph::point(min_x, min_y),
+            max: ab_glyph::point(max_x, max_y),
+        }
+    }
+}
+
+/// The shaping faces of the fonts of a glyph brush, parsed on demand.
+struct Faces<'a, F> {
+    fonts: &'a [F],
+    faces: Vec<Option<Option<rustybuzz::Face<'a>>>>,
+}
+
+impl<'a, F: Font> Faces<'a, F> {
+    fn new(fonts: &'a [F]) -> Self {
+        Self {
+            fonts,
+            faces: fonts.iter().map(|_| None).collect(),
+        }
+    }
+
+    /// Returns the shaping face of the given font, if it can be parsed.
+    fn get(&mut self, font_id: FontId) -> Option<&rustybuzz::Face<'a>> {
+        let fonts = self.fonts;
+
+        self.faces[font_id.0]
+            .get_or_insert_with(|| {
+                rustybuzz::Face::from_slice(fonts[font_id.0].font_data(), 0)
+            })
+            .as_ref()
+    }
+}
+
+/// A paragraph of text, analyzed for shaping and reordering.
+struct Paragraph<'a> {
+    /// The index of the first character of the paragraph.
+    start: usize,
+    characters: &'a [Character],
+    /// The font of the chain of fallbacks used for every character.
+    fonts: Vec<FontId>,
+    /// The byte offset of every character in the text of the paragraph.
+    offsets: Vec<usize>,
+    bidi: ParagraphBidiInfo<'a>,
+}
+
+impl<'a> Paragraph<'a> {
+    /// Returns the byte offset of the given character in the paragraph.
+    fn offset(&self, character: usize) -> usize {
+        self.offsets
+            .get(character - self.start)
+            .copied()
+            .unwrap_or(self.bidi.text.len())
+    }
+
+    /// Returns the embedding level of the character at the given index of
+    /// the paragraph.
+    fn level(&self, i: usize) -> Level {
+        self.bidi.levels[self.offsets[i]]
+    }
+}
+
+/// A laid out line of text.
+struct Line {
+    glyphs: Vec<SectionGlyph>,
+    width: f32,
+    ascent: f32,
+    height: f32,
+}
+
+impl Layout {
+    /// Shapes, wraps and reorders a paragraph of text.
+    fn lay_out_paragraph<F: Font>(
+        &self,
+        fonts: &[F],
+        faces: &mut Faces<'_, F>,
+        characters: &[Character],
+        range: std::ops::Range<usize>,
+        max_width: f32,
+    ) -> Vec<Line> {
+        let first = range.start;
+        let characters = &characters[range];
+
+        let content: String = characters.iter().map(|c| c.c).collect();
+        let offsets =
+            content.char_indices().map(|(offset, _)| offset).collect();
+
+        // Marks and joiners stay in the font of their base character, so
+        // they can be shaped together
+        let mut chosen: Vec<FontId> = Vec::with_capacity(characters.len());
+
+        for (i, character) in characters.iter().enumerate() {
+            let is_extension = matches!(
+                unicode_bidi::bidi_class(character.c),
+                BidiClass::NSM | BidiClass::BN
+            );
+
+            let font_id = match chosen.last() {
+                Some(previous)
+                    if is_extension
+                        && characters[i - 1].font_id == character.font_id =>
+                {
+                    *previous
+                }
+                _ => self
+                    .find_glyph(fonts, character.font_id, character.c)
+                    .map_or(character.font_id, |(font_id, _)| font_id),
+            };
+
+            chosen.push(font_id);
+        }
+
+        let paragraph = Paragraph {
+            start: first,
+            characters,
+            fonts: chosen,
+            offsets,
+            bidi: ParagraphBidiInfo::new(&content, None),
+        };
+
+        let scripts = scripts(characters);
+
+        // Shape the paragraph in logical order, one run at a time
+        let mut shaped = Vec::new();
+        let mut start = 0;
+
+        while start < characters.len() {
+            if characters[start].c.is_control() {
+                start += 1;
+                continue;
+            }
+
+            let run = |i: usize| {
+                (
+                    paragraph.level(i),
+                    paragraph.fonts[i],
+                    characters[i].scale,
+                    scripts[i],
+                )
+            };
+
+            let end = (start + 1..characters.len())
+                .find(|&i| characters[i].c.is_control() || run(i) != run(start))
+                .unwrap_or(characters.len());
+
+            shape(fonts, faces, &paragraph, start..end, &mut shaped);
+
+            start = end;
+        }
+
+        // Find the line break opportunities of the paragraph, which are
+        // always at the start of a cluster
+        let is_cluster_start: Vec<bool> = shaped
+            .iter()
+            .enumerate()
+            .map(|(i, glyph)| {
+                i == 0 || shaped[i - 1].character != glyph.character
+            })
+            .collect();
+
+        let mut is_break: Vec<bool> = is_cluster_start
+            .iter()
+            .map(|is_start| *is_start && self.wrapping == Wrapping::Character)
+            .collect();
+
+        for line_break in
+            BuiltInLineBreaker::UnicodeLineBreaker.line_breaks(&content)
+        {
+            if let Ok(position) =
+                paragraph.offsets.binary_search(&line_break.offset())
+            {
+                if let Some(i) = shaped
+                    .iter()
+                    .position(|shaped| shaped.character >= first + position)
+                {
+                    is_break[i] = true;
+                }
+            }
+        }
+
+        if !self.wrapping.is_wrapped() {
+            let line = match self.wrapping {
+                Wrapping::Ellipsis | Wrapping::MiddleEllipsis => self.truncate(
+                    fonts,
+                    &paragraph,
+                    shaped,
+                    &is_cluster_start,
+                    max_width,
+                ),
+                _ => shaped,
+            };
+
+            return vec![self.lay_out_line(
+                fonts,
+                &paragraph,
+                &line,
+                0..characters.len(),
+            )];
+        }
+
+        // Wrap the paragraph greedily
+        let is_whitespace =
+            |glyph: &Shaped| paragraph_char(&paragraph, glyph).is_whitespace();
+
+        let mut lines = Vec::new();
+        let mut start = 0;
+        let mut width = 0.0;
+        let mut last_break = None;
+
+        for (i, glyph) in shaped.iter().enumerate() {
+            if is_break[i] && i > start {
+                last_break = Some(i);
+            }
+
+            if !is_whitespace(glyph) && width + glyph.advance > max_width {
+                if let Some(end) = last_break.take() {
+                    lines.push(start..end);
+                    start = end;
+                    width = shaped[start..i].iter().map(|g| g.advance).sum();
+                }
+            }
+
+            width += glyph.advance;
+        }
+
+        lines.push(start..shaped.len());
+
+        // Every line spans the characters up to the first glyph of the next
+        let boundary = |i: usize| {
+            shaped
+                .get(i)
+                .map_or(characters.len(), |glyph| glyph.character - first)
+        };
+
+        lines
+            .into_iter()
+            .map(|range| {
+                let start = if range.start == 0 {
+                    0
+                } else {
+                    boundary(range.start)
+                };
+
+                let characters = start..boundary(range.end);
+
+                self.lay_out_line(fonts, &paragraph, &shaped[range], characters)
+            })
+            .collect()
+    }
+
+    /// Replaces the end or the middle of an overflowing line with an
+    /// ellipsis, depending on the [`Wrapping`] of the [`Layout`].
+    fn truncate<F: Font>(
+        &self,
+        fonts: &[F],
+        paragraph: &Paragraph<'_>,
+        mut line: Vec<Shaped>,
+        is_cluster_start: &[bool],
+        max_width: f32,
+    ) -> Vec<Shaped> {
+        let width: f32 = line.iter().map(|glyph| glyph.advance).sum();
+
+        if width <= max_width || line.is_empty() {
+            return line;
+        }
+
+        let is_whitespace =
+            |glyph: &Shaped| paragraph_char(paragraph, glyph).is_whitespace();
+
+        // Only cut the line between clusters
+        let cluster_start = |mut i: usize| {
+            while i > 0 && i < line.len() && !is_cluster_start[i] {
+                i -= 1;
+            }
+
+            i
+        };
+
+        let cluster_end = |mut i: usize| {
+            while i < line.len() && !is_cluster_start[i] {
+                i += 1;
+            }
+
+            i
+        };
+
+        let ellipsis = |glyph: &Shaped| {
+            let character =
+                paragraph.characters[glyph.character - paragraph.start];
+
+            let (font_id, id) = self
+                .find_glyph(fonts, character.font_id, '…')
+                .unwrap_or((character.font_id, GlyphId(0)));
+
+            Shaped {
+                glyph: id,
+                font_id,
+                advance: fonts[font_id.0]
+                    .as_scaled(character.scale)
+                    .h_advance(id),
+                offset: (0.0, 0.0),
+                ..*glyph
+            }
+        };
+
+        match self.wrapping {
+            Wrapping::MiddleEllipsis => {
+                let middle = ellipsis(&line[line.len() / 2]);
+                let available = (max_width - middle.advance).max(0.0);
+
+                let mut head = cluster_start(fit(line.iter(), available / 2.0));
+                let head_width: f32 =
+                    line[..head].iter().map(|glyph| glyph.advance).sum();
+
+                let mut tail = cluster_end(
+                    line.len()
+                        - fit(
+                            line[head..].iter().rev(),
+                            available - head_width,
+                        ),
+                );
+
+                while head > 0 && is_whitespace(&line[head - 1]) {
+                    head -= 1;
+                }
+
+                while tail < line.len() && is_whitespace(&line[tail]) {
+                    tail += 1;
+                }
+
+                let _ = line.splice(head..tail, [middle]);
+            }
+            _ => {
+                let end = ellipsis(&line[line.len() - 1]);
+                let mut head = cluster_start(fit(
+                    line.iter(),
+                    (max_width - end.advance).max(0.0),
+                ));
+
+                while head > 0 && is_whitespace(&line[head - 1]) {
+                    head -= 1;
+                }
+
+                line.truncate(head);
+                line.push(end);
+            }
+        }
+
+        line
+    }
+
+    /// Reorders and positions the glyphs of a line, given the range of
+    /// characters of the paragraph that it spans.
+    fn lay_out_line<F: Font>(
+        &self,
+        fonts: &[F],
+        paragraph: &Paragraph<'_>,
+        line: &[Shaped],
+        characters: std::ops::Range<usize>,
+    ) -> Line {
+        let mut ascent: f32 = 0.0;
+        let mut descent: f32 = 0.0;
+        let mut line_gap: f32 = 0.0;
+
+        for i in characters.clone() {
+            let font = fonts[paragraph.fonts[i].0]
+                .as_scaled(paragraph.characters[i].scale);
+
+            ascent = ascent.max(font.ascent());
+            descent = descent.min(font.descent());
+            line_gap = line_gap.max(font.line_gap());
+        }
+
+        let mut glyphs = Vec::with_capacity(line.len());
+        let mut x = 0.0;
+        let mut width = 0.0;
+
+        let bytes = paragraph.offset(paragraph.start + characters.start)
+            ..paragraph.offset(paragraph.start + characters.end);
+
+        let (levels, runs) = if bytes.is_empty() {
+            (Vec::new(), Vec::new())
+        } else {
+            paragraph.bidi.visual_runs(bytes)
+        };
+
+        for run in runs {
+            let in_run: Vec<&Shaped> = line
+                .iter()
+                .filter(|glyph| {
+                    run.contains(&paragraph.offset(glyph.character))
+                })
+                .collect();
+
+            let in_run: Box<dyn Iterator<Item = &Shaped>> =
+                if levels[run.start].is_rtl() {
+                    Box::new(in_run.into_iter().rev())
+                } else {
+                    Box::new(in_run.into_iter())
+                };
+
+            for glyph in in_run {
+                let character =
+                    paragraph.characters[glyph.character - paragraph.start];
+                let (offset_x, offset_y) = glyph.offset;
+
+                glyphs.push(SectionGlyph {
+                    section_index: character.section_index,
+                    byte_index: character.byte_index,
+                    glyph: glyph.glyph.with_scale_and_position(
+                        character.scale,
+                        (x + offset_x, offset_y),
+                    ),
+                    font_id: glyph.font_id,
+                });
+
+                x += glyph.advance;
+
+                if !character.c.is_whitespace() {
+                    width = x;
+                }
+            }
+        }
+
+        Line {
+            glyphs,
+            width,
+            ascent,
+            height: ascent - descent + line_gap,
+        }
+    }
+}
+
+/// Returns the character of a paragraph that produced the given glyph.
+fn paragraph_char(paragraph: &Paragraph<'_>, glyph: &Shaped) -> char {
+    paragraph.characters[glyph.character - paragraph.start].c
+}
+
+/// Resolves the script of every character, merging the common and inherited
+/// characters, like spaces and marks, into the script of their neighbors.
+fn scripts(characters: &[Character]) -> Vec<Script> {
+    let is_specific = |script: &Script| {
+        !matches!(script, Script::Common | Script::Inherited | Script::Unknown)
+    };
+
+    let first = characters
+        .iter()
+        .map(|character| character.c.script())
+        .find(is_specific)
+        .unwrap_or(Script::Common);
+
+    characters
+        .iter()
+        .scan(first, |current, character| {
+            let script = character.c.script();
+
+            if is_specific(&script) {
+                *current = script;
+            }
+
+            Some(*current)
+        })
+        .collect()
+}
+
+/// Shapes a run of characters of a paragraph sharing the same level, font,
+/// scale and script, appending the glyphs in logical order.
+fn shape<F: Font>(
+    fonts: &[F],
+    faces: &mut Faces<'_, F>,
+    paragraph: &Paragraph<'_>,
+    run: std::ops::Range<usize>,
+    shaped: &mut Vec<Shaped>,
+) {
+    let font_id = paragraph.fonts[run.start];
+    let scale = paragraph.characters[run.start].scale;
+    let font = fonts[font_id.0].as_scaled(scale);
+    let is_rtl = paragraph.level(run.start).is_rtl();
+
+    let face = match faces.get(font_id) {
+        Some(face) => face,
+        None => {
+            // Fall back to a glyph per character
+            shaped.extend(run.map(|i| {
+                let glyph = font.glyph_id(paragraph.characters[i].c);
+
+                Shaped {
+                    glyph,
+                    font_id,
+                    advance: font.h_advance(glyph),
+                    offset: (0.0, 0.0),
+                    character: paragraph.start + i,
+                }
+            }));
+
+            return;
+        }
+    };
+
+    let mut buffer = rustybuzz::UnicodeBuffer::new();
+
+    for i in run {
+        buffer.add(paragraph.characters[i].c, (paragraph.start + i) as u32);
+    }
+
+    buffer.set_direction(if is_rtl {
+        rustybuzz::Direction::RightToLeft
+    } else {
+        rustybuzz::Direction::LeftToRight
+    });
+    buffer.guess_segment_properties();
+
+    let output = rustybuzz::shape(face, &[], buffer);
+    let (scale_x, scale_y) = (font.h_scale_factor(), font.v_scale_factor());
+    let start = shaped.len();
+
+    shaped.extend(
+        output
+            .glyph_infos()
+            .iter()
+            .zip(output.glyph_positions())
+            .map(|(info, position)| Shaped {
+                glyph: GlyphId(info.glyph_id as u16),
+                font_id,
+                advance: position.x_advance as f32 * scale_x,
+                offset: (
+                    position.x_offset as f32 * scale_x,
+                    -position.y_offset as f32 * scale_y,
+                ),
+                character: info.cluster as usize,
+            }),
+    );
+
+    // Right-to-left runs are shaped in visual order
+    if is_rtl {
+        shaped[start..].reverse();
+    }
+}
+
+/// Returns the amount of glyphs, taken in order, that fit in the given width.
+fn fit<'a>(glyphs: impl Iterator<Item = &'a Shaped>, max_width: f32) -> usize {
+    let mut width = 0.0;
+
+    glyphs
+        .take_while(|glyph| {
+            width += glyph.advance;
+            width <= max_width
+        })
+        .count()
+}
+
+/// Tests whether the given point is within the boundaries of the glyphs of
+/// some laid out `content`, returning information about the nearest
+/// character.
+///
+/// If `nearest_only` is true, the hit test does not consider whether the
+/// point is interior to any glyph bounds, returning only the character with
+/// the nearest centeroid.
+pub fn hit_test<'a, F: Font>(
+    fonts: &[F],
+    glyphs: impl Iterator<Item = &'a SectionGlyph> + Clone,
+    content: &str,
+    point: Point,
+    nearest_only: bool,
+) -> Option<Hit> {
+    // Implements an iterator over the glyph bounding boxes.
+    let bounds =
+        glyphs.map(|glyph| (glyph.byte_index, glyph_bounds(fonts, glyph)));
+
+    // Implements computation of the character index based on the byte index
+    // within the input string.
+    let char_index = |byte_index| {
+        let mut b_count = 0;
+        for (i, utf8_len) in content.chars().map(|c| c.len_utf8()).enumerate() {
+            if byte_index < (b_count + utf8_len) {
+                return i;
+            }
+            b_count += utf8_len;
+        }
+
+        byte_index
+    };
+
+    if !nearest_only {
+        for (idx, bounds) in bounds.clone() {
+            if bounds.contains(point) {
+                return Some(Hit::CharOffset(char_index(idx)));
+            }
+        }
+    }
+
+    let nearest = bounds
+        .map(|(index, bounds)| (index, bounds.center()))
+        .min_by(|(_, center_a), (_, center_b)| {
+            center_a
+                .distance(point)
+                .partial_cmp(&center_b.distance(point))
+                .unwrap_or(std::cmp::Ordering::Greater)
+        });
+
+    nearest.map(|(idx, center)| {
+        Hit::NearestCharOffset(char_index(idx), point - center)
+    })
+}
+
+/// Returns the index of the section with a glyph under the given point, if
+/// any.
+pub fn hit_section<'a, F: Font>(
+    fonts: &[F],
+    mut glyphs: impl Iterator<Item = &'a SectionGlyph>,
+    point: Point,
+) -> Option<usize> {
+    glyphs
+        .find(|glyph| glyph_bounds(fonts, glyph).contains(point))
+        .map(|glyph| glyph.section_index)
+}
+
+fn glyph_bounds<F: Font>(fonts: &[F], glyph: &SectionGlyph) -> Rectangle {
+    let font = fonts[glyph.font_id.0].as_scaled(glyph.glyph.scale);
+    let position = glyph.glyph.position;
+
+    Rectangle::new(
+        Point::new(
+            position.x - font.h_side_bearing(glyph.glyph.id),
+            position.y - font.ascent(),
+        ),
+        Size::new(
+            font.h_advance(glyph.glyph.id),
+            font.ascent() - font.descent(),
+        ),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use glyph_brush::ab_glyph::FontRef;
+    use glyph_brush::SectionText;
+
+    fn font() -> FontRef<'static> {
+        FontRef::try_from_slice(include_bytes!("../fonts/Lato-Regular.ttf"))
+            .unwrap()
+    }
+
+    fn lay_out(
+        text: &str,
+        wrapping: Wrapping,
+        max_width: f32,
+    ) -> Vec<SectionGlyph> {
+        Layout::default().wrapping(wrapping).calculate_glyphs(
+            &[font()],
+            &SectionGeometry {
+                screen_position: (0.0, 0.0),
+                bounds: (max_width, f32::INFINITY),
+            },
+            &[SectionText {
+                text,
+                scale: PxScale::from(20.0),
+                font_id: FontId(0),
+            }],
+        )
+    }
+
+    fn byte_indices(glyphs: &[SectionGlyph]) -> Vec<usize> {
+        glyphs.iter().map(|glyph| glyph.byte_index).collect()
+    }
+
+    fn measure(glyphs: &[SectionGlyph]) -> Size {
+        let (width, height) =
+            glyphs
+                .iter()
+                .fold((0.0f32, 0.0f32), |(width, height), glyph| {
+                    let bounds = glyph_bounds(&[font()], glyph);
+
+                    (
+                        width.max(bounds.x + bounds.width),
+                        height.max(bounds.y + bounds.height),
+                    )
+                });
+
+        Size::new(width, height)
+    }
+
+    fn hit(text: &str, glyphs: &[SectionGlyph], point: Point) -> Option<Hit> {
+        hit_test(&[font()], glyphs.iter(), text, point, false)
+    }
+
+    #[test]
+    fn reorders_right_to_left_runs() {
+        // "abc אבג"
+        let text = "abc \u{5D0}\u{5D1}\u{5D2}";
+        let glyphs = lay_out(text, Wrapping::None, f32::INFINITY);
+
+        assert_eq!(byte_indices(&glyphs), vec![0, 1, 2, 3, 8, 6, 4]);
+
+        // "אבג abc"
+        let text = "\u{5D0}\u{5D1}\u{5D2} abc";
+        let glyphs = lay_out(text, Wrapping::None, f32::INFINITY);
+
+        assert_eq!(byte_indices(&glyphs), vec![7, 8, 9, 6, 4, 2, 0]);
+    }
+
+    #[test]
+    fn measures_mixed_directions() {
+        let ltr = lay_out("abc", Wrapping::None, f32::INFINITY);
+        let rtl =
+            lay_out("\u{5D0}\u{5D1}\u{5D2}", Wrapping::None, f32::INFINITY);
+        let mixed =
+            lay_out("abc \u{5D0}\u{5D1}\u{5D2}", Wrapping::None, f32::INFINITY);
+
+        let space = font()
+            .as_scaled(PxScale::from(20.0))
+            .h_advance(font().glyph_id(' '));
+
+        let width = measure(&mixed).width;
+        let expected = measure(&ltr).width + space + measure(&rtl).width;
+
+        assert!((width - expected).abs() < 1.0, "{width} != {expected}");
+        assert_eq!(measure(&mixed).height, measure(&ltr).height);
+    }
+
+    #[test]
+    fn wraps_mixed_directions_per_line() {
+        // "abc אבג", wrapped after the space
+        let text = "abc \u{5D0}\u{5D1}\u{5D2}";
+        let width = measure(&lay_out("abc ", Wrapping::None, f32::INFINITY))
+            .width
+            + 1.0;
+
+        let glyphs = lay_out(text, Wrapping::Word, width);
+
+        assert_eq!(byte_indices(&glyphs), vec![0, 1, 2, 3, 8, 6, 4]);
+        assert!(glyphs[4].glyph.position.y > glyphs[0].glyph.position.y);
+        assert_eq!(glyphs[4].glyph.position.x, 0.0);
+    }
+
+    #[test]
+    fn hit_tests_mixed_directions() {
+        // "abc אבג"
+        let text = "abc \u{5D0}\u{5D1}\u{5D2}";
+        let glyphs = lay_out(text, Wrapping::None, f32::INFINITY);
+
+        let center =
+            |glyph: &SectionGlyph| glyph_bounds(&[font()], glyph).center();
+
+        // The leftmost glyph of the right-to-left run is its last character
+        assert_eq!(
+            hit(text, &glyphs, center(&glyphs[4])),
+            Some(Hit::CharOffset(6))
+        );
+        assert_eq!(
+            hit(text, &glyphs, center(&glyphs[6])),
+            Some(Hit::CharOffset(4))
+        );
+        assert_eq!(
+            hit(text, &glyphs, center(&glyphs[0])),
+            Some(Hit::CharOffset(0))
+        );
+    }
+
+    #[test]
+    fn keeps_indic_clusters_together() {
+        // "किकि", with a pre-base vowel sign in every cluster
+        let text = "\u{915}\u{93F}\u{915}\u{93F}";
+        let glyphs = lay_out(text, Wrapping::None, f32::INFINITY);
+
+        assert_eq!(byte_indices(&glyphs), vec![0, 0, 6, 6]);
+
+        // The clusters are hit as a whole
+        let bounds = glyph_bounds(&[font()], &glyphs[1]);
+
+        assert_eq!(
+            hit(text, &glyphs, bounds.center()),
+            Some(Hit::CharOffset(0))
+        );
+
+        // And never broken by character wrapping
+        let width = measure(&glyphs[..2]).width + 1.0;
+        let glyphs = lay_out(text, Wrapping::Character, width);
+
+        assert_eq!(byte_indices(&glyphs), vec![0, 0, 6, 6]);
+
+        assert_eq!(glyphs[0].glyph.position.y, glyphs[1].glyph.position.y);
+        assert!(glyphs[2].glyph.position.y > glyphs[1].glyph.position.y);
+        assert_eq!(glyphs[2].glyph.position.y, glyphs[3].glyph.position.y);
+    }
+
+    fn ellipsis() -> GlyphId {
+        font().glyph_id('…')
+    }
+
+    fn glyph_ids(glyphs: &[SectionGlyph]) -> Vec<GlyphId> {
+        glyphs.iter().map(|glyph| glyph.glyph.id).collect()
+    }
+
+    #[test]
+    fn truncates_the_end_with_an_ellipsis() {
+        let text = "Hello, world!";
+        let full = measure(&lay_out(text, Wrapping::None, f32::INFINITY));
+        let max_width = full.width / 2.0;
+
+        let glyphs = lay_out(text, Wrapping::Ellipsis, max_width);
+
+        assert_eq!(glyphs.last().map(|glyph| glyph.glyph.id), Some(ellipsis()));
+        assert_eq!(glyphs[0].byte_index, 0);
+        assert!(glyphs.len() < text.len());
+        assert!(measure(&glyphs).width <= max_width);
+
+        // Text that fits is left untouched
+        let glyphs = lay_out(text, Wrapping::Ellipsis, full.width * 2.0);
+
+        assert_eq!(glyphs.len(), text.len());
+        assert!(!glyph_ids(&glyphs).contains(&ellipsis()));
+    }
+
+    #[test]
+    fn truncates_the_middle_with_an_ellipsis() {
+        let text = "Hello, world!";
+        let full = measure(&lay_out(text, Wrapping::None, f32::INFINITY));
+        let max_width = full.width / 2.0;
+
+        let glyphs = lay_out(text, Wrapping::MiddleEllipsis, max_width);
+        let ids = glyph_ids(&glyphs);
+        let middle = ids.iter().position(|id| *id == ellipsis()).unwrap();
+
+        assert!(middle > 0 && middle < glyphs.len() - 1);
+        assert_eq!(glyphs[0].byte_index, 0);
+        assert_eq!(glyphs[glyphs.len() - 1].byte_index, text.len() - 1);
+        assert!(measure(&glyphs).width <= max_width);
+    }
+
+    #[test]
+    fn truncates_empty_text() {
+        assert!(lay_out("", Wrapping::Ellipsis, 0.0).is_empty());
+        assert!(lay_out("", Wrapping::MiddleEllipsis, 0.0).is_empty());
+    }
+
+    #[test]
+    fn truncates_to_an_ellipsis_narrower_than_itself() {
+        for wrapping in [Wrapping::Ellipsis, Wrapping::MiddleEllipsis] {
+            let glyphs = lay_out("Hello, world!", wrapping, 1.0);
+
+            assert_eq!(glyph_ids(&glyphs), vec![ellipsis()]);
+        }
+    }
+}
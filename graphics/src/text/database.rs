@@ -0,0 +1,410 @@
+use iced_native::font::{Family, Stretch, Style, Weight};
+use iced_native::Font;
+
+use glyph_brush::ab_glyph::{self, Font as _};
+use glyph_brush::FontId;
+use std::collections::HashMap;
+
+/// The fonts loaded in a glyph brush, which resolves every [`Font`] into the
+/// best matching one.
+#[derive(Debug, Default)]
+pub struct Database {
+    faces: Vec<Face>,
+    aliases: HashMap<Font, FontId>,
+    cache: HashMap<Font, FontId>,
+}
+
+impl Database {
+    /// Creates a new [`Database`] with the given loaded fonts, in the order
+    /// of their ids in the glyph brush.
+    ///
+    /// The first font is the one used for the [`Font::DEFAULT`].
+    pub fn new(fonts: &[ab_glyph::FontArc]) -> Self {
+        let mut database = Self::default();
+
+        for (id, font) in fonts.iter().enumerate() {
+            database.insert(FontId(id), font);
+        }
+
+        database.alias(Font::DEFAULT, FontId(0));
+        database
+    }
+
+    /// Registers a font loaded in the glyph brush with the given id, so it
+    /// can be selected by its family and properties.
+    pub fn insert(&mut self, id: FontId, font: &ab_glyph::FontArc) {
+        if let Some(face) = Face::parse(id, font.font_data()) {
+            self.faces.push(face);
+            self.cache.clear();
+        }
+    }
+
+    /// Makes the given [`Font`] always resolve into the loaded font with the
+    /// given id.
+    pub fn alias(&mut self, font: Font, id: FontId) {
+        let _ = self.aliases.insert(font, id);
+    }
+
+    /// Resolves the given [`Font`] into the id of the best matching font.
+    ///
+    /// If no loaded font matches exactly and the `font-source` feature is
+    /// enabled, the best match installed in the system is added to the glyph
+    /// brush with `load`, which must return its id.
+    ///
+    /// The default font is used when nothing matches the [`Family`].
+    pub fn resolve(
+        &mut self,
+        font: Font,
+        load: impl FnOnce(ab_glyph::FontArc) -> FontId,
+    ) -> FontId {
+        if let Some(id) =
+            self.aliases.get(&font).or_else(|| self.cache.get(&font))
+        {
+            return *id;
+        }
+
+        let id = self
+            .find(font, true)
+            .or_else(|| self.load(font, load))
+            .or_else(|| self.find(font, false))
+            .unwrap_or(FontId(0));
+
+        let _ = self.cache.insert(font, id);
+
+        id
+    }
+
+    /// Finds the loaded font that best matches the given [`Font`].
+    fn find(&self, font: Font, exact: bool) -> Option<FontId> {
+        let default_family = self.faces.first().map(|face| &face.family);
+
+        let mut candidates =
+            self.faces.iter().filter(|face| match font.family {
+                Family::Name(name) => face.family.eq_ignore_ascii_case(name),
+                Family::SansSerif => Some(&face.family) == default_family,
+                Family::Monospace => face.is_monospaced,
+                Family::Serif => face.generic == Some(Generic::Serif),
+                Family::Cursive => face.generic == Some(Generic::Cursive),
+                Family::Fantasy => face.generic == Some(Generic::Fantasy),
+            });
+
+        if exact {
+            candidates
+                .find(|face| face.distance(font) == (0, 0, 0))
+                .map(|face| face.id)
+        } else {
+            candidates
+                .min_by_key(|face| face.distance(font))
+                .map(|face| face.id)
+        }
+    }
+
+    #[cfg(feature = "font-source")]
+    fn load(
+        &mut self,
+        font: Font,
+        load: impl FnOnce(ab_glyph::FontArc) -> FontId,
+    ) -> Option<FontId> {
+        let bytes = crate::font::Source::new().load_font(font).ok()?;
+        let face = Face::parse(FontId(0), &bytes)?;
+
+        // The best match may be a font that is already loaded
+        if let Some(loaded) = self.faces.iter().find(|loaded| {
+            loaded.family == face.family
+                && loaded.weight == face.weight
+                && loaded.stretch == face.stretch
+                && loaded.style == face.style
+        }) {
+            return Some(loaded.id);
+        }
+
+        let font = ab_glyph::FontArc::try_from_vec(bytes).ok()?;
+        let id = load(font);
+
+        self.faces.push(Face { id, ..face });
+
+        Some(id)
+    }
+
+    #[cfg(not(feature = "font-source"))]
+    fn load(
+        &mut self,
+        _font: Font,
+        _load: impl FnOnce(ab_glyph::FontArc) -> FontId,
+    ) -> Option<FontId> {
+        None
+    }
+}
+
+/// The family and properties of a loaded font.
+#[derive(Debug, Clone)]
+struct Face {
+    id: FontId,
+    family: String,
+    weight: Weight,
+    stretch: Stretch,
+    style: Style,
+    generic: Option<Generic>,
+    is_monospaced: bool,
+}
+
+/// The generic family of a [`Face`], as classified by its designer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Generic {
+    Serif,
+    SansSerif,
+    Cursive,
+    Fantasy,
+}
+
+impl Generic {
+    /// Classifies a font using the PANOSE numbers and the IBM font class of
+    /// its `OS/2` table, if present.
+    fn classify(face: &ttf_parser::Face<'_>) -> Option<Self> {
+        let os2 = face
+            .raw_face()
+            .table(ttf_parser::Tag::from_bytes(b"OS/2"))?;
+
+        // The PANOSE family kind and serif style
+        let panose = match os2.get(32..34)? {
+            [2, 2..=10] => Some(Self::Serif),
+            [2, 11..=13] => Some(Self::SansSerif),
+            [3, _] => Some(Self::Cursive),
+            [4, _] => Some(Self::Fantasy),
+            _ => None,
+        };
+
+        // The class of `sFamilyClass`, which is stored in its high byte
+        let class = match os2.get(30)? {
+            1..=5 | 7 => Some(Self::Serif),
+            8 => Some(Self::SansSerif),
+            9 => Some(Self::Fantasy),
+            10 => Some(Self::Cursive),
+            _ => None,
+        };
+
+        panose.or(class)
+    }
+}
+
+impl Face {
+    fn parse(id: FontId, data: &[u8]) -> Option<Self> {
+        use ttf_parser::name_id;
+
+        let face = ttf_parser::Face::parse(data, 0).ok()?;
+
+        let name = |id| {
+            face.names()
+                .into_iter()
+                .filter(|name| name.name_id == id)
+                .find_map(|name| name.to_string())
+        };
+
+        let family = name(name_id::TYPOGRAPHIC_FAMILY)
+            .or_else(|| name(name_id::FAMILY))?;
+
+        let weight = match face.weight().to_number() {
+            0..=149 => Weight::Thin,
+            150..=249 => Weight::ExtraLight,
+            250..=349 => Weight::Light,
+            350..=449 => Weight::Normal,
+            450..=549 => Weight::Medium,
+            550..=649 => Weight::Semibold,
+            650..=749 => Weight::Bold,
+            750..=849 => Weight::ExtraBold,
+            _ => Weight::Black,
+        };
+
+        let stretch = match face.width() {
+            ttf_parser::Width::UltraCondensed => Stretch::UltraCondensed,
+            ttf_parser::Width::ExtraCondensed => Stretch::ExtraCondensed,
+            ttf_parser::Width::Condensed => Stretch::Condensed,
+            ttf_parser::Width::SemiCondensed => Stretch::SemiCondensed,
+            ttf_parser::Width::Normal => Stretch::Normal,
+            ttf_parser::Width::SemiExpanded => Stretch::SemiExpanded,
+            ttf_parser::Width::Expanded => Stretch::Expanded,
+            ttf_parser::Width::ExtraExpanded => Stretch::ExtraExpanded,
+            ttf_parser::Width::UltraExpanded => Stretch::UltraExpanded,
+        };
+
+        let style = match face.style() {
+            ttf_parser::Style::Normal => Style::Normal,
+            ttf_parser::Style::Italic => Style::Italic,
+            ttf_parser::Style::Oblique => Style::Oblique,
+        };
+
+        Some(Face {
+            id,
+            family,
+            weight,
+            stretch,
+            style,
+            generic: Generic::classify(&face),
+            is_monospaced: face.is_monospaced(),
+        })
+    }
+
+    /// Returns how far the properties of the [`Face`] are from the given
+    /// [`Font`], in order of importance: stretch, style and weight.
+    fn distance(&self, font: Font) -> (u8, u8, u8) {
+        let style = match (self.style, font.style) {
+            (a, b) if a == b => 0,
+            (Style::Italic, Style::Oblique)
+            | (Style::Oblique, Style::Italic) => 1,
+            _ => 2,
+        };
+
+        (
+            (self.stretch as i8 - font.stretch as i8).unsigned_abs(),
+            style,
+            (self.weight as i8 - font.weight as i8).unsigned_abs(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn face(id: usize, family: &str, generic: Option<Generic>) -> Face {
+        Face {
+            id: FontId(id),
+            family: family.to_owned(),
+            weight: Weight::Normal,
+            stretch: Stretch::Normal,
+            style: Style::Normal,
+            generic,
+            is_monospaced: false,
+        }
+    }
+
+    fn database_with(faces: Vec<Face>) -> Database {
+        Database {
+            faces,
+            ..Database::default()
+        }
+    }
+
+    #[test]
+    fn parses_the_properties_of_a_face() {
+        let face = Face::parse(
+            FontId(0),
+            include_bytes!("../../fonts/Lato-Regular.ttf"),
+        )
+        .unwrap();
+
+        assert_eq!(face.family, "Lato");
+        assert_eq!(face.weight, Weight::Normal);
+        assert_eq!(face.stretch, Stretch::Normal);
+        assert_eq!(face.style, Style::Normal);
+        assert_eq!(face.generic, Some(Generic::SansSerif));
+        assert!(!face.is_monospaced);
+    }
+
+    #[test]
+    fn orders_distance_by_stretch_style_and_weight() {
+        let face = face(0, "Lato", None);
+
+        assert_eq!(face.distance(Font::DEFAULT), (0, 0, 0));
+
+        let bold = Font::DEFAULT.weight(Weight::Bold);
+        let italic = Font::DEFAULT.style(Style::Italic);
+        let condensed = Font::DEFAULT.stretch(Stretch::Condensed);
+
+        assert_eq!(face.distance(bold), (0, 0, 3));
+        assert_eq!(face.distance(italic), (0, 2, 0));
+        assert_eq!(face.distance(condensed), (2, 0, 0));
+
+        assert!(face.distance(bold) < face.distance(italic));
+        assert!(face.distance(italic) < face.distance(condensed));
+
+        // Italic and oblique are interchangeable, but not exact
+        let oblique = Face {
+            style: Style::Oblique,
+            ..face
+        };
+
+        assert_eq!(oblique.distance(italic), (0, 1, 0));
+        assert!(oblique.distance(italic) < oblique.distance(Font::DEFAULT));
+    }
+
+    #[test]
+    fn finds_the_closest_face() {
+        let database = database_with(vec![
+            face(0, "Lato", None),
+            Face {
+                weight: Weight::Bold,
+                ..face(1, "Lato", None)
+            },
+            Face {
+                weight: Weight::Light,
+                style: Style::Italic,
+                ..face(2, "Lato", None)
+            },
+        ]);
+
+        let font =
+            |weight, style| Font::with_name("lato").weight(weight).style(style);
+
+        assert_eq!(
+            database.find(font(Weight::Bold, Style::Normal), true),
+            Some(FontId(1))
+        );
+        assert_eq!(
+            database.find(font(Weight::Black, Style::Normal), true),
+            None
+        );
+        assert_eq!(
+            database.find(font(Weight::Black, Style::Normal), false),
+            Some(FontId(1))
+        );
+        assert_eq!(
+            database.find(font(Weight::Bold, Style::Italic), false),
+            Some(FontId(2))
+        );
+    }
+
+    #[test]
+    fn matches_generic_families() {
+        let database = database_with(vec![
+            face(0, "Lato", Some(Generic::SansSerif)),
+            face(1, "Serif", Some(Generic::Serif)),
+            face(2, "Script", Some(Generic::Cursive)),
+            face(3, "Ornaments", Some(Generic::Fantasy)),
+            Face {
+                is_monospaced: true,
+                ..face(4, "Mono", None)
+            },
+        ]);
+
+        let family = |family| Font {
+            family,
+            ..Font::DEFAULT
+        };
+
+        assert_eq!(
+            database.find(family(Family::SansSerif), true),
+            Some(FontId(0))
+        );
+        assert_eq!(database.find(family(Family::Serif), true), Some(FontId(1)));
+        assert_eq!(
+            database.find(family(Family::Cursive), true),
+            Some(FontId(2))
+        );
+        assert_eq!(
+            database.find(family(Family::Fantasy), true),
+            Some(FontId(3))
+        );
+        assert_eq!(
+            database.find(family(Family::Monospace), true),
+            Some(FontId(4))
+        );
+        assert_eq!(database.find(family(Family::Name("Missing")), false), None);
+
+        // Generic families never match faces of another class
+        let database = database_with(vec![face(0, "Lato", None)]);
+
+        assert_eq!(database.find(family(Family::Serif), false), None);
+        assert_eq!(database.find(family(Family::Cursive), false), None);
+    }
+}
@@ -3,6 +3,7 @@ use crate::triangle;
 use crate::widget::canvas::{path, Fill, Geometry, Path, Stroke, Style, Text};
 use crate::Primitive;
 
+use iced_native::text;
 use iced_native::{Point, Rectangle, Size, Vector};
 
 use lyon::geom::euclid;
@@ -334,6 +335,7 @@ impl Frame {
             font: text.font,
             horizontal_alignment: text.horizontal_alignment,
             vertical_alignment: text.vertical_alignment,
+            wrapping: text::Wrapping::None,
         });
     }
 
@@ -1,5 +1,6 @@
 //! Draw graphics to window surfaces.
 pub mod compositor;
+pub mod offscreen;
 
 #[cfg(feature = "opengl")]
 pub mod gl_compositor;
@@ -55,6 +55,22 @@ pub trait Compositor: Sized {
         background_color: Color,
         overlay: &[T],
     ) -> Result<(), SurfaceError>;
+
+    /// Renders the [`Renderer`] primitives to an offscreen target with the
+    /// physical size of the given [`Viewport`] and reads its pixels back.
+    ///
+    /// The returned bytes are in RGBA order, row by row, starting at the top
+    /// left corner. An error is returned if the pixels cannot be read back;
+    /// for instance, when the device is lost.
+    ///
+    /// [`Renderer`]: Self::Renderer
+    fn screenshot<T: AsRef<str>>(
+        &mut self,
+        renderer: &mut Self::Renderer,
+        viewport: &Viewport,
+        background_color: Color,
+        overlay: &[T],
+    ) -> Result<Vec<u8>, SurfaceError>;
 }
 
 /// Result of an unsuccessful call to [`Compositor::present`].
@@ -68,4 +68,20 @@ pub trait GLCompositor: Sized {
         background_color: Color,
         overlay: &[T],
     );
+
+    /// Renders the primitives of the [`Renderer`] to an offscreen framebuffer
+    /// with the physical size of the given [`Viewport`] and reads its pixels
+    /// back.
+    ///
+    /// The returned bytes are in RGBA order, row by row, starting at the top
+    /// left corner.
+    ///
+    /// [`Renderer`]: crate::Renderer
+    fn screenshot<T: AsRef<str>>(
+        &mut self,
+        renderer: &mut Self::Renderer,
+        viewport: &Viewport,
+        background_color: Color,
+        overlay: &[T],
+    ) -> Vec<u8>;
 }
@@ -0,0 +1,95 @@
+//! Render user interfaces to pixels without opening a window.
+use crate::compositor::SurfaceError;
+use crate::window::Compositor;
+use crate::{Color, Point, Viewport};
+
+#[cfg(feature = "opengl")]
+use crate::window::GLCompositor;
+
+use iced_native::application;
+use iced_native::renderer;
+use iced_native::user_interface::{self, UserInterface};
+use iced_native::Element;
+
+/// Lays out and draws the given [`Element`] with the [`Compositor`], returning
+/// the RGBA pixels of the result.
+///
+/// An error is returned if the [`Compositor`] fails to read the pixels back.
+///
+/// The [`Element`] is laid out in the logical size of the [`Viewport`] and
+/// rendered in its physical size, using its scale factor. The background and
+/// text colors are taken from the default application style of the `theme`.
+pub fn render<'a, C, Message>(
+    compositor: &mut C,
+    renderer: &mut C::Renderer,
+    element: impl Into<Element<'a, Message, C::Renderer>>,
+    viewport: &Viewport,
+    theme: &<C::Renderer as iced_native::Renderer>::Theme,
+) -> Result<Vec<u8>, SurfaceError>
+where
+    C: Compositor,
+    C::Renderer: 'a,
+    <C::Renderer as iced_native::Renderer>::Theme: application::StyleSheet,
+{
+    let background_color = draw(renderer, element, viewport, theme);
+
+    compositor.screenshot(renderer, viewport, background_color, &[] as &[&str])
+}
+
+/// Lays out and draws the given [`Element`] with the [`GLCompositor`],
+/// returning the RGBA pixels of the result.
+///
+/// See [`render`] for details.
+#[cfg(feature = "opengl")]
+pub fn render_gl<'a, C, Message>(
+    compositor: &mut C,
+    renderer: &mut C::Renderer,
+    element: impl Into<Element<'a, Message, C::Renderer>>,
+    viewport: &Viewport,
+    theme: &<C::Renderer as iced_native::Renderer>::Theme,
+) -> Vec<u8>
+where
+    C: GLCompositor,
+    C::Renderer: 'a,
+    <C::Renderer as iced_native::Renderer>::Theme: application::StyleSheet,
+{
+    let background_color = draw(renderer, element, viewport, theme);
+
+    compositor.screenshot(renderer, viewport, background_color, &[] as &[&str])
+}
+
+/// Builds a [`UserInterface`] for the [`Element`] and draws it, returning the
+/// background color of the application.
+fn draw<'a, Message, Renderer>(
+    renderer: &mut Renderer,
+    element: impl Into<Element<'a, Message, Renderer>>,
+    viewport: &Viewport,
+    theme: &Renderer::Theme,
+) -> Color
+where
+    Renderer: iced_native::Renderer + 'a,
+    Renderer::Theme: application::StyleSheet,
+{
+    use application::StyleSheet;
+
+    let appearance = theme.appearance(&Default::default());
+
+    let mut user_interface = UserInterface::build(
+        element,
+        viewport.logical_size(),
+        user_interface::Cache::default(),
+        renderer,
+    );
+
+    let _ = user_interface.draw(
+        renderer,
+        theme,
+        &renderer::Style {
+            text_color: appearance.text_color,
+        },
+        // There is no cursor when rendering offscreen
+        Point::new(-1.0, -1.0),
+    );
+
+    appearance.background_color
+}
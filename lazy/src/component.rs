@@ -197,6 +197,12 @@ where
 
         local_shell.revalidate_layout(|| shell.invalidate_layout());
 
+        if let Some(redraw_request) = local_shell.redraw_request() {
+            shell.request_redraw(redraw_request);
+        }
+
+        shell.request_input_method(local_shell.input_method());
+
         if !local_messages.is_empty() {
             let mut heads = self.state.take().unwrap().into_heads();
 
@@ -268,6 +274,10 @@ where
             ) {
                 self.operation.text_input(state, id);
             }
+
+            fn bounds(&mut self, bounds: Rectangle, id: Option<&widget::Id>) {
+                self.operation.bounds(bounds, id);
+            }
         }
 
         self.with_element(|element| {
@@ -479,6 +489,12 @@ where
 
         local_shell.revalidate_layout(|| shell.invalidate_layout());
 
+        if let Some(redraw_request) = local_shell.redraw_request() {
+            shell.request_redraw(redraw_request);
+        }
+
+        shell.request_input_method(local_shell.input_method());
+
         if !local_messages.is_empty() {
             let overlay = self.overlay.take().unwrap().into_heads();
             let mut heads = overlay.instance.state.take().unwrap().into_heads();
@@ -21,10 +21,12 @@ mod lazy;
 
 pub mod component;
 pub mod responsive;
+pub mod virtual_list;
 
 pub use component::Component;
 pub use lazy::Lazy;
 pub use responsive::Responsive;
+pub use virtual_list::VirtualList;
 
 mod cache;
 
@@ -64,3 +66,18 @@ where
 {
     Responsive::new(f)
 }
+
+/// Creates a new [`VirtualList`] with the given amount of items, their
+/// [`ItemHeight`](virtual_list::ItemHeight) and a closure that produces the
+/// item with a given index.
+pub fn virtual_list<'a, Message, Renderer>(
+    count: usize,
+    item_height: virtual_list::ItemHeight,
+    view: impl Fn(usize) -> Element<'a, Message, Renderer> + 'a,
+) -> VirtualList<'a, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+    Renderer::Theme: iced_native::widget::scrollable::StyleSheet,
+{
+    VirtualList::new(count, item_height, view)
+}
@@ -0,0 +1,1005 @@
+//! Display a huge list of items by only building the visible ones.
+use iced_native::event;
+use iced_native::layout::{self, Layout};
+use iced_native::mouse;
+use iced_native::overlay;
+use iced_native::renderer;
+use iced_native::widget::operation::Operation;
+use iced_native::widget::scrollable::{self, RelativeOffset, StyleSheet};
+use iced_native::widget::tree::{self, Tree};
+use iced_native::widget::{self, Widget};
+use iced_native::{
+    Clipboard, Element, Event, Length, Point, Rectangle, Shell, Size, Vector,
+};
+
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::rc::Rc;
+
+/// A scrollable list that only builds, lays out and keeps the state of the
+/// items that are visible.
+///
+/// The items of a [`VirtualList`] are produced by a closure that takes their
+/// index, which is only called for the items in the viewport plus some
+/// overscan. Therefore, it can display lists with millions of items.
+#[allow(missing_debug_implementations)]
+pub struct VirtualList<'a, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    id: Option<scrollable::Id>,
+    count: usize,
+    item_height: ItemHeight,
+    overscan: usize,
+    width: Length,
+    height: Length,
+    scrollbar_width: u16,
+    scrollbar_margin: u16,
+    scroller_width: u16,
+    on_scroll: Option<Box<dyn Fn(RelativeOffset) -> Message + 'a>>,
+    style: <Renderer::Theme as StyleSheet>::Style,
+    view: Box<dyn Fn(usize) -> Element<'a, Message, Renderer> + 'a>,
+    content: RefCell<Content<'a, Message, Renderer>>,
+}
+
+/// The height of the items of a [`VirtualList`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ItemHeight {
+    /// Every item has exactly the given height.
+    Fixed(u16),
+
+    /// Items are measured once they are visible, while the given height is
+    /// used as an estimate for the rest.
+    Estimated(u16),
+}
+
+impl ItemHeight {
+    fn estimate(self) -> f32 {
+        match self {
+            ItemHeight::Fixed(height) | ItemHeight::Estimated(height) => {
+                f32::from(height.max(1))
+            }
+        }
+    }
+
+    fn limits(self, width: f32) -> layout::Limits {
+        match self {
+            ItemHeight::Fixed(_) => {
+                let size = Size::new(width, self.estimate());
+
+                layout::Limits::new(size, size)
+            }
+            ItemHeight::Estimated(_) => layout::Limits::new(
+                Size::new(width, 0.0),
+                Size::new(width, f32::INFINITY),
+            ),
+        }
+    }
+}
+
+impl<'a, Message, Renderer> VirtualList<'a, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`VirtualList`] with the given amount of items, their
+    /// [`ItemHeight`] and a closure that produces the item with a given
+    /// index.
+    pub fn new(
+        count: usize,
+        item_height: ItemHeight,
+        view: impl Fn(usize) -> Element<'a, Message, Renderer> + 'a,
+    ) -> Self {
+        Self {
+            id: None,
+            count,
+            item_height,
+            overscan: 3,
+            width: Length::Fill,
+            height: Length::Fill,
+            scrollbar_width: 10,
+            scrollbar_margin: 0,
+            scroller_width: 10,
+            on_scroll: None,
+            style: Default::default(),
+            view: Box::new(view),
+            content: RefCell::new(Content {
+                width: 0.0,
+                items: Vec::new(),
+                heights: Rc::new(RefCell::new(Heights::default())),
+            }),
+        }
+    }
+
+    /// Sets the [`scrollable::Id`] of the [`VirtualList`].
+    ///
+    /// It can be used to snap the [`VirtualList`] with
+    /// [`scrollable::snap_to`].
+    pub fn id(mut self, id: scrollable::Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets the amount of items outside of the viewport that are built,
+    /// above and below it.
+    pub fn overscan(mut self, overscan: usize) -> Self {
+        self.overscan = overscan;
+        self
+    }
+
+    /// Sets the width of the [`VirtualList`].
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`VirtualList`].
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the scrollbar width of the [`VirtualList`].
+    ///
+    /// It silently enforces a minimum value of 1.
+    pub fn scrollbar_width(mut self, scrollbar_width: u16) -> Self {
+        self.scrollbar_width = scrollbar_width.max(1);
+        self
+    }
+
+    /// Sets the scrollbar margin of the [`VirtualList`].
+    pub fn scrollbar_margin(mut self, scrollbar_margin: u16) -> Self {
+        self.scrollbar_margin = scrollbar_margin;
+        self
+    }
+
+    /// Sets the scroller width of the [`VirtualList`].
+    ///
+    /// It silently enforces a minimum value of 1.
+    pub fn scroller_width(mut self, scroller_width: u16) -> Self {
+        self.scroller_width = scroller_width.max(1);
+        self
+    }
+
+    /// Sets a function to call when the [`VirtualList`] is scrolled.
+    ///
+    /// The function takes the new [`RelativeOffset`] of the [`VirtualList`].
+    pub fn on_scroll(
+        mut self,
+        f: impl Fn(RelativeOffset) -> Message + 'a,
+    ) -> Self {
+        self.on_scroll = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the style of the scrollbar of the [`VirtualList`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Builds and lays out the items that are visible in the viewport with
+    /// the given vertical `offset`, keeping the [`Tree`] of each one.
+    fn update_rows(
+        &self,
+        content: &mut Content<'a, Message, Renderer>,
+        rows: &mut Vec<Row>,
+        renderer: &Renderer,
+        content_bounds: Rectangle,
+        offset: f32,
+        height: f32,
+    ) {
+        if content.width != content_bounds.width {
+            content.width = content_bounds.width;
+            content.heights.borrow_mut().set_width(content_bounds.width);
+
+            for item in &mut content.items {
+                item.node = None;
+            }
+        }
+
+        let extent = self.extent(&content.heights.borrow());
+
+        let (first, tops) =
+            place(self.count, extent, offset, height, self.overscan, |index| {
+                content
+                    .layout(index, self.item_height, renderer, &self.view)
+                    .size()
+                    .height
+            });
+
+        let mut old_rows = std::mem::take(rows);
+
+        for (index, top) in (first..).zip(tops) {
+            let tree = match old_rows.iter().position(|row| row.index == index)
+            {
+                Some(position) => old_rows.swap_remove(position).tree,
+                None => {
+                    let item = content.item(index, &self.view);
+                    item.is_diffed = true;
+
+                    Tree::new(&item.element)
+                }
+            };
+
+            let mut node = content
+                .layout(index, self.item_height, renderer, &self.view)
+                .clone();
+
+            node.move_to(Point::new(0.0, top));
+
+            rows.push(Row { index, tree, node });
+        }
+
+        content.align(rows, &self.view);
+    }
+
+    /// Returns the total height of the items, using the estimate for the
+    /// ones that have not been measured yet.
+    fn extent(&self, heights: &Heights) -> f32 {
+        match self.item_height {
+            ItemHeight::Fixed(_) => {
+                self.count as f32 * self.item_height.estimate()
+            }
+            ItemHeight::Estimated(_) => {
+                heights.extent(self.count, self.item_height.estimate())
+            }
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for VirtualList<'a, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            scrollable: scrollable::State::new(),
+            rows: RefCell::new(Vec::new()),
+            heights: Rc::clone(&self.content.borrow().heights),
+        })
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+        let mut content = self.content.borrow_mut();
+
+        // Keep the heights measured by previous instances of the list
+        state.heights.borrow_mut().truncate(self.count);
+        content.heights = Rc::clone(&state.heights);
+
+        // Forget the rows of the items that were removed
+        state.rows.get_mut().retain(|row| row.index < self.count);
+        content.items.retain(|item| item.index < self.count);
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        scrollable::layout(
+            renderer,
+            limits,
+            self.width,
+            self.height,
+            u32::MAX,
+            scrollable::Direction::Vertical,
+            |_renderer, limits| {
+                let extent =
+                    self.extent(&self.content.borrow().heights.borrow());
+
+                layout::Node::new(Size::new(limits.max().width, extent))
+            },
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+        let id = self.id.clone().map(widget::Id::from);
+
+        operation.scrollable(&mut state.scrollable, id.as_ref());
+        operation.bounds(layout.bounds(), id.as_ref());
+
+        let rows = state.rows.get_mut();
+        let mut content = self.content.borrow_mut();
+        let offset =
+            layout.children().next().unwrap().position() - Point::ORIGIN;
+
+        content.align(rows, &self.view);
+
+        operation.container(None, &mut |operation| {
+            for (row, item) in rows.iter_mut().zip(&content.items) {
+                item.element.as_widget().operate(
+                    &mut row.tree,
+                    Layout::with_offset(offset, &row.node),
+                    operation,
+                );
+            }
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let State {
+            scrollable, rows, ..
+        } = tree.state.downcast_mut::<State>();
+        let rows = rows.get_mut();
+        let mut content = self.content.borrow_mut();
+
+        let bounds = layout.bounds();
+        let content_bounds = layout.children().next().unwrap().bounds();
+        let offset = scrollable.offset(bounds, content_bounds).y;
+
+        let status = scrollable::update(
+            scrollable,
+            event,
+            layout,
+            cursor_position,
+            clipboard,
+            shell,
+            scrollable::Direction::Vertical,
+            self.scrollbar_width,
+            self.scrollbar_margin,
+            self.scroller_width,
+            &self.on_scroll,
+            |event, layout, cursor_position, clipboard, shell| {
+                self.update_rows(
+                    &mut content,
+                    rows,
+                    renderer,
+                    content_bounds,
+                    offset,
+                    bounds.height,
+                );
+
+                let offset = layout.position() - Point::ORIGIN;
+
+                rows.iter_mut()
+                    .zip(&mut content.items)
+                    .map(|(row, item)| {
+                        item.element.as_widget_mut().on_event(
+                            &mut row.tree,
+                            event.clone(),
+                            Layout::with_offset(offset, &row.node),
+                            cursor_position,
+                            renderer,
+                            clipboard,
+                            shell,
+                        )
+                    })
+                    .fold(event::Status::Ignored, event::Status::merge)
+            },
+        );
+
+        // Measuring the rows at the new offset may correct the estimated
+        // extent, which the content was laid out with
+        self.update_rows(
+            &mut content,
+            rows,
+            renderer,
+            content_bounds,
+            scrollable.offset(bounds, content_bounds).y,
+            bounds.height,
+        );
+
+        if self.extent(&content.heights.borrow()) != content_bounds.height {
+            shell.invalidate_layout();
+        }
+
+        status
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let mut rows = state.rows.borrow_mut();
+        let mut content = self.content.borrow_mut();
+
+        scrollable::draw(
+            &state.scrollable,
+            renderer,
+            theme,
+            layout,
+            cursor_position,
+            scrollable::Direction::Vertical,
+            self.scrollbar_width,
+            self.scrollbar_margin,
+            self.scroller_width,
+            &self.style,
+            |renderer, layout, cursor_position, viewport| {
+                let content_bounds = layout.bounds();
+
+                self.update_rows(
+                    &mut content,
+                    &mut rows,
+                    renderer,
+                    content_bounds,
+                    viewport.y - content_bounds.y,
+                    viewport.height,
+                );
+
+                let offset = layout.position() - Point::ORIGIN;
+
+                for (row, item) in rows.iter().zip(&content.items) {
+                    item.element.as_widget().draw(
+                        &row.tree,
+                        renderer,
+                        theme,
+                        style,
+                        Layout::with_offset(offset, &row.node),
+                        cursor_position,
+                        viewport,
+                    );
+                }
+            },
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let mut rows = state.rows.borrow_mut();
+        let mut content = self.content.borrow_mut();
+
+        scrollable::mouse_interaction(
+            &state.scrollable,
+            layout,
+            cursor_position,
+            scrollable::Direction::Vertical,
+            self.scrollbar_width,
+            self.scrollbar_margin,
+            self.scroller_width,
+            |layout, cursor_position, viewport| {
+                let content_bounds = layout.bounds();
+
+                self.update_rows(
+                    &mut content,
+                    &mut rows,
+                    renderer,
+                    content_bounds,
+                    viewport.y - content_bounds.y,
+                    viewport.height,
+                );
+
+                let offset = layout.position() - Point::ORIGIN;
+
+                rows.iter()
+                    .zip(&content.items)
+                    .map(|(row, item)| {
+                        item.element.as_widget().mouse_interaction(
+                            &row.tree,
+                            Layout::with_offset(offset, &row.node),
+                            cursor_position,
+                            viewport,
+                            renderer,
+                        )
+                    })
+                    .max()
+                    .unwrap_or_default()
+            },
+        )
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let state = tree.state.downcast_mut::<State>();
+        let rows = state.rows.get_mut();
+        let content = self.content.get_mut();
+
+        content.align(rows, &self.view);
+
+        let bounds = layout.bounds();
+        let content_layout = layout.children().next().unwrap();
+        let offset = content_layout.position() - Point::ORIGIN;
+        let scroll_offset =
+            state.scrollable.offset(bounds, content_layout.bounds());
+
+        rows.iter_mut()
+            .zip(&mut content.items)
+            .filter_map(|(row, item)| {
+                item.element.as_widget_mut().overlay(
+                    &mut row.tree,
+                    Layout::with_offset(offset, &row.node),
+                    renderer,
+                )
+            })
+            .next()
+            .map(|overlay| {
+                overlay
+                    .translate(Vector::new(-scroll_offset.x, -scroll_offset.y))
+            })
+    }
+}
+
+impl<'a, Message, Renderer> From<VirtualList<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: iced_native::Renderer + 'a,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(virtual_list: VirtualList<'a, Message, Renderer>) -> Self {
+        Self::new(virtual_list)
+    }
+}
+
+/// The local state of a [`VirtualList`].
+struct State {
+    scrollable: scrollable::State,
+    rows: RefCell<Vec<Row>>,
+    heights: Rc<RefCell<Heights>>,
+}
+
+/// The heights of the items of a [`VirtualList`] measured so far, which
+/// outlive the items themselves.
+#[derive(Debug, Default)]
+struct Heights {
+    width: f32,
+    measured: BTreeMap<usize, f32>,
+    total: f32,
+}
+
+impl Heights {
+    /// Returns the total height of `count` items, using the `estimate` for
+    /// the ones that have not been measured.
+    fn extent(&self, count: usize, estimate: f32) -> f32 {
+        let unmeasured = count.saturating_sub(self.measured.len());
+
+        self.total + unmeasured as f32 * estimate
+    }
+
+    fn insert(&mut self, index: usize, height: f32) {
+        if let Some(previous) = self.measured.insert(index, height) {
+            self.total -= previous;
+        }
+
+        self.total += height;
+    }
+
+    /// Forgets the heights of the items past the given `count`.
+    fn truncate(&mut self, count: usize) {
+        let removed = self.measured.split_off(&count);
+
+        self.total -= removed.values().sum::<f32>();
+    }
+
+    /// Forgets every height when the items are laid out with a new width.
+    fn set_width(&mut self, width: f32) {
+        if self.width != width {
+            self.width = width;
+            self.measured.clear();
+            self.total = 0.0;
+        }
+    }
+}
+
+/// A visible item of a [`VirtualList`].
+struct Row {
+    index: usize,
+    tree: Tree,
+    node: layout::Node,
+}
+
+/// The items of a [`VirtualList`] built so far.
+struct Content<'a, Message, Renderer> {
+    width: f32,
+    items: Vec<Item<'a, Message, Renderer>>,
+    heights: Rc<RefCell<Heights>>,
+}
+
+struct Item<'a, Message, Renderer> {
+    index: usize,
+    element: Element<'a, Message, Renderer>,
+    node: Option<layout::Node>,
+    is_diffed: bool,
+}
+
+impl<'a, Message, Renderer> Content<'a, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+{
+    fn item(
+        &mut self,
+        index: usize,
+        view: &dyn Fn(usize) -> Element<'a, Message, Renderer>,
+    ) -> &mut Item<'a, Message, Renderer> {
+        let position =
+            match self.items.iter().position(|item| item.index == index) {
+                Some(position) => position,
+                None => {
+                    self.items.push(Item {
+                        index,
+                        element: view(index),
+                        node: None,
+                        is_diffed: false,
+                    });
+
+                    self.items.len() - 1
+                }
+            };
+
+        &mut self.items[position]
+    }
+
+    fn layout(
+        &mut self,
+        index: usize,
+        item_height: ItemHeight,
+        renderer: &Renderer,
+        view: &dyn Fn(usize) -> Element<'a, Message, Renderer>,
+    ) -> &layout::Node {
+        let width = self.width;
+        let heights = Rc::clone(&self.heights);
+        let item = self.item(index, view);
+
+        item.node.get_or_insert_with(|| {
+            let node = item
+                .element
+                .as_widget()
+                .layout(renderer, &item_height.limits(width));
+
+            if let ItemHeight::Estimated(_) = item_height {
+                heights.borrow_mut().insert(index, node.size().height);
+            }
+
+            node
+        })
+    }
+
+    /// Makes the items match the given rows one to one, building the missing
+    /// ones and diffing their trees.
+    fn align(
+        &mut self,
+        rows: &mut [Row],
+        view: &dyn Fn(usize) -> Element<'a, Message, Renderer>,
+    ) {
+        for row in rows.iter_mut() {
+            let item = self.item(row.index, view);
+
+            if !item.is_diffed {
+                row.tree.diff(&item.element);
+                item.is_diffed = true;
+            }
+        }
+
+        self.items
+            .retain(|item| rows.iter().any(|row| row.index == item.index));
+        self.items.sort_by_key(|item| item.index);
+    }
+}
+
+/// Places the rows that are visible in a viewport with the given `offset`
+/// and `height`, returning the index of the first row and the position of
+/// each row.
+///
+/// Rows are laid out from an anchor that moves from the top to the bottom of
+/// the viewport while scrolling. This way, the first and last rows can always
+/// be reached, even when the `extent` of the rows is partially estimated.
+fn place(
+    count: usize,
+    extent: f32,
+    offset: f32,
+    height: f32,
+    overscan: usize,
+    mut row_height: impl FnMut(usize) -> f32,
+) -> (usize, Vec<f32>) {
+    if count == 0 {
+        return (0, Vec::new());
+    }
+
+    let max_offset = (extent - height).max(0.0);
+
+    let progress = if max_offset > 0.0 {
+        (offset / max_offset).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+
+    let anchor = offset + height * progress;
+    let position = if extent > 0.0 {
+        (anchor / extent * count as f32).clamp(0.0, count as f32)
+    } else {
+        0.0
+    };
+    let mut first = (position as usize).min(count - 1);
+    let mut top = anchor - row_height(first) * (position - first as f32);
+
+    fn fill_upwards(
+        first: &mut usize,
+        top: &mut f32,
+        offset: f32,
+        row_height: &mut impl FnMut(usize) -> f32,
+    ) {
+        while *first > 0 && *top > offset {
+            *first -= 1;
+            *top -= row_height(*first);
+        }
+
+        // The first row never leaves a gap at the top
+        if *first == 0 {
+            *top = top.min(offset);
+        }
+    }
+
+    fill_upwards(&mut first, &mut top, offset, &mut row_height);
+
+    let mut last = first;
+    let mut bottom = top + row_height(first);
+
+    while last + 1 < count && bottom < offset + height {
+        last += 1;
+        bottom += row_height(last);
+    }
+
+    // The last row does not leave a gap at the bottom either, unless every
+    // row fits in the viewport
+    if last + 1 == count && bottom < offset + height {
+        top += offset + height - bottom;
+
+        fill_upwards(&mut first, &mut top, offset, &mut row_height);
+    }
+
+    for _ in 0..overscan {
+        if first == 0 {
+            break;
+        }
+
+        first -= 1;
+        top -= row_height(first);
+    }
+
+    let last = (last + overscan).min(count - 1);
+
+    let tops = (first..=last)
+        .scan(top, |top, index| {
+            let current = *top;
+            *top += row_height(index);
+
+            Some(current)
+        })
+        .collect();
+
+    (first, tops)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use iced_native::clipboard;
+    use iced_native::renderer::Null;
+    use iced_native::widget::Space;
+
+    type VirtualList<'a> = super::VirtualList<'a, (), Null>;
+
+    fn virtual_list(
+        count: usize,
+        item_height: ItemHeight,
+    ) -> VirtualList<'static> {
+        VirtualList::new(count, item_height, move |index| {
+            assert!(index < count, "item {index} of {count} was built");
+
+            Space::with_height(Length::Units(10 + index as u16 % 3)).into()
+        })
+    }
+
+    fn update(
+        list: &mut VirtualList<'_>,
+        tree: &mut Tree,
+        node: &layout::Node,
+    ) -> bool {
+        let mut messages = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+
+        let _ = list.on_event(
+            tree,
+            Event::Mouse(mouse::Event::CursorMoved {
+                position: Point::ORIGIN,
+            }),
+            Layout::new(node),
+            Point::ORIGIN,
+            &Null::new(),
+            &mut clipboard::Null,
+            &mut shell,
+        );
+
+        shell.is_layout_invalid()
+    }
+
+    fn layout(list: &VirtualList<'_>) -> layout::Node {
+        Widget::layout(
+            list,
+            &Null::new(),
+            &layout::Limits::new(Size::ZERO, Size::new(100.0, 50.0)),
+        )
+    }
+
+    #[test]
+    fn forgets_the_rows_of_removed_items() {
+        let mut list = virtual_list(100, ItemHeight::Fixed(10));
+        let mut tree = Tree::new(&list as &dyn Widget<_, _>);
+        let node = layout(&list);
+
+        tree.state
+            .downcast_mut::<State>()
+            .scrollable
+            .snap_to(RelativeOffset { x: 0.0, y: 1.0 });
+
+        let _ = update(&mut list, &mut tree, &node);
+
+        assert!(tree
+            .state
+            .downcast_ref::<State>()
+            .rows
+            .borrow()
+            .iter()
+            .any(|row| row.index >= 90));
+
+        let mut list = virtual_list(10, ItemHeight::Fixed(10));
+        tree.diff(&list as &dyn Widget<_, _>);
+
+        let node = layout(&list);
+        let _ = Widget::overlay(
+            &mut list,
+            &mut tree,
+            Layout::new(&node),
+            &Null::new(),
+        );
+
+        assert!(tree
+            .state
+            .downcast_ref::<State>()
+            .rows
+            .borrow()
+            .iter()
+            .all(|row| row.index < 10));
+
+        let _ = update(&mut list, &mut tree, &node);
+    }
+
+    #[test]
+    fn invalidates_the_layout_when_measuring_rows() {
+        let mut list = virtual_list(100, ItemHeight::Estimated(10));
+        let mut tree = Tree::new(&list as &dyn Widget<_, _>);
+        let node = layout(&list);
+
+        assert!(update(&mut list, &mut tree, &node));
+
+        let node = layout(&list);
+
+        assert!(!update(&mut list, &mut tree, &node));
+    }
+
+    #[test]
+    fn place_fixed_rows() {
+        let (first, tops) = place(100, 1000.0, 55.0, 30.0, 0, |_| 10.0);
+
+        assert_eq!(first, 5);
+        assert_eq!(tops.len(), 4);
+
+        for (i, top) in tops.iter().enumerate() {
+            assert!((top - (first + i) as f32 * 10.0).abs() < 0.01);
+        }
+    }
+
+    #[test]
+    fn place_overscan() {
+        let (first, tops) = place(100, 1000.0, 0.0, 30.0, 2, |_| 10.0);
+
+        assert_eq!(first, 0);
+        assert_eq!(tops, vec![0.0, 10.0, 20.0, 30.0, 40.0]);
+    }
+
+    #[test]
+    fn place_reaches_the_end_of_taller_rows() {
+        let (first, tops) = place(100, 1000.0, 970.0, 30.0, 0, |_| 20.0);
+
+        assert_eq!(first + tops.len(), 100);
+        assert!((tops.last().unwrap() + 20.0 - 1000.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn place_rows_that_fit() {
+        let (first, tops) = place(3, 30.0, 0.0, 100.0, 0, |_| 5.0);
+
+        assert_eq!(first, 0);
+        assert_eq!(tops, vec![0.0, 5.0, 10.0]);
+    }
+
+    #[test]
+    fn extent_of_uneven_heights() {
+        let mut heights = Heights::default();
+
+        heights.insert(0, 50.0);
+        heights.insert(1, 5.0);
+        heights.insert(7, 20.0);
+
+        assert_eq!(heights.extent(10, 10.0), 75.0 + 7.0 * 10.0);
+
+        // Measuring an item again replaces its height
+        heights.insert(1, 15.0);
+
+        assert_eq!(heights.extent(10, 10.0), 85.0 + 7.0 * 10.0);
+
+        // Removed items are forgotten
+        heights.truncate(5);
+
+        assert_eq!(heights.extent(5, 10.0), 65.0 + 3.0 * 10.0);
+
+        // And so is everything when the width changes
+        heights.set_width(100.0);
+
+        assert_eq!(heights.extent(5, 10.0), 50.0);
+    }
+
+    #[test]
+    fn place_uneven_rows_within_the_extent() {
+        let row_height = |index: usize| [30.0, 10.0][index % 2];
+        let extent: f32 = (0..50).map(row_height).sum();
+
+        let (first, tops) = place(50, extent, 0.0, 100.0, 0, row_height);
+
+        assert_eq!(first, 0);
+        assert_eq!(tops[..3], [0.0, 30.0, 40.0]);
+
+        // The last row ends right at the end of the extent
+        let (first, tops) =
+            place(50, extent, extent - 100.0, 100.0, 0, row_height);
+        let last = first + tops.len() - 1;
+
+        assert_eq!(last, 49);
+        assert!(
+            (tops.last().unwrap() + row_height(last) - extent).abs() < 0.01
+        );
+    }
+}
@@ -332,6 +332,10 @@ where
             ) {
                 self.operation.text_input(state, id);
             }
+
+            fn bounds(&mut self, bounds: Rectangle, id: Option<&widget::Id>) {
+                self.operation.bounds(bounds, id);
+            }
         }
 
         self.widget
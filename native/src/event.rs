@@ -1,4 +1,5 @@
 //! Handle events of a user interface.
+use crate::input_method;
 use crate::keyboard;
 use crate::mouse;
 use crate::touch;
@@ -18,12 +19,15 @@ pub enum Event {
     /// A mouse event
     Mouse(mouse::Event),
 
-    /// A window event
-    Window(window::Event),
+    /// An event of the window with the given [`window::Id`]
+    Window(window::Id, window::Event),
 
     /// A touch event
     Touch(touch::Event),
 
+    /// An input method event
+    InputMethod(input_method::Event),
+
     /// A platform specific event
     PlatformSpecific(PlatformSpecific),
 }
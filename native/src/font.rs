@@ -0,0 +1,9 @@
+//! Load and use fonts.
+pub use iced_core::font::*;
+
+/// An error while loading a font.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    /// The provided bytes could not be parsed as a font.
+    Invalid,
+}
@@ -0,0 +1,63 @@
+//! Interact with input method editors.
+use crate::Rectangle;
+
+use std::ops::Range;
+
+/// An input method event.
+///
+/// Input method editors (IMEs) let users compose text that cannot be typed
+/// directly with their keyboard, like Japanese or Chinese characters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Event {
+    /// The input method was opened.
+    ///
+    /// [`Preedit`] and [`Commit`] events may follow.
+    ///
+    /// [`Preedit`]: Self::Preedit
+    /// [`Commit`]: Self::Commit
+    Opened,
+
+    /// The text being composed changed.
+    ///
+    /// The range is the byte-wise selection inside the composed text, if the
+    /// cursor should be shown. An empty text clears the current composition.
+    Preedit(String, Option<Range<usize>>),
+
+    /// The composed text was confirmed and should be inserted.
+    Commit(String),
+
+    /// The input method was closed.
+    ///
+    /// Any text being composed should be discarded.
+    Closed,
+}
+
+/// A request of a widget to the input method editor of a shell.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Request {
+    /// The input method is not needed.
+    #[default]
+    Disabled,
+
+    /// The input method is needed, with the text cursor at the given bounds.
+    ///
+    /// The shell may use the bounds to place the candidate window of the
+    /// input method next to the cursor.
+    Enabled {
+        /// The bounds of the text cursor, in logical coordinates.
+        cursor: Rectangle,
+    },
+}
+
+impl Request {
+    /// Merges two [`Request`] into one.
+    ///
+    /// `Enabled` takes precedence over `Disabled`, and the latest `Enabled`
+    /// request is kept.
+    pub fn merge(self, other: Self) -> Self {
+        match other {
+            Self::Enabled { .. } => other,
+            Self::Disabled => self,
+        }
+    }
+}
@@ -47,13 +47,16 @@
 pub mod clipboard;
 pub mod command;
 pub mod event;
+pub mod font;
 pub mod image;
+pub mod input_method;
 pub mod keyboard;
 pub mod layout;
 pub mod mouse;
 pub mod overlay;
 pub mod program;
 pub mod renderer;
+pub mod simulator;
 pub mod subscription;
 pub mod svg;
 pub mod system;
@@ -78,6 +81,7 @@ mod debug;
 mod debug;
 
 pub use iced_core::alignment;
+pub use iced_core::animation;
 pub use iced_core::time;
 pub use iced_core::{
     color, Alignment, Background, Color, ContentFit, Font, Length, Padding,
@@ -2,9 +2,11 @@
 mod element;
 
 pub mod menu;
+pub mod nested_menu;
 
 pub use element::Element;
 pub use menu::Menu;
+pub use nested_menu::NestedMenu;
 
 use crate::event::{self, Event};
 use crate::layout;
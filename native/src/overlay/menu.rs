@@ -15,6 +15,8 @@ use crate::{
     Shell, Size, Vector, Widget,
 };
 
+use std::ops::Range;
+
 pub use iced_style::menu::{Appearance, StyleSheet};
 
 /// A list of selectable options.
@@ -28,6 +30,7 @@ where
     options: &'a [T],
     hovered_option: &'a mut Option<usize>,
     last_selection: &'a mut Option<T>,
+    highlights: &'a [Vec<Range<usize>>],
     width: u16,
     padding: Padding,
     text_size: Option<u16>,
@@ -55,6 +58,7 @@ where
             options,
             hovered_option,
             last_selection,
+            highlights: &[],
             width: 0,
             padding: Padding::ZERO,
             text_size: None,
@@ -63,6 +67,13 @@ where
         }
     }
 
+    /// Sets the byte ranges of the text of each option that will be
+    /// highlighted, like the parts matching a search.
+    pub fn highlights(mut self, highlights: &'a [Vec<Range<usize>>]) -> Self {
+        self.highlights = highlights;
+        self
+    }
+
     /// Sets the width of the [`Menu`].
     pub fn width(mut self, width: u16) -> Self {
         self.width = width;
@@ -164,6 +175,7 @@ where
             options,
             hovered_option,
             last_selection,
+            highlights,
             width,
             padding,
             font,
@@ -175,6 +187,7 @@ where
             options,
             hovered_option,
             last_selection,
+            highlights,
             font,
             text_size,
             padding,
@@ -312,6 +325,7 @@ where
     options: &'a [T],
     hovered_option: &'a mut Option<usize>,
     last_selection: &'a mut Option<T>,
+    highlights: &'a [Vec<Range<usize>>],
     padding: Padding,
     text_size: Option<u16>,
     font: Renderer::Font,
@@ -485,24 +499,50 @@ where
                 );
             }
 
-            renderer.fill_text(Text {
-                content: &option.to_string(),
-                bounds: Rectangle {
-                    x: bounds.x + self.padding.left as f32,
-                    y: bounds.center_y(),
-                    width: f32::INFINITY,
-                    ..bounds
-                },
-                size: f32::from(text_size),
-                font: self.font.clone(),
-                color: if is_selected {
-                    appearance.selected_text_color
-                } else {
-                    appearance.text_color
-                },
-                horizontal_alignment: alignment::Horizontal::Left,
-                vertical_alignment: alignment::Vertical::Center,
-            });
+            let content = option.to_string();
+            let bounds = Rectangle {
+                x: bounds.x + self.padding.left as f32,
+                y: bounds.center_y(),
+                width: f32::INFINITY,
+                ..bounds
+            };
+            let color = if is_selected {
+                appearance.selected_text_color
+            } else {
+                appearance.text_color
+            };
+
+            match self.highlights.get(i) {
+                Some(highlights) if !highlights.is_empty() && !is_selected => {
+                    let spans = highlighted_spans(
+                        &content,
+                        highlights,
+                        appearance.highlighted_text_color,
+                        f32::from(text_size),
+                        &self.font,
+                    );
+
+                    renderer.fill_rich_text(text::RichText {
+                        spans: &spans,
+                        bounds,
+                        color,
+                        horizontal_alignment: alignment::Horizontal::Left,
+                        vertical_alignment: alignment::Vertical::Center,
+                    });
+                }
+                _ => {
+                    renderer.fill_text(Text {
+                        content: &content,
+                        bounds,
+                        size: f32::from(text_size),
+                        font: self.font.clone(),
+                        color,
+                        horizontal_alignment: alignment::Horizontal::Left,
+                        vertical_alignment: alignment::Vertical::Center,
+                        wrapping: text::Wrapping::default(),
+                    });
+                }
+            }
         }
     }
 }
@@ -519,3 +559,37 @@ where
         Element::new(list)
     }
 }
+
+/// Splits the given content into spans, coloring the given byte ranges.
+fn highlighted_spans<'a, Font: Clone>(
+    content: &'a str,
+    highlights: &[Range<usize>],
+    color: Color,
+    size: f32,
+    font: &Font,
+) -> Vec<text::Span<'a, Font>> {
+    let span = |content, color| text::Span {
+        content,
+        size,
+        color,
+        font: font.clone(),
+    };
+
+    let mut spans = Vec::new();
+    let mut start = 0;
+
+    for range in highlights {
+        match (content.get(start..range.start), content.get(range.clone())) {
+            (Some(before), Some(highlight)) => {
+                spans.push(span(before, None));
+                spans.push(span(highlight, Some(color)));
+            }
+            _ => break,
+        }
+
+        start = range.end;
+    }
+
+    spans.push(span(&content[start..], None));
+    spans
+}
@@ -0,0 +1,939 @@
+//! Build and show menus with nested submenus.
+use crate::alignment;
+use crate::event::{self, Event};
+use crate::keyboard;
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::renderer;
+use crate::text::{self, Text};
+use crate::touch;
+use crate::{Clipboard, Color, Layout, Padding, Point, Rectangle, Shell, Size};
+
+pub use iced_style::menu::{Appearance, StyleSheet};
+
+/// The symbol shown next to an item that opens a submenu.
+const SUBMENU_SYMBOL: &str = "›";
+
+/// An item of a [`NestedMenu`].
+#[derive(Debug, Clone)]
+pub struct Item<Message> {
+    label: String,
+    kind: Kind<Message>,
+    accelerator: Option<String>,
+    checked: Option<bool>,
+}
+
+#[derive(Debug, Clone)]
+enum Kind<Message> {
+    Action(Option<Message>),
+    Submenu(Vec<Item<Message>>),
+    Separator,
+}
+
+impl<Message> Item<Message> {
+    /// Creates a new [`Item`] with the given label.
+    ///
+    /// The [`Item`] is disabled until [`Item::on_press`] is called.
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            kind: Kind::Action(None),
+            accelerator: None,
+            checked: None,
+        }
+    }
+
+    /// Creates a new [`Item`] that opens a submenu with the given items.
+    ///
+    /// The [`Item`] is disabled if the submenu is empty.
+    pub fn submenu(label: impl Into<String>, items: Vec<Self>) -> Self {
+        Self {
+            label: label.into(),
+            kind: Kind::Submenu(items),
+            accelerator: None,
+            checked: None,
+        }
+    }
+
+    /// Creates a new separator [`Item`].
+    pub fn separator() -> Self {
+        Self {
+            label: String::new(),
+            kind: Kind::Separator,
+            accelerator: None,
+            checked: None,
+        }
+    }
+
+    /// Sets the message that will be produced when the [`Item`] is pressed.
+    pub fn on_press(mut self, message: Message) -> Self {
+        if let Kind::Action(on_press) = &mut self.kind {
+            *on_press = Some(message);
+        }
+
+        self
+    }
+
+    /// Sets the keyboard accelerator shown next to the label of the
+    /// [`Item`].
+    ///
+    /// The accelerator is only displayed; handling the key combination is
+    /// left to the application.
+    pub fn accelerator(mut self, accelerator: impl Into<String>) -> Self {
+        self.accelerator = Some(accelerator.into());
+        self
+    }
+
+    /// Makes the [`Item`] checkable and sets whether it is checked.
+    pub fn checked(mut self, is_checked: bool) -> Self {
+        self.checked = Some(is_checked);
+        self
+    }
+
+    /// Returns the label of the [`Item`].
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Returns the items of the submenu of the [`Item`], if it has one.
+    pub fn items(&self) -> Option<&[Self]> {
+        match &self.kind {
+            Kind::Submenu(items) => Some(items),
+            _ => None,
+        }
+    }
+
+    /// Returns the message produced when the [`Item`] is pressed, if it is
+    /// an enabled action.
+    pub fn message(&self) -> Option<&Message> {
+        match &self.kind {
+            Kind::Action(on_press) => on_press.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Returns whether the [`Item`] is enabled.
+    pub fn is_enabled(&self) -> bool {
+        match &self.kind {
+            Kind::Action(on_press) => on_press.is_some(),
+            Kind::Submenu(items) => !items.is_empty(),
+            Kind::Separator => false,
+        }
+    }
+
+    fn is_separator(&self) -> bool {
+        matches!(self.kind, Kind::Separator)
+    }
+}
+
+/// The local state of a [`NestedMenu`].
+#[derive(Debug, Clone, Default)]
+pub struct State {
+    is_open: bool,
+    path: Vec<usize>,
+    hovered: Option<usize>,
+}
+
+impl State {
+    /// Creates a new closed [`State`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns whether the menu is open.
+    pub fn is_open(&self) -> bool {
+        self.is_open
+    }
+
+    /// Opens the menu, with all of its submenus closed.
+    pub fn open(&mut self) {
+        self.is_open = true;
+        self.path.clear();
+        self.hovered = None;
+    }
+
+    /// Closes the menu.
+    pub fn close(&mut self) {
+        self.is_open = false;
+        self.path.clear();
+        self.hovered = None;
+    }
+
+    /// Returns whether the deepest open submenu is the root menu.
+    pub fn is_at_root(&self) -> bool {
+        self.path.is_empty()
+    }
+}
+
+/// A menu of [`Item`]s with nested submenus that open on hover.
+#[allow(missing_debug_implementations)]
+pub struct NestedMenu<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    state: &'a mut State,
+    items: &'a [Item<Message>],
+    width: u16,
+    padding: Padding,
+    text_size: Option<u16>,
+    font: Renderer::Font,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> NestedMenu<'a, Message, Renderer>
+where
+    Message: Clone + 'a,
+    Renderer: text::Renderer + 'a,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`NestedMenu`] with the given [`State`] and items.
+    pub fn new(state: &'a mut State, items: &'a [Item<Message>]) -> Self {
+        NestedMenu {
+            state,
+            items,
+            width: 0,
+            padding: Padding::ZERO,
+            text_size: None,
+            font: Default::default(),
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the minimum width of every panel of the [`NestedMenu`].
+    pub fn width(mut self, width: u16) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the [`Padding`] of the items of the [`NestedMenu`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`NestedMenu`].
+    pub fn text_size(mut self, text_size: u16) -> Self {
+        self.text_size = Some(text_size);
+        self
+    }
+
+    /// Sets the font of the [`NestedMenu`].
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`NestedMenu`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Turns the [`NestedMenu`] into an overlay [`Element`] at the given
+    /// target position.
+    ///
+    /// The `target_height` will be used to display the root menu either on
+    /// top of the target or under it, depending on the screen position and
+    /// the dimensions of the menu.
+    ///
+    /// [`Element`]: overlay::Element
+    pub fn overlay(
+        self,
+        position: Point,
+        target_height: f32,
+    ) -> overlay::Element<'a, Message, Renderer> {
+        overlay::Element::new(
+            position,
+            Box::new(Overlay {
+                menu: self,
+                target_height,
+            }),
+        )
+    }
+
+    fn resolved_text_size(&self, renderer: &Renderer) -> f32 {
+        f32::from(self.text_size.unwrap_or_else(|| renderer.default_size()))
+    }
+
+    fn layout_panel(
+        &self,
+        renderer: &Renderer,
+        items: &[Item<Message>],
+    ) -> layout::Node {
+        let text_size = self.resolved_text_size(renderer);
+        let item_height = text_size + f32::from(self.padding.vertical());
+        let separator_height = f32::from(self.padding.vertical()) + 1.0;
+
+        let measure = |content: &str| {
+            renderer.measure_width(content, text_size as u16, self.font.clone())
+        };
+
+        let columns = Columns::new(items, text_size);
+        let label_width = items
+            .iter()
+            .map(|item| measure(&item.label))
+            .fold(0.0, f32::max);
+        let accelerator_width = items
+            .iter()
+            .filter_map(|item| item.accelerator.as_deref())
+            .map(measure)
+            .fold(0.0, f32::max);
+
+        let width = f32::from(self.width).max(
+            f32::from(self.padding.horizontal())
+                + columns.check
+                + label_width
+                + if accelerator_width > 0.0 {
+                    text_size + accelerator_width
+                } else {
+                    0.0
+                }
+                + columns.arrow,
+        );
+
+        let mut y = 0.0;
+
+        let nodes = items
+            .iter()
+            .map(|item| {
+                let height = if item.is_separator() {
+                    separator_height
+                } else {
+                    item_height
+                };
+
+                let mut node = layout::Node::new(Size::new(width, height));
+                node.move_to(Point::new(0.0, y));
+                y += height;
+
+                node
+            })
+            .collect();
+
+        layout::Node::with_children(Size::new(width, y), nodes)
+    }
+
+    /// Returns the item at the given index of the panel at the given level,
+    /// unless the layout has gone out of sync with the open submenus.
+    fn item(&self, level: usize, index: usize) -> Option<&'a Item<Message>> {
+        let path = self.state.path.get(..level)?;
+
+        submenu(self.items, path).get(index)
+    }
+
+    fn hover(
+        &mut self,
+        level: usize,
+        index: usize,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let item = match self.item(level, index) {
+            Some(item) => item,
+            None => {
+                self.state.close();
+                shell.invalidate_layout();
+
+                return;
+            }
+        };
+
+        let previous_path = self.state.path.clone();
+        self.state.path.truncate(level);
+
+        if item.items().is_some() && item.is_enabled() {
+            self.state.path.push(index);
+            self.state.hovered = None;
+        } else if item.is_enabled() {
+            self.state.hovered = Some(index);
+        } else {
+            self.state.hovered = None;
+        }
+
+        if self.state.path != previous_path {
+            shell.invalidate_layout();
+        }
+    }
+
+    fn activate(
+        &mut self,
+        level: usize,
+        index: usize,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let item = match self.item(level, index) {
+            Some(item) => item,
+            None => {
+                self.state.close();
+                shell.invalidate_layout();
+
+                return;
+            }
+        };
+
+        if let Some(message) = item.message() {
+            shell.publish(message.clone());
+
+            self.state.close();
+            shell.invalidate_layout();
+        }
+    }
+}
+
+/// The widths of the optional columns of a panel.
+struct Columns {
+    check: f32,
+    arrow: f32,
+}
+
+impl Columns {
+    fn new<Message>(items: &[Item<Message>], text_size: f32) -> Self {
+        let has_checks = items.iter().any(|item| item.checked.is_some());
+        let has_submenus = items.iter().any(|item| item.items().is_some());
+
+        Self {
+            check: if has_checks { text_size } else { 0.0 },
+            arrow: if has_submenus { text_size } else { 0.0 },
+        }
+    }
+}
+
+/// Returns the items of the submenu at the end of the given path.
+fn submenu<'a, Message>(
+    items: &'a [Item<Message>],
+    path: &[usize],
+) -> &'a [Item<Message>] {
+    path.iter().fold(items, |items, &index| {
+        items.get(index).and_then(Item::items).unwrap_or_default()
+    })
+}
+
+/// Returns the index of the next enabled item after `current`, wrapping
+/// around the ends of the menu.
+fn step<Message>(
+    items: &[Item<Message>],
+    current: Option<usize>,
+    forward: bool,
+) -> Option<usize> {
+    let len = items.len();
+
+    (1..=len)
+        .map(|offset| match (current, forward) {
+            (Some(current), true) => (current + offset) % len,
+            (Some(current), false) => (current + len - offset % len) % len,
+            (None, true) => offset - 1,
+            (None, false) => len - offset,
+        })
+        .find(|&index| items[index].is_enabled())
+}
+
+/// Returns the position of a submenu of the given size, opened from an item
+/// with the given bounds.
+///
+/// The submenu is placed to the right of the item, unless it would overflow
+/// the viewport, in which case it is placed to the left.
+fn place_submenu(item: Rectangle, size: Size, viewport: Size) -> Point {
+    let x = if item.x + item.width + size.width <= viewport.width {
+        item.x + item.width
+    } else {
+        (item.x - size.width).max(0.0)
+    };
+
+    let y = item.y.min(viewport.height - size.height).max(0.0);
+
+    Point::new(x, y)
+}
+
+struct Overlay<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    menu: NestedMenu<'a, Message, Renderer>,
+    target_height: f32,
+}
+
+impl<'a, Message, Renderer> Overlay<'a, Message, Renderer>
+where
+    Message: Clone + 'a,
+    Renderer: text::Renderer + 'a,
+    Renderer::Theme: StyleSheet,
+{
+    /// Returns the level and index of the item under the cursor, if any.
+    fn item_at(
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Option<(usize, usize)> {
+        layout
+            .children()
+            .enumerate()
+            .filter(|(_, panel)| panel.bounds().contains(cursor_position))
+            .last()
+            .and_then(|(level, panel)| {
+                panel
+                    .children()
+                    .position(|item| item.bounds().contains(cursor_position))
+                    .map(|index| (level, index))
+            })
+    }
+}
+
+impl<'a, Message, Renderer> crate::Overlay<Message, Renderer>
+    for Overlay<'a, Message, Renderer>
+where
+    Message: Clone + 'a,
+    Renderer: text::Renderer + 'a,
+    Renderer::Theme: StyleSheet,
+{
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        bounds: Size,
+        position: Point,
+    ) -> layout::Node {
+        let mut items = self.menu.items;
+        let mut root = self.menu.layout_panel(renderer, items);
+
+        let size = root.size();
+        let space_below = bounds.height - (position.y + self.target_height);
+        let space_above = position.y;
+
+        let y = if size.height <= space_below || space_below >= space_above {
+            position.y + self.target_height
+        } else {
+            (position.y - size.height).max(0.0)
+        };
+
+        root.move_to(Point::new(
+            position.x.min(bounds.width - size.width).max(0.0),
+            y,
+        ));
+
+        let mut panels = vec![root];
+
+        for &index in &self.menu.state.path {
+            let children = match items.get(index).and_then(Item::items) {
+                Some(children) => children,
+                None => break,
+            };
+
+            let parent = panels.last().expect("Root panel");
+            let parent_position = parent.bounds().position();
+            let item = parent.children()[index].bounds();
+
+            let mut panel = self.menu.layout_panel(renderer, children);
+
+            panel.move_to(place_submenu(
+                Rectangle {
+                    x: parent_position.x + item.x,
+                    y: parent_position.y + item.y,
+                    ..item
+                },
+                panel.size(),
+                bounds,
+            ));
+
+            panels.push(panel);
+            items = children;
+        }
+
+        let (top_left, bottom_right) =
+            panels.iter().map(layout::Node::bounds).fold(
+                (
+                    Point::new(f32::INFINITY, f32::INFINITY),
+                    Point::new(f32::NEG_INFINITY, f32::NEG_INFINITY),
+                ),
+                |(top_left, bottom_right), bounds| {
+                    (
+                        Point::new(
+                            top_left.x.min(bounds.x),
+                            top_left.y.min(bounds.y),
+                        ),
+                        Point::new(
+                            bottom_right.x.max(bounds.x + bounds.width),
+                            bottom_right.y.max(bounds.y + bounds.height),
+                        ),
+                    )
+                },
+            );
+
+        for panel in &mut panels {
+            let bounds = panel.bounds();
+
+            panel.move_to(Point::new(
+                bounds.x - top_left.x,
+                bounds.y - top_left.y,
+            ));
+        }
+
+        let mut node = layout::Node::with_children(
+            Size::new(bottom_right.x - top_left.x, bottom_right.y - top_left.y),
+            panels,
+        );
+
+        node.move_to(top_left);
+
+        node
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let is_over_menu = layout
+            .children()
+            .any(|panel| panel.bounds().contains(cursor_position));
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                if let Some((level, index)) =
+                    Self::item_at(layout, cursor_position)
+                {
+                    self.menu.hover(level, index, shell);
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(_)) if is_over_menu => {
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                if is_over_menu =>
+            {
+                if let Some((level, index)) =
+                    Self::item_at(layout, cursor_position)
+                {
+                    self.menu.activate(level, index, shell);
+                }
+
+                return event::Status::Captured;
+            }
+            Event::Touch(touch::Event::FingerPressed { .. })
+                if is_over_menu =>
+            {
+                if let Some((level, index)) =
+                    Self::item_at(layout, cursor_position)
+                {
+                    self.menu.hover(level, index, shell);
+                    self.menu.activate(level, index, shell);
+                }
+
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code, ..
+            }) => {
+                let state = &mut *self.menu.state;
+                let items = submenu(self.menu.items, &state.path);
+                let hovered = state.hovered.and_then(|index| items.get(index));
+
+                match key_code {
+                    keyboard::KeyCode::Down => {
+                        state.hovered = step(items, state.hovered, true);
+                    }
+                    keyboard::KeyCode::Up => {
+                        state.hovered = step(items, state.hovered, false);
+                    }
+                    keyboard::KeyCode::Right | keyboard::KeyCode::Enter
+                        if hovered.and_then(Item::items).is_some() =>
+                    {
+                        let children = hovered.and_then(Item::items);
+
+                        state.path.extend(state.hovered);
+                        state.hovered =
+                            step(children.unwrap_or_default(), None, true);
+
+                        shell.invalidate_layout();
+                    }
+                    keyboard::KeyCode::Enter if hovered.is_some() => {
+                        let level = state.path.len();
+
+                        if let Some(index) = state.hovered {
+                            self.menu.activate(level, index, shell);
+                        }
+                    }
+                    keyboard::KeyCode::Left | keyboard::KeyCode::Escape
+                        if !state.path.is_empty() =>
+                    {
+                        state.hovered = state.path.pop();
+
+                        shell.invalidate_layout();
+                    }
+                    keyboard::KeyCode::Escape => {
+                        state.close();
+
+                        shell.invalidate_layout();
+                    }
+                    _ => return event::Status::Ignored,
+                }
+
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        match Self::item_at(layout, cursor_position) {
+            Some((level, index))
+                if self
+                    .menu
+                    .item(level, index)
+                    .filter(|item| item.is_enabled())
+                    .is_some() =>
+            {
+                mouse::Interaction::Pointer
+            }
+            _ => mouse::Interaction::default(),
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+    ) {
+        let appearance = theme.appearance(&self.menu.style);
+        let state = &self.menu.state;
+        let text_size = self.menu.resolved_text_size(renderer);
+        let padding = self.menu.padding;
+
+        for (level, panel) in layout.children().enumerate() {
+            let items = submenu(self.menu.items, &state.path[..level]);
+            let selected = state.path.get(level).copied().or(
+                if level == state.path.len() {
+                    state.hovered
+                } else {
+                    None
+                },
+            );
+            let columns = Columns::new(items, text_size);
+            let bounds = panel.bounds();
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border_color: appearance.border_color,
+                    border_width: appearance.border_width,
+                    border_radius: appearance.border_radius.into(),
+                },
+                appearance.background,
+            );
+
+            for (index, (item, layout)) in
+                items.iter().zip(panel.children()).enumerate()
+            {
+                let bounds = layout.bounds();
+
+                if item.is_separator() {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: Rectangle {
+                                x: bounds.x + f32::from(padding.left),
+                                y: bounds.center_y().floor(),
+                                width: bounds.width
+                                    - f32::from(padding.horizontal()),
+                                height: 1.0,
+                            },
+                            border_color: Color::TRANSPARENT,
+                            border_width: 0.0,
+                            border_radius: 0.0.into(),
+                        },
+                        appearance.separator_color,
+                    );
+
+                    continue;
+                }
+
+                let is_selected = selected == Some(index);
+
+                if is_selected {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds,
+                            border_color: Color::TRANSPARENT,
+                            border_width: 0.0,
+                            border_radius: appearance.border_radius.into(),
+                        },
+                        appearance.selected_background,
+                    );
+                }
+
+                let color = if !item.is_enabled() {
+                    appearance.disabled_text_color
+                } else if is_selected {
+                    appearance.selected_text_color
+                } else {
+                    appearance.text_color
+                };
+
+                let left = bounds.x + f32::from(padding.left);
+                let right = bounds.x + bounds.width - f32::from(padding.right);
+
+                if item.checked == Some(true) {
+                    renderer.fill_text(Text {
+                        content: &Renderer::CHECKMARK_ICON.to_string(),
+                        bounds: Rectangle {
+                            x: left + columns.check / 2.0,
+                            y: bounds.center_y(),
+                            width: columns.check,
+                            height: bounds.height,
+                        },
+                        size: text_size * 0.7,
+                        font: Renderer::ICON_FONT,
+                        color,
+                        horizontal_alignment: alignment::Horizontal::Center,
+                        vertical_alignment: alignment::Vertical::Center,
+                        wrapping: text::Wrapping::default(),
+                    });
+                }
+
+                renderer.fill_text(Text {
+                    content: &item.label,
+                    bounds: Rectangle {
+                        x: left + columns.check,
+                        y: bounds.center_y(),
+                        width: f32::INFINITY,
+                        ..bounds
+                    },
+                    size: text_size,
+                    font: self.menu.font.clone(),
+                    color,
+                    horizontal_alignment: alignment::Horizontal::Left,
+                    vertical_alignment: alignment::Vertical::Center,
+                    wrapping: text::Wrapping::default(),
+                });
+
+                if let Some(accelerator) = &item.accelerator {
+                    renderer.fill_text(Text {
+                        content: accelerator,
+                        bounds: Rectangle {
+                            x: right - columns.arrow,
+                            y: bounds.center_y(),
+                            width: f32::INFINITY,
+                            ..bounds
+                        },
+                        size: text_size,
+                        font: self.menu.font.clone(),
+                        color: if is_selected {
+                            appearance.selected_text_color
+                        } else {
+                            appearance.accelerator_text_color
+                        },
+                        horizontal_alignment: alignment::Horizontal::Right,
+                        vertical_alignment: alignment::Vertical::Center,
+                        wrapping: text::Wrapping::default(),
+                    });
+                }
+
+                if item.items().is_some() {
+                    renderer.fill_text(Text {
+                        content: SUBMENU_SYMBOL,
+                        bounds: Rectangle {
+                            x: right - columns.arrow / 2.0,
+                            y: bounds.center_y(),
+                            width: columns.arrow,
+                            ..bounds
+                        },
+                        size: text_size,
+                        font: self.menu.font.clone(),
+                        color,
+                        horizontal_alignment: alignment::Horizontal::Center,
+                        vertical_alignment: alignment::Vertical::Center,
+                        wrapping: text::Wrapping::default(),
+                    });
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::renderer::Null;
+
+    #[test]
+    fn step_skips_disabled_items_and_wraps() {
+        let items: Vec<Item<()>> = vec![
+            Item::new("Undo").on_press(()),
+            Item::separator(),
+            Item::new("Cut"),
+            Item::new("Copy").on_press(()),
+        ];
+
+        assert_eq!(step(&items, None, true), Some(0));
+        assert_eq!(step(&items, None, false), Some(3));
+        assert_eq!(step(&items, Some(0), true), Some(3));
+        assert_eq!(step(&items, Some(3), true), Some(0));
+        assert_eq!(step(&items, Some(0), false), Some(3));
+        assert_eq!(step(&items[1..3], None, true), None);
+    }
+
+    #[test]
+    fn submenu_flips_when_overflowing() {
+        let viewport = Size::new(400.0, 300.0);
+        let size = Size::new(100.0, 120.0);
+
+        let item =
+            Rectangle::new(Point::new(100.0, 20.0), Size::new(150.0, 20.0));
+        assert_eq!(
+            place_submenu(item, size, viewport),
+            Point::new(250.0, 20.0)
+        );
+
+        let item =
+            Rectangle::new(Point::new(200.0, 250.0), Size::new(150.0, 20.0));
+        assert_eq!(
+            place_submenu(item, size, viewport),
+            Point::new(100.0, 180.0)
+        );
+    }
+
+    #[test]
+    fn out_of_sync_items_close_the_menu() {
+        let items: Vec<Item<()>> = vec![
+            Item::new("Undo").on_press(()),
+            Item::submenu("Edit", vec![Item::new("Cut").on_press(())]),
+        ];
+
+        let mut state = State::new();
+        state.open();
+
+        let mut messages = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+
+        NestedMenu::<(), Null>::new(&mut state, &items).hover(0, 5, &mut shell);
+
+        assert!(!state.is_open());
+        assert!(shell.is_layout_invalid());
+
+        state.open();
+
+        let mut menu = NestedMenu::<(), Null>::new(&mut state, &items);
+        menu.hover(0, 1, &mut shell);
+        menu.activate(2, 0, &mut shell);
+
+        assert!(!state.is_open());
+        assert!(messages.is_empty());
+    }
+}
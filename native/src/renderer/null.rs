@@ -1,6 +1,7 @@
+use crate::font::{self, Font};
 use crate::renderer::{self, Renderer};
-use crate::text::{self, Text};
-use crate::{Background, Font, Point, Rectangle, Size, Theme, Vector};
+use crate::text::{self, RichText, Span, Text};
+use crate::{Background, Point, Rectangle, Size, Theme, Vector};
 
 /// A renderer that does nothing.
 ///
@@ -40,7 +41,7 @@ impl Renderer for Null {
 impl text::Renderer for Null {
     type Font = Font;
 
-    const ICON_FONT: Font = Font::Default;
+    const ICON_FONT: Font = Font::DEFAULT;
     const CHECKMARK_ICON: char = '0';
     const ARROW_DOWN_ICON: char = '0';
 
@@ -54,6 +55,7 @@ impl text::Renderer for Null {
         _size: u16,
         _font: Font,
         _bounds: Size,
+        _wrapping: text::Wrapping,
     ) -> (f32, f32) {
         (0.0, 20.0)
     }
@@ -70,5 +72,28 @@ impl text::Renderer for Null {
         None
     }
 
+    fn measure_spans(
+        &self,
+        _spans: &[Span<'_, Self::Font>],
+        _bounds: Size,
+    ) -> (f32, f32) {
+        (0.0, 20.0)
+    }
+
+    fn hit_span(
+        &self,
+        _spans: &[Span<'_, Self::Font>],
+        _bounds: Size,
+        _point: Point,
+    ) -> Option<usize> {
+        None
+    }
+
     fn fill_text(&mut self, _text: Text<'_, Self::Font>) {}
+
+    fn fill_rich_text(&mut self, _text: RichText<'_, '_, Self::Font>) {}
+
+    fn load_font(&mut self, _bytes: Vec<u8>) -> Result<(), font::Error> {
+        Ok(())
+    }
 }
@@ -1,3 +1,6 @@
+use crate::input_method;
+use crate::window;
+
 /// A connection to the state of a shell.
 ///
 /// A [`Widget`] can leverage a [`Shell`] to trigger changes in an application,
@@ -7,6 +10,8 @@
 #[derive(Debug)]
 pub struct Shell<'a, Message> {
     messages: &'a mut Vec<Message>,
+    redraw_request: Option<window::RedrawRequest>,
+    input_method: input_method::Request,
     is_layout_invalid: bool,
     are_widgets_invalid: bool,
 }
@@ -16,6 +21,8 @@ impl<'a, Message> Shell<'a, Message> {
     pub fn new(messages: &'a mut Vec<Message>) -> Self {
         Self {
             messages,
+            redraw_request: None,
+            input_method: input_method::Request::Disabled,
             is_layout_invalid: false,
             are_widgets_invalid: false,
         }
@@ -41,6 +48,37 @@ impl<'a, Message> Shell<'a, Message> {
         self.messages.push(message);
     }
 
+    /// Requests a new frame to be drawn at the given [`window::RedrawRequest`].
+    ///
+    /// If multiple redraws are requested, the earliest one is kept.
+    pub fn request_redraw(&mut self, request: window::RedrawRequest) {
+        self.redraw_request = Some(match self.redraw_request {
+            Some(current) => current.min(request),
+            None => request,
+        });
+    }
+
+    /// Returns the earliest [`window::RedrawRequest`] of the [`Shell`], if
+    /// any.
+    pub fn redraw_request(&self) -> Option<window::RedrawRequest> {
+        self.redraw_request
+    }
+
+    /// Requests the given [`input_method::Request`] to the input method editor
+    /// of the shell.
+    ///
+    /// Widgets that accept text input should request it while focused every
+    /// time they process a [`window::Event::RedrawRequested`]; the input
+    /// method is disabled otherwise.
+    pub fn request_input_method(&mut self, request: input_method::Request) {
+        self.input_method = self.input_method.merge(request);
+    }
+
+    /// Returns the current [`input_method::Request`] of the [`Shell`].
+    pub fn input_method(&self) -> input_method::Request {
+        self.input_method
+    }
+
     /// Invalidates the current application layout.
     ///
     /// The shell will relayout the application widgets.
@@ -62,6 +100,12 @@ impl<'a, Message> Shell<'a, Message> {
     pub fn merge<B>(&mut self, other: Shell<'_, B>, f: impl Fn(B) -> Message) {
         self.messages.extend(other.messages.drain(..).map(f));
 
+        if let Some(request) = other.redraw_request {
+            self.request_redraw(request);
+        }
+
+        self.request_input_method(other.input_method);
+
         self.is_layout_invalid =
             self.is_layout_invalid || other.is_layout_invalid;
 
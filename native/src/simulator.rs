@@ -0,0 +1,547 @@
+//! Simulate user interactions with a [`Program`] without a window or GPU.
+//!
+//! A [`Simulator`] feeds synthetic events to the widgets of a [`Program`] and
+//! keeps track of the messages they produce. It is mostly useful to write
+//! integration tests for widgets and applications.
+use crate::application;
+use crate::command;
+use crate::event::{self, Event};
+use crate::keyboard;
+use crate::mouse;
+use crate::user_interface::{self, UserInterface};
+use crate::widget::operation::{self, Operation};
+use crate::widget::Id;
+use crate::{Clipboard, Point, Program, Rectangle, Size};
+
+/// A headless runner that simulates user interactions with a [`Program`].
+///
+/// Every message produced by the widgets is recorded and then fed to
+/// [`Program::update`]. Widget operations returned as a [`Command`] are run
+/// immediately, while any other actions are discarded.
+///
+/// [`Command`]: crate::Command
+#[allow(missing_debug_implementations)]
+pub struct Simulator<P: Program> {
+    program: P,
+    renderer: P::Renderer,
+    size: Size,
+    cache: user_interface::Cache,
+    cursor_position: Point,
+    modifiers: keyboard::Modifiers,
+    clipboard: Memory,
+    messages: Vec<P::Message>,
+}
+
+impl<P> Simulator<P>
+where
+    P: Program,
+    P::Message: Clone,
+    <P::Renderer as crate::Renderer>::Theme: application::StyleSheet,
+{
+    /// Creates a new [`Simulator`] for the given [`Program`] with the given
+    /// logical size, using a default renderer.
+    ///
+    /// The default renderer of [`Program`]s that use the
+    /// [`Null`](crate::renderer::Null) renderer does not draw anything.
+    pub fn new(program: P, size: Size) -> Self
+    where
+        P::Renderer: Default,
+    {
+        Self::with_renderer(program, size, P::Renderer::default())
+    }
+
+    /// Creates a new [`Simulator`] for the given [`Program`] with the given
+    /// logical size and renderer.
+    pub fn with_renderer(
+        program: P,
+        size: Size,
+        renderer: P::Renderer,
+    ) -> Self {
+        Self {
+            program,
+            renderer,
+            size,
+            cache: user_interface::Cache::default(),
+            cursor_position: Point::new(-1.0, -1.0),
+            modifiers: keyboard::Modifiers::default(),
+            clipboard: Memory::default(),
+            messages: Vec::new(),
+        }
+    }
+
+    /// Returns a reference to the [`Program`] of the [`Simulator`].
+    pub fn program(&self) -> &P {
+        &self.program
+    }
+
+    /// Returns all the messages produced so far, in order.
+    pub fn messages(&self) -> &[P::Message] {
+        &self.messages
+    }
+
+    /// Clears the messages produced so far.
+    pub fn clear_messages(&mut self) {
+        self.messages.clear();
+    }
+
+    /// Returns the current position of the simulated mouse cursor.
+    pub fn cursor_position(&self) -> Point {
+        self.cursor_position
+    }
+
+    /// Returns the contents of the simulated clipboard.
+    pub fn clipboard(&self) -> Option<&str> {
+        self.clipboard.0.as_deref()
+    }
+
+    /// Sets the contents of the simulated clipboard.
+    pub fn set_clipboard(&mut self, contents: impl Into<String>) {
+        self.clipboard.0 = Some(contents.into());
+    }
+
+    /// Processes the given events in order, returning whether each of them was
+    /// captured by a widget.
+    ///
+    /// Cursor movements and changes of modifiers are tracked for subsequent
+    /// events.
+    pub fn simulate(
+        &mut self,
+        events: impl IntoIterator<Item = Event>,
+    ) -> Vec<event::Status> {
+        events
+            .into_iter()
+            .map(|event| {
+                match event {
+                    Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                        self.cursor_position = position;
+                    }
+                    Event::Mouse(mouse::Event::CursorLeft) => {
+                        self.cursor_position = Point::new(-1.0, -1.0);
+                    }
+                    Event::Keyboard(keyboard::Event::ModifiersChanged(
+                        modifiers,
+                    )) => {
+                        self.modifiers = modifiers;
+                    }
+                    _ => {}
+                }
+
+                let mut messages = Vec::new();
+
+                let mut user_interface = UserInterface::build(
+                    self.program.view(),
+                    self.size,
+                    std::mem::take(&mut self.cache),
+                    &mut self.renderer,
+                );
+
+                let (_, statuses) = user_interface.update(
+                    &[event],
+                    self.cursor_position,
+                    &mut self.renderer,
+                    &mut self.clipboard,
+                    &mut messages,
+                );
+
+                self.cache = user_interface.into_cache();
+                self.update(messages);
+
+                statuses
+                    .into_iter()
+                    .next()
+                    .unwrap_or(event::Status::Ignored)
+            })
+            .collect()
+    }
+
+    /// Moves the simulated mouse cursor to the given position.
+    pub fn move_cursor(&mut self, position: impl Into<Point>) -> event::Status {
+        let position = position.into();
+
+        self.simulate_one(Event::Mouse(mouse::Event::CursorMoved { position }))
+    }
+
+    /// Clicks the left mouse button at the given position.
+    pub fn click(&mut self, position: impl Into<Point>) -> event::Status {
+        let position = position.into();
+
+        self.simulate([
+            Event::Mouse(mouse::Event::CursorMoved { position }),
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+        ])
+        .into_iter()
+        .skip(1)
+        .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    /// Clicks the left mouse button at the center of the widget with the given
+    /// [`Id`].
+    ///
+    /// Returns `None` if no widget with the given [`Id`] reports its bounds.
+    pub fn click_on(&mut self, id: impl Into<Id>) -> Option<event::Status> {
+        let bounds = self.find(id)?;
+
+        Some(self.click(bounds.center()))
+    }
+
+    /// Scrolls the mouse wheel at the given position.
+    pub fn scroll(
+        &mut self,
+        position: impl Into<Point>,
+        delta: mouse::ScrollDelta,
+    ) -> event::Status {
+        let position = position.into();
+
+        self.simulate([
+            Event::Mouse(mouse::Event::CursorMoved { position }),
+            Event::Mouse(mouse::Event::WheelScrolled { delta }),
+        ])
+        .into_iter()
+        .last()
+        .unwrap_or(event::Status::Ignored)
+    }
+
+    /// Scrolls the mouse wheel at the center of the widget with the given
+    /// [`Id`].
+    ///
+    /// Returns `None` if no widget with the given [`Id`] reports its bounds.
+    pub fn scroll_on(
+        &mut self,
+        id: impl Into<Id>,
+        delta: mouse::ScrollDelta,
+    ) -> Option<event::Status> {
+        let bounds = self.find(id)?;
+
+        Some(self.scroll(bounds.center(), delta))
+    }
+
+    /// Changes the keyboard modifiers held down.
+    pub fn set_modifiers(
+        &mut self,
+        modifiers: keyboard::Modifiers,
+    ) -> event::Status {
+        self.simulate_one(Event::Keyboard(keyboard::Event::ModifiersChanged(
+            modifiers,
+        )))
+    }
+
+    /// Presses and releases the given key with the current modifiers.
+    pub fn tap_key(&mut self, key_code: keyboard::KeyCode) -> event::Status {
+        let modifiers = self.modifiers;
+
+        self.simulate([
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code,
+                modifiers,
+            }),
+            Event::Keyboard(keyboard::Event::KeyReleased {
+                key_code,
+                modifiers,
+            }),
+        ])
+        .into_iter()
+        .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    /// Types the given text, one character at a time.
+    pub fn type_text(&mut self, text: &str) -> event::Status {
+        self.simulate(
+            text.chars().map(|c| {
+                Event::Keyboard(keyboard::Event::CharacterReceived(c))
+            }),
+        )
+        .into_iter()
+        .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    /// Focuses the widget with the given [`Id`].
+    pub fn focus(&mut self, id: impl Into<Id>) {
+        let _ = self.operate(operation::focusable::focus::<()>(id.into()));
+    }
+
+    /// Returns the layout bounds of the widget with the given [`Id`], if it
+    /// reports them.
+    pub fn find(&mut self, id: impl Into<Id>) -> Option<Rectangle> {
+        struct Find {
+            target: Id,
+            bounds: Option<Rectangle>,
+        }
+
+        impl Operation<Rectangle> for Find {
+            fn container(
+                &mut self,
+                _id: Option<&Id>,
+                operate_on_children: &mut dyn FnMut(
+                    &mut dyn Operation<Rectangle>,
+                ),
+            ) {
+                operate_on_children(self)
+            }
+
+            fn bounds(&mut self, bounds: Rectangle, id: Option<&Id>) {
+                if self.bounds.is_none() && id == Some(&self.target) {
+                    self.bounds = Some(bounds);
+                }
+            }
+
+            fn finish(&self) -> operation::Outcome<Rectangle> {
+                self.bounds
+                    .map(operation::Outcome::Some)
+                    .unwrap_or(operation::Outcome::None)
+            }
+        }
+
+        self.operate(Find {
+            target: id.into(),
+            bounds: None,
+        })
+    }
+
+    /// Runs the given [`Operation`] on the widgets of the [`Program`],
+    /// following any chained operations, and returns its output, if any.
+    ///
+    /// This can be used to query or update the internal state of widgets.
+    pub fn operate<T>(
+        &mut self,
+        operation: impl Operation<T> + 'static,
+    ) -> Option<T> {
+        self.run(Box::new(operation))
+    }
+
+    fn simulate_one(&mut self, event: Event) -> event::Status {
+        self.simulate([event])
+            .into_iter()
+            .next()
+            .unwrap_or(event::Status::Ignored)
+    }
+
+    fn update(&mut self, mut messages: Vec<P::Message>) {
+        while !messages.is_empty() {
+            for message in std::mem::take(&mut messages) {
+                self.messages.push(message.clone());
+
+                let command = self.program.update(message);
+
+                for action in command.actions() {
+                    if let command::Action::Widget(action) = action {
+                        messages.extend(self.run(action.into_operation()));
+                    }
+                }
+            }
+        }
+    }
+
+    fn run<T>(&mut self, operation: Box<dyn Operation<T>>) -> Option<T> {
+        let mut user_interface = UserInterface::build(
+            self.program.view(),
+            self.size,
+            std::mem::take(&mut self.cache),
+            &mut self.renderer,
+        );
+
+        let mut current = operation;
+
+        let output = loop {
+            user_interface.operate(
+                &self.renderer,
+                &mut Forward {
+                    operation: current.as_mut(),
+                },
+            );
+
+            match current.finish() {
+                operation::Outcome::None => break None,
+                operation::Outcome::Some(output) => break Some(output),
+                operation::Outcome::Chain(next) => {
+                    current = next;
+                }
+            }
+        };
+
+        self.cache = user_interface.into_cache();
+
+        output
+    }
+}
+
+/// Forwards an [`Operation`] to the widgets of a [`UserInterface`] with a
+/// different message type.
+struct Forward<'a, T> {
+    operation: &'a mut dyn Operation<T>,
+}
+
+impl<'a, T, B> Operation<B> for Forward<'a, T> {
+    fn container(
+        &mut self,
+        id: Option<&Id>,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<B>),
+    ) {
+        self.operation.container(id, &mut |operation| {
+            operate_on_children(&mut Forward { operation });
+        });
+    }
+
+    fn focusable(
+        &mut self,
+        state: &mut dyn operation::Focusable,
+        id: Option<&Id>,
+    ) {
+        self.operation.focusable(state, id);
+    }
+
+    fn scrollable(
+        &mut self,
+        state: &mut dyn operation::Scrollable,
+        id: Option<&Id>,
+    ) {
+        self.operation.scrollable(state, id);
+    }
+
+    fn text_input(
+        &mut self,
+        state: &mut dyn operation::TextInput,
+        id: Option<&Id>,
+    ) {
+        self.operation.text_input(state, id);
+    }
+
+    fn bounds(&mut self, bounds: Rectangle, id: Option<&Id>) {
+        self.operation.bounds(bounds, id);
+    }
+}
+
+/// A [`Clipboard`] that keeps its contents in memory.
+#[derive(Debug, Default)]
+struct Memory(Option<String>);
+
+impl Clipboard for Memory {
+    fn read(&self) -> Option<String> {
+        self.0.clone()
+    }
+
+    fn write(&mut self, contents: String) {
+        self.0 = Some(contents);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::renderer::Null;
+    use crate::widget::scrollable::RelativeOffset;
+    use crate::widget::{
+        button, column, container, scrollable, text, text_input,
+    };
+    use crate::{Command, Element, Length};
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        NameChanged(String),
+        Submit,
+        Scrolled(RelativeOffset),
+    }
+
+    #[derive(Default)]
+    struct Form {
+        name: String,
+        submitted: usize,
+    }
+
+    impl Program for Form {
+        type Renderer = Null;
+        type Message = Message;
+
+        fn update(&mut self, message: Message) -> Command<Message> {
+            match message {
+                Message::NameChanged(name) => self.name = name,
+                Message::Submit => self.submitted += 1,
+                Message::Scrolled(_) => {}
+            }
+
+            Command::none()
+        }
+
+        fn view(&self) -> Element<'_, Message, Null> {
+            let items =
+                (0..20).fold(column(vec![]), |column, i| column.push(text(i)));
+
+            crate::column![
+                text_input("Name", &self.name, Message::NameChanged)
+                    .id(text_input::Id::new("name")),
+                button(text("Submit"))
+                    .id(button::Id::new("submit"))
+                    .width(Length::Units(100))
+                    .on_press(Message::Submit),
+                container(
+                    scrollable(items)
+                        .id(scrollable::Id::new("items"))
+                        .on_scroll(Message::Scrolled),
+                )
+                .id(container::Id::new("list"))
+                .height(Length::Units(100)),
+            ]
+            .into()
+        }
+    }
+
+    fn simulator() -> Simulator<Form> {
+        Simulator::new(Form::default(), Size::new(400.0, 300.0))
+    }
+
+    #[test]
+    fn finds_the_bounds_of_widgets() {
+        let mut simulator = simulator();
+
+        let submit = simulator.find(button::Id::new("submit")).unwrap();
+        let list = simulator.find(container::Id::new("list")).unwrap();
+
+        assert_eq!(submit.width, 100.0);
+        assert_eq!(list.height, 100.0);
+        assert!(list.y >= submit.y + submit.height);
+        assert_eq!(simulator.find(button::Id::new("missing")), None);
+    }
+
+    #[test]
+    fn clicks_on_buttons() {
+        let mut simulator = simulator();
+
+        assert_eq!(
+            simulator.click_on(button::Id::new("submit")),
+            Some(event::Status::Captured)
+        );
+        assert_eq!(simulator.program().submitted, 1);
+        assert_eq!(simulator.messages(), &[Message::Submit]);
+    }
+
+    #[test]
+    fn types_into_text_inputs() {
+        let mut simulator = simulator();
+
+        let _ = simulator.click_on(text_input::Id::new("name"));
+        let _ = simulator.type_text("Ferris");
+
+        assert_eq!(simulator.program().name, "Ferris");
+        assert_eq!(
+            simulator.messages().last(),
+            Some(&Message::NameChanged(String::from("Ferris")))
+        );
+    }
+
+    #[test]
+    fn scrolls_on_scrollables() {
+        let mut simulator = simulator();
+
+        let status = simulator.scroll_on(
+            scrollable::Id::new("items"),
+            mouse::ScrollDelta::Lines { x: 0.0, y: -1.0 },
+        );
+
+        assert_eq!(status, Some(event::Status::Captured));
+        assert!(matches!(
+            simulator.messages(),
+            [Message::Scrolled(offset)] if offset.y > 0.0
+        ));
+    }
+}
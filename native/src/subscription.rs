@@ -1,5 +1,6 @@
 //! Listen to external events in your application.
 use crate::event::{self, Event};
+use crate::window;
 use crate::Hasher;
 
 use iced_futures::futures::{self, Future, Stream};
@@ -52,6 +53,9 @@ pub fn events() -> Subscription<Event> {
 ///
 /// - Returns `None`, the [`Event`] will be discarded.
 /// - Returns `Some` message, the `Message` will be produced.
+///
+/// [`window::Event::RedrawRequested`] events are never passed to the function,
+/// since they happen every frame. Use [`raw_events`] to listen to them.
 pub fn events_with<Message>(
     f: fn(Event, event::Status) -> Option<Message>,
 ) -> Subscription<Message>
@@ -64,6 +68,36 @@ where
             use futures::future;
             use futures::stream::StreamExt;
 
+            events.filter_map(move |(event, status)| {
+                future::ready(match event {
+                    Event::Window(_, window::Event::RedrawRequested(_)) => None,
+                    _ => f(event, status),
+                })
+            })
+        },
+    })
+}
+
+/// Returns a [`Subscription`] that filters all the runtime events with the
+/// provided function, including [`window::Event::RedrawRequested`].
+///
+/// Redraws can happen every frame, so the function should discard the events
+/// it does not need as cheaply as possible.
+pub fn raw_events<Message>(
+    f: fn(Event, event::Status) -> Option<Message>,
+) -> Subscription<Message>
+where
+    Message: 'static + MaybeSend,
+{
+    #[derive(Hash)]
+    struct RawEvents;
+
+    Subscription::from_recipe(Runner {
+        id: (RawEvents, f),
+        spawn: move |events| {
+            use futures::future;
+            use futures::stream::StreamExt;
+
             events.filter_map(move |(event, status)| {
                 future::ready(f(event, status))
             })
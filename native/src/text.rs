@@ -1,5 +1,6 @@
 //! Draw and interact with text.
 use crate::alignment;
+use crate::font;
 use crate::{Color, Point, Rectangle, Size, Vector};
 
 /// A paragraph.
@@ -25,6 +26,76 @@ pub struct Text<'a, Font> {
 
     /// The vertical alignment of the [`Text`].
     pub vertical_alignment: alignment::Vertical,
+
+    /// The [`Wrapping`] strategy of the [`Text`].
+    pub wrapping: Wrapping,
+}
+
+/// The strategy used to fit text that overflows the width of its bounds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Wrapping {
+    /// Wraps the text at word boundaries.
+    #[default]
+    Word,
+
+    /// Wraps the text at any character.
+    Character,
+
+    /// Does not wrap the text, clipping the overflowing characters.
+    None,
+
+    /// Does not wrap the text, replacing its overflowing end with an
+    /// ellipsis.
+    Ellipsis,
+
+    /// Does not wrap the text, replacing its overflowing middle with an
+    /// ellipsis.
+    MiddleEllipsis,
+}
+
+impl Wrapping {
+    /// Returns true if the [`Wrapping`] breaks overflowing text into
+    /// multiple lines.
+    pub fn is_wrapped(self) -> bool {
+        matches!(self, Self::Word | Self::Character)
+    }
+}
+
+/// A span of a paragraph of rich text.
+#[derive(Debug, Clone, Copy)]
+pub struct Span<'a, Font> {
+    /// The content of the [`Span`].
+    pub content: &'a str,
+
+    /// The size of the [`Span`].
+    pub size: f32,
+
+    /// The color of the [`Span`].
+    ///
+    /// If `None`, the color of the [`RichText`] is used.
+    pub color: Option<Color>,
+
+    /// The font of the [`Span`].
+    pub font: Font,
+}
+
+/// A paragraph made of multiple spans, wrapped together.
+#[derive(Debug, Clone, Copy)]
+pub struct RichText<'a, 'b, Font> {
+    /// The spans of the paragraph.
+    pub spans: &'b [Span<'a, Font>],
+
+    /// The bounds of the paragraph.
+    pub bounds: Rectangle,
+
+    /// The default color of the spans of the [`RichText`].
+    pub color: Color,
+
+    /// The horizontal alignment of the [`RichText`].
+    pub horizontal_alignment: alignment::Horizontal,
+
+    /// The vertical alignment of the [`RichText`].
+    pub vertical_alignment: alignment::Vertical,
 }
 
 /// The result of hit testing on text.
@@ -57,7 +128,7 @@ impl Hit {
 /// A renderer capable of measuring and drawing [`Text`].
 pub trait Renderer: crate::Renderer {
     /// The font type used.
-    type Font: Default + Clone;
+    type Font: Default + Clone + PartialEq + 'static;
 
     /// The icon font of the backend.
     const ICON_FONT: Self::Font;
@@ -75,19 +146,22 @@ pub trait Renderer: crate::Renderer {
     /// Returns the default size of [`Text`].
     fn default_size(&self) -> u16;
 
-    /// Measures the text in the given bounds and returns the minimum boundaries
-    /// that can fit the contents.
+    /// Measures the text in the given bounds, fitted with the given
+    /// [`Wrapping`], and returns the minimum boundaries that can fit the
+    /// contents.
     fn measure(
         &self,
         content: &str,
         size: u16,
         font: Self::Font,
         bounds: Size,
+        wrapping: Wrapping,
     ) -> (f32, f32);
 
     /// Measures the width of the text as if it were laid out in a single line.
     fn measure_width(&self, content: &str, size: u16, font: Self::Font) -> f32 {
-        let (width, _) = self.measure(content, size, font, Size::INFINITY);
+        let (width, _) =
+            self.measure(content, size, font, Size::INFINITY, Wrapping::None);
 
         width
     }
@@ -109,6 +183,31 @@ pub trait Renderer: crate::Renderer {
         nearest_only: bool,
     ) -> Option<Hit>;
 
+    /// Measures the given spans laid out as a single paragraph in the given
+    /// bounds and returns the minimum boundaries that can fit them.
+    fn measure_spans(
+        &self,
+        spans: &[Span<'_, Self::Font>],
+        bounds: Size,
+    ) -> (f32, f32);
+
+    /// Returns the index of the [`Span`] with a glyph under the given point,
+    /// if any, when the spans are laid out as a single paragraph in the given
+    /// bounds.
+    fn hit_span(
+        &self,
+        spans: &[Span<'_, Self::Font>],
+        bounds: Size,
+        point: Point,
+    ) -> Option<usize>;
+
     /// Draws the given [`Text`].
     fn fill_text(&mut self, text: Text<'_, Self::Font>);
+
+    /// Draws the given [`RichText`].
+    fn fill_rich_text(&mut self, text: RichText<'_, '_, Self::Font>);
+
+    /// Loads a font from its bytes, making it available to any [`Text`]
+    /// drawn or measured afterwards.
+    fn load_font(&mut self, bytes: Vec<u8>) -> Result<(), font::Error>;
 }
@@ -1,10 +1,12 @@
 //! Implement your own event loop to drive a user interface.
 use crate::application;
 use crate::event::{self, Event};
+use crate::input_method;
 use crate::layout;
 use crate::mouse;
 use crate::renderer;
 use crate::widget;
+use crate::window;
 use crate::{Clipboard, Element, Layout, Point, Rectangle, Shell, Size};
 
 /// A set of interactive graphical elements with a specific [`Layout`].
@@ -188,7 +190,9 @@ where
     ) -> (State, Vec<event::Status>) {
         use std::mem::ManuallyDrop;
 
-        let mut state = State::Updated;
+        let mut outdated = false;
+        let mut redraw_request = None;
+        let mut input_method = input_method::Request::Disabled;
         let mut manual_overlay =
             ManuallyDrop::new(self.root.as_widget_mut().overlay(
                 &mut self.state,
@@ -244,8 +248,12 @@ where
                 }
 
                 if shell.are_widgets_invalid() {
-                    state = State::Outdated;
+                    outdated = true;
                 }
+
+                redraw_request =
+                    earliest_redraw_request(redraw_request, &shell);
+                input_method = input_method.merge(shell.input_method());
             }
 
             let base_cursor = if layout.bounds().contains(cursor_position) {
@@ -299,13 +307,26 @@ where
                 });
 
                 if shell.are_widgets_invalid() {
-                    state = State::Outdated;
+                    outdated = true;
                 }
 
+                redraw_request =
+                    earliest_redraw_request(redraw_request, &shell);
+                input_method = input_method.merge(shell.input_method());
+
                 event_status.merge(overlay_status)
             })
             .collect();
 
+        let state = if outdated {
+            State::Outdated
+        } else {
+            State::Updated {
+                redraw_request,
+                input_method,
+            }
+        };
+
         (state, event_statuses)
     }
 
@@ -557,5 +578,21 @@ pub enum State {
 
     /// The [`UserInterface`] is up-to-date and can be reused without
     /// rebuilding.
-    Updated,
+    Updated {
+        /// The earliest [`window::RedrawRequest`] of the widgets, if any.
+        redraw_request: Option<window::RedrawRequest>,
+
+        /// The [`input_method::Request`] of the widgets.
+        input_method: input_method::Request,
+    },
+}
+
+fn earliest_redraw_request<Message>(
+    current: Option<window::RedrawRequest>,
+    shell: &Shell<'_, Message>,
+) -> Option<window::RedrawRequest> {
+    match (current, shell.redraw_request()) {
+        (Some(current), Some(new)) => Some(current.min(new)),
+        (current, new) => current.or(new),
+    }
 }
@@ -1,5 +1,6 @@
 use crate::widget::operation::{self, Focusable, Operation, Scrollable};
 use crate::widget::Id;
+use crate::Rectangle;
 
 use iced_futures::MaybeSend;
 
@@ -84,6 +85,10 @@ where
             ) {
                 self.operation.focusable(state, id);
             }
+
+            fn bounds(&mut self, bounds: Rectangle, id: Option<&Id>) {
+                self.operation.bounds(bounds, id);
+            }
         }
 
         let Self { operation, .. } = self;
@@ -118,6 +123,10 @@ where
         self.operation.text_input(state, id);
     }
 
+    fn bounds(&mut self, bounds: Rectangle, id: Option<&Id>) {
+        self.operation.bounds(bounds, id);
+    }
+
     fn finish(&self) -> operation::Outcome<B> {
         match self.operation.finish() {
             operation::Outcome::None => operation::Outcome::None,
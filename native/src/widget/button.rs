@@ -1,14 +1,17 @@
 //! Allow your users to perform actions by pressing a button.
 //!
 //! A [`Button`] has some local [`State`].
+use crate::animation::{Animation, Easing, Motion};
 use crate::event::{self, Event};
 use crate::layout;
 use crate::mouse;
 use crate::overlay;
 use crate::renderer;
+use crate::time::{Duration, Instant};
 use crate::touch;
 use crate::widget::tree::{self, Tree};
-use crate::widget::Operation;
+use crate::widget::{self, Operation};
+use crate::window;
 use crate::{
     Background, Clipboard, Color, Element, Layout, Length, Padding, Point,
     Rectangle, Shell, Vector, Widget,
@@ -56,11 +59,13 @@ where
     Renderer: crate::Renderer,
     Renderer::Theme: StyleSheet,
 {
+    id: Option<Id>,
     content: Element<'a, Message, Renderer>,
     on_press: Option<Message>,
     width: Length,
     height: Length,
     padding: Padding,
+    motion: Option<Motion>,
     style: <Renderer::Theme as StyleSheet>::Style,
 }
 
@@ -72,15 +77,23 @@ where
     /// Creates a new [`Button`] with the given content.
     pub fn new(content: impl Into<Element<'a, Message, Renderer>>) -> Self {
         Button {
+            id: None,
             content: content.into(),
             on_press: None,
             width: Length::Shrink,
             height: Length::Shrink,
             padding: Padding::new(5),
+            motion: None,
             style: <Renderer::Theme as StyleSheet>::Style::default(),
         }
     }
 
+    /// Sets the [`Id`] of the [`Button`].
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
     /// Sets the width of the [`Button`].
     pub fn width(mut self, width: Length) -> Self {
         self.width = width;
@@ -107,6 +120,16 @@ where
         self
     }
 
+    /// Sets the [`Motion`] used to animate the [`Button`] between its
+    /// appearances.
+    ///
+    /// By default, or if `None`, the appearance of the [`Button`] changes
+    /// instantly.
+    pub fn motion(mut self, motion: impl Into<Option<Motion>>) -> Self {
+        self.motion = motion.into();
+        self
+    }
+
     /// Sets the style variant of this [`Button`].
     pub fn style(
         mut self,
@@ -129,7 +152,14 @@ where
     }
 
     fn state(&self) -> tree::State {
-        tree::State::new(State::new())
+        let mut state = State::with_status(Status::new(
+            self.on_press.is_some(),
+            false,
+            false,
+        ));
+        state.set_motion(self.motion);
+
+        tree::State::new(state)
     }
 
     fn children(&self) -> Vec<Tree> {
@@ -137,6 +167,7 @@ where
     }
 
     fn diff(&self, tree: &mut Tree) {
+        tree.state.downcast_mut::<State>().set_motion(self.motion);
         tree.diff_children(std::slice::from_ref(&self.content))
     }
 
@@ -171,13 +202,18 @@ where
         layout: Layout<'_>,
         operation: &mut dyn Operation<Message>,
     ) {
-        operation.container(None, &mut |operation| {
-            self.content.as_widget().operate(
-                &mut tree.children[0],
-                layout.children().next().unwrap(),
-                operation,
-            );
-        });
+        operation.bounds(layout.bounds(), self.id.as_ref().map(|id| &id.0));
+
+        operation.container(
+            self.id.as_ref().map(|id| &id.0),
+            &mut |operation| {
+                self.content.as_widget().operate(
+                    &mut tree.children[0],
+                    layout.children().next().unwrap(),
+                    operation,
+                );
+            },
+        );
     }
 
     fn on_event(
@@ -285,10 +321,36 @@ where
     }
 }
 
+/// The identifier of a [`Button`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Id(widget::Id);
+
+impl Id {
+    /// Creates a custom [`Id`].
+    pub fn new(id: impl Into<std::borrow::Cow<'static, str>>) -> Self {
+        Self(widget::Id::new(id))
+    }
+
+    /// Creates a unique [`Id`].
+    ///
+    /// This function produces a different [`Id`] every time it is called.
+    pub fn unique() -> Self {
+        Self(widget::Id::unique())
+    }
+}
+
+impl From<Id> for widget::Id {
+    fn from(id: Id) -> Self {
+        id.0
+    }
+}
+
 /// The local state of a [`Button`].
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy)]
 pub struct State {
     is_pressed: bool,
+    status: Animation<Status>,
+    now: Option<Instant>,
 }
 
 impl State {
@@ -296,6 +358,68 @@ impl State {
     pub fn new() -> State {
         State::default()
     }
+
+    /// Creates a new [`State`] settled in the given [`Status`].
+    fn with_status(status: Status) -> State {
+        State {
+            is_pressed: false,
+            status: Animation::new(status),
+            now: None,
+        }
+    }
+
+    /// Sets the [`Motion`] used to animate the [`Button`] between its
+    /// appearances.
+    ///
+    /// If `None`, the appearance of the [`Button`] changes instantly.
+    pub fn set_motion(&mut self, motion: Option<Motion>) {
+        self.status.set_motion(motion.unwrap_or(Motion::Tween {
+            duration: Duration::ZERO,
+            easing: Easing::Linear,
+        }));
+    }
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self::with_status(Status::Active)
+    }
+}
+
+/// Two states are equal if their buttons are pressed alike and head to the
+/// same [`Status`], regardless of the progress of their animations.
+impl PartialEq for State {
+    fn eq(&self, other: &Self) -> bool {
+        self.is_pressed == other.is_pressed
+            && self.status.state() == other.status.state()
+    }
+}
+
+impl Eq for State {}
+
+/// The interaction status of a [`Button`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Status {
+    Active,
+    Hovered,
+    Pressed,
+    Disabled,
+}
+
+impl Status {
+    fn new(is_enabled: bool, is_mouse_over: bool, is_pressed: bool) -> Self {
+        if !is_enabled {
+            Status::Disabled
+        } else if is_mouse_over {
+            if is_pressed {
+                Status::Pressed
+            } else {
+                Status::Hovered
+            }
+        } else {
+            Status::Active
+        }
+    }
 }
 
 /// Processes the given [`Event`] and updates the [`State`] of a [`Button`]
@@ -308,20 +432,18 @@ pub fn update<'a, Message: Clone>(
     on_press: &Option<Message>,
     state: impl FnOnce() -> &'a mut State,
 ) -> event::Status {
+    let bounds = layout.bounds();
+
     match event {
         Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
-        | Event::Touch(touch::Event::FingerPressed { .. }) => {
-            if on_press.is_some() {
-                let bounds = layout.bounds();
-
-                if bounds.contains(cursor_position) {
-                    let state = state();
+        | Event::Touch(touch::Event::FingerPressed { .. })
+            if on_press.is_some() && bounds.contains(cursor_position) =>
+        {
+            let state = state();
 
-                    state.is_pressed = true;
+            state.is_pressed = true;
 
-                    return event::Status::Captured;
-                }
-            }
+            return event::Status::Captured;
         }
         Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
         | Event::Touch(touch::Event::FingerLifted { .. }) => {
@@ -331,8 +453,6 @@ pub fn update<'a, Message: Clone>(
                 if state.is_pressed {
                     state.is_pressed = false;
 
-                    let bounds = layout.bounds();
-
                     if bounds.contains(cursor_position) {
                         shell.publish(on_press);
                     }
@@ -346,6 +466,23 @@ pub fn update<'a, Message: Clone>(
 
             state.is_pressed = false;
         }
+        Event::Window(_, window::Event::RedrawRequested(now)) => {
+            let state = state();
+
+            // Transitions start and are drawn with the time of the redraw
+            let status = Status::new(
+                on_press.is_some(),
+                bounds.contains(cursor_position),
+                state.is_pressed,
+            );
+
+            state.now = Some(now);
+            state.status.go(status, now);
+
+            if state.status.is_animating(now) {
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+            }
+        }
         _ => {}
     }
 
@@ -367,20 +504,23 @@ pub fn draw<'a, Renderer: crate::Renderer>(
 where
     Renderer::Theme: StyleSheet,
 {
+    let state = state();
     let is_mouse_over = bounds.contains(cursor_position);
 
-    let styling = if !is_enabled {
-        style_sheet.disabled(style)
-    } else if is_mouse_over {
-        let state = state();
+    let appearance = |status| match status {
+        Status::Active => style_sheet.active(style),
+        Status::Hovered => style_sheet.hovered(style),
+        Status::Pressed => style_sheet.pressed(style),
+        Status::Disabled => style_sheet.disabled(style),
+    };
 
-        if state.is_pressed {
-            style_sheet.pressed(style)
-        } else {
-            style_sheet.hovered(style)
+    let status = Status::new(is_enabled, is_mouse_over, state.is_pressed);
+
+    let styling = match state.now {
+        Some(now) if state.status.state() == status => {
+            state.status.interpolate_with(appearance, now)
         }
-    } else {
-        style_sheet.active(style)
+        _ => appearance(status),
     };
 
     if styling.background.is_some() || styling.border_width > 0.0 {
@@ -451,3 +591,65 @@ pub fn mouse_interaction(
         mouse::Interaction::default()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bounds() -> layout::Node {
+        layout::Node::new(crate::Size::new(100.0, 30.0))
+    }
+
+    #[test]
+    fn leaves_the_state_alone_for_unrelated_events() {
+        let node = bounds();
+        let mut messages = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+
+        let status = update(
+            Event::Mouse(mouse::Event::CursorMoved {
+                position: Point::new(10.0, 10.0),
+            }),
+            Layout::new(&node),
+            Point::new(10.0, 10.0),
+            &mut shell,
+            &Some(()),
+            || -> &mut State { panic!("the state was requested") },
+        );
+
+        assert_eq!(status, event::Status::Ignored);
+    }
+
+    #[test]
+    fn animates_with_the_time_of_the_redraw() {
+        let node = bounds();
+        let mut messages = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+
+        let mut state = State::new();
+        state.set_motion(Some(Motion::default()));
+
+        let now = Instant::now() + Duration::from_secs(60);
+
+        let _ = update(
+            Event::Window(
+                window::Id::MAIN,
+                window::Event::RedrawRequested(now),
+            ),
+            Layout::new(&node),
+            Point::new(10.0, 10.0),
+            &mut shell,
+            &Some(()),
+            || &mut state,
+        );
+
+        assert_eq!(state.now, Some(now));
+        assert_eq!(state.status.state(), Status::Hovered);
+        assert!(state.status.is_animating(now));
+        assert!(!state.status.is_animating(now + Duration::from_secs(1)));
+        assert_eq!(
+            shell.redraw_request(),
+            Some(window::RedrawRequest::NextFrame)
+        );
+    }
+}
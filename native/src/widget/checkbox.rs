@@ -256,6 +256,7 @@ where
                     color: custom_style.checkmark_color,
                     horizontal_alignment: alignment::Horizontal::Center,
                     vertical_alignment: alignment::Vertical::Center,
+                    wrapping: text::Wrapping::default(),
                 });
             }
         }
@@ -275,6 +276,7 @@ where
                 },
                 alignment::Horizontal::Left,
                 alignment::Vertical::Center,
+                text::Wrapping::default(),
             );
         }
     }
@@ -0,0 +1,749 @@
+//! Search and pick a value from a long list of options.
+//!
+//! A [`ComboBox`] has some local [`State`].
+use crate::event::{self, Event};
+use crate::keyboard;
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::overlay::menu::{self, Menu};
+use crate::renderer;
+use crate::text;
+use crate::widget::container;
+use crate::widget::operation::Operation;
+use crate::widget::scrollable;
+use crate::widget::text_input::{self, Value};
+use crate::widget::tree::{self, Tree};
+use crate::{
+    Clipboard, Element, Layout, Length, Padding, Point, Rectangle, Shell,
+    Widget,
+};
+
+use std::borrow::Cow;
+use std::cmp::Reverse;
+use std::ops::Range;
+
+pub use iced_style::text_input::{Appearance, StyleSheet};
+
+/// A text input that filters a list of options as the user types and shows
+/// the matching ones in a dropdown menu.
+///
+/// The options are matched against the typed text with a matcher, which is
+/// [`fuzzy`] by default. The best matches are shown first, with their
+/// matching parts highlighted. The arrow keys move through the matches and
+/// `Enter` selects the hovered one.
+///
+/// # Example
+/// ```
+/// # use iced_native::widget::combo_box;
+/// # use iced_native::renderer::Null;
+/// #
+/// # type ComboBox<'a, T, Message> = combo_box::ComboBox<'a, T, Message, Null>;
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     CountrySelected(String),
+/// }
+///
+/// let countries = vec![
+///     String::from("Argentina"),
+///     String::from("Austria"),
+///     String::from("Australia"),
+/// ];
+///
+/// let combo_box = ComboBox::new(
+///     countries,
+///     "Search a country...",
+///     None,
+///     Message::CountrySelected,
+/// )
+/// .matcher(combo_box::substring);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct ComboBox<'a, T, Message, Renderer>
+where
+    [T]: ToOwned<Owned = Vec<T>>,
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet + menu::StyleSheet,
+{
+    options: Cow<'a, [T]>,
+    placeholder: String,
+    selected: Option<T>,
+    on_selected: Box<dyn Fn(T) -> Message + 'a>,
+    on_input: Option<Box<dyn Fn(String) -> Message + 'a>>,
+    matcher: Box<dyn Fn(&str, &str) -> Option<Match> + 'a>,
+    width: Length,
+    padding: Padding,
+    size: Option<u16>,
+    font: Renderer::Font,
+    style: <Renderer::Theme as StyleSheet>::Style,
+    menu_style: <Renderer::Theme as menu::StyleSheet>::Style,
+}
+
+impl<'a, T, Message, Renderer> ComboBox<'a, T, Message, Renderer>
+where
+    T: ToString,
+    [T]: ToOwned<Owned = Vec<T>>,
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet + menu::StyleSheet,
+{
+    /// The default padding of a [`ComboBox`].
+    pub const DEFAULT_PADDING: Padding = Padding::new(5);
+
+    /// Creates a new [`ComboBox`] with the given list of options, a
+    /// placeholder, the current selected value, and the message to produce
+    /// when an option is selected.
+    pub fn new(
+        options: impl Into<Cow<'a, [T]>>,
+        placeholder: &str,
+        selected: Option<T>,
+        on_selected: impl Fn(T) -> Message + 'a,
+    ) -> Self {
+        Self {
+            options: options.into(),
+            placeholder: String::from(placeholder),
+            selected,
+            on_selected: Box::new(on_selected),
+            on_input: None,
+            matcher: Box::new(fuzzy),
+            width: Length::Fill,
+            padding: Self::DEFAULT_PADDING,
+            size: None,
+            font: Default::default(),
+            style: Default::default(),
+            menu_style: Default::default(),
+        }
+    }
+
+    /// Sets the message that will be produced when the text of the
+    /// [`ComboBox`] changes.
+    pub fn on_input(
+        mut self,
+        on_input: impl Fn(String) -> Message + 'a,
+    ) -> Self {
+        self.on_input = Some(Box::new(on_input));
+        self
+    }
+
+    /// Sets the function used to match the options against the text of the
+    /// [`ComboBox`].
+    ///
+    /// It receives the text and the label of an option, and returns a
+    /// [`Match`] if the option should be shown.
+    pub fn matcher(
+        mut self,
+        matcher: impl Fn(&str, &str) -> Option<Match> + 'a,
+    ) -> Self {
+        self.matcher = Box::new(matcher);
+        self
+    }
+
+    /// Sets the width of the [`ComboBox`].
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`ComboBox`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`ComboBox`].
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the font of the [`ComboBox`].
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the text input of the [`ComboBox`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the style of the menu of the [`ComboBox`].
+    pub fn menu_style(
+        mut self,
+        style: impl Into<<Renderer::Theme as menu::StyleSheet>::Style>,
+    ) -> Self {
+        self.menu_style = style.into();
+        self
+    }
+
+    fn label(&self) -> String {
+        self.selected
+            .as_ref()
+            .map(ToString::to_string)
+            .unwrap_or_default()
+    }
+
+    fn open(&self, state: &mut State<T>)
+    where
+        T: Clone,
+    {
+        self.filter(state);
+
+        state.is_open = true;
+        state.hovered_option = if state.options.is_empty() {
+            None
+        } else {
+            Some(0)
+        };
+    }
+
+    fn filter(&self, state: &mut State<T>)
+    where
+        T: Clone,
+    {
+        let (options, highlights) = filter(
+            &self.options,
+            &state.value.to_string(),
+            self.matcher.as_ref(),
+        );
+
+        state.options = options;
+        state.highlights = highlights;
+    }
+}
+
+/// An option matching the text of a [`ComboBox`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Match {
+    /// The score of the [`Match`]. Options with a higher score are shown
+    /// first.
+    pub score: i32,
+
+    /// The byte ranges of the label of the option that will be highlighted.
+    pub ranges: Vec<Range<usize>>,
+}
+
+/// Matches an option if the characters of the text appear in its label in
+/// the same order, ignoring case and whitespace.
+///
+/// Consecutive characters and characters at the start of a word score
+/// higher.
+pub fn fuzzy(text: &str, label: &str) -> Option<Match> {
+    let mut query = text.chars().filter(|c| !c.is_whitespace()).peekable();
+    let mut result = Match::default();
+    let mut previous = None;
+    let mut last_match = None;
+
+    for (position, (index, c)) in label.char_indices().enumerate() {
+        let next = match query.peek() {
+            Some(next) => *next,
+            None => break,
+        };
+
+        if eq_ignore_case(next, c) {
+            let _ = query.next();
+
+            let gap = position - last_match.map_or(0, |last| last + 1);
+
+            result.score += 1 - gap.min(3) as i32;
+
+            if gap == 0 && last_match.is_some() {
+                result.score += 4;
+            }
+
+            if is_word_start(previous, c) {
+                result.score += 3;
+            }
+
+            push_range(&mut result.ranges, index..index + c.len_utf8());
+            last_match = Some(position);
+        }
+
+        previous = Some(c);
+    }
+
+    if query.peek().is_some() {
+        None
+    } else {
+        Some(result)
+    }
+}
+
+/// Matches an option if its label contains the text, ignoring case.
+///
+/// Options starting with the text score higher.
+pub fn substring(text: &str, label: &str) -> Option<Match> {
+    if text.is_empty() {
+        return Some(Match::default());
+    }
+
+    label.char_indices().find_map(|(start, _)| {
+        let mut candidate = label[start..].char_indices();
+
+        let is_match = text.chars().all(|c| {
+            candidate
+                .next()
+                .filter(|(_, other)| eq_ignore_case(c, *other))
+                .is_some()
+        });
+
+        is_match.then(|| {
+            let end = candidate
+                .next()
+                .map_or(label.len(), |(offset, _)| start + offset);
+
+            Match {
+                score: i32::from(start == 0),
+                ranges: std::iter::once(start..end).collect(),
+            }
+        })
+    })
+}
+
+fn eq_ignore_case(a: char, b: char) -> bool {
+    a == b || a.to_lowercase().eq(b.to_lowercase())
+}
+
+fn is_word_start(previous: Option<char>, c: char) -> bool {
+    match previous {
+        None => true,
+        Some(previous) => {
+            !previous.is_alphanumeric()
+                || (previous.is_lowercase() && c.is_uppercase())
+        }
+    }
+}
+
+fn push_range(ranges: &mut Vec<Range<usize>>, range: Range<usize>) {
+    match ranges.last_mut() {
+        Some(last) if last.end == range.start => last.end = range.end,
+        _ => ranges.push(range),
+    }
+}
+
+/// Returns the options matching the given text, best first, together with
+/// their highlighted ranges.
+fn filter<T>(
+    options: &[T],
+    text: &str,
+    matcher: &dyn Fn(&str, &str) -> Option<Match>,
+) -> (Vec<T>, Vec<Vec<Range<usize>>>)
+where
+    T: Clone + ToString,
+{
+    let mut matches: Vec<_> = options
+        .iter()
+        .filter_map(|option| {
+            matcher(text, &option.to_string()).map(|result| (option, result))
+        })
+        .collect();
+
+    matches.sort_by_key(|(_, result)| Reverse(result.score));
+
+    matches
+        .into_iter()
+        .map(|(option, result)| (option.clone(), result.ranges))
+        .unzip()
+}
+
+/// The local state of a [`ComboBox`].
+#[derive(Debug)]
+pub struct State<T> {
+    text_input: text_input::State,
+    value: Value,
+    is_open: bool,
+    menu: menu::State,
+    options: Vec<T>,
+    highlights: Vec<Vec<Range<usize>>>,
+    hovered_option: Option<usize>,
+    last_selection: Option<T>,
+}
+
+impl<T> State<T> {
+    /// Creates a new [`State`] for a [`ComboBox`] with the given text.
+    pub fn new(text: &str) -> Self {
+        Self {
+            text_input: text_input::State::new(),
+            value: Value::new(text),
+            is_open: false,
+            menu: menu::State::default(),
+            options: Vec::new(),
+            highlights: Vec::new(),
+            hovered_option: None,
+            last_selection: None,
+        }
+    }
+
+    /// Returns whether the [`ComboBox`] is showing its menu.
+    pub fn is_open(&self) -> bool {
+        self.is_open
+    }
+
+    fn close(&mut self) {
+        self.is_open = false;
+        self.hovered_option = None;
+    }
+}
+
+impl<T> Default for State<T> {
+    fn default() -> Self {
+        Self::new("")
+    }
+}
+
+impl<'a, T, Message, Renderer> Widget<Message, Renderer>
+    for ComboBox<'a, T, Message, Renderer>
+where
+    T: Clone + ToString + 'static,
+    [T]: ToOwned<Owned = Vec<T>>,
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet
+        + menu::StyleSheet
+        + scrollable::StyleSheet
+        + container::StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<T>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::<T>::new(&self.label()))
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State<T>>();
+
+        // The options may have changed while the menu is open, and the
+        // selection while it is not being edited
+        if state.text_input.is_focused() {
+            if state.is_open {
+                self.filter(state);
+
+                state.hovered_option = state
+                    .hovered_option
+                    .filter(|&index| index < state.options.len());
+            }
+        } else {
+            state.value = Value::new(&self.label());
+        }
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        text_input::layout(
+            renderer,
+            limits,
+            self.width,
+            self.padding,
+            self.size,
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        _layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State<T>>();
+
+        operation.focusable(&mut state.text_input, None);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State<T>>();
+
+        // An option was clicked in the menu, which has already processed
+        // the event
+        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) =
+            event
+        {
+            if let Some(selection) = state.last_selection.take() {
+                state.value = Value::new(&selection.to_string());
+                state.text_input.move_cursor_to_end();
+                state.close();
+
+                shell.publish((self.on_selected)(selection));
+
+                return event::Status::Captured;
+            }
+        }
+
+        if let Event::Keyboard(keyboard::Event::KeyPressed {
+            key_code, ..
+        }) = event
+        {
+            if state.text_input.is_focused() {
+                match key_code {
+                    keyboard::KeyCode::Up | keyboard::KeyCode::Down => {
+                        if state.is_open {
+                            state.hovered_option = hover(
+                                state.hovered_option,
+                                state.options.len(),
+                                key_code == keyboard::KeyCode::Down,
+                            );
+                        } else {
+                            self.open(state);
+                        }
+
+                        return event::Status::Captured;
+                    }
+                    keyboard::KeyCode::Enter
+                    | keyboard::KeyCode::NumpadEnter
+                        if state.is_open =>
+                    {
+                        if let Some(selection) = state
+                            .hovered_option
+                            .and_then(|index| state.options.get(index))
+                            .cloned()
+                        {
+                            state.value = Value::new(&selection.to_string());
+                            state.text_input.move_cursor_to_end();
+
+                            shell.publish((self.on_selected)(selection));
+                        }
+
+                        state.close();
+
+                        return event::Status::Captured;
+                    }
+                    keyboard::KeyCode::Escape if state.is_open => {
+                        state.close();
+
+                        return event::Status::Captured;
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        let was_focused = state.text_input.is_focused();
+        let previous_value = state.value.to_string();
+
+        let mut inputs = Vec::new();
+        let mut local_shell = Shell::new(&mut inputs);
+
+        let status = text_input::update(
+            event,
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            &mut local_shell,
+            &mut state.value,
+            self.size,
+            &self.font,
+            false,
+            &text_input::Constraints::default(),
+            &String::from,
+            None,
+            &None,
+            || &mut state.text_input,
+        );
+
+        // The text is read back from the value instead of the messages of
+        // the text input
+        if let Some(request) = local_shell.redraw_request() {
+            shell.request_redraw(request);
+        }
+
+        shell.request_input_method(local_shell.input_method());
+
+        if local_shell.is_layout_invalid() {
+            shell.invalidate_layout();
+        }
+
+        let value = state.value.to_string();
+
+        if value != previous_value {
+            self.open(state);
+
+            if let Some(on_input) = &self.on_input {
+                shell.publish(on_input(value));
+            }
+        } else if !was_focused && state.text_input.is_focused() {
+            self.open(state);
+        } else if was_focused && !state.text_input.is_focused() {
+            state.value = Value::new(&self.label());
+            state.close();
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        text_input::mouse_interaction(layout, cursor_position)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State<T>>();
+
+        text_input::draw(
+            renderer,
+            theme,
+            layout,
+            cursor_position,
+            &state.text_input,
+            &state.value,
+            &self.placeholder,
+            self.size,
+            &self.font,
+            false,
+            &self.style,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let state = tree.state.downcast_mut::<State<T>>();
+
+        if !state.is_open
+            || !state.text_input.is_focused()
+            || state.options.is_empty()
+        {
+            return None;
+        }
+
+        let bounds = layout.bounds();
+
+        let mut menu = Menu::new(
+            &mut state.menu,
+            &state.options,
+            &mut state.hovered_option,
+            &mut state.last_selection,
+        )
+        .highlights(&state.highlights)
+        .width(bounds.width.round() as u16)
+        .padding(self.padding)
+        .font(self.font.clone())
+        .style(self.menu_style.clone());
+
+        if let Some(size) = self.size {
+            menu = menu.text_size(size);
+        }
+
+        Some(menu.overlay(layout.position(), bounds.height))
+    }
+}
+
+/// Moves the hovered option up or down, staying within the options.
+fn hover(current: Option<usize>, len: usize, down: bool) -> Option<usize> {
+    if len == 0 {
+        return None;
+    }
+
+    Some(match current {
+        Some(current) if down => (current + 1).min(len - 1),
+        Some(current) => current.saturating_sub(1),
+        None if down => 0,
+        None => len - 1,
+    })
+}
+
+impl<'a, T, Message, Renderer> From<ComboBox<'a, T, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    T: Clone + ToString + 'static,
+    [T]: ToOwned<Owned = Vec<T>>,
+    Message: 'a,
+    Renderer: text::Renderer + 'a,
+    Renderer::Theme: StyleSheet
+        + menu::StyleSheet
+        + scrollable::StyleSheet
+        + container::StyleSheet,
+{
+    fn from(combo_box: ComboBox<'a, T, Message, Renderer>) -> Self {
+        Self::new(combo_box)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fuzzy_matches_in_order_and_ignores_case() {
+        let result = fuzzy("nyk", "New York").unwrap();
+
+        assert_eq!(result.ranges, vec![0..1, 4..5, 7..8]);
+        assert!(fuzzy("ykn", "New York").is_none());
+        assert!(fuzzy("new york", "New York").is_some());
+        assert_eq!(fuzzy("", "New York"), Some(Match::default()));
+    }
+
+    #[test]
+    fn fuzzy_prefers_consecutive_and_word_starts() {
+        let prefix = fuzzy("ger", "Germany").unwrap();
+        let scattered = fuzzy("ger", "Algeria").unwrap();
+
+        assert!(prefix.score > scattered.score);
+
+        let (options, highlights) =
+            filter(&["Niger", "Algeria", "Germany"], "ger", &fuzzy);
+
+        assert_eq!(options, vec!["Germany", "Niger", "Algeria"]);
+        assert_eq!(highlights[0], [Range { start: 0, end: 3 }]);
+    }
+
+    #[test]
+    fn substring_matches_contiguous_text() {
+        let result = substring("STRIA", "Austria").unwrap();
+
+        assert_eq!(result.score, 0);
+        assert_eq!(result.ranges, [Range { start: 2, end: 7 }]);
+        assert_eq!(substring("aus", "Austria").unwrap().score, 1);
+        assert!(substring("ai", "Austria").is_none());
+    }
+
+    #[test]
+    fn hover_stays_within_options() {
+        assert_eq!(hover(None, 3, true), Some(0));
+        assert_eq!(hover(None, 3, false), Some(2));
+        assert_eq!(hover(Some(2), 3, true), Some(2));
+        assert_eq!(hover(Some(0), 3, false), Some(0));
+        assert_eq!(hover(Some(0), 0, true), None);
+    }
+}
@@ -0,0 +1,319 @@
+//! Show a menu when some content is right-clicked.
+//!
+//! A [`ContextMenu`] has some local [`State`].
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::overlay::nested_menu::{self, NestedMenu};
+use crate::renderer;
+use crate::text;
+use crate::widget::operation::Operation;
+use crate::widget::tree::{self, Tree};
+use crate::{
+    Clipboard, Element, Layout, Length, Padding, Point, Rectangle, Shell,
+    Widget,
+};
+
+pub use iced_style::menu::{Appearance, StyleSheet};
+pub use nested_menu::Item;
+
+/// Some content that shows a menu of [`Item`]s at the cursor when it is
+/// right-clicked.
+///
+/// # Example
+/// ```
+/// # use iced_native::widget::context_menu::{self, Item};
+/// # use iced_native::widget::text;
+/// # use iced_native::renderer::Null;
+/// #
+/// # type ContextMenu<'a, Message> = context_menu::ContextMenu<'a, Message, Null>;
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Copy,
+///     Paste,
+/// }
+///
+/// let context_menu = ContextMenu::new(
+///     text("Right-click me!"),
+///     vec![
+///         Item::new("Copy").on_press(Message::Copy).accelerator("Ctrl+C"),
+///         Item::new("Paste").on_press(Message::Paste).accelerator("Ctrl+V"),
+///     ],
+/// );
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct ContextMenu<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    content: Element<'a, Message, Renderer>,
+    items: Vec<Item<Message>>,
+    width: u16,
+    padding: Padding,
+    text_size: Option<u16>,
+    font: Renderer::Font,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> ContextMenu<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// The default [`Padding`] of the items of a [`ContextMenu`].
+    pub const DEFAULT_PADDING: Padding = Padding::new(5);
+
+    /// Creates a new [`ContextMenu`] with the given content and items.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Renderer>>,
+        items: Vec<Item<Message>>,
+    ) -> Self {
+        ContextMenu {
+            content: content.into(),
+            items,
+            width: 150,
+            padding: Self::DEFAULT_PADDING,
+            text_size: None,
+            font: Default::default(),
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the minimum width of the menus of the [`ContextMenu`].
+    pub fn width(mut self, width: u16) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the [`Padding`] of the items of the [`ContextMenu`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`ContextMenu`].
+    pub fn text_size(mut self, size: u16) -> Self {
+        self.text_size = Some(size);
+        self
+    }
+
+    /// Sets the font of the [`ContextMenu`].
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`ContextMenu`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+/// The local state of a [`ContextMenu`].
+#[derive(Debug, Clone, Default)]
+pub struct State {
+    position: Point,
+    menu: nested_menu::State,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns whether the menu of the [`ContextMenu`] is open.
+    pub fn is_open(&self) -> bool {
+        self.menu.is_open()
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for ContextMenu<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content))
+    }
+
+    fn width(&self) -> Length {
+        self.content.as_widget().width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.as_widget().height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content.as_widget().layout(renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        // Presses over the menu are captured by its overlay, so any press
+        // that reaches the widget happened outside of it
+        if let Event::Mouse(mouse::Event::ButtonPressed(_)) = event {
+            if state.menu.is_open() {
+                state.menu.close();
+                shell.invalidate_layout();
+
+                return event::Status::Captured;
+            }
+        }
+
+        let status = self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event.clone(),
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        );
+
+        if status == event::Status::Captured {
+            return status;
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Right))
+                if layout.bounds().contains(cursor_position)
+                    && !self.items.is_empty() =>
+            {
+                state.position = cursor_position;
+                state.menu.open();
+                shell.invalidate_layout();
+
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor_position,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor_position,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let state = tree.state.downcast_mut::<State>();
+
+        if !state.menu.is_open() {
+            return self.content.as_widget_mut().overlay(
+                &mut tree.children[0],
+                layout,
+                renderer,
+            );
+        }
+
+        let mut menu = NestedMenu::new(&mut state.menu, &self.items)
+            .width(self.width)
+            .padding(self.padding)
+            .font(self.font.clone())
+            .style(self.style.clone());
+
+        if let Some(text_size) = self.text_size {
+            menu = menu.text_size(text_size);
+        }
+
+        Some(menu.overlay(state.position, 0.0))
+    }
+}
+
+impl<'a, Message, Renderer> From<ContextMenu<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: Clone + 'a,
+    Renderer: text::Renderer + 'a,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(context_menu: ContextMenu<'a, Message, Renderer>) -> Self {
+        Self::new(context_menu)
+    }
+}
@@ -117,6 +117,29 @@ where
     widget::Text::new(text.to_string())
 }
 
+/// Creates a new [`RichText`] widget with the provided spans.
+///
+/// [`RichText`]: widget::RichText
+pub fn rich_text<'a, Message, Renderer>(
+    spans: Vec<widget::rich_text::Span<'a, Message, Renderer::Font>>,
+) -> widget::RichText<'a, Message, Renderer>
+where
+    Renderer: crate::text::Renderer,
+    Renderer::Theme: widget::text::StyleSheet,
+{
+    widget::RichText::new(spans)
+}
+
+/// Creates a new [`Span`] of a [`RichText`] with the provided content.
+///
+/// [`Span`]: widget::rich_text::Span
+/// [`RichText`]: widget::RichText
+pub fn span<'a, Message, Font>(
+    content: impl Into<Cow<'a, str>>,
+) -> widget::rich_text::Span<'a, Message, Font> {
+    widget::rich_text::Span::new(content)
+}
+
 /// Creates a new [`Checkbox`].
 ///
 /// [`Checkbox`]: widget::Checkbox
@@ -181,6 +204,39 @@ where
     widget::TextInput::new(placeholder, value, on_change)
 }
 
+/// Creates a new [`TextEditor`].
+///
+/// [`TextEditor`]: widget::TextEditor
+pub fn text_editor<'a, Message, Renderer>(
+    placeholder: &str,
+    value: &str,
+    on_change: impl Fn(String) -> Message + 'a,
+) -> widget::TextEditor<'a, Message, Renderer>
+where
+    Renderer: crate::text::Renderer,
+    Renderer::Theme: widget::text_editor::StyleSheet,
+{
+    widget::TextEditor::new(placeholder, value, on_change)
+}
+
+/// Creates a new [`NumberInput`].
+///
+/// [`NumberInput`]: widget::NumberInput
+pub fn number_input<'a, T, Message, Renderer>(
+    range: std::ops::RangeInclusive<T>,
+    value: T,
+    on_change: impl Fn(T) -> Message + 'a,
+) -> widget::NumberInput<'a, T, Message, Renderer>
+where
+    T: Copy + From<u8> + std::cmp::PartialOrd,
+    Message: Clone,
+    Renderer: crate::text::Renderer,
+    Renderer::Theme:
+        widget::number_input::StyleSheet + widget::text_input::StyleSheet,
+{
+    widget::NumberInput::new(range, value, on_change)
+}
+
 /// Creates a new [`Slider`].
 ///
 /// [`Slider`]: widget::Slider
@@ -237,6 +293,97 @@ where
     widget::PickList::new(options, selected, on_selected)
 }
 
+/// Creates a new [`ComboBox`].
+///
+/// [`ComboBox`]: widget::ComboBox
+pub fn combo_box<'a, T, Message, Renderer>(
+    options: impl Into<Cow<'a, [T]>>,
+    placeholder: &str,
+    selected: Option<T>,
+    on_selected: impl Fn(T) -> Message + 'a,
+) -> widget::ComboBox<'a, T, Message, Renderer>
+where
+    T: ToString,
+    [T]: ToOwned<Owned = Vec<T>>,
+    Renderer: crate::text::Renderer,
+    Renderer::Theme: widget::text_input::StyleSheet + overlay::menu::StyleSheet,
+{
+    widget::ComboBox::new(options, placeholder, selected, on_selected)
+}
+
+/// Creates a new [`Table`] with the given columns and rows.
+///
+/// [`Table`]: widget::Table
+pub fn table<'a, T, Message, Renderer>(
+    columns: Vec<widget::table::Column<'a, T, Message, Renderer>>,
+    rows: &'a [T],
+) -> widget::Table<'a, T, Message, Renderer>
+where
+    Renderer: crate::text::Renderer,
+    Renderer::Theme: widget::table::StyleSheet + widget::scrollable::StyleSheet,
+{
+    widget::Table::new(columns, rows)
+}
+
+/// Creates a new [`Tabs`] widget with the index of the active tab and its
+/// page.
+///
+/// [`Tabs`]: widget::Tabs
+pub fn tabs<'a, Message, Renderer>(
+    active: usize,
+    content: impl Into<Element<'a, Message, Renderer>>,
+) -> widget::Tabs<'a, Message, Renderer>
+where
+    Renderer: crate::text::Renderer,
+    Renderer::Theme: widget::tabs::StyleSheet,
+{
+    widget::Tabs::new(active, content)
+}
+
+/// Creates a new [`TreeView`] with the given root nodes.
+///
+/// [`TreeView`]: widget::TreeView
+pub fn tree_view<'a, Id, Message, Renderer>(
+    roots: Vec<widget::tree_view::Node<Id>>,
+    children: impl Fn(&Id) -> Vec<widget::tree_view::Node<Id>> + 'a,
+    view: impl Fn(&Id) -> Element<'a, Message, Renderer> + 'a,
+) -> widget::TreeView<'a, Id, Message, Renderer>
+where
+    Id: 'static + Clone + Eq + std::hash::Hash,
+    Renderer: crate::Renderer,
+    Renderer::Theme: widget::tree_view::StyleSheet,
+{
+    widget::TreeView::new(roots, children, view)
+}
+
+/// Creates a new [`MenuBar`] with the given top-level items.
+///
+/// [`MenuBar`]: widget::MenuBar
+pub fn menu_bar<Message, Renderer>(
+    items: Vec<widget::menu_bar::Item<Message>>,
+) -> widget::MenuBar<Message, Renderer>
+where
+    Renderer: crate::text::Renderer,
+    Renderer::Theme: widget::menu_bar::StyleSheet,
+{
+    widget::MenuBar::new(items)
+}
+
+/// Creates a new [`ContextMenu`] that shows the given items when its content
+/// is right-clicked.
+///
+/// [`ContextMenu`]: widget::ContextMenu
+pub fn context_menu<'a, Message, Renderer>(
+    content: impl Into<Element<'a, Message, Renderer>>,
+    items: Vec<widget::context_menu::Item<Message>>,
+) -> widget::ContextMenu<'a, Message, Renderer>
+where
+    Renderer: crate::text::Renderer,
+    Renderer::Theme: widget::context_menu::StyleSheet,
+{
+    widget::ContextMenu::new(content, items)
+}
+
 /// Creates a new [`Image`].
 ///
 /// [`Image`]: widget::Image
@@ -0,0 +1,496 @@
+//! Show the menus of an application in a bar.
+//!
+//! A [`MenuBar`] has some local [`State`].
+use crate::alignment;
+use crate::event::{self, Event};
+use crate::keyboard;
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::overlay::nested_menu::{self, NestedMenu};
+use crate::renderer;
+use crate::text::{self, Text};
+use crate::touch;
+use crate::widget::tree::{self, Tree};
+use crate::{
+    Clipboard, Color, Element, Layout, Length, Padding, Point, Rectangle,
+    Shell, Size, Widget,
+};
+
+pub use iced_style::menu::{Appearance, StyleSheet};
+pub use nested_menu::Item;
+
+/// A bar of menus, like the one at the top of the window of most desktop
+/// applications.
+///
+/// Every top-level [`Item`] with a submenu opens it in an overlay when
+/// pressed. Once a menu is open, hovering another top-level [`Item`] or
+/// pressing the left and right arrow keys switches to its menu.
+///
+/// # Example
+/// ```
+/// # use iced_native::widget::menu_bar::{self, Item};
+/// # use iced_native::renderer::Null;
+/// #
+/// # type MenuBar<Message> = menu_bar::MenuBar<Message, Null>;
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Open,
+///     Save,
+///     ToggleSidebar,
+/// }
+///
+/// let is_sidebar_visible = true;
+///
+/// let menu_bar = MenuBar::new(vec![
+///     Item::submenu(
+///         "File",
+///         vec![
+///             Item::new("Open…").on_press(Message::Open).accelerator("Ctrl+O"),
+///             Item::new("Save").on_press(Message::Save).accelerator("Ctrl+S"),
+///             Item::separator(),
+///             Item::submenu("Recent", vec![Item::new("No recent files")]),
+///         ],
+///     ),
+///     Item::submenu(
+///         "View",
+///         vec![Item::new("Sidebar")
+///             .on_press(Message::ToggleSidebar)
+///             .checked(is_sidebar_visible)],
+///     ),
+/// ]);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct MenuBar<Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    items: Vec<Item<Message>>,
+    width: Length,
+    padding: Padding,
+    menu_width: u16,
+    menu_padding: Padding,
+    text_size: Option<u16>,
+    font: Renderer::Font,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<Message, Renderer> MenuBar<Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// The default [`Padding`] of the items of a [`MenuBar`].
+    pub const DEFAULT_PADDING: Padding = Padding::new(5);
+
+    /// Creates a new [`MenuBar`] with the given top-level items.
+    pub fn new(items: Vec<Item<Message>>) -> Self {
+        MenuBar {
+            items,
+            width: Length::Fill,
+            padding: Self::DEFAULT_PADDING,
+            menu_width: 150,
+            menu_padding: Self::DEFAULT_PADDING,
+            text_size: None,
+            font: Default::default(),
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the width of the [`MenuBar`].
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the [`Padding`] of the top-level items of the [`MenuBar`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the minimum width of the menus of the [`MenuBar`].
+    pub fn menu_width(mut self, width: u16) -> Self {
+        self.menu_width = width;
+        self
+    }
+
+    /// Sets the [`Padding`] of the items of the menus of the [`MenuBar`].
+    pub fn menu_padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.menu_padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`MenuBar`] and its menus.
+    pub fn text_size(mut self, size: u16) -> Self {
+        self.text_size = Some(size);
+        self
+    }
+
+    /// Sets the font of the [`MenuBar`] and its menus.
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`MenuBar`] and its menus.
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+/// The local state of a [`MenuBar`].
+#[derive(Debug, Clone, Default)]
+pub struct State {
+    active: Option<usize>,
+    menu: nested_menu::State,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the index of the top-level item whose menu is open, if any.
+    pub fn active(&self) -> Option<usize> {
+        if self.menu.is_open() {
+            self.active
+        } else {
+            None
+        }
+    }
+
+    fn open(&mut self, index: usize) {
+        self.active = Some(index);
+        self.menu.open();
+    }
+
+    fn close(&mut self) {
+        self.active = None;
+        self.menu.close();
+    }
+}
+
+/// Returns the index of the next top-level item with a menu, wrapping
+/// around the ends of the bar.
+fn step<Message>(
+    items: &[Item<Message>],
+    current: usize,
+    forward: bool,
+) -> Option<usize> {
+    let len = items.len();
+
+    (1..len)
+        .map(|offset| {
+            if forward {
+                (current + offset) % len
+            } else {
+                (current + len - offset) % len
+            }
+        })
+        .find(|&index| {
+            items[index].items().is_some() && items[index].is_enabled()
+        })
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for MenuBar<Message, Renderer>
+where
+    Message: Clone,
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new())
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(Length::Shrink);
+
+        let text_size =
+            self.text_size.unwrap_or_else(|| renderer.default_size());
+        let height = f32::from(text_size + self.padding.vertical());
+
+        let mut x = 0.0;
+
+        let items = self
+            .items
+            .iter()
+            .map(|item| {
+                let width = renderer.measure_width(
+                    item.label(),
+                    text_size,
+                    self.font.clone(),
+                ) + f32::from(self.padding.horizontal());
+
+                let mut node = layout::Node::new(Size::new(width, height));
+                node.move_to(Point::new(x, 0.0));
+                x += width;
+
+                node
+            })
+            .collect();
+
+        let size = limits.resolve(Size::new(x, height));
+
+        layout::Node::with_children(size, items)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let hovered_item = layout
+            .children()
+            .position(|layout| layout.bounds().contains(cursor_position));
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                match hovered_item {
+                    Some(index) => {
+                        let item = &self.items[index];
+
+                        if state.active() == Some(index) {
+                            state.close();
+                        } else if item.items().is_some() {
+                            if item.is_enabled() {
+                                state.open(index);
+                            }
+                        } else if let Some(message) = item.message() {
+                            state.close();
+                            shell.publish(message.clone());
+                        }
+
+                        shell.invalidate_layout();
+
+                        return event::Status::Captured;
+                    }
+                    None if state.menu.is_open() => {
+                        state.close();
+                        shell.invalidate_layout();
+
+                        return event::Status::Captured;
+                    }
+                    None => {}
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                if let (Some(active), Some(index)) =
+                    (state.active(), hovered_item)
+                {
+                    let item = &self.items[index];
+
+                    if active != index
+                        && item.items().is_some()
+                        && item.is_enabled()
+                    {
+                        state.open(index);
+                        shell.invalidate_layout();
+                    }
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code:
+                    key_code @ (keyboard::KeyCode::Left | keyboard::KeyCode::Right),
+                ..
+            }) => {
+                // Only reached when the open menu has ignored the key
+                if let Some(active) = state.active() {
+                    let forward = key_code == keyboard::KeyCode::Right;
+
+                    if let Some(index) = step(&self.items, active, forward) {
+                        state.open(index);
+                        shell.invalidate_layout();
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let is_over_enabled_item = self
+            .items
+            .iter()
+            .zip(layout.children())
+            .any(|(item, layout)| {
+                item.is_enabled() && layout.bounds().contains(cursor_position)
+            });
+
+        if is_over_enabled_item {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let appearance = theme.appearance(&self.style);
+        let bounds = layout.bounds();
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_color: Color::TRANSPARENT,
+                border_width: 0.0,
+                border_radius: 0.0.into(),
+            },
+            appearance.background,
+        );
+
+        let text_size =
+            self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        for (index, (item, layout)) in
+            self.items.iter().zip(layout.children()).enumerate()
+        {
+            let bounds = layout.bounds();
+
+            let is_selected = item.is_enabled()
+                && match state.active() {
+                    Some(active) => active == index,
+                    None => bounds.contains(cursor_position),
+                };
+
+            if is_selected {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border_color: Color::TRANSPARENT,
+                        border_width: 0.0,
+                        border_radius: appearance.border_radius.into(),
+                    },
+                    appearance.selected_background,
+                );
+            }
+
+            renderer.fill_text(Text {
+                content: item.label(),
+                bounds: Rectangle {
+                    x: bounds.center_x(),
+                    y: bounds.center_y(),
+                    ..bounds
+                },
+                size: f32::from(text_size),
+                font: self.font.clone(),
+                color: if !item.is_enabled() {
+                    appearance.disabled_text_color
+                } else if is_selected {
+                    appearance.selected_text_color
+                } else {
+                    appearance.text_color
+                },
+                horizontal_alignment: alignment::Horizontal::Center,
+                vertical_alignment: alignment::Vertical::Center,
+                wrapping: text::Wrapping::default(),
+            });
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let state = tree.state.downcast_mut::<State>();
+        let active = state.active()?;
+
+        let items = self.items.get(active)?.items()?;
+        let bounds = layout.children().nth(active)?.bounds();
+
+        let mut menu = NestedMenu::new(&mut state.menu, items)
+            .width(self.menu_width)
+            .padding(self.menu_padding)
+            .font(self.font.clone())
+            .style(self.style.clone());
+
+        if let Some(text_size) = self.text_size {
+            menu = menu.text_size(text_size);
+        }
+
+        Some(menu.overlay(bounds.position(), bounds.height))
+    }
+}
+
+impl<'a, Message, Renderer> From<MenuBar<Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: Clone + 'a,
+    Renderer: text::Renderer + 'a,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(menu_bar: MenuBar<Message, Renderer>) -> Self {
+        Self::new(menu_bar)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn step_switches_between_menus() {
+        let items: Vec<Item<()>> = vec![
+            Item::submenu("File", vec![Item::new("Open").on_press(())]),
+            Item::new("Help").on_press(()),
+            Item::submenu("Edit", vec![]),
+            Item::submenu("View", vec![Item::new("Zoom").on_press(())]),
+        ];
+
+        assert_eq!(step(&items, 0, true), Some(3));
+        assert_eq!(step(&items, 3, true), Some(0));
+        assert_eq!(step(&items, 0, false), Some(3));
+        assert_eq!(step(&items[..1], 0, true), None);
+    }
+}
@@ -0,0 +1,897 @@
+//! Display fields that can only be filled with numbers.
+//!
+//! A [`NumberInput`] has some local [`State`].
+use crate::alignment;
+use crate::event::{self, Event};
+use crate::keyboard;
+use crate::layout;
+use crate::mouse;
+use crate::renderer;
+use crate::text::{self, Text};
+use crate::touch;
+use crate::widget::operation::Operation;
+use crate::widget::text_input::{self, Value};
+use crate::widget::tree::{self, Tree};
+use crate::{
+    Clipboard, Color, Element, Layout, Length, Padding, Point, Rectangle,
+    Shell, Size, Widget,
+};
+
+use std::ops::RangeInclusive;
+
+pub use iced_style::number_input::{Appearance, StyleSheet};
+
+/// A field that can only be filled with numbers, with buttons to decrease
+/// and increase its value.
+///
+/// The value can also be stepped with the arrow keys while focused, and with
+/// the mouse wheel while focused and hovered. Typed values are only produced when they
+/// fall in the range of the [`NumberInput`], and they are clamped to it once
+/// the field loses focus or is submitted.
+///
+/// Like a [`Slider`], the [`NumberInput`] range of numeric values is generic
+/// and its step size defaults to 1 unit.
+///
+/// # Example
+/// ```
+/// # use iced_native::widget::number_input;
+/// # use iced_native::renderer::Null;
+/// #
+/// # type NumberInput<'a, T, Message> =
+/// #     number_input::NumberInput<'a, T, Message, Null>;
+/// #
+/// #[derive(Clone)]
+/// pub enum Message {
+///     QuantityChanged(u32),
+/// }
+///
+/// let quantity = 3;
+///
+/// NumberInput::new(1..=10, quantity, Message::QuantityChanged);
+/// ```
+///
+/// [`Slider`]: crate::widget::Slider
+#[allow(missing_debug_implementations)]
+pub struct NumberInput<'a, T, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet + text_input::StyleSheet,
+{
+    range: RangeInclusive<T>,
+    step: T,
+    value: T,
+    on_change: Box<dyn Fn(T) -> Message + 'a>,
+    on_submit: Option<Message>,
+    format: Option<Box<dyn Fn(T) -> String + 'a>>,
+    font: Renderer::Font,
+    width: Length,
+    padding: Padding,
+    size: Option<u16>,
+    style: <Renderer::Theme as StyleSheet>::Style,
+    input_style: <Renderer::Theme as text_input::StyleSheet>::Style,
+}
+
+impl<'a, T, Message, Renderer> NumberInput<'a, T, Message, Renderer>
+where
+    T: Copy + From<u8> + std::cmp::PartialOrd,
+    Message: Clone,
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet + text_input::StyleSheet,
+{
+    /// Creates a new [`NumberInput`].
+    ///
+    /// It expects:
+    ///   * an inclusive range of possible values
+    ///   * the current value of the [`NumberInput`]
+    ///   * a function that will be called when the value changes. It
+    ///     receives the new value of the [`NumberInput`] and must produce a
+    ///     `Message`.
+    pub fn new<F>(range: RangeInclusive<T>, value: T, on_change: F) -> Self
+    where
+        F: 'a + Fn(T) -> Message,
+    {
+        let value = if value >= *range.start() {
+            value
+        } else {
+            *range.start()
+        };
+
+        let value = if value <= *range.end() {
+            value
+        } else {
+            *range.end()
+        };
+
+        NumberInput {
+            range,
+            step: T::from(1),
+            value,
+            on_change: Box::new(on_change),
+            on_submit: None,
+            format: None,
+            font: Default::default(),
+            width: Length::Fill,
+            padding: Padding::new(5),
+            size: None,
+            style: Default::default(),
+            input_style: Default::default(),
+        }
+    }
+
+    /// Sets the step size of the [`NumberInput`].
+    pub fn step(mut self, step: T) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Sets the message that should be produced when the [`NumberInput`] is
+    /// focused and the enter key is pressed.
+    pub fn on_submit(mut self, message: Message) -> Self {
+        self.on_submit = Some(message);
+        self
+    }
+
+    /// Sets the function used to display the value of the [`NumberInput`]
+    /// while it is not being edited.
+    ///
+    /// By default, the value is displayed with as many decimals as the step
+    /// size has.
+    pub fn format(mut self, format: impl Fn(T) -> String + 'a) -> Self {
+        self.format = Some(Box::new(format));
+        self
+    }
+
+    /// Sets the [`Font`] of the [`NumberInput`].
+    ///
+    /// [`Font`]: text::Renderer::Font
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the width of the [`NumberInput`].
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`NumberInput`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`NumberInput`].
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the style of the buttons of the [`NumberInput`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the style of the field of the [`NumberInput`].
+    pub fn input_style(
+        mut self,
+        style: impl Into<<Renderer::Theme as text_input::StyleSheet>::Style>,
+    ) -> Self {
+        self.input_style = style.into();
+        self
+    }
+}
+
+impl<'a, T, Message, Renderer> NumberInput<'a, T, Message, Renderer>
+where
+    T: Copy + Into<f64> + num_traits::FromPrimitive,
+    Message: Clone,
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet + text_input::StyleSheet,
+{
+    /// Returns the amount of decimals needed to represent the step size.
+    fn decimals(&self) -> usize {
+        let step = self.step.into().abs();
+
+        (0..MAX_DECIMALS)
+            .find(|decimals| {
+                let scaled = step * 10f64.powi(*decimals as i32);
+
+                (scaled - scaled.round()).abs() < 1e-6 * scaled.max(1.0)
+            })
+            .unwrap_or(MAX_DECIMALS)
+    }
+
+    /// Returns the editable representation of the given value.
+    fn plain(&self, value: T) -> String {
+        format!("{:.*}", self.decimals(), value.into())
+    }
+
+    /// Returns the displayed representation of the given value.
+    fn display(&self, value: T) -> String {
+        match &self.format {
+            Some(format) => format(value),
+            None => self.plain(value),
+        }
+    }
+
+    fn contains(&self, value: f64) -> bool {
+        (*self.range.start()).into() <= value
+            && value <= (*self.range.end()).into()
+    }
+
+    fn clamp(&self, value: f64) -> f64 {
+        value
+            .max((*self.range.start()).into())
+            .min((*self.range.end()).into())
+    }
+
+    /// Returns the value after moving the given amount of steps, if it
+    /// changes.
+    fn stepped(&self, steps: f64) -> Option<T> {
+        let start = (*self.range.start()).into();
+        let step = self.step.into();
+        let current = self.value.into();
+
+        let value = self.clamp(current + steps * step);
+        let value = start + ((value - start) / step).round() * step;
+        let value = self.clamp(value);
+
+        if value == current {
+            None
+        } else {
+            T::from_f64(value)
+        }
+    }
+
+    /// Steps the value of the [`NumberInput`], updating the field if it is
+    /// being edited.
+    ///
+    /// Returns whether the value changed.
+    fn step_by(
+        &self,
+        steps: f64,
+        state: &mut State,
+        shell: &mut Shell<'_, Message>,
+    ) -> bool {
+        match self.stepped(steps) {
+            Some(value) => {
+                shell.publish((self.on_change)(value));
+
+                if state.input.is_focused() {
+                    state.text = Value::new(&self.plain(value));
+                    state.input.move_cursor_to_end();
+                }
+
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Parses the given text as a value of the [`NumberInput`].
+    fn parse(&self, text: &str) -> Option<f64> {
+        text.trim()
+            .parse::<f64>()
+            .ok()
+            .filter(|value| value.is_finite())
+    }
+
+    /// Clamps the text being edited to the range of the [`NumberInput`],
+    /// producing the resulting value if it changed.
+    fn commit(&self, state: &mut State, shell: &mut Shell<'_, Message>) {
+        let value = self
+            .parse(&state.text.to_string())
+            .map(|value| self.clamp(value))
+            .and_then(T::from_f64)
+            .unwrap_or(self.value);
+
+        if value.into() != self.value.into() {
+            shell.publish((self.on_change)(value));
+        }
+
+        state.text = Value::new(&self.plain(value));
+    }
+
+    fn is_enabled(&self, button: Button) -> bool {
+        let bound = match button {
+            Button::Decrease => *self.range.start(),
+            Button::Increase => *self.range.end(),
+        };
+
+        self.value.into() != bound.into()
+    }
+}
+
+impl<'a, T, Message, Renderer> Widget<Message, Renderer>
+    for NumberInput<'a, T, Message, Renderer>
+where
+    T: Copy + Into<f64> + num_traits::FromPrimitive,
+    Message: Clone,
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet + text_input::StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new())
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let text_size = self.size.unwrap_or_else(|| renderer.default_size());
+        let button_size =
+            f32::from(text_size) + f32::from(self.padding.vertical());
+
+        let limits = limits.width(self.width).height(Length::Shrink);
+
+        let input = text_input::layout(
+            renderer,
+            &limits.shrink(Size::new(2.0 * button_size, 0.0)),
+            self.width,
+            self.padding,
+            self.size,
+        );
+
+        let input_size = input.size();
+
+        let mut decrease =
+            layout::Node::new(Size::new(button_size, input_size.height));
+        decrease.move_to(Point::new(input_size.width, 0.0));
+
+        let mut increase =
+            layout::Node::new(Size::new(button_size, input_size.height));
+        increase.move_to(Point::new(input_size.width + button_size, 0.0));
+
+        layout::Node::with_children(
+            limits.resolve(Size::new(
+                input_size.width + 2.0 * button_size,
+                input_size.height,
+            )),
+            vec![input, decrease, increase],
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        _layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        operation.focusable(&mut state.input, None);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        let mut children = layout.children();
+        let input_layout = children.next().unwrap();
+        let decrease_layout = children.next().unwrap();
+        let increase_layout = children.next().unwrap();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let button =
+                    if decrease_layout.bounds().contains(cursor_position) {
+                        Some(Button::Decrease)
+                    } else if increase_layout.bounds().contains(cursor_position)
+                    {
+                        Some(Button::Increase)
+                    } else {
+                        None
+                    };
+
+                if let Some(button) = button {
+                    state.pressed = Some(button);
+
+                    let _ = self.step_by(button.direction(), state, shell);
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                state.pressed = None;
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if state.input.is_focused()
+                    && layout.bounds().contains(cursor_position) =>
+            {
+                let y = match delta {
+                    mouse::ScrollDelta::Lines { y, .. }
+                    | mouse::ScrollDelta::Pixels { y, .. } => y,
+                };
+
+                if y != 0.0 {
+                    let _ = self.step_by(y.signum().into(), state, shell);
+                }
+
+                return event::Status::Captured;
+            }
+            // Keys that can not step past the bounds are left to others
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Up,
+                ..
+            }) if state.input.is_focused() => {
+                if self.step_by(1.0, state, shell) {
+                    return event::Status::Captured;
+                }
+
+                return event::Status::Ignored;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Down,
+                ..
+            }) if state.input.is_focused() => {
+                if self.step_by(-1.0, state, shell) {
+                    return event::Status::Captured;
+                }
+
+                return event::Status::Ignored;
+            }
+            _ => {}
+        }
+
+        let was_focused = state.input.is_focused();
+
+        if !was_focused {
+            state.text = Value::new(&self.plain(self.value));
+        }
+
+        let allows_decimals = self.decimals() > 0;
+
+        let constraints = text_input::Constraints {
+            filter: Some(Box::new(move |c: char| {
+                c.is_ascii_digit() || c == '-' || (allows_decimals && c == '.')
+            })),
+            ..text_input::Constraints::default()
+        };
+
+        let mut edits = Vec::new();
+        let mut input_shell = Shell::new(&mut edits);
+
+        let status = text_input::update(
+            event,
+            input_layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            &mut input_shell,
+            &mut state.text,
+            self.size,
+            &self.font,
+            false,
+            &constraints,
+            &Edit::Changed,
+            None,
+            &Some(Edit::Submitted),
+            || &mut state.input,
+        );
+
+        if let Some(request) = input_shell.redraw_request() {
+            shell.request_redraw(request);
+        }
+
+        shell.request_input_method(input_shell.input_method());
+
+        if input_shell.is_layout_invalid() {
+            shell.invalidate_layout();
+        }
+
+        if input_shell.are_widgets_invalid() {
+            shell.invalidate_widgets();
+        }
+
+        for edit in edits {
+            match edit {
+                Edit::Changed(text) => {
+                    let value = self
+                        .parse(&text)
+                        .filter(|value| self.contains(*value))
+                        .and_then(T::from_f64);
+
+                    if let Some(value) = value {
+                        if value.into() != self.value.into() {
+                            shell.publish((self.on_change)(value));
+                        }
+                    }
+                }
+                Edit::Submitted => {
+                    self.commit(state, shell);
+
+                    if let Some(on_submit) = self.on_submit.clone() {
+                        shell.publish(on_submit);
+                    }
+                }
+            }
+        }
+
+        if was_focused && !state.input.is_focused() {
+            self.commit(state, shell);
+        }
+
+        status
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+
+        let mut children = layout.children();
+        let input_layout = children.next().unwrap();
+        let decrease_layout = children.next().unwrap();
+        let increase_layout = children.next().unwrap();
+
+        let value = if state.input.is_focused() {
+            state.text.clone()
+        } else {
+            Value::new(&self.display(self.value))
+        };
+
+        text_input::draw(
+            renderer,
+            theme,
+            input_layout,
+            cursor_position,
+            &state.input,
+            &value,
+            "",
+            self.size,
+            &self.font,
+            false,
+            &self.input_style,
+        );
+
+        for (button, layout) in [
+            (Button::Decrease, decrease_layout),
+            (Button::Increase, increase_layout),
+        ] {
+            let bounds = layout.bounds();
+
+            let appearance = if !self.is_enabled(button) {
+                theme.disabled(&self.style)
+            } else if state.pressed == Some(button) {
+                theme.pressed(&self.style)
+            } else if bounds.contains(cursor_position) {
+                theme.hovered(&self.style)
+            } else {
+                theme.active(&self.style)
+            };
+
+            if let Some(background) = appearance.button_background {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border_radius: appearance.border_radius.into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    background,
+                );
+            }
+
+            renderer.fill_text(Text {
+                content: button.icon(),
+                bounds: Rectangle {
+                    x: bounds.center_x(),
+                    y: bounds.center_y(),
+                    ..bounds
+                },
+                size: f32::from(
+                    self.size.unwrap_or_else(|| renderer.default_size()),
+                ),
+                color: appearance.icon_color,
+                font: self.font.clone(),
+                horizontal_alignment: alignment::Horizontal::Center,
+                vertical_alignment: alignment::Vertical::Center,
+                wrapping: text::Wrapping::default(),
+            });
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let mut children = layout.children();
+        let input_layout = children.next().unwrap();
+
+        for button in [Button::Decrease, Button::Increase] {
+            let layout = children.next().unwrap();
+
+            if layout.bounds().contains(cursor_position) {
+                return if self.is_enabled(button) {
+                    mouse::Interaction::Pointer
+                } else {
+                    mouse::Interaction::default()
+                };
+            }
+        }
+
+        text_input::mouse_interaction(input_layout, cursor_position)
+    }
+}
+
+impl<'a, T, Message, Renderer> From<NumberInput<'a, T, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    T: 'a + Copy + Into<f64> + num_traits::FromPrimitive,
+    Message: 'a + Clone,
+    Renderer: 'a + text::Renderer,
+    Renderer::Theme: StyleSheet + text_input::StyleSheet,
+{
+    fn from(
+        number_input: NumberInput<'a, T, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(number_input)
+    }
+}
+
+/// The local state of a [`NumberInput`].
+#[derive(Debug, Clone)]
+pub struct State {
+    input: text_input::State,
+    text: Value,
+    pressed: Option<Button>,
+}
+
+impl State {
+    /// Creates a new [`State`], representing an unfocused [`NumberInput`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns whether the [`NumberInput`] is currently focused or not.
+    pub fn is_focused(&self) -> bool {
+        self.input.is_focused()
+    }
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self {
+            input: text_input::State::new(),
+            text: Value::new(""),
+            pressed: None,
+        }
+    }
+}
+
+/// The maximum amount of decimals displayed by default.
+const MAX_DECIMALS: usize = 6;
+
+/// A button of a [`NumberInput`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Button {
+    Decrease,
+    Increase,
+}
+
+impl Button {
+    fn direction(self) -> f64 {
+        match self {
+            Button::Decrease => -1.0,
+            Button::Increase => 1.0,
+        }
+    }
+
+    fn icon(self) -> &'static str {
+        match self {
+            Button::Decrease => "-",
+            Button::Increase => "+",
+        }
+    }
+}
+
+/// An edit of the field of a [`NumberInput`].
+#[derive(Debug, Clone)]
+enum Edit {
+    Changed(String),
+    Submitted,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::clipboard;
+    use crate::renderer::Null;
+
+    type NumberInput<'a, T> = super::NumberInput<'a, T, T, Null>;
+
+    fn scroll<T>(number_input: &mut NumberInput<'_, T>, state: State) -> Vec<T>
+    where
+        T: Copy + Into<f64> + num_traits::FromPrimitive,
+    {
+        let (_, messages) = send(
+            number_input,
+            state,
+            Event::Mouse(mouse::Event::WheelScrolled {
+                delta: mouse::ScrollDelta::Lines { x: 0.0, y: 1.0 },
+            }),
+        );
+
+        messages
+    }
+
+    fn send<T>(
+        number_input: &mut NumberInput<'_, T>,
+        state: State,
+        event: Event,
+    ) -> (event::Status, Vec<T>)
+    where
+        T: Copy + Into<f64> + num_traits::FromPrimitive,
+    {
+        let renderer = Null::new();
+        let node = Widget::<T, Null>::layout(
+            number_input,
+            &renderer,
+            &layout::Limits::new(Size::ZERO, Size::new(200.0, 100.0)),
+        );
+
+        let mut tree = Tree::empty();
+        tree.state = tree::State::new(state);
+
+        let mut messages = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+
+        let status = number_input.on_event(
+            &mut tree,
+            event,
+            Layout::new(&node),
+            Point::new(10.0, 10.0),
+            &renderer,
+            &mut clipboard::Null,
+            &mut shell,
+        );
+
+        (status, messages)
+    }
+
+    #[test]
+    fn steps_within_bounds() {
+        let number_input = NumberInput::new(0..=10, 9, |value| value);
+
+        assert_eq!(number_input.stepped(1.0), Some(10));
+        assert_eq!(number_input.stepped(5.0), Some(10));
+        assert_eq!(number_input.stepped(-20.0), Some(0));
+
+        let number_input = NumberInput::new(0..=10, 10, |value| value);
+
+        assert_eq!(number_input.stepped(1.0), None);
+    }
+
+    #[test]
+    fn steps_by_the_step_size() {
+        let number_input =
+            NumberInput::new(0.0..=1.0, 0.5, |value: f64| value).step(0.25);
+
+        assert_eq!(number_input.stepped(1.0), Some(0.75));
+        assert_eq!(number_input.stepped(-1.0), Some(0.25));
+        assert_eq!(number_input.plain(0.25), "0.25");
+    }
+
+    #[test]
+    fn clamps_committed_values_to_bounds() {
+        let number_input = NumberInput::new(0..=10, 5, |value| value);
+
+        let mut state = State::new();
+        state.text = Value::new("42");
+
+        let mut messages = Vec::new();
+        number_input.commit(&mut state, &mut Shell::new(&mut messages));
+
+        assert_eq!(messages, [10]);
+        assert_eq!(state.text.to_string(), "10");
+    }
+
+    #[test]
+    fn reverts_invalid_input() {
+        let number_input = NumberInput::new(0..=10, 5, |value| value);
+
+        assert_eq!(number_input.parse(" 7 "), Some(7.0));
+        assert_eq!(number_input.parse("abc"), None);
+        assert_eq!(number_input.parse("-"), None);
+        assert_eq!(number_input.parse("inf"), None);
+
+        let mut state = State::new();
+        state.text = Value::new("1-2");
+
+        let mut messages = Vec::new();
+        number_input.commit(&mut state, &mut Shell::new(&mut messages));
+
+        assert!(messages.is_empty());
+        assert_eq!(state.text.to_string(), "5");
+    }
+
+    #[test]
+    fn steps_with_the_wheel_only_while_focused() {
+        let mut number_input = NumberInput::new(0..=10, 5, |value| value);
+
+        assert!(scroll(&mut number_input, State::new()).is_empty());
+
+        let mut state = State::new();
+        state.input.focus();
+
+        assert_eq!(scroll(&mut number_input, state), [6]);
+    }
+
+    #[test]
+    fn ignores_arrow_keys_at_the_bounds() {
+        let press = |value, key_code| {
+            let mut number_input =
+                NumberInput::new(0..=10, value, |value| value);
+
+            let mut state = State::new();
+            state.input.focus();
+
+            send(
+                &mut number_input,
+                state,
+                Event::Keyboard(keyboard::Event::KeyPressed {
+                    key_code,
+                    modifiers: keyboard::Modifiers::default(),
+                }),
+            )
+        };
+
+        assert_eq!(
+            press(10, keyboard::KeyCode::Up),
+            (event::Status::Ignored, vec![])
+        );
+        assert_eq!(
+            press(10, keyboard::KeyCode::Down),
+            (event::Status::Captured, vec![9])
+        );
+        assert_eq!(
+            press(0, keyboard::KeyCode::Down),
+            (event::Status::Ignored, vec![])
+        );
+        assert_eq!(
+            press(0, keyboard::KeyCode::Up),
+            (event::Status::Captured, vec![1])
+        );
+    }
+}
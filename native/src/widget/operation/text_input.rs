@@ -12,6 +12,10 @@ pub trait TextInput {
     fn move_cursor_to(&mut self, position: usize);
     /// Selects all the content of the text input.
     fn select_all(&mut self);
+    /// Reverts the last group of edits of the text input.
+    fn undo(&mut self);
+    /// Reapplies the last group of edits of the text input that was undone.
+    fn redo(&mut self);
 }
 
 /// Produces an [`Operation`] that moves the cursor of the widget with the given [`Id`] to the
@@ -129,3 +133,61 @@ pub fn select_all<T>(target: Id) -> impl Operation<T> {
 
     MoveCursor { target }
 }
+
+/// Produces an [`Operation`] that reverts the last group of edits of the widget with the given
+/// [`Id`].
+pub fn undo<T>(target: Id) -> impl Operation<T> {
+    struct Undo {
+        target: Id,
+    }
+
+    impl<T> Operation<T> for Undo {
+        fn text_input(&mut self, state: &mut dyn TextInput, id: Option<&Id>) {
+            match id {
+                Some(id) if id == &self.target => {
+                    state.undo();
+                }
+                _ => {}
+            }
+        }
+
+        fn container(
+            &mut self,
+            _id: Option<&Id>,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            operate_on_children(self)
+        }
+    }
+
+    Undo { target }
+}
+
+/// Produces an [`Operation`] that reapplies the last group of edits that was undone in the widget
+/// with the given [`Id`].
+pub fn redo<T>(target: Id) -> impl Operation<T> {
+    struct Redo {
+        target: Id,
+    }
+
+    impl<T> Operation<T> for Redo {
+        fn text_input(&mut self, state: &mut dyn TextInput, id: Option<&Id>) {
+            match id {
+                Some(id) if id == &self.target => {
+                    state.redo();
+                }
+                _ => {}
+            }
+        }
+
+        fn container(
+            &mut self,
+            _id: Option<&Id>,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            operate_on_children(self)
+        }
+    }
+
+    Redo { target }
+}
@@ -315,6 +315,7 @@ where
                     text_size,
                     font.clone(),
                     Size::new(f32::INFINITY, f32::INFINITY),
+                    text::Wrapping::None,
                 );
 
                 width.round() as u32
@@ -553,6 +554,7 @@ pub fn draw<T, Renderer>(
         color: style.text_color,
         horizontal_alignment: alignment::Horizontal::Right,
         vertical_alignment: alignment::Vertical::Center,
+        wrapping: text::Wrapping::default(),
     });
 
     let label = selected.map(ToString::to_string);
@@ -578,6 +580,7 @@ pub fn draw<T, Renderer>(
             },
             horizontal_alignment: alignment::Horizontal::Left,
             vertical_alignment: alignment::Vertical::Top,
+            wrapping: text::Wrapping::default(),
         });
     }
 }
@@ -285,6 +285,7 @@ where
                 },
                 alignment::Horizontal::Left,
                 alignment::Vertical::Center,
+                text::Wrapping::default(),
             );
         }
     }
@@ -0,0 +1,346 @@
+//! Write paragraphs that mix different styles and links.
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::renderer;
+use crate::text;
+use crate::touch;
+use crate::widget::tree::{self, Tree};
+use crate::{
+    alignment, Clipboard, Color, Element, Layout, Length, Point, Rectangle,
+    Shell, Size, Widget,
+};
+
+use std::borrow::Cow;
+
+pub use iced_style::text::{Appearance, StyleSheet};
+
+/// A paragraph made of [`Span`]s with their own size, color and font, which
+/// are wrapped together.
+///
+/// # Example
+///
+/// ```
+/// # use iced_native::Color;
+/// # use iced_native::widget::rich_text::Span;
+/// #
+/// # type RichText<'a, Message> =
+/// #     iced_native::widget::RichText<'a, Message, iced_native::renderer::Null>;
+/// #
+/// #[derive(Clone)]
+/// enum Message {
+///     LinkPressed,
+/// }
+///
+/// let text = RichText::new(vec![
+///     Span::new("Read the "),
+///     Span::new("documentation")
+///         .color(Color::from_rgb(0.2, 0.4, 1.0))
+///         .link(Message::LinkPressed),
+///     Span::new(" to learn more!"),
+/// ]);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct RichText<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    spans: Vec<Span<'a, Message, Renderer::Font>>,
+    size: Option<u16>,
+    font: Renderer::Font,
+    width: Length,
+    height: Length,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> RichText<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`RichText`] with the given [`Span`]s.
+    pub fn new(spans: Vec<Span<'a, Message, Renderer::Font>>) -> Self {
+        RichText {
+            spans,
+            size: None,
+            font: Default::default(),
+            width: Length::Shrink,
+            height: Length::Shrink,
+            style: Default::default(),
+        }
+    }
+
+    /// Adds a [`Span`] to the end of the [`RichText`].
+    pub fn push(mut self, span: Span<'a, Message, Renderer::Font>) -> Self {
+        self.spans.push(span);
+        self
+    }
+
+    /// Sets the default size of the [`Span`]s of the [`RichText`].
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the default [`Font`] of the [`Span`]s of the [`RichText`].
+    ///
+    /// [`Font`]: crate::text::Renderer::Font
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = font.into();
+        self
+    }
+
+    /// Sets the width of the [`RichText`] boundaries.
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`RichText`] boundaries.
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the style of the [`RichText`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    fn text_spans(
+        &self,
+        renderer: &Renderer,
+    ) -> Vec<text::Span<'_, Renderer::Font>> {
+        let size = self.size.unwrap_or_else(|| renderer.default_size());
+
+        self.spans
+            .iter()
+            .map(|span| text::Span {
+                content: &span.content,
+                size: f32::from(span.size.unwrap_or(size)),
+                color: span.color,
+                font: span.font.clone().unwrap_or_else(|| self.font.clone()),
+            })
+            .collect()
+    }
+
+    /// Returns the index of the link [`Span`] under the cursor, if any.
+    fn link_at(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+    ) -> Option<usize> {
+        let bounds = layout.bounds();
+
+        if !bounds.contains(cursor_position) {
+            return None;
+        }
+
+        let index = renderer.hit_span(
+            &self.text_spans(renderer),
+            bounds.size(),
+            Point::new(
+                cursor_position.x - bounds.x,
+                cursor_position.y - bounds.y,
+            ),
+        )?;
+
+        self.spans[index].link.is_some().then_some(index)
+    }
+}
+
+/// A run of text of a [`RichText`] with its own style, which can be a link.
+#[allow(missing_debug_implementations)]
+pub struct Span<'a, Message, Font> {
+    content: Cow<'a, str>,
+    size: Option<u16>,
+    color: Option<Color>,
+    font: Option<Font>,
+    link: Option<Message>,
+}
+
+impl<'a, Message, Font> Span<'a, Message, Font> {
+    /// Creates a new [`Span`] with the given content.
+    pub fn new(content: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            content: content.into(),
+            size: None,
+            color: None,
+            font: None,
+            link: None,
+        }
+    }
+
+    /// Sets the size of the [`Span`].
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the color of the [`Span`].
+    pub fn color(mut self, color: impl Into<Color>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Sets the font of the [`Span`].
+    pub fn font(mut self, font: impl Into<Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Turns the [`Span`] into a link that produces the given message when
+    /// pressed.
+    pub fn link(mut self, message: Message) -> Self {
+        self.link = Some(message);
+        self
+    }
+}
+
+/// The local state of a [`RichText`].
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    pressed_link: Option<usize>,
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for RichText<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        let (width, height) =
+            renderer.measure_spans(&self.text_spans(renderer), limits.max());
+
+        let size = limits.resolve(Size::new(width, height));
+
+        layout::Node::new(size)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some(index) =
+                    self.link_at(layout, cursor_position, renderer)
+                {
+                    state.pressed_link = Some(index);
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. }) => {
+                if let Some(index) = state.pressed_link.take() {
+                    if self.link_at(layout, cursor_position, renderer)
+                        == Some(index)
+                    {
+                        if let Some(message) = &self.spans[index].link {
+                            shell.publish(message.clone());
+                        }
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Touch(touch::Event::FingerLost { .. }) => {
+                state.pressed_link = None;
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self.link_at(layout, cursor_position, renderer).is_some() {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let appearance = theme.appearance(self.style);
+        let spans = self.text_spans(renderer);
+
+        renderer.fill_rich_text(text::RichText {
+            spans: &spans,
+            bounds: layout.bounds(),
+            color: appearance.color.unwrap_or(style.text_color),
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Top,
+        });
+    }
+}
+
+impl<'a, Message, Renderer> From<RichText<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: Clone + 'a,
+    Renderer: text::Renderer + 'a,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(
+        rich_text: RichText<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(rich_text)
+    }
+}
@@ -1,5 +1,6 @@
 //! Navigate an endless amount of content with a scrollbar.
 use crate::event::{self, Event};
+use crate::keyboard;
 use crate::layout;
 use crate::mouse;
 use crate::overlay;
@@ -16,6 +17,7 @@ use crate::{
 use std::{f32, u32};
 
 pub use iced_style::scrollable::StyleSheet;
+pub use operation::scrollable::RelativeOffset;
 
 pub mod style {
     //! The styles of a [`Scrollable`].
@@ -24,8 +26,8 @@ pub mod style {
     pub use iced_style::scrollable::{Scrollbar, Scroller};
 }
 
-/// A widget that can vertically display an infinite amount of content with a
-/// scrollbar.
+/// A widget that can display an infinite amount of content with vertical
+/// and horizontal scrollbars.
 #[allow(missing_debug_implementations)]
 pub struct Scrollable<'a, Message, Renderer>
 where
@@ -34,11 +36,12 @@ where
 {
     id: Option<Id>,
     height: Length,
+    direction: Direction,
     scrollbar_width: u16,
     scrollbar_margin: u16,
     scroller_width: u16,
     content: Element<'a, Message, Renderer>,
-    on_scroll: Option<Box<dyn Fn(f32) -> Message + 'a>>,
+    on_scroll: Option<Box<dyn Fn(RelativeOffset) -> Message + 'a>>,
     style: <Renderer::Theme as StyleSheet>::Style,
 }
 
@@ -52,6 +55,7 @@ where
         Scrollable {
             id: None,
             height: Length::Shrink,
+            direction: Direction::default(),
             scrollbar_width: 10,
             scrollbar_margin: 0,
             scroller_width: 10,
@@ -73,6 +77,12 @@ where
         self
     }
 
+    /// Sets the [`Direction`] in which the [`Scrollable`] can scroll.
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+        self
+    }
+
     /// Sets the scrollbar width of the [`Scrollable`] .
     /// Silently enforces a minimum value of 1.
     pub fn scrollbar_width(mut self, scrollbar_width: u16) -> Self {
@@ -96,9 +106,11 @@ where
 
     /// Sets a function to call when the [`Scrollable`] is scrolled.
     ///
-    /// The function takes the new relative offset of the [`Scrollable`]
-    /// (e.g. `0` means top, while `1` means bottom).
-    pub fn on_scroll(mut self, f: impl Fn(f32) -> Message + 'a) -> Self {
+    /// The function takes the new [`RelativeOffset`] of the [`Scrollable`].
+    pub fn on_scroll(
+        mut self,
+        f: impl Fn(RelativeOffset) -> Message + 'a,
+    ) -> Self {
         self.on_scroll = Some(Box::new(f));
         self
     }
@@ -154,6 +166,7 @@ where
             Widget::<Message, Renderer>::width(self),
             self.height,
             u32::MAX,
+            self.direction,
             |renderer, limits| {
                 self.content.as_widget().layout(renderer, limits)
             },
@@ -167,9 +180,21 @@ where
         operation: &mut dyn Operation<Message>,
     ) {
         let state = tree.state.downcast_mut::<State>();
+        let (offset_x, offset_y) = (state.offset_x, state.offset_y);
 
         operation.scrollable(state, self.id.as_ref().map(|id| &id.0));
 
+        // Keep the offsets along the axes that the direction does not scroll
+        if !self.direction.is_horizontal() {
+            state.offset_x = offset_x;
+        }
+
+        if !self.direction.is_vertical() {
+            state.offset_y = offset_y;
+        }
+
+        operation.bounds(layout.bounds(), self.id.as_ref().map(|id| &id.0));
+
         operation.container(None, &mut |operation| {
             self.content.as_widget().operate(
                 &mut tree.children[0],
@@ -196,6 +221,7 @@ where
             cursor_position,
             clipboard,
             shell,
+            self.direction,
             self.scrollbar_width,
             self.scrollbar_margin,
             self.scroller_width,
@@ -230,6 +256,7 @@ where
             theme,
             layout,
             cursor_position,
+            self.direction,
             self.scrollbar_width,
             self.scrollbar_margin,
             self.scroller_width,
@@ -260,6 +287,7 @@ where
             tree.state.downcast_ref::<State>(),
             layout,
             cursor_position,
+            self.direction,
             self.scrollbar_width,
             self.scrollbar_margin,
             self.scroller_width,
@@ -297,7 +325,7 @@ where
                     .downcast_ref::<State>()
                     .offset(bounds, content_bounds);
 
-                overlay.translate(Vector::new(0.0, -(offset as f32)))
+                overlay.translate(Vector::new(-offset.x, -offset.y))
             })
     }
 }
@@ -341,9 +369,50 @@ impl From<Id> for widget::Id {
 }
 
 /// Produces a [`Command`] that snaps the [`Scrollable`] with the given [`Id`]
-/// to the provided `percentage`.
-pub fn snap_to<Message: 'static>(id: Id, percentage: f32) -> Command<Message> {
-    Command::widget(operation::scrollable::snap_to(id.0, percentage))
+/// to the provided [`RelativeOffset`].
+pub fn snap_to<Message: 'static>(
+    id: Id,
+    offset: RelativeOffset,
+) -> Command<Message> {
+    Command::widget(operation::scrollable::snap_to(id.0, offset))
+}
+
+/// The direction in which a [`Scrollable`] can scroll.
+///
+/// The content of a [`Scrollable`] is laid out with unbounded space along
+/// the directions it can scroll, so it should not fill them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Direction {
+    /// Scroll vertically only.
+    #[default]
+    Vertical,
+
+    /// Scroll horizontally only.
+    Horizontal,
+
+    /// Scroll both vertically and horizontally.
+    Both,
+}
+
+impl Direction {
+    /// Returns whether the [`Direction`] allows scrolling vertically.
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Self::Vertical | Self::Both)
+    }
+
+    /// Returns whether the [`Direction`] allows scrolling horizontally.
+    pub fn is_horizontal(self) -> bool {
+        matches!(self, Self::Horizontal | Self::Both)
+    }
+
+    /// Discards the components of a scrolling delta that the [`Direction`]
+    /// does not allow.
+    fn align(self, delta: Vector) -> Vector {
+        Vector::new(
+            if self.is_horizontal() { delta.x } else { 0.0 },
+            if self.is_vertical() { delta.y } else { 0.0 },
+        )
+    }
 }
 
 /// Computes the layout of a [`Scrollable`].
@@ -353,13 +422,36 @@ pub fn layout<Renderer>(
     width: Length,
     height: Length,
     max_height: u32,
+    direction: Direction,
     layout_content: impl FnOnce(&Renderer, &layout::Limits) -> layout::Node,
 ) -> layout::Node {
     let limits = limits.max_height(max_height).width(width).height(height);
 
     let child_limits = layout::Limits::new(
-        Size::new(limits.min().width, 0.0),
-        Size::new(limits.max().width, f32::INFINITY),
+        Size::new(
+            if direction.is_horizontal() {
+                0.0
+            } else {
+                limits.min().width
+            },
+            if direction.is_vertical() {
+                0.0
+            } else {
+                limits.min().height
+            },
+        ),
+        Size::new(
+            if direction.is_horizontal() {
+                f32::INFINITY
+            } else {
+                limits.max().width
+            },
+            if direction.is_vertical() {
+                f32::INFINITY
+            } else {
+                limits.max().height
+            },
+        ),
     );
 
     let content = layout_content(renderer, &child_limits);
@@ -377,10 +469,11 @@ pub fn update<Message>(
     cursor_position: Point,
     clipboard: &mut dyn Clipboard,
     shell: &mut Shell<'_, Message>,
+    direction: Direction,
     scrollbar_width: u16,
     scrollbar_margin: u16,
     scroller_width: u16,
-    on_scroll: &Option<Box<dyn Fn(f32) -> Message + '_>>,
+    on_scroll: &Option<Box<dyn Fn(RelativeOffset) -> Message + '_>>,
     update_content: impl FnOnce(
         Event,
         Layout<'_>,
@@ -395,25 +488,23 @@ pub fn update<Message>(
     let content = layout.children().next().unwrap();
     let content_bounds = content.bounds();
 
-    let scrollbar = scrollbar(
+    let scrollbars = Scrollbars::new(
         state,
+        direction,
         scrollbar_width,
         scrollbar_margin,
         scroller_width,
         bounds,
         content_bounds,
     );
-    let is_mouse_over_scrollbar = scrollbar
-        .as_ref()
-        .map(|scrollbar| scrollbar.is_mouse_over(cursor_position))
-        .unwrap_or(false);
+    let (is_mouse_over_y_scrollbar, is_mouse_over_x_scrollbar) =
+        scrollbars.is_mouse_over(cursor_position);
 
     let event_status = {
-        let cursor_position = if is_mouse_over && !is_mouse_over_scrollbar {
-            Point::new(
-                cursor_position.x,
-                cursor_position.y + state.offset(bounds, content_bounds) as f32,
-            )
+        let cursor_position = if is_mouse_over
+            && !(is_mouse_over_y_scrollbar || is_mouse_over_x_scrollbar)
+        {
+            cursor_position + state.offset(bounds, content_bounds)
         } else {
             // TODO: Make `cursor_position` an `Option<Point>` so we can encode
             // cursor availability.
@@ -435,18 +526,46 @@ pub fn update<Message>(
         return event::Status::Captured;
     }
 
+    if let Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) = event
+    {
+        state.keyboard_modifiers = modifiers;
+
+        return event::Status::Ignored;
+    }
+
     if is_mouse_over {
         match event {
             Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
-                match delta {
-                    mouse::ScrollDelta::Lines { y, .. } => {
+                let delta = match delta {
+                    mouse::ScrollDelta::Lines { x, y } => {
+                        // macOS already turns the wheel of the mouse into
+                        // horizontal scrolling when Shift is pressed
+                        let (x, y) = if state.keyboard_modifiers.shift()
+                            && !cfg!(target_os = "macos")
+                        {
+                            (y, x)
+                        } else {
+                            (x, y)
+                        };
+
                         // TODO: Configurable speed (?)
-                        state.scroll(y * 60.0, bounds, content_bounds);
-                    }
-                    mouse::ScrollDelta::Pixels { y, .. } => {
-                        state.scroll(y, bounds, content_bounds);
+                        Vector::new(x * 60.0, y * 60.0)
                     }
-                }
+                    mouse::ScrollDelta::Pixels { x, y } => Vector::new(x, y),
+                };
+
+                // A plain wheel scrolls horizontally when it is the only
+                // axis that can scroll
+                let scrolls_vertically = direction.is_vertical()
+                    && bounds.height < content_bounds.height;
+
+                let delta = if !scrolls_vertically && delta.x == 0.0 {
+                    Vector::new(delta.y, 0.0)
+                } else {
+                    delta
+                };
+
+                state.scroll(direction.align(delta), bounds, content_bounds);
 
                 notify_on_scroll(
                     state,
@@ -467,10 +586,13 @@ pub fn update<Message>(
                         if let Some(scroll_box_touched_at) =
                             state.scroll_box_touched_at
                         {
-                            let delta =
-                                cursor_position.y - scroll_box_touched_at.y;
+                            let delta = cursor_position - scroll_box_touched_at;
 
-                            state.scroll(delta, bounds, content_bounds);
+                            state.scroll(
+                                direction.align(delta),
+                                bounds,
+                                content_bounds,
+                            );
 
                             state.scroll_box_touched_at = Some(cursor_position);
 
@@ -500,16 +622,40 @@ pub fn update<Message>(
             Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerLifted { .. })
             | Event::Touch(touch::Event::FingerLost { .. }) => {
-                state.scroller_grabbed_at = None;
+                state.y_scroller_grabbed_at = None;
+                state.x_scroller_grabbed_at = None;
 
                 return event::Status::Captured;
             }
             Event::Mouse(mouse::Event::CursorMoved { .. })
             | Event::Touch(touch::Event::FingerMoved { .. }) => {
                 if let (Some(scrollbar), Some(scroller_grabbed_at)) =
-                    (scrollbar, state.scroller_grabbed_at)
+                    (&scrollbars.y, state.y_scroller_grabbed_at)
                 {
-                    state.scroll_to(
+                    state.scroll_y_to(
+                        scrollbar.scroll_percentage(
+                            scroller_grabbed_at,
+                            cursor_position,
+                        ),
+                        bounds,
+                        content_bounds,
+                    );
+
+                    notify_on_scroll(
+                        state,
+                        on_scroll,
+                        bounds,
+                        content_bounds,
+                        shell,
+                    );
+
+                    return event::Status::Captured;
+                }
+
+                if let (Some(scrollbar), Some(scroller_grabbed_at)) =
+                    (&scrollbars.x, state.x_scroller_grabbed_at)
+                {
+                    state.scroll_x_to(
                         scrollbar.scroll_percentage(
                             scroller_grabbed_at,
                             cursor_position,
@@ -531,35 +677,68 @@ pub fn update<Message>(
             }
             _ => {}
         }
-    } else if is_mouse_over_scrollbar {
+    } else if is_mouse_over_y_scrollbar || is_mouse_over_x_scrollbar {
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
-                if let Some(scrollbar) = scrollbar {
-                    if let Some(scroller_grabbed_at) =
-                        scrollbar.grab_scroller(cursor_position)
-                    {
-                        state.scroll_to(
-                            scrollbar.scroll_percentage(
-                                scroller_grabbed_at,
-                                cursor_position,
-                            ),
-                            bounds,
-                            content_bounds,
-                        );
-
-                        state.scroller_grabbed_at = Some(scroller_grabbed_at);
-
-                        notify_on_scroll(
-                            state,
-                            on_scroll,
-                            bounds,
-                            content_bounds,
-                            shell,
-                        );
-
-                        return event::Status::Captured;
-                    }
+                if let Some((scrollbar, scroller_grabbed_at)) =
+                    scrollbars.y.as_ref().and_then(|scrollbar| {
+                        Some((
+                            scrollbar,
+                            scrollbar.grab_scroller(cursor_position)?,
+                        ))
+                    })
+                {
+                    state.scroll_y_to(
+                        scrollbar.scroll_percentage(
+                            scroller_grabbed_at,
+                            cursor_position,
+                        ),
+                        bounds,
+                        content_bounds,
+                    );
+
+                    state.y_scroller_grabbed_at = Some(scroller_grabbed_at);
+
+                    notify_on_scroll(
+                        state,
+                        on_scroll,
+                        bounds,
+                        content_bounds,
+                        shell,
+                    );
+
+                    return event::Status::Captured;
+                }
+
+                if let Some((scrollbar, scroller_grabbed_at)) =
+                    scrollbars.x.as_ref().and_then(|scrollbar| {
+                        Some((
+                            scrollbar,
+                            scrollbar.grab_scroller(cursor_position)?,
+                        ))
+                    })
+                {
+                    state.scroll_x_to(
+                        scrollbar.scroll_percentage(
+                            scroller_grabbed_at,
+                            cursor_position,
+                        ),
+                        bounds,
+                        content_bounds,
+                    );
+
+                    state.x_scroller_grabbed_at = Some(scroller_grabbed_at);
+
+                    notify_on_scroll(
+                        state,
+                        on_scroll,
+                        bounds,
+                        content_bounds,
+                        shell,
+                    );
+
+                    return event::Status::Captured;
                 }
             }
             _ => {}
@@ -574,6 +753,7 @@ pub fn mouse_interaction(
     state: &State,
     layout: Layout<'_>,
     cursor_position: Point,
+    direction: Direction,
     scrollbar_width: u16,
     scrollbar_margin: u16,
     scroller_width: u16,
@@ -586,8 +766,9 @@ pub fn mouse_interaction(
     let bounds = layout.bounds();
     let content_layout = layout.children().next().unwrap();
     let content_bounds = content_layout.bounds();
-    let scrollbar = scrollbar(
+    let scrollbars = Scrollbars::new(
         state,
+        direction,
         scrollbar_width,
         scrollbar_margin,
         scroller_width,
@@ -596,18 +777,19 @@ pub fn mouse_interaction(
     );
 
     let is_mouse_over = bounds.contains(cursor_position);
-    let is_mouse_over_scrollbar = scrollbar
-        .as_ref()
-        .map(|scrollbar| scrollbar.is_mouse_over(cursor_position))
-        .unwrap_or(false);
+    let (is_mouse_over_y_scrollbar, is_mouse_over_x_scrollbar) =
+        scrollbars.is_mouse_over(cursor_position);
 
-    if is_mouse_over_scrollbar || state.is_scroller_grabbed() {
+    if is_mouse_over_y_scrollbar
+        || is_mouse_over_x_scrollbar
+        || state.is_scroller_grabbed()
+    {
         mouse::Interaction::Idle
     } else {
         let offset = state.offset(bounds, content_bounds);
 
-        let cursor_position = if is_mouse_over && !is_mouse_over_scrollbar {
-            Point::new(cursor_position.x, cursor_position.y + offset as f32)
+        let cursor_position = if is_mouse_over {
+            cursor_position + offset
         } else {
             Point::new(cursor_position.x, -1.0)
         };
@@ -616,7 +798,8 @@ pub fn mouse_interaction(
             content_layout,
             cursor_position,
             &Rectangle {
-                y: bounds.y + offset as f32,
+                x: bounds.x + offset.x,
+                y: bounds.y + offset.y,
                 ..bounds
             },
         )
@@ -630,6 +813,7 @@ pub fn draw<Renderer>(
     theme: &Renderer::Theme,
     layout: Layout<'_>,
     cursor_position: Point,
+    direction: Direction,
     scrollbar_width: u16,
     scrollbar_margin: u16,
     scroller_width: u16,
@@ -643,8 +827,9 @@ pub fn draw<Renderer>(
     let content_layout = layout.children().next().unwrap();
     let content_bounds = content_layout.bounds();
     let offset = state.offset(bounds, content_bounds);
-    let scrollbar = scrollbar(
+    let scrollbars = Scrollbars::new(
         state,
+        direction,
         scrollbar_width,
         scrollbar_margin,
         scroller_width,
@@ -653,53 +838,45 @@ pub fn draw<Renderer>(
     );
 
     let is_mouse_over = bounds.contains(cursor_position);
-    let is_mouse_over_scrollbar = scrollbar
-        .as_ref()
-        .map(|scrollbar| scrollbar.is_mouse_over(cursor_position))
-        .unwrap_or(false);
+    let (is_mouse_over_y_scrollbar, is_mouse_over_x_scrollbar) =
+        scrollbars.is_mouse_over(cursor_position);
 
-    let cursor_position = if is_mouse_over && !is_mouse_over_scrollbar {
-        Point::new(cursor_position.x, cursor_position.y + offset as f32)
+    let cursor_position = if is_mouse_over
+        && !(is_mouse_over_y_scrollbar || is_mouse_over_x_scrollbar)
+    {
+        cursor_position + offset
     } else {
         Point::new(cursor_position.x, -1.0)
     };
 
-    if let Some(scrollbar) = scrollbar {
+    let viewport = Rectangle {
+        x: bounds.x + offset.x,
+        y: bounds.y + offset.y,
+        ..bounds
+    };
+
+    if scrollbars.y.is_some() || scrollbars.x.is_some() {
         renderer.with_layer(bounds, |renderer| {
             renderer.with_translation(
-                Vector::new(0.0, -(offset as f32)),
+                Vector::new(-offset.x, -offset.y),
                 |renderer| {
                     draw_content(
                         renderer,
                         content_layout,
                         cursor_position,
-                        &Rectangle {
-                            y: bounds.y + offset as f32,
-                            ..bounds
-                        },
+                        &viewport,
                     );
                 },
             );
         });
 
-        let style = if state.is_scroller_grabbed() {
-            theme.dragging(style)
-        } else if is_mouse_over_scrollbar {
-            theme.hovered(style)
-        } else {
-            theme.active(style)
-        };
+        let draw_scrollbar =
+            |renderer: &mut Renderer,
+             style: style::Scrollbar,
+             scrollbar: &Scrollbar| {
+                let is_scrollbar_visible =
+                    style.background.is_some() || style.border_width > 0.0;
 
-        let is_scrollbar_visible =
-            style.background.is_some() || style.border_width > 0.0;
-
-        renderer.with_layer(
-            Rectangle {
-                width: bounds.width + 2.0,
-                height: bounds.height + 2.0,
-                ..bounds
-            },
-            |renderer| {
                 if is_scrollbar_visible {
                     renderer.fill_quad(
                         renderer::Quad {
@@ -727,118 +904,105 @@ pub fn draw<Renderer>(
                         style.scroller.color,
                     );
                 }
-            },
-        );
-    } else {
-        draw_content(
-            renderer,
-            content_layout,
-            cursor_position,
-            &Rectangle {
-                y: bounds.y + offset as f32,
+            };
+
+        renderer.with_layer(
+            Rectangle {
+                width: bounds.width + 2.0,
+                height: bounds.height + 2.0,
                 ..bounds
             },
-        );
-    }
-}
-
-fn scrollbar(
-    state: &State,
-    scrollbar_width: u16,
-    scrollbar_margin: u16,
-    scroller_width: u16,
-    bounds: Rectangle,
-    content_bounds: Rectangle,
-) -> Option<Scrollbar> {
-    let offset = state.offset(bounds, content_bounds);
-
-    if content_bounds.height > bounds.height {
-        let outer_width =
-            scrollbar_width.max(scroller_width) + 2 * scrollbar_margin;
-
-        let outer_bounds = Rectangle {
-            x: bounds.x + bounds.width - outer_width as f32,
-            y: bounds.y,
-            width: outer_width as f32,
-            height: bounds.height,
-        };
-
-        let scrollbar_bounds = Rectangle {
-            x: bounds.x + bounds.width
-                - f32::from(outer_width / 2 + scrollbar_width / 2),
-            y: bounds.y,
-            width: scrollbar_width as f32,
-            height: bounds.height,
-        };
-
-        let ratio = bounds.height / content_bounds.height;
-        let scroller_height = bounds.height * ratio;
-        let y_offset = offset as f32 * ratio;
+            |renderer| {
+                if let Some(scrollbar) = &scrollbars.y {
+                    let style = if state.y_scroller_grabbed_at.is_some() {
+                        theme.dragging(style)
+                    } else if is_mouse_over_y_scrollbar {
+                        theme.hovered(style)
+                    } else {
+                        theme.active(style)
+                    };
+
+                    draw_scrollbar(renderer, style, scrollbar);
+                }
 
-        let scroller_bounds = Rectangle {
-            x: bounds.x + bounds.width
-                - f32::from(outer_width / 2 + scroller_width / 2),
-            y: scrollbar_bounds.y + y_offset,
-            width: scroller_width as f32,
-            height: scroller_height,
-        };
+                if let Some(scrollbar) = &scrollbars.x {
+                    let style = if state.x_scroller_grabbed_at.is_some() {
+                        theme.dragging(style)
+                    } else if is_mouse_over_x_scrollbar {
+                        theme.hovered(style)
+                    } else {
+                        theme.active(style)
+                    };
 
-        Some(Scrollbar {
-            outer_bounds,
-            bounds: scrollbar_bounds,
-            scroller: Scroller {
-                bounds: scroller_bounds,
+                    draw_scrollbar(renderer, style, scrollbar);
+                }
             },
-        })
+        );
     } else {
-        None
+        draw_content(renderer, content_layout, cursor_position, &viewport);
     }
 }
 
 fn notify_on_scroll<Message>(
     state: &State,
-    on_scroll: &Option<Box<dyn Fn(f32) -> Message + '_>>,
+    on_scroll: &Option<Box<dyn Fn(RelativeOffset) -> Message + '_>>,
     bounds: Rectangle,
     content_bounds: Rectangle,
     shell: &mut Shell<'_, Message>,
 ) {
-    if content_bounds.height <= bounds.height {
+    if content_bounds.width <= bounds.width
+        && content_bounds.height <= bounds.height
+    {
         return;
     }
 
     if let Some(on_scroll) = on_scroll {
-        shell.publish(on_scroll(
-            state.offset.absolute(bounds, content_bounds)
-                / (content_bounds.height - bounds.height),
-        ));
+        let relative = |offset: Offset, viewport: f32, content: f32| {
+            if content > viewport {
+                offset.absolute(viewport, content) / (content - viewport)
+            } else {
+                0.0
+            }
+        };
+
+        shell.publish(on_scroll(RelativeOffset {
+            x: relative(state.offset_x, bounds.width, content_bounds.width),
+            y: relative(state.offset_y, bounds.height, content_bounds.height),
+        }));
     }
 }
 
 /// The local state of a [`Scrollable`].
 #[derive(Debug, Clone, Copy)]
 pub struct State {
-    scroller_grabbed_at: Option<f32>,
+    y_scroller_grabbed_at: Option<f32>,
+    x_scroller_grabbed_at: Option<f32>,
     scroll_box_touched_at: Option<Point>,
-    offset: Offset,
+    offset_y: Offset,
+    offset_x: Offset,
+    keyboard_modifiers: keyboard::Modifiers,
 }
 
 impl Default for State {
     fn default() -> Self {
         Self {
-            scroller_grabbed_at: None,
+            y_scroller_grabbed_at: None,
+            x_scroller_grabbed_at: None,
             scroll_box_touched_at: None,
-            offset: Offset::Absolute(0.0),
+            offset_y: Offset::Absolute(0.0),
+            offset_x: Offset::Absolute(0.0),
+            keyboard_modifiers: keyboard::Modifiers::default(),
         }
     }
 }
 
 impl operation::Scrollable for State {
-    fn snap_to(&mut self, percentage: f32) {
-        State::snap_to(self, percentage);
+    fn snap_to(&mut self, offset: RelativeOffset) {
+        State::snap_to(self, offset);
     }
 }
 
-/// The local state of a [`Scrollable`].
+/// The scrolling offset of a [`Scrollable`] along a single axis.
 #[derive(Debug, Clone, Copy)]
 enum Offset {
     Absolute(f32),
@@ -846,23 +1010,22 @@ enum Offset {
 }
 
 impl Offset {
-    fn absolute(self, bounds: Rectangle, content_bounds: Rectangle) -> f32 {
+    fn absolute(self, viewport: f32, content: f32) -> f32 {
         match self {
             Self::Absolute(absolute) => {
-                let hidden_content =
-                    (content_bounds.height - bounds.height).max(0.0);
+                let hidden_content = (content - viewport).max(0.0);
 
                 absolute.min(hidden_content)
             }
             Self::Relative(percentage) => {
-                ((content_bounds.height - bounds.height) * percentage).max(0.0)
+                ((content - viewport) * percentage).max(0.0)
             }
         }
     }
 }
 
 impl State {
-    /// Creates a new [`State`] with the scrollbar located at the top.
+    /// Creates a new [`State`] with the scrollbars located at the top-left.
     pub fn new() -> Self {
         State::default()
     }
@@ -871,58 +1034,93 @@ impl State {
     /// the [`Scrollable`] and its contents.
     pub fn scroll(
         &mut self,
-        delta_y: f32,
+        delta: Vector,
         bounds: Rectangle,
         content_bounds: Rectangle,
     ) {
-        if bounds.height >= content_bounds.height {
-            return;
+        if bounds.height < content_bounds.height {
+            self.offset_y = Offset::Absolute(
+                (self.offset_y.absolute(bounds.height, content_bounds.height)
+                    - delta.y)
+                    .clamp(0.0, content_bounds.height - bounds.height),
+            );
         }
 
-        self.offset = Offset::Absolute(
-            (self.offset.absolute(bounds, content_bounds) - delta_y)
-                .clamp(0.0, content_bounds.height - bounds.height),
-        );
+        if bounds.width < content_bounds.width {
+            self.offset_x = Offset::Absolute(
+                (self.offset_x.absolute(bounds.width, content_bounds.width)
+                    - delta.x)
+                    .clamp(0.0, content_bounds.width - bounds.width),
+            );
+        }
     }
 
-    /// Scrolls the [`Scrollable`] to a relative amount.
+    /// Scrolls the [`Scrollable`] vertically to a relative amount.
     ///
     /// `0` represents scrollbar at the top, while `1` represents scrollbar at
     /// the bottom.
-    pub fn scroll_to(
+    pub fn scroll_y_to(
         &mut self,
         percentage: f32,
         bounds: Rectangle,
         content_bounds: Rectangle,
     ) {
-        self.snap_to(percentage);
+        self.offset_y = Offset::Relative(percentage.clamp(0.0, 1.0));
         self.unsnap(bounds, content_bounds);
     }
 
-    /// Snaps the scroll position to a relative amount.
+    /// Scrolls the [`Scrollable`] horizontally to a relative amount.
     ///
-    /// `0` represents scrollbar at the top, while `1` represents scrollbar at
-    /// the bottom.
-    pub fn snap_to(&mut self, percentage: f32) {
-        self.offset = Offset::Relative(percentage.clamp(0.0, 1.0));
+    /// `0` represents scrollbar at the left, while `1` represents scrollbar at
+    /// the right.
+    pub fn scroll_x_to(
+        &mut self,
+        percentage: f32,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) {
+        self.offset_x = Offset::Relative(percentage.clamp(0.0, 1.0));
+        self.unsnap(bounds, content_bounds);
+    }
+
+    /// Snaps the scroll position to a [`RelativeOffset`].
+    pub fn snap_to(&mut self, offset: RelativeOffset) {
+        self.offset_x = Offset::Relative(offset.x.clamp(0.0, 1.0));
+        self.offset_y = Offset::Relative(offset.y.clamp(0.0, 1.0));
     }
 
     /// Unsnaps the current scroll position, if snapped, given the bounds of the
     /// [`Scrollable`] and its contents.
     pub fn unsnap(&mut self, bounds: Rectangle, content_bounds: Rectangle) {
-        self.offset =
-            Offset::Absolute(self.offset.absolute(bounds, content_bounds));
+        self.offset_x = Offset::Absolute(
+            self.offset_x.absolute(bounds.width, content_bounds.width),
+        );
+        self.offset_y = Offset::Absolute(
+            self.offset_y.absolute(bounds.height, content_bounds.height),
+        );
     }
 
     /// Returns the current scrolling offset of the [`State`], given the bounds
     /// of the [`Scrollable`] and its contents.
-    pub fn offset(&self, bounds: Rectangle, content_bounds: Rectangle) -> u32 {
-        self.offset.absolute(bounds, content_bounds) as u32
+    pub fn offset(
+        &self,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) -> Vector {
+        Vector::new(
+            self.offset_x
+                .absolute(bounds.width, content_bounds.width)
+                .floor(),
+            self.offset_y
+                .absolute(bounds.height, content_bounds.height)
+                .floor(),
+        )
     }
 
-    /// Returns whether the scroller is currently grabbed or not.
+    /// Returns whether any scroller is currently grabbed or not.
     pub fn is_scroller_grabbed(&self) -> bool {
-        self.scroller_grabbed_at.is_some()
+        self.y_scroller_grabbed_at.is_some()
+            || self.x_scroller_grabbed_at.is_some()
     }
 
     /// Returns whether the scroll box is currently touched or not.
@@ -931,9 +1129,148 @@ impl State {
     }
 }
 
+/// The scrollbars of a [`Scrollable`], if its content overflows.
+#[derive(Debug)]
+struct Scrollbars {
+    y: Option<Scrollbar>,
+    x: Option<Scrollbar>,
+}
+
+impl Scrollbars {
+    fn new(
+        state: &State,
+        direction: Direction,
+        scrollbar_width: u16,
+        scrollbar_margin: u16,
+        scroller_width: u16,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) -> Self {
+        let offset = state.offset(bounds, content_bounds);
+
+        let outer_width =
+            scrollbar_width.max(scroller_width) + 2 * scrollbar_margin;
+
+        let show_y =
+            direction.is_vertical() && content_bounds.height > bounds.height;
+        let show_x =
+            direction.is_horizontal() && content_bounds.width > bounds.width;
+
+        // Each scrollbar leaves room for the corner of the other one
+        let corner = |is_shown: bool| {
+            if is_shown {
+                f32::from(outer_width)
+            } else {
+                0.0
+            }
+        };
+
+        let y = show_y.then(|| {
+            let height = bounds.height - corner(show_x);
+
+            let outer_bounds = Rectangle {
+                x: bounds.x + bounds.width - f32::from(outer_width),
+                y: bounds.y,
+                width: f32::from(outer_width),
+                height,
+            };
+
+            let scrollbar_bounds = Rectangle {
+                x: bounds.x + bounds.width
+                    - f32::from(outer_width / 2 + scrollbar_width / 2),
+                y: bounds.y,
+                width: f32::from(scrollbar_width),
+                height,
+            };
+
+            let ratio = height / content_bounds.height;
+
+            let scroller_bounds = Rectangle {
+                x: bounds.x + bounds.width
+                    - f32::from(outer_width / 2 + scroller_width / 2),
+                y: scrollbar_bounds.y + offset.y * ratio,
+                width: f32::from(scroller_width),
+                height: bounds.height * ratio,
+            };
+
+            Scrollbar {
+                axis: Axis::Vertical,
+                outer_bounds,
+                bounds: scrollbar_bounds,
+                scroller: Scroller {
+                    bounds: scroller_bounds,
+                },
+            }
+        });
+
+        let x = show_x.then(|| {
+            let width = bounds.width - corner(show_y);
+
+            let outer_bounds = Rectangle {
+                x: bounds.x,
+                y: bounds.y + bounds.height - f32::from(outer_width),
+                width,
+                height: f32::from(outer_width),
+            };
+
+            let scrollbar_bounds = Rectangle {
+                x: bounds.x,
+                y: bounds.y + bounds.height
+                    - f32::from(outer_width / 2 + scrollbar_width / 2),
+                width,
+                height: f32::from(scrollbar_width),
+            };
+
+            let ratio = width / content_bounds.width;
+
+            let scroller_bounds = Rectangle {
+                x: scrollbar_bounds.x + offset.x * ratio,
+                y: bounds.y + bounds.height
+                    - f32::from(outer_width / 2 + scroller_width / 2),
+                width: bounds.width * ratio,
+                height: f32::from(scroller_width),
+            };
+
+            Scrollbar {
+                axis: Axis::Horizontal,
+                outer_bounds,
+                bounds: scrollbar_bounds,
+                scroller: Scroller {
+                    bounds: scroller_bounds,
+                },
+            }
+        });
+
+        Self { y, x }
+    }
+
+    /// Returns whether the mouse is over the vertical and the horizontal
+    /// [`Scrollbar`], respectively.
+    fn is_mouse_over(&self, cursor_position: Point) -> (bool, bool) {
+        let is_mouse_over = |scrollbar: &Option<Scrollbar>| {
+            scrollbar
+                .as_ref()
+                .map(|scrollbar| scrollbar.is_mouse_over(cursor_position))
+                .unwrap_or(false)
+        };
+
+        (is_mouse_over(&self.y), is_mouse_over(&self.x))
+    }
+}
+
+/// The axis a [`Scrollbar`] scrolls along.
+#[derive(Debug, Clone, Copy)]
+enum Axis {
+    Vertical,
+    Horizontal,
+}
+
 /// The scrollbar of a [`Scrollable`].
 #[derive(Debug)]
 struct Scrollbar {
+    /// The [`Axis`] of the [`Scrollbar`].
+    axis: Axis,
+
     /// The outer bounds of the scrollable, including the [`Scrollbar`] and
     /// [`Scroller`].
     outer_bounds: Rectangle,
@@ -953,8 +1290,16 @@ impl Scrollbar {
     fn grab_scroller(&self, cursor_position: Point) -> Option<f32> {
         if self.outer_bounds.contains(cursor_position) {
             Some(if self.scroller.bounds.contains(cursor_position) {
-                (cursor_position.y - self.scroller.bounds.y)
-                    / self.scroller.bounds.height
+                match self.axis {
+                    Axis::Vertical => {
+                        (cursor_position.y - self.scroller.bounds.y)
+                            / self.scroller.bounds.height
+                    }
+                    Axis::Horizontal => {
+                        (cursor_position.x - self.scroller.bounds.x)
+                            / self.scroller.bounds.width
+                    }
+                }
             } else {
                 0.5
             })
@@ -968,10 +1313,20 @@ impl Scrollbar {
         grabbed_at: f32,
         cursor_position: Point,
     ) -> f32 {
-        (cursor_position.y
-            - self.bounds.y
-            - self.scroller.bounds.height * grabbed_at)
-            / (self.bounds.height - self.scroller.bounds.height)
+        match self.axis {
+            Axis::Vertical => {
+                (cursor_position.y
+                    - self.bounds.y
+                    - self.scroller.bounds.height * grabbed_at)
+                    / (self.bounds.height - self.scroller.bounds.height)
+            }
+            Axis::Horizontal => {
+                (cursor_position.x
+                    - self.bounds.x
+                    - self.scroller.bounds.width * grabbed_at)
+                    / (self.bounds.width - self.scroller.bounds.width)
+            }
+        }
     }
 }
 
@@ -981,3 +1336,145 @@ struct Scroller {
     /// The bounds of the [`Scroller`].
     bounds: Rectangle,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::renderer::Null;
+    use crate::widget::Space;
+
+    const BOUNDS: Rectangle = Rectangle {
+        x: 0.0,
+        y: 0.0,
+        width: 100.0,
+        height: 100.0,
+    };
+
+    const CONTENT_BOUNDS: Rectangle = Rectangle {
+        x: 0.0,
+        y: 0.0,
+        width: 300.0,
+        height: 500.0,
+    };
+
+    #[test]
+    fn scroll_is_clamped_along_both_axes() {
+        let mut state = State::new();
+
+        state.scroll(Vector::new(-50.0, -1000.0), BOUNDS, CONTENT_BOUNDS);
+        assert_eq!(
+            state.offset(BOUNDS, CONTENT_BOUNDS),
+            Vector::new(50.0, 400.0)
+        );
+
+        state.scroll(Vector::new(100.0, 0.0), BOUNDS, CONTENT_BOUNDS);
+        assert_eq!(
+            state.offset(BOUNDS, CONTENT_BOUNDS),
+            Vector::new(0.0, 400.0)
+        );
+    }
+
+    #[test]
+    fn snap_to_relative_offset() {
+        let mut state = State::new();
+
+        state.snap_to(RelativeOffset::END);
+        assert_eq!(
+            state.offset(BOUNDS, CONTENT_BOUNDS),
+            Vector::new(200.0, 400.0)
+        );
+
+        state.snap_to(RelativeOffset { x: 0.5, y: 0.0 });
+        assert_eq!(
+            state.offset(BOUNDS, CONTENT_BOUNDS),
+            Vector::new(100.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn direction_discards_disallowed_deltas() {
+        let delta = Vector::new(10.0, 20.0);
+
+        assert_eq!(Direction::Vertical.align(delta), Vector::new(0.0, 20.0));
+        assert_eq!(Direction::Horizontal.align(delta), Vector::new(10.0, 0.0));
+        assert_eq!(Direction::Both.align(delta), delta);
+    }
+
+    fn scrollable(direction: Direction) -> Scrollable<'static, (), Null> {
+        Scrollable::new(Space::new(Length::Units(300), Length::Units(500)))
+            .id(Id::new("scrollable"))
+            .height(Length::Units(100))
+            .direction(direction)
+    }
+
+    fn layout(scrollable: &Scrollable<'_, (), Null>) -> layout::Node {
+        Widget::<(), Null>::layout(
+            scrollable,
+            &Null,
+            &layout::Limits::new(Size::ZERO, Size::new(100.0, 100.0)),
+        )
+    }
+
+    #[test]
+    fn wheel_scrolls_horizontally_when_only_horizontal() {
+        let mut scrollable = scrollable(Direction::Horizontal);
+        let mut tree = Tree::new(&scrollable as &dyn Widget<(), Null>);
+        let node = layout(&scrollable);
+
+        let mut messages = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+
+        let status = scrollable.on_event(
+            &mut tree,
+            Event::Mouse(mouse::Event::WheelScrolled {
+                delta: mouse::ScrollDelta::Lines { x: 0.0, y: -1.0 },
+            }),
+            Layout::new(&node),
+            Point::new(50.0, 50.0),
+            &Null,
+            &mut crate::clipboard::Null,
+            &mut shell,
+        );
+
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = Layout::new(&node).bounds();
+        let content_bounds =
+            Layout::new(&node).children().next().unwrap().bounds();
+
+        assert_eq!(status, event::Status::Captured);
+        assert_eq!(
+            state.offset(bounds, content_bounds),
+            Vector::new(60.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn snap_to_ignores_axes_that_do_not_scroll() {
+        let scrollable = scrollable(Direction::Horizontal);
+        let mut tree = Tree::new(&scrollable as &dyn Widget<(), Null>);
+        let node = layout(&scrollable);
+
+        let mut operation = operation::scrollable::snap_to(
+            Id::new("scrollable").0,
+            RelativeOffset::END,
+        );
+
+        Widget::<(), Null>::operate(
+            &scrollable,
+            &mut tree,
+            Layout::new(&node),
+            &mut operation,
+        );
+
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = Layout::new(&node).bounds();
+        let content_bounds =
+            Layout::new(&node).children().next().unwrap().bounds();
+
+        assert_eq!(
+            state.offset(bounds, content_bounds),
+            Vector::new(200.0, 0.0)
+        );
+    }
+}
@@ -0,0 +1,1352 @@
+//! Display data in rows and columns under a sticky header.
+//!
+//! A [`Table`] has some local [`State`].
+use crate::alignment;
+use crate::event::{self, Event};
+use crate::keyboard;
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::renderer;
+use crate::text::{self, Text};
+use crate::widget::operation::Operation;
+use crate::widget::scrollable;
+use crate::widget::tree::{self, Tree};
+use crate::{
+    Clipboard, Color, Element, Layout, Length, Point, Rectangle, Shell, Size,
+    Vector, Widget,
+};
+
+pub use iced_style::table::{Appearance, StyleSheet};
+
+/// The minimum width a [`Column`] can be resized to.
+const MIN_COLUMN_WIDTH: f32 = 20.0;
+
+/// The width of the sort indicator of a [`Column`].
+const INDICATOR_WIDTH: f32 = 8.0;
+
+/// A grid of rows of some data, where every [`Column`] displays a different
+/// part of each row.
+///
+/// The header of a [`Table`] stays in place while its rows are scrolled.
+/// Clicking a sortable header produces a message with the desired [`Order`],
+/// dragging the divider between two headers resizes the column on its left,
+/// and clicking a row changes the selection.
+///
+/// Like the splits of a [`PaneGrid`], the sorting, the widths of the columns
+/// and the selection are owned by the application.
+///
+/// # Example
+/// ```
+/// # use iced_native::widget::{table, text};
+/// # use iced_native::renderer::Null;
+/// #
+/// # type Table<'a, T, Message> = table::Table<'a, T, Message, Null>;
+/// # type Column<'a, T, Message> = table::Column<'a, T, Message, Null>;
+/// #
+/// struct Person {
+///     name: String,
+///     age: u32,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Sorted(usize, table::Order),
+///     Resized(table::ResizeEvent),
+///     Selected(Vec<usize>),
+/// }
+///
+/// let people = vec![Person { name: String::from("Ferris"), age: 7 }];
+/// let selected = vec![0];
+///
+/// let table = Table::new(
+///     vec![
+///         Column::new("Name", |person: &Person| text(&person.name).into())
+///             .sortable(true),
+///         Column::new("Age", |person: &Person| text(person.age).into())
+///             .width(60.0)
+///             .sortable(true),
+///     ],
+///     &people,
+/// )
+/// .sort(Some((0, table::Order::Ascending)))
+/// .on_sort(Message::Sorted)
+/// .on_resize(10, Message::Resized)
+/// .on_select(&selected, Message::Selected);
+/// ```
+///
+/// [`PaneGrid`]: crate::widget::PaneGrid
+#[allow(missing_debug_implementations)]
+pub struct Table<'a, T, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet + scrollable::StyleSheet,
+{
+    columns: Vec<Column<'a, T, Message, Renderer>>,
+    cells: Vec<Element<'a, Message, Renderer>>,
+    width: Length,
+    height: Length,
+    padding: u16,
+    text_size: Option<u16>,
+    font: Renderer::Font,
+    scrollbar_width: u16,
+    scrollbar_margin: u16,
+    scroller_width: u16,
+    sort: Option<(usize, Order)>,
+    on_sort: Option<Box<dyn Fn(usize, Order) -> Message + 'a>>,
+    on_resize: Option<(u16, Box<dyn Fn(ResizeEvent) -> Message + 'a>)>,
+    selection: Selection,
+    selected: &'a [usize],
+    on_select: Option<Box<dyn Fn(Vec<usize>) -> Message + 'a>>,
+    style: <Renderer::Theme as StyleSheet>::Style,
+    scrollable_style: <Renderer::Theme as scrollable::StyleSheet>::Style,
+}
+
+impl<'a, T, Message, Renderer> Table<'a, T, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet + scrollable::StyleSheet,
+{
+    /// Creates a new [`Table`] with the given columns and rows.
+    ///
+    /// A cell is built for every row and [`Column`] right away.
+    pub fn new(
+        columns: Vec<Column<'a, T, Message, Renderer>>,
+        rows: &'a [T],
+    ) -> Self {
+        let cells = rows
+            .iter()
+            .flat_map(|row| {
+                columns.iter().map(move |column| (column.view)(row))
+            })
+            .collect();
+
+        Table {
+            columns,
+            cells,
+            width: Length::Fill,
+            height: Length::Shrink,
+            padding: 5,
+            text_size: None,
+            font: Default::default(),
+            scrollbar_width: 10,
+            scrollbar_margin: 0,
+            scroller_width: 10,
+            sort: None,
+            on_sort: None,
+            on_resize: None,
+            selection: Selection::default(),
+            selected: &[],
+            on_select: None,
+            style: Default::default(),
+            scrollable_style: Default::default(),
+        }
+    }
+
+    /// Sets the width of the [`Table`].
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Table`].
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the padding of the cells and the headers of the [`Table`].
+    pub fn padding(mut self, padding: u16) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the text size of the headers of the [`Table`].
+    pub fn text_size(mut self, size: u16) -> Self {
+        self.text_size = Some(size);
+        self
+    }
+
+    /// Sets the font of the headers of the [`Table`].
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the scrollbar width of the [`Table`].
+    pub fn scrollbar_width(mut self, scrollbar_width: u16) -> Self {
+        self.scrollbar_width = scrollbar_width.max(1);
+        self
+    }
+
+    /// Sets the scrollbar margin of the [`Table`].
+    pub fn scrollbar_margin(mut self, scrollbar_margin: u16) -> Self {
+        self.scrollbar_margin = scrollbar_margin;
+        self
+    }
+
+    /// Sets the scroller width of the [`Table`].
+    pub fn scroller_width(mut self, scroller_width: u16) -> Self {
+        self.scroller_width = scroller_width.max(1);
+        self
+    }
+
+    /// Sets the index of the [`Column`] the rows are sorted by, and the
+    /// [`Order`] of the sort.
+    ///
+    /// The sort indicator is displayed in the header of that [`Column`].
+    pub fn sort(mut self, sort: Option<(usize, Order)>) -> Self {
+        self.sort = sort;
+        self
+    }
+
+    /// Sets the message that will be produced when the header of a sortable
+    /// [`Column`] is clicked.
+    ///
+    /// It receives the index of the [`Column`] and the [`Order`] the rows
+    /// should be sorted in.
+    pub fn on_sort<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Fn(usize, Order) -> Message,
+    {
+        self.on_sort = Some(Box::new(f));
+        self
+    }
+
+    /// Enables the resize interactions of the [`Table`], which will use the
+    /// provided function to produce messages.
+    ///
+    /// The `leeway` describes the amount of space around the divider on the
+    /// right of each header that can be dragged.
+    pub fn on_resize<F>(mut self, leeway: u16, f: F) -> Self
+    where
+        F: 'a + Fn(ResizeEvent) -> Message,
+    {
+        self.on_resize = Some((leeway, Box::new(f)));
+        self
+    }
+
+    /// Sets the [`Selection`] mode of the [`Table`].
+    pub fn selection(mut self, selection: Selection) -> Self {
+        self.selection = selection;
+        self
+    }
+
+    /// Enables the selection of rows in the [`Table`].
+    ///
+    /// It expects:
+    ///   * the indices of the rows that are currently selected
+    ///   * a function that will be called when the selection changes. It
+    ///     receives the indices of the new selection, in ascending order.
+    pub fn on_select<F>(mut self, selected: &'a [usize], f: F) -> Self
+    where
+        F: 'a + Fn(Vec<usize>) -> Message,
+    {
+        self.selected = selected;
+        self.on_select = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the style of the [`Table`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the style of the scrollbars of the [`Table`].
+    pub fn scrollable_style(
+        mut self,
+        style: impl Into<<Renderer::Theme as scrollable::StyleSheet>::Style>,
+    ) -> Self {
+        self.scrollable_style = style.into();
+        self
+    }
+
+    /// Returns the index of the [`Column`] whose divider is at the given
+    /// horizontal position of the header, if any.
+    fn divider_at(&self, x: f32, leeway: u16) -> Option<usize> {
+        let leeway = f32::from(leeway) / 2.0;
+        let mut right = 0.0;
+
+        self.columns.iter().position(|column| {
+            right += column.width;
+
+            (x - right).abs() <= leeway
+        })
+    }
+
+    /// Returns the index of the [`Column`] at the given horizontal position
+    /// of the header, if any.
+    fn column_at(&self, x: f32) -> Option<usize> {
+        let mut right = 0.0;
+
+        self.columns.iter().position(|column| {
+            right += column.width;
+
+            x < right
+        })
+    }
+}
+
+/// A column of a [`Table`].
+#[allow(missing_debug_implementations)]
+pub struct Column<'a, T, Message, Renderer> {
+    header: String,
+    width: f32,
+    sortable: bool,
+    view: Box<dyn Fn(&'a T) -> Element<'a, Message, Renderer> + 'a>,
+}
+
+impl<'a, T, Message, Renderer> Column<'a, T, Message, Renderer> {
+    /// Creates a new [`Column`] with the given header and a function that
+    /// produces its cell for a row.
+    pub fn new(
+        header: impl Into<String>,
+        view: impl Fn(&'a T) -> Element<'a, Message, Renderer> + 'a,
+    ) -> Self {
+        Column {
+            header: header.into(),
+            width: 100.0,
+            sortable: false,
+            view: Box::new(view),
+        }
+    }
+
+    /// Sets the width of the [`Column`], in pixels.
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = width.max(MIN_COLUMN_WIDTH);
+        self
+    }
+
+    /// Sets whether the [`Table`] can be sorted by clicking the header of the
+    /// [`Column`].
+    pub fn sortable(mut self, sortable: bool) -> Self {
+        self.sortable = sortable;
+        self
+    }
+}
+
+/// The order the rows of a [`Table`] are sorted in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Order {
+    /// From the lowest to the highest value.
+    Ascending,
+    /// From the highest to the lowest value.
+    Descending,
+}
+
+impl Order {
+    /// Returns the opposite [`Order`].
+    pub fn reverse(self) -> Self {
+        match self {
+            Order::Ascending => Order::Descending,
+            Order::Descending => Order::Ascending,
+        }
+    }
+}
+
+/// How the rows of a [`Table`] can be selected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Selection {
+    /// A single row can be selected at a time.
+    #[default]
+    Single,
+
+    /// Many rows can be selected at a time.
+    ///
+    /// Clicking a row while holding the command key toggles it, and holding
+    /// Shift selects the range between the last clicked row and the new one.
+    Multiple,
+}
+
+/// An event produced while a [`Column`] of a [`Table`] is being resized.
+#[derive(Debug, Clone, Copy)]
+pub struct ResizeEvent {
+    /// The index of the [`Column`] being resized.
+    pub column: usize,
+
+    /// The new width of the [`Column`].
+    pub width: f32,
+}
+
+/// The local state of a [`Table`].
+#[derive(Debug, Clone, Default)]
+pub struct State {
+    scrollable: scrollable::State,
+    resizing: Option<Resizing>,
+    anchor: Option<usize>,
+    keyboard_modifiers: keyboard::Modifiers,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Resizing {
+    column: usize,
+    origin: f32,
+    width: f32,
+}
+
+impl<'a, T, Message, Renderer> Widget<Message, Renderer>
+    for Table<'a, T, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet + scrollable::StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.cells.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.cells);
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let padding = f32::from(self.padding);
+        let header_height = f32::from(
+            self.text_size.unwrap_or_else(|| renderer.default_size()),
+        ) + padding * 2.0;
+
+        let limits = limits.width(self.width).height(self.height);
+        let body_limits = limits.shrink(Size::new(0.0, header_height));
+
+        let columns_width: f32 =
+            self.columns.iter().map(|column| column.width).sum();
+        let min_width = body_limits.min().width;
+        let row_width = if min_width.is_finite() {
+            columns_width.max(min_width)
+        } else {
+            columns_width
+        };
+
+        let mut body = scrollable::layout(
+            renderer,
+            &body_limits,
+            self.width,
+            self.height,
+            u32::MAX,
+            scrollable::Direction::Both,
+            |renderer, _limits| {
+                let mut y = 0.0;
+
+                let rows = self
+                    .cells
+                    .chunks(self.columns.len().max(1))
+                    .map(|cells| {
+                        let mut x = 0.0;
+                        let mut height: f32 = 0.0;
+
+                        let mut nodes: Vec<_> = cells
+                            .iter()
+                            .zip(&self.columns)
+                            .map(|(cell, column)| {
+                                let limits = layout::Limits::new(
+                                    Size::ZERO,
+                                    Size::new(
+                                        (column.width - padding * 2.0).max(0.0),
+                                        f32::INFINITY,
+                                    ),
+                                );
+
+                                let mut node =
+                                    cell.as_widget().layout(renderer, &limits);
+
+                                node.move_to(Point::new(x + padding, 0.0));
+                                x += column.width;
+                                height = height.max(node.size().height);
+
+                                node
+                            })
+                            .collect();
+
+                        for node in &mut nodes {
+                            let bounds = node.bounds();
+
+                            node.move_to(Point::new(
+                                bounds.x,
+                                padding + (height - bounds.height) / 2.0,
+                            ));
+                        }
+
+                        let mut row = layout::Node::with_children(
+                            Size::new(row_width, height + padding * 2.0),
+                            nodes,
+                        );
+
+                        row.move_to(Point::new(0.0, y));
+                        y += row.size().height;
+
+                        row
+                    })
+                    .collect();
+
+                layout::Node::with_children(Size::new(row_width, y), rows)
+            },
+        );
+
+        let body_size = body.size();
+        body.move_to(Point::new(0.0, header_height));
+
+        let header =
+            layout::Node::new(Size::new(body_size.width, header_height));
+
+        layout::Node::with_children(
+            Size::new(body_size.width, header_height + body_size.height),
+            vec![header, body],
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+        let body = layout.children().nth(1).unwrap();
+        let content = body.children().next().unwrap();
+
+        operation.scrollable(&mut state.scrollable, None);
+
+        operation.container(None, &mut |operation| {
+            self.cells
+                .iter()
+                .zip(&mut tree.children)
+                .zip(content.children().flat_map(Layout::children))
+                .for_each(|((cell, tree), layout)| {
+                    cell.as_widget().operate(tree, layout, operation);
+                });
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let State {
+            scrollable,
+            resizing,
+            anchor,
+            keyboard_modifiers,
+        } = tree.state.downcast_mut::<State>();
+
+        let mut children = layout.children();
+        let header_bounds = children.next().unwrap().bounds();
+        let body = children.next().unwrap();
+        let offset = scrollable
+            .offset(body.bounds(), body.children().next().unwrap().bounds());
+
+        if let Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) =
+            event
+        {
+            *keyboard_modifiers = modifiers;
+        }
+
+        if let Some(current) = *resizing {
+            match event {
+                Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                    if let Some((_, on_resize)) = &self.on_resize {
+                        let width = (current.width + position.x
+                            - current.origin)
+                            .max(MIN_COLUMN_WIDTH);
+
+                        shell.publish(on_resize(ResizeEvent {
+                            column: current.column,
+                            width,
+                        }));
+                    }
+
+                    return event::Status::Captured;
+                }
+                Event::Mouse(mouse::Event::ButtonReleased(
+                    mouse::Button::Left,
+                )) => {
+                    *resizing = None;
+
+                    return event::Status::Captured;
+                }
+                _ => {}
+            }
+        }
+
+        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) =
+            event
+        {
+            if header_bounds.contains(cursor_position) {
+                let x = cursor_position.x - header_bounds.x + offset.x;
+
+                if let Some((leeway, _)) = &self.on_resize {
+                    if let Some(column) = self.divider_at(x, *leeway) {
+                        *resizing = Some(Resizing {
+                            column,
+                            origin: cursor_position.x,
+                            width: self.columns[column].width,
+                        });
+
+                        return event::Status::Captured;
+                    }
+                }
+
+                if let (Some(on_sort), Some(column)) =
+                    (&self.on_sort, self.column_at(x))
+                {
+                    if self.columns[column].sortable {
+                        let order = match self.sort {
+                            Some((sorted, order)) if sorted == column => {
+                                order.reverse()
+                            }
+                            _ => Order::Ascending,
+                        };
+
+                        shell.publish(on_sort(column, order));
+                    }
+                }
+
+                return event::Status::Captured;
+            }
+        }
+
+        let columns = self.columns.len();
+        let selection = self.selection;
+        let selected = self.selected;
+        let on_select = &self.on_select;
+        let cells = &mut self.cells;
+        let children = &mut tree.children;
+
+        scrollable::update(
+            scrollable,
+            event,
+            body,
+            cursor_position,
+            clipboard,
+            shell,
+            scrollable::Direction::Both,
+            self.scrollbar_width,
+            self.scrollbar_margin,
+            self.scroller_width,
+            &None,
+            |event, layout, cursor_position, clipboard, shell| {
+                let status = cells
+                    .iter_mut()
+                    .zip(children)
+                    .zip(layout.children().flat_map(Layout::children))
+                    .map(|((cell, tree), layout)| {
+                        cell.as_widget_mut().on_event(
+                            tree,
+                            event.clone(),
+                            layout,
+                            cursor_position,
+                            renderer,
+                            clipboard,
+                            shell,
+                        )
+                    })
+                    .fold(event::Status::Ignored, event::Status::merge);
+
+                if status == event::Status::Captured {
+                    return status;
+                }
+
+                if let (
+                    Event::Mouse(mouse::Event::ButtonPressed(
+                        mouse::Button::Left,
+                    )),
+                    Some(on_select),
+                ) = (event, on_select)
+                {
+                    if let Some(index) = layout
+                        .children()
+                        .position(|row| row.bounds().contains(cursor_position))
+                    {
+                        let rows = cells.len() / columns.max(1);
+                        let (selection, new_anchor) = select(
+                            selection,
+                            selected,
+                            anchor.map(|anchor| anchor.min(rows - 1)),
+                            index,
+                            *keyboard_modifiers,
+                        );
+
+                        *anchor = Some(new_anchor);
+                        shell.publish(on_select(selection));
+
+                        return event::Status::Captured;
+                    }
+                }
+
+                event::Status::Ignored
+            },
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let appearance = theme.appearance(&self.style);
+
+        let mut children = layout.children();
+        let header_bounds = children.next().unwrap().bounds();
+        let body = children.next().unwrap();
+        let offset = state
+            .scrollable
+            .offset(body.bounds(), body.children().next().unwrap().bounds());
+
+        let columns = self.columns.len().max(1);
+
+        scrollable::draw(
+            &state.scrollable,
+            renderer,
+            theme,
+            body,
+            cursor_position,
+            scrollable::Direction::Both,
+            self.scrollbar_width,
+            self.scrollbar_margin,
+            self.scroller_width,
+            &self.scrollable_style,
+            |renderer, layout, cursor_position, viewport| {
+                for (index, ((cells, trees), row)) in self
+                    .cells
+                    .chunks(columns)
+                    .zip(tree.children.chunks(columns))
+                    .zip(layout.children())
+                    .enumerate()
+                {
+                    let bounds = row.bounds();
+
+                    if bounds.intersection(viewport).is_none() {
+                        continue;
+                    }
+
+                    let is_selected = self.selected.contains(&index);
+
+                    let background = if is_selected {
+                        Some(appearance.selected_row_background)
+                    } else if self.on_select.is_some()
+                        && bounds.contains(cursor_position)
+                    {
+                        appearance.hovered_row_background
+                    } else if index % 2 == 1 {
+                        appearance.alternate_row_background
+                    } else {
+                        appearance.row_background
+                    };
+
+                    if let Some(background) = background {
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds,
+                                border_radius: 0.0.into(),
+                                border_width: 0.0,
+                                border_color: Color::TRANSPARENT,
+                            },
+                            background,
+                        );
+                    }
+
+                    let style = if is_selected {
+                        renderer::Style {
+                            text_color: appearance.selected_text_color,
+                        }
+                    } else {
+                        *style
+                    };
+
+                    for ((cell, tree), layout) in
+                        cells.iter().zip(trees).zip(row.children())
+                    {
+                        cell.as_widget().draw(
+                            tree,
+                            renderer,
+                            theme,
+                            &style,
+                            layout,
+                            cursor_position,
+                            viewport,
+                        );
+                    }
+                }
+            },
+        );
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: header_bounds,
+                border_radius: 0.0.into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            appearance.header_background,
+        );
+
+        let hovered_divider =
+            state.resizing.map(|resizing| resizing.column).or_else(|| {
+                let (leeway, _) = self.on_resize.as_ref()?;
+
+                if header_bounds.contains(cursor_position) {
+                    self.divider_at(
+                        cursor_position.x - header_bounds.x + offset.x,
+                        *leeway,
+                    )
+                } else {
+                    None
+                }
+            });
+
+        let padding = f32::from(self.padding);
+        let text_size =
+            self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        renderer.with_layer(header_bounds, |renderer| {
+            renderer.with_translation(
+                Vector::new(-offset.x, 0.0),
+                |renderer| {
+                    let mut x = header_bounds.x;
+
+                    for (index, column) in self.columns.iter().enumerate() {
+                        let order = match self.sort {
+                            Some((sorted, order)) if sorted == index => {
+                                Some(order)
+                            }
+                            _ => None,
+                        };
+
+                        let indicator_width = if order.is_some() {
+                            INDICATOR_WIDTH + padding
+                        } else {
+                            0.0
+                        };
+
+                        renderer.fill_text(Text {
+                            content: &column.header,
+                            bounds: Rectangle {
+                                x: x + padding,
+                                y: header_bounds.center_y(),
+                                width: (column.width
+                                    - padding * 2.0
+                                    - indicator_width)
+                                    .max(0.0),
+                                height: header_bounds.height,
+                            },
+                            size: f32::from(text_size),
+                            color: appearance.header_text_color,
+                            font: self.font.clone(),
+                            horizontal_alignment: alignment::Horizontal::Left,
+                            vertical_alignment: alignment::Vertical::Center,
+                            wrapping: text::Wrapping::Ellipsis,
+                        });
+
+                        if let Some(order) = order {
+                            draw_indicator(
+                                renderer,
+                                Point::new(
+                                    x + column.width
+                                        - padding
+                                        - INDICATOR_WIDTH / 2.0,
+                                    header_bounds.center_y(),
+                                ),
+                                order,
+                                appearance.header_text_color,
+                            );
+                        }
+
+                        x += column.width;
+
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: Rectangle {
+                                    x: x - 1.0,
+                                    y: header_bounds.y,
+                                    width: 1.0,
+                                    height: header_bounds.height,
+                                },
+                                border_radius: 0.0.into(),
+                                border_width: 0.0,
+                                border_color: Color::TRANSPARENT,
+                            },
+                            if hovered_divider == Some(index) {
+                                appearance.hovered_divider_color
+                            } else {
+                                appearance.divider_color
+                            },
+                        );
+                    }
+                },
+            );
+        });
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    y: header_bounds.y + header_bounds.height - 1.0,
+                    height: 1.0,
+                    ..header_bounds
+                },
+                border_radius: 0.0.into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            appearance.divider_color,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if state.resizing.is_some() {
+            return mouse::Interaction::ResizingHorizontally;
+        }
+
+        let mut children = layout.children();
+        let header_bounds = children.next().unwrap().bounds();
+        let body = children.next().unwrap();
+
+        if header_bounds.contains(cursor_position) {
+            let offset = state.scrollable.offset(
+                body.bounds(),
+                body.children().next().unwrap().bounds(),
+            );
+            let x = cursor_position.x - header_bounds.x + offset.x;
+
+            if let Some((leeway, _)) = &self.on_resize {
+                if self.divider_at(x, *leeway).is_some() {
+                    return mouse::Interaction::ResizingHorizontally;
+                }
+            }
+
+            let is_sortable = self.on_sort.is_some()
+                && self
+                    .column_at(x)
+                    .map(|column| self.columns[column].sortable)
+                    .unwrap_or(false);
+
+            return if is_sortable {
+                mouse::Interaction::Pointer
+            } else {
+                mouse::Interaction::default()
+            };
+        }
+
+        scrollable::mouse_interaction(
+            &state.scrollable,
+            body,
+            cursor_position,
+            scrollable::Direction::Both,
+            self.scrollbar_width,
+            self.scrollbar_margin,
+            self.scroller_width,
+            |layout, cursor_position, viewport| {
+                self.cells
+                    .iter()
+                    .zip(&tree.children)
+                    .zip(layout.children().flat_map(Layout::children))
+                    .map(|((cell, tree), layout)| {
+                        cell.as_widget().mouse_interaction(
+                            tree,
+                            layout,
+                            cursor_position,
+                            viewport,
+                            renderer,
+                        )
+                    })
+                    .max()
+                    .unwrap_or_default()
+            },
+        )
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let state = tree.state.downcast_ref::<State>();
+        let body = layout.children().nth(1).unwrap();
+        let content = body.children().next().unwrap();
+        let offset = state.scrollable.offset(body.bounds(), content.bounds());
+
+        self.cells
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(content.children().flat_map(Layout::children))
+            .find_map(|((cell, tree), layout)| {
+                cell.as_widget_mut().overlay(tree, layout, renderer)
+            })
+            .map(|overlay| overlay.translate(Vector::new(-offset.x, -offset.y)))
+    }
+}
+
+impl<'a, T, Message, Renderer> From<Table<'a, T, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    T: 'a,
+    Message: 'a,
+    Renderer: 'a + text::Renderer,
+    Renderer::Theme: StyleSheet + scrollable::StyleSheet,
+{
+    fn from(table: Table<'a, T, Message, Renderer>) -> Self {
+        Element::new(table)
+    }
+}
+
+/// Computes the new selection of a [`Table`] after the row with the given
+/// index is clicked, along with the row that anchors range selections.
+fn select(
+    selection: Selection,
+    selected: &[usize],
+    anchor: Option<usize>,
+    index: usize,
+    modifiers: keyboard::Modifiers,
+) -> (Vec<usize>, usize) {
+    if selection == Selection::Single {
+        return (vec![index], index);
+    }
+
+    if modifiers.shift() {
+        let anchor = anchor.unwrap_or(index);
+
+        let mut rows = if modifiers.command() {
+            selected.to_vec()
+        } else {
+            Vec::new()
+        };
+
+        for row in anchor.min(index)..=anchor.max(index) {
+            if !rows.contains(&row) {
+                rows.push(row);
+            }
+        }
+
+        rows.sort_unstable();
+
+        (rows, anchor)
+    } else if modifiers.command() {
+        let mut rows = selected.to_vec();
+
+        if let Some(position) = rows.iter().position(|row| *row == index) {
+            let _ = rows.remove(position);
+        } else {
+            rows.push(index);
+            rows.sort_unstable();
+        }
+
+        (rows, index)
+    } else {
+        (vec![index], index)
+    }
+}
+
+/// Draws the triangle indicating the [`Order`] of a sorted [`Column`].
+fn draw_indicator<Renderer>(
+    renderer: &mut Renderer,
+    center: Point,
+    order: Order,
+    color: Color,
+) where
+    Renderer: crate::Renderer,
+{
+    let steps = (INDICATOR_WIDTH / 2.0) as usize;
+
+    for step in 0..steps {
+        let width = match order {
+            Order::Ascending => (step + 1) as f32 * 2.0,
+            Order::Descending => (steps - step) as f32 * 2.0,
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: center.x - width / 2.0,
+                    y: center.y - steps as f32 / 2.0 + step as f32,
+                    width,
+                    height: 1.0,
+                },
+                border_radius: 0.0.into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            color,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::renderer::Null;
+
+    const SHIFT: keyboard::Modifiers = keyboard::Modifiers::SHIFT;
+    const COMMAND: keyboard::Modifiers = keyboard::Modifiers::COMMAND;
+
+    static ROWS: [u32; 2] = [1, 2];
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Sorted(usize, Order),
+        Resized(usize, f32),
+    }
+
+    fn table(
+        sort: Option<(usize, Order)>,
+    ) -> Table<'static, u32, Message, Null> {
+        let cell = |row: &u32| crate::widget::Text::new(row.to_string()).into();
+
+        Table::new(
+            vec![
+                Column::new("Sortable", cell).width(100.0).sortable(true),
+                Column::new("Fixed", cell).width(80.0),
+            ],
+            &ROWS,
+        )
+        .sort(sort)
+        .on_sort(Message::Sorted)
+        .on_resize(10, |event| Message::Resized(event.column, event.width))
+    }
+
+    fn send(
+        table: &mut Table<'static, u32, Message, Null>,
+        tree: &mut Tree,
+        event: Event,
+        cursor_position: Point,
+    ) -> (event::Status, Vec<Message>) {
+        let node = Widget::<Message, Null>::layout(
+            table,
+            &Null,
+            &layout::Limits::new(Size::ZERO, Size::new(400.0, 400.0)),
+        );
+
+        let mut messages = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+
+        let status = table.on_event(
+            tree,
+            event,
+            Layout::new(&node),
+            cursor_position,
+            &Null,
+            &mut crate::clipboard::Null,
+            &mut shell,
+        );
+
+        (status, messages)
+    }
+
+    fn click(
+        table: &mut Table<'static, u32, Message, Null>,
+        x: f32,
+    ) -> (event::Status, Vec<Message>) {
+        let mut tree = Tree::new(&*table as &dyn Widget<Message, Null>);
+
+        send(
+            table,
+            &mut tree,
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            Point::new(x, 10.0),
+        )
+    }
+
+    #[test]
+    fn clicking_a_sortable_header_sorts_the_rows() {
+        assert_eq!(
+            click(&mut table(None), 50.0),
+            (
+                event::Status::Captured,
+                vec![Message::Sorted(0, Order::Ascending)]
+            )
+        );
+        assert_eq!(
+            click(&mut table(Some((0, Order::Ascending))), 50.0),
+            (
+                event::Status::Captured,
+                vec![Message::Sorted(0, Order::Descending)]
+            )
+        );
+        assert_eq!(
+            click(&mut table(Some((0, Order::Descending))), 50.0),
+            (
+                event::Status::Captured,
+                vec![Message::Sorted(0, Order::Ascending)]
+            )
+        );
+    }
+
+    #[test]
+    fn clicking_other_headers_does_not_sort_the_rows() {
+        assert_eq!(
+            click(&mut table(Some((0, Order::Ascending))), 150.0),
+            (event::Status::Captured, vec![])
+        );
+        assert_eq!(
+            click(&mut table(None), 300.0),
+            (event::Status::Captured, vec![])
+        );
+    }
+
+    #[test]
+    fn dividers_are_hit_within_their_leeway() {
+        let mut table = table(None);
+
+        assert_eq!(table.divider_at(96.0, 10), Some(0));
+        assert_eq!(table.divider_at(105.0, 10), Some(0));
+        assert_eq!(table.divider_at(106.0, 10), None);
+        assert_eq!(table.divider_at(181.0, 10), Some(1));
+
+        // Grabbing a divider does not sort the rows
+        assert_eq!(click(&mut table, 97.0), (event::Status::Captured, vec![]));
+    }
+
+    #[test]
+    fn dragging_a_divider_resizes_its_column() {
+        let mut table = table(None);
+        let mut tree = Tree::new(&table as &dyn Widget<Message, Null>);
+
+        let _ = send(
+            &mut table,
+            &mut tree,
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            Point::new(98.0, 10.0),
+        );
+
+        let drag = |table: &mut Table<'static, u32, Message, Null>,
+                    tree: &mut Tree,
+                    x: f32| {
+            let position = Point::new(x, 10.0);
+
+            send(
+                table,
+                tree,
+                Event::Mouse(mouse::Event::CursorMoved { position }),
+                position,
+            )
+        };
+
+        assert_eq!(
+            drag(&mut table, &mut tree, 130.0),
+            (event::Status::Captured, vec![Message::Resized(0, 132.0)])
+        );
+        assert_eq!(
+            drag(&mut table, &mut tree, 0.0),
+            (
+                event::Status::Captured,
+                vec![Message::Resized(0, MIN_COLUMN_WIDTH)]
+            )
+        );
+
+        let _ = send(
+            &mut table,
+            &mut tree,
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+            Point::new(0.0, 10.0),
+        );
+
+        assert_eq!(
+            drag(&mut table, &mut tree, 130.0),
+            (event::Status::Ignored, vec![])
+        );
+    }
+
+    #[test]
+    fn select_single_row() {
+        let modifiers = SHIFT | COMMAND;
+
+        assert_eq!(
+            select(Selection::Single, &[1], Some(1), 4, modifiers),
+            (vec![4], 4)
+        );
+    }
+
+    #[test]
+    fn select_toggles_rows() {
+        let selected = [1, 3];
+
+        assert_eq!(
+            select(Selection::Multiple, &selected, Some(3), 2, COMMAND),
+            (vec![1, 2, 3], 2)
+        );
+        assert_eq!(
+            select(Selection::Multiple, &selected, Some(3), 1, COMMAND),
+            (vec![3], 1)
+        );
+    }
+
+    #[test]
+    fn select_range_from_anchor() {
+        assert_eq!(
+            select(Selection::Multiple, &[5], Some(2), 5, SHIFT),
+            (vec![2, 3, 4, 5], 2)
+        );
+        assert_eq!(
+            select(Selection::Multiple, &[0, 5], Some(2), 0, SHIFT | COMMAND),
+            (vec![0, 1, 2, 5], 2)
+        );
+        assert_eq!(
+            select(Selection::Multiple, &[], None, 3, SHIFT),
+            (vec![3], 3)
+        );
+    }
+
+    #[test]
+    fn select_replaces_selection() {
+        assert_eq!(
+            select(
+                Selection::Multiple,
+                &[0, 1, 2],
+                Some(0),
+                7,
+                keyboard::Modifiers::empty()
+            ),
+            (vec![7], 7)
+        );
+    }
+}
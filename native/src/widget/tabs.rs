@@ -0,0 +1,1133 @@
+//! Switch between pages of content with a bar of tabs.
+//!
+//! A [`Tabs`] widget has some local [`State`].
+use crate::alignment;
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::renderer;
+use crate::text::{self, Text};
+use crate::touch;
+use crate::widget::operation::Operation;
+use crate::widget::tree::{self, Tree};
+use crate::{
+    Clipboard, Color, Element, Hasher, Layout, Length, Point, Rectangle, Shell,
+    Size, Vector, Widget,
+};
+
+use std::hash::{Hash, Hasher as _};
+
+pub use iced_style::tabs::{Appearance, Bar, StyleSheet};
+
+/// The symbol of the close button of a tab.
+const CLOSE_SYMBOL: &str = "×";
+
+/// A bar of tabs on top of the page of the active one.
+///
+/// Only the page of the active tab is part of a [`Tabs`] widget, so the
+/// pages of the other tabs are never built. When the tabs do not fit in the
+/// bar, it can be scrolled horizontally with the mouse wheel.
+///
+/// The state of the page and of the element labels follows the identity of
+/// their tab, so closing or reordering other tabs does not reset it. Text
+/// labels are identified by their text and element labels by their position,
+/// unless they are pushed with a key using [`Tabs::push_keyed`].
+///
+/// # Example
+/// ```
+/// # use iced_native::widget::{tabs, text};
+/// # use iced_native::renderer::Null;
+/// #
+/// # type Tabs<'a, Message> = tabs::Tabs<'a, Message, Null>;
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     TabSelected(usize),
+///     TabClosed(usize),
+/// }
+///
+/// let active = 1;
+///
+/// let tabs = Tabs::new(active, text(format!("Page {active}")))
+///     .push("Overview")
+///     .push("Settings")
+///     .push(tabs::Label::Element(text("Logs").into()))
+///     .on_select(Message::TabSelected)
+///     .on_close(Message::TabClosed);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Tabs<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    labels: Vec<Label<'a, Message, Renderer>>,
+    keys: Vec<Key>,
+    active: usize,
+    content: Element<'a, Message, Renderer>,
+    on_select: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    on_close: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    width: Length,
+    height: Length,
+    padding: u16,
+    spacing: u16,
+    text_size: Option<u16>,
+    font: Renderer::Font,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+/// The label of a tab.
+#[allow(missing_debug_implementations)]
+pub enum Label<'a, Message, Renderer> {
+    /// Some text, drawn with the colors of the [`StyleSheet`].
+    Text(String),
+
+    /// An arbitrary element.
+    Element(Element<'a, Message, Renderer>),
+}
+
+impl<'a, Message, Renderer> From<&str> for Label<'a, Message, Renderer> {
+    fn from(text: &str) -> Self {
+        Label::Text(text.to_owned())
+    }
+}
+
+impl<'a, Message, Renderer> From<String> for Label<'a, Message, Renderer> {
+    fn from(text: String) -> Self {
+        Label::Text(text)
+    }
+}
+
+impl<'a, Message, Renderer> Tabs<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`Tabs`] widget with the index of the active tab and
+    /// its page.
+    pub fn new(
+        active: usize,
+        content: impl Into<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        Tabs {
+            labels: Vec::new(),
+            keys: Vec::new(),
+            active,
+            content: content.into(),
+            on_select: None,
+            on_close: None,
+            width: Length::Fill,
+            height: Length::Shrink,
+            padding: 8,
+            spacing: 0,
+            text_size: None,
+            font: Default::default(),
+            style: Default::default(),
+        }
+    }
+
+    /// Adds a tab with the given [`Label`] to the [`Tabs`].
+    pub fn push(
+        mut self,
+        label: impl Into<Label<'a, Message, Renderer>>,
+    ) -> Self {
+        let label = label.into();
+        let key = match &label {
+            Label::Text(content) => {
+                Key::Hash(hash(&(content, self.occurrences(content))))
+            }
+            Label::Element(_) => Key::Position(self.labels.len()),
+        };
+
+        self.labels.push(label);
+        self.keys.push(key);
+        self
+    }
+
+    /// Adds a tab with the given [`Label`] to the [`Tabs`], identified by
+    /// the given key.
+    ///
+    /// The key should be unique among the tabs and remain the same for as
+    /// long as the tab is open.
+    pub fn push_keyed(
+        mut self,
+        key: impl Hash,
+        label: impl Into<Label<'a, Message, Renderer>>,
+    ) -> Self {
+        self.labels.push(label.into());
+        self.keys.push(Key::Custom(hash(&key)));
+        self
+    }
+
+    /// Sets the message that will be produced when a tab is clicked.
+    ///
+    /// It receives the index of the tab.
+    pub fn on_select<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Fn(usize) -> Message,
+    {
+        self.on_select = Some(Box::new(f));
+        self
+    }
+
+    /// Shows a close button on every tab, which will produce the message of
+    /// the given function when clicked.
+    ///
+    /// It receives the index of the tab.
+    pub fn on_close<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Fn(usize) -> Message,
+    {
+        self.on_close = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the width of the [`Tabs`].
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Tabs`].
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the padding of each tab.
+    pub fn padding(mut self, padding: u16) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the horizontal spacing between the tabs.
+    pub fn spacing(mut self, spacing: u16) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the text size of the labels and the close buttons of the tabs.
+    pub fn text_size(mut self, size: u16) -> Self {
+        self.text_size = Some(size);
+        self
+    }
+
+    /// Sets the font of the text labels of the tabs.
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`Tabs`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+/// The identity of a tab.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Key {
+    Hash(u64),
+    Position(usize),
+    Custom(u64),
+}
+
+/// The local state of a [`Tabs`] widget.
+#[derive(Debug, Clone, Default)]
+pub struct State {
+    active: Option<Key>,
+    keys: Vec<Key>,
+    offset: f32,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the horizontal scroll offset of the bar of tabs, given its
+    /// bounds and the width of all of its tabs.
+    fn offset(&self, bounds: Rectangle, tabs_width: f32) -> f32 {
+        let max_offset = (tabs_width - bounds.width).max(0.0);
+
+        self.offset.max(0.0).min(max_offset)
+    }
+
+    /// Scrolls the bar of tabs by the given amount of pixels, given its
+    /// bounds and the width of all of its tabs.
+    fn scroll(&mut self, delta: f32, bounds: Rectangle, tabs_width: f32) {
+        let max_offset = (tabs_width - bounds.width).max(0.0);
+
+        self.offset = (self.offset(bounds, tabs_width) - delta)
+            .max(0.0)
+            .min(max_offset);
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Tabs<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            active: self.keys.get(self.active).copied(),
+            keys: self.keys.clone(),
+            offset: 0.0,
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.labels
+            .iter()
+            .map(label_tree)
+            .chain(std::iter::once(Tree::new(&self.content)))
+            .collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+        let active = self.keys.get(self.active).copied();
+        let content = tree.children.pop();
+
+        // The page of a different tab must not inherit the state of the
+        // previous one
+        let mut content = match content {
+            Some(content) if state.active == active => content,
+            _ => Tree::new(&self.content),
+        };
+
+        content.diff(&self.content);
+
+        // The labels keep their state when other tabs are closed or moved
+        let mut previous: Vec<_> = std::mem::take(&mut tree.children)
+            .into_iter()
+            .map(Some)
+            .collect();
+
+        tree.children = self
+            .labels
+            .iter()
+            .zip(&self.keys)
+            .map(|(label, key)| {
+                let tree = state
+                    .keys
+                    .iter()
+                    .position(|previous| previous == key)
+                    .and_then(|index| previous.get_mut(index)?.take());
+
+                match (label, tree) {
+                    (Label::Element(element), Some(mut tree)) => {
+                        tree.diff(element);
+                        tree
+                    }
+                    (label, _) => label_tree(label),
+                }
+            })
+            .collect();
+
+        tree.children.push(content);
+
+        state.active = active;
+        state.keys = self.keys.clone();
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        let padding = f32::from(self.padding);
+        let text_size =
+            self.text_size.unwrap_or_else(|| renderer.default_size());
+        let close_width = if self.on_close.is_some() {
+            f32::from(text_size) + padding
+        } else {
+            0.0
+        };
+
+        let labels: Vec<_> = self
+            .labels
+            .iter()
+            .map(|label| match label {
+                Label::Text(content) => {
+                    let (width, height) = renderer.measure(
+                        content,
+                        text_size,
+                        self.font.clone(),
+                        Size::INFINITY,
+                        text::Wrapping::None,
+                    );
+
+                    layout::Node::new(Size::new(width, height))
+                }
+                Label::Element(element) => element.as_widget().layout(
+                    renderer,
+                    &layout::Limits::new(
+                        Size::ZERO,
+                        Size::new(f32::INFINITY, limits.max().height),
+                    ),
+                ),
+            })
+            .collect();
+
+        let label_height = labels
+            .iter()
+            .map(|label| label.size().height)
+            .fold(f32::from(text_size), f32::max);
+        let bar_height = label_height + padding * 2.0;
+
+        let mut x = 0.0;
+
+        let tabs: Vec<_> = labels
+            .into_iter()
+            .enumerate()
+            .map(|(index, mut label)| {
+                if index > 0 {
+                    x += f32::from(self.spacing);
+                }
+
+                let label_size = label.size();
+
+                label.move_to(Point::new(
+                    padding,
+                    (bar_height - label_size.height) / 2.0,
+                ));
+
+                let mut tab = layout::Node::with_children(
+                    Size::new(
+                        label_size.width + close_width + padding * 2.0,
+                        bar_height,
+                    ),
+                    vec![label],
+                );
+
+                tab.move_to(Point::new(x, 0.0));
+                x += tab.size().width;
+
+                tab
+            })
+            .collect();
+
+        let mut content = self
+            .content
+            .as_widget()
+            .layout(renderer, &limits.shrink(Size::new(0.0, bar_height)));
+        content.move_to(Point::new(0.0, bar_height));
+
+        let size = limits.resolve(Size::new(
+            x.max(content.size().width),
+            bar_height + content.size().height,
+        ));
+
+        let bar = layout::Node::with_children(
+            Size::new(size.width, bar_height),
+            tabs,
+        );
+
+        layout::Node::with_children(size, vec![bar, content])
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let content_layout = layout.children().nth(1).unwrap();
+
+        operation.container(None, &mut |operation| {
+            if let Some(tree) = tree.children.last_mut() {
+                self.content.as_widget().operate(
+                    tree,
+                    content_layout,
+                    operation,
+                );
+            }
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        let mut children = layout.children();
+        let bar = children.next().unwrap();
+        let content_layout = children.next().unwrap();
+
+        let bar_bounds = bar.bounds();
+        let tabs_width = tabs_width(bar);
+        let is_over_bar = bar_bounds.contains(cursor_position);
+
+        let (label_trees, content_tree) =
+            tree.children.split_at_mut(self.labels.len());
+
+        let content_status = self.content.as_widget_mut().on_event(
+            &mut content_tree[0],
+            event.clone(),
+            content_layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        );
+
+        if content_status == event::Status::Captured {
+            return content_status;
+        }
+
+        let offset = state.offset(bar_bounds, tabs_width);
+
+        let tab_cursor = if is_over_bar {
+            cursor_position + Vector::new(offset, 0.0)
+        } else {
+            Point::new(-1.0, -1.0)
+        };
+
+        let labels_status = self
+            .labels
+            .iter_mut()
+            .zip(label_trees)
+            .zip(bar.children())
+            .filter_map(|((label, tree), tab)| match label {
+                Label::Element(element) => {
+                    Some(element.as_widget_mut().on_event(
+                        tree,
+                        event.clone(),
+                        tab.children().next().unwrap(),
+                        tab_cursor,
+                        renderer,
+                        clipboard,
+                        shell,
+                    ))
+                }
+                Label::Text(_) => None,
+            })
+            .fold(event::Status::Ignored, event::Status::merge);
+
+        if labels_status == event::Status::Captured {
+            return labels_status;
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if is_over_bar && tabs_width > bar_bounds.width =>
+            {
+                let (x, y) = match delta {
+                    mouse::ScrollDelta::Lines { x, y } => (x * 60.0, y * 60.0),
+                    mouse::ScrollDelta::Pixels { x, y } => (x, y),
+                };
+
+                state.scroll(
+                    if x != 0.0 { x } else { y },
+                    bar_bounds,
+                    tabs_width,
+                );
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if is_over_bar =>
+            {
+                let text_size =
+                    self.text_size.unwrap_or_else(|| renderer.default_size());
+
+                if let Some(index) = bar
+                    .children()
+                    .position(|tab| tab.bounds().contains(tab_cursor))
+                {
+                    let tab = bar.children().nth(index).unwrap().bounds();
+                    let close_bounds = self.close_bounds(tab, text_size);
+
+                    match (&self.on_close, &self.on_select) {
+                        (Some(on_close), _)
+                            if close_bounds.contains(tab_cursor) =>
+                        {
+                            shell.publish(on_close(index));
+                        }
+                        (_, Some(on_select)) if index != self.active => {
+                            shell.publish(on_select(index));
+                        }
+                        _ => {}
+                    }
+                }
+
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+
+        let mut children = layout.children();
+        let bar = children.next().unwrap();
+        let content_layout = children.next().unwrap();
+
+        let bar_bounds = bar.bounds();
+        let bar_appearance = theme.bar(&self.style);
+
+        if let Some(background) = bar_appearance.background {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: bar_bounds,
+                    border_radius: 0.0.into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                background,
+            );
+        }
+
+        let offset = state.offset(bar_bounds, tabs_width(bar));
+
+        let tab_cursor = if bar_bounds.contains(cursor_position) {
+            cursor_position + Vector::new(offset, 0.0)
+        } else {
+            Point::new(-1.0, -1.0)
+        };
+
+        let text_size =
+            self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        renderer.with_layer(bar_bounds, |renderer| {
+            renderer.with_translation(Vector::new(-offset, 0.0), |renderer| {
+                for (index, ((label, tree), tab)) in self
+                    .labels
+                    .iter()
+                    .zip(&tree.children)
+                    .zip(bar.children())
+                    .enumerate()
+                {
+                    let bounds = tab.bounds();
+                    let is_active = index == self.active;
+
+                    let appearance = if bounds.contains(tab_cursor) {
+                        theme.hovered(&self.style, is_active)
+                    } else {
+                        theme.active(&self.style, is_active)
+                    };
+
+                    if let Some(background) = appearance.background {
+                        let radius = appearance.border_radius;
+
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds,
+                                border_radius: [radius, radius, 0.0, 0.0]
+                                    .into(),
+                                border_width: 0.0,
+                                border_color: Color::TRANSPARENT,
+                            },
+                            background,
+                        );
+                    }
+
+                    if let Some(color) = appearance.indicator_color {
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: Rectangle {
+                                    y: bounds.y + bounds.height - 2.0,
+                                    height: 2.0,
+                                    ..bounds
+                                },
+                                border_radius: 0.0.into(),
+                                border_width: 0.0,
+                                border_color: Color::TRANSPARENT,
+                            },
+                            color,
+                        );
+                    }
+
+                    let label_layout = tab.children().next().unwrap();
+
+                    match label {
+                        Label::Text(content) => {
+                            let label_bounds = label_layout.bounds();
+
+                            renderer.fill_text(Text {
+                                content,
+                                bounds: Rectangle {
+                                    y: label_bounds.center_y(),
+                                    ..label_bounds
+                                },
+                                size: f32::from(text_size),
+                                color: appearance.text_color,
+                                font: self.font.clone(),
+                                horizontal_alignment:
+                                    alignment::Horizontal::Left,
+                                vertical_alignment: alignment::Vertical::Center,
+                                wrapping: text::Wrapping::None,
+                            });
+                        }
+                        Label::Element(element) => {
+                            element.as_widget().draw(
+                                tree,
+                                renderer,
+                                theme,
+                                &renderer::Style {
+                                    text_color: appearance.text_color,
+                                },
+                                label_layout,
+                                tab_cursor,
+                                &bounds,
+                            );
+                        }
+                    }
+
+                    if self.on_close.is_some() {
+                        let close_bounds = self.close_bounds(bounds, text_size);
+
+                        renderer.fill_text(Text {
+                            content: CLOSE_SYMBOL,
+                            bounds: Rectangle {
+                                x: close_bounds.center_x(),
+                                y: close_bounds.center_y(),
+                                ..close_bounds
+                            },
+                            size: f32::from(text_size),
+                            color: if close_bounds.contains(tab_cursor) {
+                                appearance.text_color
+                            } else {
+                                appearance.close_color
+                            },
+                            font: Default::default(),
+                            horizontal_alignment: alignment::Horizontal::Center,
+                            vertical_alignment: alignment::Vertical::Center,
+                            wrapping: text::Wrapping::None,
+                        });
+                    }
+                }
+            });
+        });
+
+        if bar_appearance.border_width > 0.0 {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        y: bar_bounds.y + bar_bounds.height
+                            - bar_appearance.border_width,
+                        height: bar_appearance.border_width,
+                        ..bar_bounds
+                    },
+                    border_radius: 0.0.into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                bar_appearance.border_color,
+            );
+        }
+
+        if let Some(tree) = tree.children.last() {
+            self.content.as_widget().draw(
+                tree,
+                renderer,
+                theme,
+                style,
+                content_layout,
+                cursor_position,
+                viewport,
+            );
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        let mut children = layout.children();
+        let bar = children.next().unwrap();
+        let content_layout = children.next().unwrap();
+
+        if !bar.bounds().contains(cursor_position) {
+            return tree
+                .children
+                .last()
+                .map(|tree| {
+                    self.content.as_widget().mouse_interaction(
+                        tree,
+                        content_layout,
+                        cursor_position,
+                        viewport,
+                        renderer,
+                    )
+                })
+                .unwrap_or_default();
+        }
+
+        let offset = state.offset(bar.bounds(), tabs_width(bar));
+        let tab_cursor = cursor_position + Vector::new(offset, 0.0);
+        let text_size =
+            self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        let hovered = self
+            .labels
+            .iter()
+            .zip(&tree.children)
+            .zip(bar.children())
+            .enumerate()
+            .find(|(_, (_, tab))| tab.bounds().contains(tab_cursor));
+
+        match hovered {
+            Some((index, ((label, tree), tab))) => {
+                let interaction = match label {
+                    Label::Element(element) => {
+                        element.as_widget().mouse_interaction(
+                            tree,
+                            tab.children().next().unwrap(),
+                            tab_cursor,
+                            &tab.bounds(),
+                            renderer,
+                        )
+                    }
+                    Label::Text(_) => mouse::Interaction::default(),
+                };
+
+                let is_clickable = (self.on_select.is_some()
+                    && index != self.active)
+                    || (self.on_close.is_some()
+                        && self
+                            .close_bounds(tab.bounds(), text_size)
+                            .contains(tab_cursor));
+
+                if interaction == mouse::Interaction::default() && is_clickable
+                {
+                    mouse::Interaction::Pointer
+                } else {
+                    interaction
+                }
+            }
+            None => mouse::Interaction::default(),
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let content_layout = layout.children().nth(1).unwrap();
+
+        self.content.as_widget_mut().overlay(
+            tree.children.last_mut()?,
+            content_layout,
+            renderer,
+        )
+    }
+}
+
+impl<'a, Message, Renderer> Tabs<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Returns the number of tabs with the given text label.
+    fn occurrences(&self, text: &str) -> usize {
+        self.labels
+            .iter()
+            .filter(|label| matches!(label, Label::Text(content) if content == text))
+            .count()
+    }
+
+    /// Returns the bounds of the close button of the tab with the given
+    /// bounds.
+    fn close_bounds(&self, tab: Rectangle, text_size: u16) -> Rectangle {
+        let size = f32::from(text_size);
+
+        Rectangle {
+            x: tab.x + tab.width - f32::from(self.padding) - size,
+            y: tab.center_y() - size / 2.0,
+            width: size,
+            height: size,
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<Tabs<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(tabs: Tabs<'a, Message, Renderer>) -> Self {
+        Element::new(tabs)
+    }
+}
+
+/// Creates the [`Tree`] of a [`Label`].
+fn label_tree<Message, Renderer>(label: &Label<'_, Message, Renderer>) -> Tree
+where
+    Renderer: crate::Renderer,
+{
+    match label {
+        Label::Text(_) => Tree::empty(),
+        Label::Element(element) => Tree::new(element),
+    }
+}
+
+/// Hashes the given key of a tab.
+fn hash(key: &impl Hash) -> u64 {
+    let mut hasher = Hasher::default();
+    key.hash(&mut hasher);
+
+    hasher.finish()
+}
+
+/// Returns the total width of the tabs in the given bar.
+fn tabs_width(bar: Layout<'_>) -> f32 {
+    let x = bar.bounds().x;
+
+    bar.children()
+        .last()
+        .map(|tab| {
+            let bounds = tab.bounds();
+
+            bounds.x + bounds.width - x
+        })
+        .unwrap_or(0.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::clipboard;
+    use crate::renderer::Null;
+    use crate::widget::text_input::{self, TextInput};
+
+    type Tabs<'a> = super::Tabs<'a, usize, Null>;
+
+    fn page(value: &str) -> TextInput<'_, usize, Null> {
+        TextInput::new("", value, |_| 0)
+    }
+
+    fn is_focused(tree: &Tree) -> bool {
+        tree.children
+            .last()
+            .unwrap()
+            .state
+            .downcast_ref::<text_input::State>()
+            .is_focused()
+    }
+
+    fn focus(tree: &mut Tree) {
+        tree.children
+            .last_mut()
+            .unwrap()
+            .state
+            .downcast_mut::<text_input::State>()
+            .focus();
+    }
+
+    #[test]
+    fn keeps_the_page_when_closing_a_tab_before_the_active_one() {
+        let tabs = Tabs::new(2, page("c")).push("a").push("b").push("c");
+        let mut tree = Tree::new(&tabs as &dyn Widget<_, _>);
+        focus(&mut tree);
+
+        let tabs = Tabs::new(1, page("c")).push("a").push("c");
+        tree.diff(&tabs as &dyn Widget<_, _>);
+
+        assert!(is_focused(&tree));
+        assert_eq!(tree.children.len(), 3);
+    }
+
+    #[test]
+    fn resets_the_page_when_closing_the_active_tab() {
+        let tabs = Tabs::new(1, page("b")).push("a").push("b").push("c");
+        let mut tree = Tree::new(&tabs as &dyn Widget<_, _>);
+        focus(&mut tree);
+
+        let tabs = Tabs::new(1, page("c")).push("a").push("c");
+        tree.diff(&tabs as &dyn Widget<_, _>);
+
+        assert!(!is_focused(&tree));
+    }
+
+    #[test]
+    fn keeps_the_page_of_duplicate_and_keyed_tabs() {
+        let tabs = Tabs::new(1, page(""))
+            .push_keyed(1, "Untitled")
+            .push_keyed(2, "Untitled");
+        let mut tree = Tree::new(&tabs as &dyn Widget<_, _>);
+        focus(&mut tree);
+
+        let tabs = Tabs::new(0, page("")).push_keyed(2, "Untitled");
+        tree.diff(&tabs as &dyn Widget<_, _>);
+        assert!(is_focused(&tree));
+
+        let tabs = Tabs::new(1, page("")).push("Untitled").push("Untitled");
+        let mut tree = Tree::new(&tabs as &dyn Widget<_, _>);
+        focus(&mut tree);
+
+        let tabs = Tabs::new(0, page("")).push("Untitled").push("Untitled");
+        tree.diff(&tabs as &dyn Widget<_, _>);
+        assert!(!is_focused(&tree));
+    }
+
+    #[test]
+    fn moves_element_labels_with_their_tabs() {
+        let tabs = Tabs::new(0, page(""))
+            .push_keyed("a", Label::Element(page("a").into()))
+            .push_keyed("b", Label::Element(page("b").into()));
+        let mut tree = Tree::new(&tabs as &dyn Widget<_, _>);
+        tree.children[1]
+            .state
+            .downcast_mut::<text_input::State>()
+            .focus();
+
+        let tabs = Tabs::new(0, page(""))
+            .push_keyed("b", Label::Element(page("b").into()));
+        tree.diff(&tabs as &dyn Widget<_, _>);
+
+        assert_eq!(tree.children.len(), 2);
+        assert!(tree.children[0]
+            .state
+            .downcast_ref::<text_input::State>()
+            .is_focused());
+    }
+
+    #[test]
+    fn scrolls_and_selects_overflowing_tabs() {
+        let renderer = Null::new();
+        let mut messages = Vec::new();
+
+        // Every tab is as wide as its padding, since the null renderer
+        // measures text with no width
+        let mut tabs = Tabs::new(0, page(""))
+            .push("a")
+            .push("b")
+            .push("c")
+            .push("d")
+            .push("e")
+            .width(Length::Units(40))
+            .on_select(|index| index);
+
+        let mut tree = Tree::new(&tabs as &dyn Widget<_, _>);
+        let node = Widget::layout(
+            &tabs,
+            &renderer,
+            &layout::Limits::new(Size::ZERO, Size::new(100.0, 100.0)),
+        );
+        let layout = Layout::new(&node);
+        let mut shell = Shell::new(&mut messages);
+
+        let status = tabs.on_event(
+            &mut tree,
+            Event::Mouse(mouse::Event::WheelScrolled {
+                delta: mouse::ScrollDelta::Pixels { x: 0.0, y: -20.0 },
+            }),
+            layout,
+            Point::new(20.0, 10.0),
+            &renderer,
+            &mut clipboard::Null,
+            &mut shell,
+        );
+
+        assert_eq!(status, event::Status::Captured);
+        assert_eq!(tree.state.downcast_ref::<State>().offset, 20.0);
+
+        let _ = tabs.on_event(
+            &mut tree,
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            layout,
+            Point::new(4.0, 10.0),
+            &renderer,
+            &mut clipboard::Null,
+            &mut shell,
+        );
+
+        assert_eq!(messages, vec![1]);
+    }
+
+    #[test]
+    fn scroll_is_clamped_to_the_tabs() {
+        let bounds = Rectangle {
+            x: 0.0,
+            y: 0.0,
+            width: 100.0,
+            height: 30.0,
+        };
+
+        let mut state = State::new();
+
+        state.scroll(-60.0, bounds, 250.0);
+        assert_eq!(state.offset, 60.0);
+
+        state.scroll(-200.0, bounds, 250.0);
+        assert_eq!(state.offset, 150.0);
+
+        state.scroll(500.0, bounds, 250.0);
+        assert_eq!(state.offset, 0.0);
+
+        state.scroll(-60.0, bounds, 80.0);
+        assert_eq!(state.offset, 0.0);
+    }
+
+    #[test]
+    fn offset_is_clamped_when_tabs_are_closed() {
+        let bounds = Rectangle {
+            x: 0.0,
+            y: 0.0,
+            width: 100.0,
+            height: 30.0,
+        };
+
+        let mut state = State::new();
+        state.scroll(-150.0, bounds, 250.0);
+
+        assert_eq!(state.offset(bounds, 250.0), 150.0);
+        assert_eq!(state.offset(bounds, 180.0), 80.0);
+        assert_eq!(state.offset(bounds, 60.0), 0.0);
+
+        state.scroll(-10.0, bounds, 180.0);
+        assert_eq!(state.offset, 80.0);
+    }
+}
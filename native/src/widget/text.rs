@@ -1,15 +1,36 @@
 //! Write some text for your users to read.
+mod paragraph;
+
 use crate::alignment;
+use crate::event::{self, Event};
+use crate::keyboard;
 use crate::layout;
+use crate::mouse::{self, click};
 use crate::renderer;
 use crate::text;
-use crate::widget::Tree;
-use crate::{Element, Layout, Length, Point, Rectangle, Size, Widget};
+use crate::touch;
+use crate::widget::text_input::{Cursor, Value};
+use crate::widget::tree::{self, Tree};
+use crate::{
+    Clipboard, Color, Element, Layout, Length, Point, Rectangle, Shell, Size,
+    Widget,
+};
 
 use std::borrow::Cow;
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::atomic::{self, AtomicUsize};
 
+pub use crate::text::Wrapping;
 pub use iced_style::text::{Appearance, StyleSheet};
 
+pub(crate) use paragraph::{Line, Paragraph};
+
+/// The number of selections started in any selectable [`Text`].
+///
+/// Only the [`Text`] where the last one started copies its selection.
+static SELECTIONS: AtomicUsize = AtomicUsize::new(0);
+
 /// A paragraph of text.
 ///
 /// # Example
@@ -24,6 +45,13 @@ pub use iced_style::text::{Appearance, StyleSheet};
 ///     .style(Color::from([0.0, 0.0, 1.0]));
 /// ```
 ///
+/// A [`Text`] can be made [`selectable`](Text::selectable), which lets users
+/// select its contents with the mouse and copy them.
+///
+/// Contents that do not fit the width of a [`Text`] are wrapped at word
+/// boundaries by default. A different [`text::Wrapping`] can be set to wrap
+/// them at any character, clip them or truncate them with an ellipsis.
+///
 /// ![Text drawn by `iced_wgpu`](https://github.com/iced-rs/iced/blob/7760618fb112074bc40b148944521f312152012a/docs/images/text.png?raw=true)
 #[allow(missing_debug_implementations)]
 pub struct Text<'a, Renderer>
@@ -39,6 +67,9 @@ where
     vertical_alignment: alignment::Vertical,
     font: Renderer::Font,
     style: <Renderer::Theme as StyleSheet>::Style,
+    wrapping: text::Wrapping,
+    is_selectable: bool,
+    cache: Option<Rc<RefCell<Option<Cache<Renderer::Font>>>>>,
 }
 
 impl<'a, Renderer> Text<'a, Renderer>
@@ -57,6 +88,9 @@ where
             horizontal_alignment: alignment::Horizontal::Left,
             vertical_alignment: alignment::Vertical::Top,
             style: Default::default(),
+            wrapping: text::Wrapping::default(),
+            is_selectable: false,
+            cache: None,
         }
     }
 
@@ -112,6 +146,130 @@ where
         self.vertical_alignment = alignment;
         self
     }
+
+    /// Sets the [`text::Wrapping`] of the [`Text`].
+    ///
+    /// A selectable [`Text`] is clipped instead of truncated with an ellipsis.
+    pub fn wrapping(mut self, wrapping: text::Wrapping) -> Self {
+        self.wrapping = wrapping;
+        self
+    }
+
+    /// Sets whether the contents of the [`Text`] can be selected with the
+    /// mouse and copied.
+    ///
+    /// By default, it is disabled.
+    pub fn selectable(mut self, is_selectable: bool) -> Self {
+        self.is_selectable = is_selectable;
+        self.cache = is_selectable.then(Rc::default);
+        self
+    }
+
+    /// Calls the given function with the [`Paragraph`] of the [`Text`] laid
+    /// out in the given width.
+    ///
+    /// The lines are reused from the given [`Cache`] until the contents,
+    /// size, font, width or wrapping of the [`Text`] change.
+    fn with_paragraph<T>(
+        &self,
+        cache: &RefCell<Option<Cache<Renderer::Font>>>,
+        renderer: &Renderer,
+        width: f32,
+        f: impl FnOnce(&Paragraph<'_, Renderer>) -> T,
+    ) -> T {
+        let size = self.size.unwrap_or_else(|| renderer.default_size());
+        let mut cache = cache.borrow_mut();
+
+        let is_outdated = match cache.as_ref() {
+            Some(cache) => {
+                cache.content != self.content
+                    || cache.size != size
+                    || cache.font != self.font
+                    || cache.width != width
+                    || cache.wrapping != self.wrapping
+            }
+            None => true,
+        };
+
+        if is_outdated {
+            let value = Value::new(&self.content);
+
+            let (lines, line_height) = {
+                let paragraph = Paragraph::new(
+                    renderer,
+                    &value,
+                    size,
+                    &self.font,
+                    width,
+                    self.wrapping,
+                );
+
+                (paragraph.lines.into_owned(), paragraph.line_height)
+            };
+
+            *cache = Some(Cache {
+                content: self.content.to_string(),
+                value,
+                size,
+                font: self.font.clone(),
+                width,
+                wrapping: self.wrapping,
+                lines,
+                line_height,
+            });
+        }
+
+        let cache = cache.as_ref().expect("Paragraph cache");
+
+        f(&Paragraph::with_lines(
+            renderer,
+            &cache.value,
+            size,
+            &cache.font,
+            &cache.lines,
+            cache.line_height,
+        ))
+    }
+
+    /// Returns the position of the contents closest to the given point.
+    fn position_at(
+        &self,
+        paragraph: &Paragraph<'_, Renderer>,
+        bounds: Rectangle,
+        point: Point,
+    ) -> usize {
+        let top = top(paragraph, bounds, self.vertical_alignment);
+
+        let line = ((point.y - top) / paragraph.line_height).max(0.0) as usize;
+        let line = line.min(paragraph.lines.len() - 1);
+
+        let x = line_x(paragraph, line, bounds, self.horizontal_alignment);
+
+        paragraph.position_in(line, point.x - x)
+    }
+}
+
+/// The local state of a [`Text`].
+#[derive(Debug)]
+struct State<Font> {
+    cursor: Cursor,
+    is_dragging: bool,
+    last_click: Option<mouse::Click>,
+    selection: usize,
+    cache: Rc<RefCell<Option<Cache<Font>>>>,
+}
+
+/// The lines of a selectable [`Text`] laid out by its last [`Paragraph`].
+#[derive(Debug)]
+struct Cache<Font> {
+    content: String,
+    value: Value,
+    size: u16,
+    font: Font,
+    width: f32,
+    wrapping: text::Wrapping,
+    lines: Vec<Line>,
+    line_height: f32,
 }
 
 impl<'a, Message, Renderer> Widget<Message, Renderer> for Text<'a, Renderer>
@@ -119,6 +277,32 @@ where
     Renderer: text::Renderer,
     Renderer::Theme: StyleSheet,
 {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Renderer::Font>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            cursor: Cursor::default(),
+            is_dragging: false,
+            last_click: None,
+            selection: 0,
+            cache: self.cache.clone().unwrap_or_default(),
+        })
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        if let Some(cache) = &self.cache {
+            // Hand the lines laid out for the previous widget over to this
+            // one, since `layout` has no access to the state
+            let state = tree.state.downcast_mut::<State<Renderer::Font>>();
+            let lines = state.cache.borrow_mut().take();
+
+            *cache.borrow_mut() = lines;
+            state.cache = Rc::clone(cache);
+        }
+    }
+
     fn width(&self) -> Length {
         self.width
     }
@@ -138,17 +322,169 @@ where
 
         let bounds = limits.max();
 
-        let (width, height) =
-            renderer.measure(&self.content, size, self.font.clone(), bounds);
+        let (width, height) = if let Some(cache) = &self.cache {
+            self.with_paragraph(cache, renderer, bounds.width, |paragraph| {
+                let width = paragraph
+                    .lines
+                    .iter()
+                    .map(|line| line.width)
+                    .fold(0.0, f32::max);
+
+                (width, paragraph.height())
+            })
+        } else {
+            renderer.measure(
+                &self.content,
+                size,
+                self.font.clone(),
+                bounds,
+                self.wrapping,
+            )
+        };
 
         let size = limits.resolve(Size::new(width, height));
 
         layout::Node::new(size)
     }
 
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        _shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        if !self.is_selectable {
+            return event::Status::Ignored;
+        }
+
+        let state = tree.state.downcast_mut::<State<Renderer::Font>>();
+        let cache = Rc::clone(&state.cache);
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if !bounds.contains(cursor_position) {
+                    state.cursor = Cursor::default();
+                    state.is_dragging = false;
+
+                    return event::Status::Ignored;
+                }
+
+                let click =
+                    mouse::Click::new(cursor_position, state.last_click);
+
+                self.with_paragraph(
+                    &cache,
+                    renderer,
+                    bounds.width,
+                    |paragraph| {
+                        let position = self.position_at(
+                            paragraph,
+                            bounds,
+                            cursor_position,
+                        );
+
+                        match click.kind() {
+                            click::Kind::Single => {
+                                state.cursor.move_to(position);
+                                state.is_dragging = true;
+                            }
+                            click::Kind::Double => {
+                                let value = paragraph.value;
+
+                                state.cursor.select_range(
+                                    value.previous_start_of_word(position),
+                                    value.next_end_of_word(position),
+                                );
+                                state.is_dragging = false;
+                            }
+                            click::Kind::Triple => {
+                                let line = paragraph.lines
+                                    [paragraph.line_of(position)];
+
+                                state.cursor.select_range(line.start, line.end);
+                                state.is_dragging = false;
+                            }
+                        }
+                    },
+                );
+
+                state.last_click = Some(click);
+                state.selection =
+                    SELECTIONS.fetch_add(1, atomic::Ordering::Relaxed) + 1;
+
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                state.is_dragging = false;
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. })
+                if state.is_dragging =>
+            {
+                self.with_paragraph(
+                    &cache,
+                    renderer,
+                    bounds.width,
+                    |paragraph| {
+                        let target =
+                            self.position_at(paragraph, bounds, position);
+
+                        state.cursor.select_range(
+                            state.cursor.start(paragraph.value),
+                            target,
+                        );
+                    },
+                );
+
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::C,
+                modifiers,
+            }) if modifiers.command()
+                && state.selection
+                    == SELECTIONS.load(atomic::Ordering::Relaxed) =>
+            {
+                let value = Value::new(&self.content);
+
+                if let Some((start, end)) = state.cursor.selection(&value) {
+                    clipboard.write(value.select(start, end).to_string());
+
+                    return event::Status::Captured;
+                }
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self.is_selectable && layout.bounds().contains(cursor_position) {
+            mouse::Interaction::Text
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
     fn draw(
         &self,
-        _state: &Tree,
+        tree: &Tree,
         renderer: &mut Renderer,
         theme: &Renderer::Theme,
         style: &renderer::Style,
@@ -156,6 +492,39 @@ where
         _cursor_position: Point,
         _viewport: &Rectangle,
     ) {
+        if self.is_selectable {
+            let state = tree.state.downcast_ref::<State<Renderer::Font>>();
+            let bounds = layout.bounds();
+
+            let lines = self.with_paragraph(
+                &state.cache,
+                renderer,
+                bounds.width,
+                |paragraph| {
+                    selected_lines(
+                        paragraph,
+                        bounds,
+                        self.horizontal_alignment,
+                        self.vertical_alignment,
+                        &state.cursor,
+                    )
+                },
+            );
+
+            draw_selectable(
+                renderer,
+                style,
+                layout,
+                lines,
+                self.size,
+                self.font.clone(),
+                theme.appearance(self.style),
+                theme.selection_color(self.style),
+            );
+
+            return;
+        }
+
         draw(
             renderer,
             style,
@@ -166,6 +535,7 @@ where
             theme.appearance(self.style),
             self.horizontal_alignment,
             self.vertical_alignment,
+            self.wrapping,
         );
     }
 }
@@ -180,6 +550,8 @@ where
 ///   used.
 /// * The alignment attributes do not affect the position of the bounds of the
 ///   [`Layout`].
+/// * Contents clipped by [`text::Wrapping::None`] are drawn in a new layer.
+#[allow(clippy::too_many_arguments)]
 pub fn draw<Renderer>(
     renderer: &mut Renderer,
     style: &renderer::Style,
@@ -190,10 +562,12 @@ pub fn draw<Renderer>(
     appearance: Appearance,
     horizontal_alignment: alignment::Horizontal,
     vertical_alignment: alignment::Vertical,
+    wrapping: text::Wrapping,
 ) where
     Renderer: text::Renderer,
 {
     let bounds = layout.bounds();
+    let size = size.unwrap_or_else(|| renderer.default_size());
 
     let x = match horizontal_alignment {
         alignment::Horizontal::Left => bounds.x,
@@ -207,15 +581,168 @@ pub fn draw<Renderer>(
         alignment::Vertical::Bottom => bounds.y + bounds.height,
     };
 
-    renderer.fill_text(crate::text::Text {
+    let is_clipped = wrapping == text::Wrapping::None
+        && renderer.measure_width(content, size, font.clone()) > bounds.width;
+
+    let text = text::Text {
         content,
-        size: f32::from(size.unwrap_or_else(|| renderer.default_size())),
+        size: f32::from(size),
         bounds: Rectangle { x, y, ..bounds },
         color: appearance.color.unwrap_or(style.text_color),
         font,
         horizontal_alignment,
         vertical_alignment,
-    });
+        wrapping,
+    };
+
+    if is_clipped {
+        renderer.with_layer(bounds, |renderer| renderer.fill_text(text));
+    } else {
+        renderer.fill_text(text);
+    }
+}
+
+/// A line of selectable text positioned in its bounds, with the highlight of
+/// its selected part, if any.
+type SelectedLine = (String, f32, f32, Option<Rectangle>);
+
+/// Positions the lines of a [`Paragraph`] in the given bounds, highlighting
+/// the selection of the given [`Cursor`].
+fn selected_lines<Renderer: text::Renderer>(
+    paragraph: &Paragraph<'_, Renderer>,
+    bounds: Rectangle,
+    horizontal_alignment: alignment::Horizontal,
+    vertical_alignment: alignment::Vertical,
+    cursor: &Cursor,
+) -> (Vec<SelectedLine>, f32) {
+    let selection = cursor.selection(paragraph.value);
+    let top = top(paragraph, bounds, vertical_alignment);
+
+    let lines = paragraph
+        .lines
+        .iter()
+        .enumerate()
+        .map(|(i, line)| {
+            let x = line_x(paragraph, i, bounds, horizontal_alignment);
+            let y = top + i as f32 * paragraph.line_height;
+
+            let highlight = selection.and_then(|(start, end)| {
+                let from = start.max(line.start);
+                let to = end.min(line.end);
+
+                (from < to).then(|| Rectangle {
+                    x: x + paragraph.measure(line.start, from),
+                    y,
+                    width: paragraph.measure(from, to),
+                    height: paragraph.line_height,
+                })
+            });
+
+            (paragraph.text(*line), x, y, highlight)
+        })
+        .collect();
+
+    let width = paragraph
+        .lines
+        .iter()
+        .map(|line| line.width)
+        .fold(0.0, f32::max);
+
+    (lines, width)
+}
+
+/// Draws the lines of some selectable text one by one, with their
+/// highlights.
+#[allow(clippy::too_many_arguments)]
+fn draw_selectable<Renderer>(
+    renderer: &mut Renderer,
+    style: &renderer::Style,
+    layout: Layout<'_>,
+    (lines, width): (Vec<SelectedLine>, f32),
+    size: Option<u16>,
+    font: Renderer::Font,
+    appearance: Appearance,
+    selection_color: Color,
+) where
+    Renderer: text::Renderer,
+{
+    let bounds = layout.bounds();
+    let size = size.unwrap_or_else(|| renderer.default_size());
+
+    let color = appearance.color.unwrap_or(style.text_color);
+
+    let draw_lines = |renderer: &mut Renderer| {
+        for (line, x, y, highlight) in lines {
+            if let Some(highlight) = highlight {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: highlight,
+                        border_radius: 0.0.into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    selection_color,
+                );
+            }
+
+            renderer.fill_text(text::Text {
+                content: &line,
+                size: f32::from(size),
+                bounds: Rectangle {
+                    x,
+                    y,
+                    width: f32::INFINITY,
+                    ..bounds
+                },
+                color,
+                font: font.clone(),
+                horizontal_alignment: alignment::Horizontal::Left,
+                vertical_alignment: alignment::Vertical::Top,
+                wrapping: text::Wrapping::None,
+            });
+        }
+    };
+
+    if width > bounds.width {
+        renderer.with_layer(bounds, draw_lines);
+    } else {
+        draw_lines(renderer);
+    }
+}
+
+/// Returns the top of the lines of a [`Paragraph`] aligned in the given
+/// bounds.
+fn top<Renderer: text::Renderer>(
+    paragraph: &Paragraph<'_, Renderer>,
+    bounds: Rectangle,
+    alignment: alignment::Vertical,
+) -> f32 {
+    match alignment {
+        alignment::Vertical::Top => bounds.y,
+        alignment::Vertical::Center => {
+            bounds.center_y() - paragraph.height() / 2.0
+        }
+        alignment::Vertical::Bottom => {
+            bounds.y + bounds.height - paragraph.height()
+        }
+    }
+}
+
+/// Returns the left side of a line of a [`Paragraph`] aligned in the given
+/// bounds.
+fn line_x<Renderer: text::Renderer>(
+    paragraph: &Paragraph<'_, Renderer>,
+    line: usize,
+    bounds: Rectangle,
+    alignment: alignment::Horizontal,
+) -> f32 {
+    let width = paragraph.lines[line].width;
+
+    match alignment {
+        alignment::Horizontal::Left => bounds.x,
+        alignment::Horizontal::Center => bounds.center_x() - width / 2.0,
+        alignment::Horizontal::Right => bounds.x + bounds.width - width,
+    }
 }
 
 impl<'a, Message, Renderer> From<Text<'a, Renderer>>
@@ -244,6 +771,9 @@ where
             vertical_alignment: self.vertical_alignment,
             font: self.font.clone(),
             style: self.style,
+            wrapping: self.wrapping,
+            is_selectable: self.is_selectable,
+            cache: self.cache.as_ref().map(|_| Rc::default()),
         }
     }
 }
@@ -257,3 +787,83 @@ where
         Text::new(contents).into()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::renderer::Null;
+
+    #[derive(Default)]
+    struct Recording(Vec<String>);
+
+    impl Clipboard for Recording {
+        fn read(&self) -> Option<String> {
+            self.0.last().cloned()
+        }
+
+        fn write(&mut self, contents: String) {
+            self.0.push(contents);
+        }
+    }
+
+    fn event(
+        text: &mut Text<'_, Null>,
+        tree: &mut Tree,
+        event: Event,
+        clipboard: &mut Recording,
+    ) -> event::Status {
+        let node = layout::Node::new(Size::new(100.0, 20.0));
+        let mut messages: Vec<()> = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+
+        Widget::<(), Null>::on_event(
+            text,
+            tree,
+            event,
+            Layout::new(&node),
+            Point::new(10.0, 10.0),
+            &Null,
+            clipboard,
+            &mut shell,
+        )
+    }
+
+    fn select_all(text: &mut Text<'_, Null>, tree: &mut Tree) {
+        for _ in 0..3 {
+            let _ = event(
+                text,
+                tree,
+                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+                &mut Recording::default(),
+            );
+        }
+    }
+
+    #[test]
+    fn copies_the_last_selection_only() {
+        let mut first = Text::new("first").selectable(true);
+        let mut second = Text::new("second").selectable(true);
+
+        let mut first_tree = Tree::new(&first as &dyn Widget<(), Null>);
+        let mut second_tree = Tree::new(&second as &dyn Widget<(), Null>);
+
+        select_all(&mut first, &mut first_tree);
+        select_all(&mut second, &mut second_tree);
+
+        let copy = Event::Keyboard(keyboard::Event::KeyPressed {
+            key_code: keyboard::KeyCode::C,
+            modifiers: keyboard::Modifiers::CTRL,
+        });
+        let mut clipboard = Recording::default();
+
+        let first_status =
+            event(&mut first, &mut first_tree, copy.clone(), &mut clipboard);
+        let second_status =
+            event(&mut second, &mut second_tree, copy, &mut clipboard);
+
+        assert_eq!(first_status, event::Status::Ignored);
+        assert_eq!(second_status, event::Status::Captured);
+        assert_eq!(clipboard.0, vec![String::from("second")]);
+    }
+}
@@ -0,0 +1,463 @@
+//! Lay out text in visual lines that fit some width.
+use crate::text;
+use crate::widget::text_input::Value;
+use crate::{Point, Size};
+
+use std::borrow::Cow;
+
+/// A visual line of a [`Paragraph`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Line {
+    /// The index of the first grapheme of the line.
+    pub(crate) start: usize,
+
+    /// The index right after the last grapheme of the line, excluding
+    /// line breaks.
+    pub(crate) end: usize,
+
+    /// Whether the line continues in the next one because it did not fit.
+    pub(crate) is_wrapped: bool,
+
+    /// The width of the graphemes of the line.
+    pub(crate) width: f32,
+}
+
+/// The graphemes of a [`Value`] laid out in visual lines that fit some width.
+pub(crate) struct Paragraph<'a, Renderer: text::Renderer> {
+    renderer: &'a Renderer,
+    pub(crate) value: &'a Value,
+    size: u16,
+    font: &'a Renderer::Font,
+    pub(crate) lines: Cow<'a, [Line]>,
+    pub(crate) line_height: f32,
+}
+
+impl<'a, Renderer: text::Renderer> Paragraph<'a, Renderer> {
+    /// Lays out the graphemes of a [`Value`] in lines.
+    ///
+    /// The lines are only broken to fit the given width if the
+    /// [`text::Wrapping`] wraps them, at any grapheme with
+    /// [`text::Wrapping::Character`] and preferably after whitespace otherwise.
+    pub(crate) fn new(
+        renderer: &'a Renderer,
+        value: &'a Value,
+        size: u16,
+        font: &'a Renderer::Font,
+        width: f32,
+        wrapping: text::Wrapping,
+    ) -> Self {
+        let (_, line_height) = renderer.measure(
+            "M",
+            size,
+            font.clone(),
+            Size::INFINITY,
+            text::Wrapping::None,
+        );
+
+        let mut paragraph = Self {
+            renderer,
+            value,
+            size,
+            font,
+            lines: Cow::Owned(Vec::new()),
+            line_height: line_height.max(f32::from(size)),
+        };
+
+        let width = if wrapping.is_wrapped() {
+            width
+        } else {
+            f32::INFINITY
+        };
+
+        let graphemes = value.graphemes();
+        let mut start = 0;
+
+        loop {
+            let end = graphemes[start..]
+                .iter()
+                .position(|grapheme| is_line_break(grapheme))
+                .map_or(graphemes.len(), |i| start + i);
+
+            paragraph.wrap(start, end, width, wrapping);
+
+            if end == graphemes.len() {
+                break;
+            }
+
+            start = end + 1;
+        }
+
+        paragraph
+    }
+
+    /// Creates a [`Paragraph`] with the lines of a previous one, laid out
+    /// with the same [`Value`], size and font.
+    pub(crate) fn with_lines(
+        renderer: &'a Renderer,
+        value: &'a Value,
+        size: u16,
+        font: &'a Renderer::Font,
+        lines: &'a [Line],
+        line_height: f32,
+    ) -> Self {
+        Self {
+            renderer,
+            value,
+            size,
+            font,
+            lines: Cow::Borrowed(lines),
+            line_height,
+        }
+    }
+
+    /// Breaks the graphemes from `start` to `end` into lines that fit the
+    /// given width, following the given [`text::Wrapping`].
+    ///
+    /// The graphemes are measured in segments between break opportunities,
+    /// so each one is measured about once no matter how long the line is.
+    fn wrap(
+        &mut self,
+        start: usize,
+        end: usize,
+        width: f32,
+        wrapping: text::Wrapping,
+    ) {
+        let mut line_start = start;
+
+        if width.is_finite() {
+            let mut line_width = 0.0;
+            let mut segment_start = start;
+
+            while segment_start < end {
+                let segment_end = self.next_break(segment_start, end, wrapping);
+                let segment_width = self.measure(segment_start, segment_end);
+
+                if line_width + segment_width <= width {
+                    line_width += segment_width;
+                    segment_start = segment_end;
+
+                    continue;
+                }
+
+                // Segments that do not fit in a line of their own are broken
+                // at any grapheme
+                let line_end = if segment_start > line_start {
+                    segment_start
+                } else {
+                    self.fit(segment_start, segment_end, width)
+                };
+
+                self.push_line(line_start, line_end, true);
+
+                line_start = line_end;
+                line_width = 0.0;
+                segment_start = line_end;
+            }
+        }
+
+        self.push_line(line_start, end, false);
+    }
+
+    /// Returns the index right after the next break opportunity from the
+    /// grapheme at `start`.
+    fn next_break(
+        &self,
+        start: usize,
+        end: usize,
+        wrapping: text::Wrapping,
+    ) -> usize {
+        if wrapping == text::Wrapping::Character {
+            return start + 1;
+        }
+
+        self.value.graphemes()[start..end]
+            .iter()
+            .position(|grapheme| grapheme.trim().is_empty())
+            .map_or(end, |i| start + i + 1)
+    }
+
+    /// Returns the end of the longest run of graphemes from `start` that fits
+    /// the given width, which contains one grapheme at least.
+    ///
+    /// The graphemes from `start` to `end` must not fit.
+    fn fit(&self, start: usize, end: usize, width: f32) -> usize {
+        let (mut low, mut high) = (start + 1, end);
+
+        while high - low > 1 {
+            let middle = (low + high) / 2;
+
+            if self.measure(start, middle) <= width {
+                low = middle;
+            } else {
+                high = middle;
+            }
+        }
+
+        low
+    }
+
+    fn push_line(&mut self, start: usize, end: usize, is_wrapped: bool) {
+        let width = self.measure(start, end);
+
+        self.lines.to_mut().push(Line {
+            start,
+            end,
+            is_wrapped,
+            width,
+        });
+    }
+
+    /// Returns the total height of the lines.
+    pub(crate) fn height(&self) -> f32 {
+        self.lines.len() as f32 * self.line_height
+    }
+
+    /// Returns the contents of the given line.
+    pub(crate) fn text(&self, line: Line) -> String {
+        self.value.graphemes()[line.start..line.end].concat()
+    }
+
+    /// Measures the width of the graphemes from `start` to `end`.
+    pub(crate) fn measure(&self, start: usize, end: usize) -> f32 {
+        self.renderer.measure_width(
+            &self.value.graphemes()[start..end].concat(),
+            self.size,
+            self.font.clone(),
+        )
+    }
+
+    /// Returns the index of the line containing the given position.
+    ///
+    /// A position right at a wrapping point belongs to the next line.
+    pub(crate) fn line_of(&self, position: usize) -> usize {
+        self.lines
+            .iter()
+            .rposition(|line| line.start <= position)
+            .unwrap_or(0)
+    }
+
+    /// Returns the horizontal offset of the given position in its line.
+    pub(crate) fn x_of(&self, position: usize) -> f32 {
+        let line = self.lines[self.line_of(position)];
+
+        if position >= line.end {
+            return line.width;
+        }
+
+        self.measure(line.start, position)
+    }
+
+    /// Returns the position closest to the given horizontal offset in the
+    /// given line.
+    pub(crate) fn position_in(&self, line: usize, x: f32) -> usize {
+        let line = self.lines[line];
+
+        let index = if x > 0.0 {
+            self.renderer
+                .hit_test(
+                    &self.text(line),
+                    f32::from(self.size),
+                    self.font.clone(),
+                    Size::INFINITY,
+                    Point::new(x, self.line_height / 2.0),
+                    true,
+                )
+                .map(text::Hit::cursor)
+                .unwrap_or(0)
+        } else {
+            0
+        };
+
+        // Keep the cursor in the same line when it is wrapped
+        let length = if line.is_wrapped {
+            line.end - line.start - 1
+        } else {
+            line.end - line.start
+        };
+
+        line.start + index.min(length)
+    }
+
+    /// Returns the position closest to the given point, relative to the
+    /// top-left corner of the lines.
+    pub(crate) fn position_at(&self, point: Point) -> usize {
+        let line = (point.y / self.line_height).max(0.0) as usize;
+
+        self.position_in(line.min(self.lines.len() - 1), point.x)
+    }
+}
+
+fn is_line_break(grapheme: &str) -> bool {
+    grapheme == "\n" || grapheme == "\r\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::font::{self, Font};
+    use crate::renderer;
+    use crate::text::{RichText, Span, Text};
+    use crate::{Background, Rectangle, Theme, Vector};
+
+    use std::cell::Cell;
+
+    /// A renderer with glyphs 10 units wide that counts its measurements.
+    #[derive(Default)]
+    struct Monospace {
+        measurements: Cell<usize>,
+    }
+
+    impl crate::Renderer for Monospace {
+        type Theme = Theme;
+
+        fn with_layer(
+            &mut self,
+            _bounds: Rectangle,
+            _f: impl FnOnce(&mut Self),
+        ) {
+        }
+
+        fn with_translation(
+            &mut self,
+            _translation: Vector,
+            _f: impl FnOnce(&mut Self),
+        ) {
+        }
+
+        fn clear(&mut self) {}
+
+        fn fill_quad(
+            &mut self,
+            _quad: renderer::Quad,
+            _background: impl Into<Background>,
+        ) {
+        }
+    }
+
+    impl text::Renderer for Monospace {
+        type Font = Font;
+
+        const ICON_FONT: Font = Font::DEFAULT;
+        const CHECKMARK_ICON: char = '0';
+        const ARROW_DOWN_ICON: char = '0';
+
+        fn default_size(&self) -> u16 {
+            20
+        }
+
+        fn measure(
+            &self,
+            content: &str,
+            _size: u16,
+            _font: Font,
+            _bounds: Size,
+            _wrapping: text::Wrapping,
+        ) -> (f32, f32) {
+            self.measurements.set(self.measurements.get() + 1);
+
+            (content.chars().count() as f32 * 10.0, 20.0)
+        }
+
+        fn hit_test(
+            &self,
+            _contents: &str,
+            _size: f32,
+            _font: Font,
+            _bounds: Size,
+            _point: Point,
+            _nearest_only: bool,
+        ) -> Option<text::Hit> {
+            None
+        }
+
+        fn measure_spans(
+            &self,
+            _spans: &[Span<'_, Font>],
+            _bounds: Size,
+        ) -> (f32, f32) {
+            (0.0, 20.0)
+        }
+
+        fn hit_span(
+            &self,
+            _spans: &[Span<'_, Font>],
+            _bounds: Size,
+            _point: Point,
+        ) -> Option<usize> {
+            None
+        }
+
+        fn fill_text(&mut self, _text: Text<'_, Font>) {}
+
+        fn fill_rich_text(&mut self, _text: RichText<'_, '_, Font>) {}
+
+        fn load_font(&mut self, _bytes: Vec<u8>) -> Result<(), font::Error> {
+            Ok(())
+        }
+    }
+
+    fn lines(content: &str, width: f32, wrapping: text::Wrapping) -> Vec<Line> {
+        let renderer = Monospace::default();
+        let value = Value::new(content);
+        let font = Font::default();
+
+        Paragraph::new(&renderer, &value, 20, &font, width, wrapping)
+            .lines
+            .into_owned()
+    }
+
+    fn line(start: usize, end: usize, is_wrapped: bool) -> Line {
+        Line {
+            start,
+            end,
+            is_wrapped,
+            width: (end - start) as f32 * 10.0,
+        }
+    }
+
+    #[test]
+    fn wraps_after_the_last_word_that_fits() {
+        assert_eq!(
+            lines("hello big world\nbye", 100.0, text::Wrapping::Word),
+            vec![line(0, 10, true), line(10, 15, false), line(16, 19, false)]
+        );
+    }
+
+    #[test]
+    fn breaks_words_wider_than_a_line() {
+        assert_eq!(
+            lines("abcdefghij", 35.0, text::Wrapping::Word),
+            vec![
+                line(0, 3, true),
+                line(3, 6, true),
+                line(6, 9, true),
+                line(9, 10, false)
+            ]
+        );
+
+        assert_eq!(
+            lines("ab cdefg", 35.0, text::Wrapping::Character),
+            vec![line(0, 3, true), line(3, 6, true), line(6, 8, false)]
+        );
+    }
+
+    #[test]
+    fn measures_each_word_about_once() {
+        let renderer = Monospace::default();
+        let value = Value::new(&"word ".repeat(1000));
+        let font = Font::default();
+
+        let paragraph = Paragraph::new(
+            &renderer,
+            &value,
+            20,
+            &font,
+            200.0,
+            text::Wrapping::Word,
+        );
+
+        assert_eq!(paragraph.lines.len(), 250);
+        assert!(renderer.measurements.get() < 2000);
+    }
+}
@@ -0,0 +1,1130 @@
+//! Display a multi-line text field that can be edited.
+//!
+//! A [`TextEditor`] has some local [`State`].
+use crate::alignment;
+use crate::event::{self, Event};
+use crate::input_method;
+use crate::keyboard;
+use crate::layout;
+use crate::mouse::{self, click};
+use crate::renderer;
+use crate::text::{self, Text};
+use crate::time::{Duration, Instant};
+use crate::touch;
+use crate::widget;
+use crate::widget::operation::{self, Operation};
+use crate::widget::text::Paragraph;
+use crate::widget::text_input::editor::Editor;
+use crate::widget::text_input::history::{self, Edit, History};
+use crate::widget::text_input::platform;
+use crate::widget::text_input::{Cursor, Value};
+use crate::widget::tree::{self, Tree};
+use crate::window;
+use crate::{
+    Clipboard, Color, Command, Element, Layout, Length, Padding, Point,
+    Rectangle, Shell, Size, Widget,
+};
+
+pub use iced_style::text_input::{Appearance, StyleSheet};
+
+/// A multi-line field that can be filled with text.
+///
+/// Long lines are wrapped to fit the width of the [`TextEditor`], and its
+/// contents can be scrolled vertically when they do not fit its height.
+///
+/// # Example
+/// ```
+/// # pub type TextEditor<'a, Message> = iced_native::widget::TextEditor<'a, Message, iced_native::renderer::Null>;
+/// # use iced_native::Length;
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     NotesChanged(String),
+/// }
+///
+/// let notes = "Some notes\nspanning multiple lines";
+///
+/// let editor = TextEditor::new(
+///     "Write your notes here...",
+///     notes,
+///     Message::NotesChanged,
+/// )
+/// .height(Length::Units(200));
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct TextEditor<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    id: Option<Id>,
+    placeholder: String,
+    value: Value,
+    font: Renderer::Font,
+    width: Length,
+    height: Length,
+    padding: Padding,
+    size: Option<u16>,
+    on_change: Box<dyn Fn(String) -> Message + 'a>,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> TextEditor<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`TextEditor`].
+    ///
+    /// It expects:
+    /// - a placeholder,
+    /// - the current value, and
+    /// - a function that produces a message when the [`TextEditor`] changes.
+    pub fn new<F>(placeholder: &str, value: &str, on_change: F) -> Self
+    where
+        F: 'a + Fn(String) -> Message,
+    {
+        TextEditor {
+            id: None,
+            placeholder: String::from(placeholder),
+            value: Value::new(value),
+            font: Default::default(),
+            width: Length::Fill,
+            height: Length::Shrink,
+            padding: Padding::new(5),
+            size: None,
+            on_change: Box::new(on_change),
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the [`Id`] of the [`TextEditor`].
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets the [`Font`] of the [`TextEditor`].
+    ///
+    /// [`Font`]: text::Renderer::Font
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the width of the [`TextEditor`].
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`TextEditor`].
+    ///
+    /// By default, a [`TextEditor`] grows to fit all of its lines.
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`TextEditor`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`TextEditor`].
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the style of the [`TextEditor`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    fn paragraph<'b>(
+        &'b self,
+        renderer: &'b Renderer,
+        size: u16,
+        width: f32,
+    ) -> Paragraph<'b, Renderer> {
+        Paragraph::new(
+            renderer,
+            &self.value,
+            size,
+            &self.font,
+            width,
+            text::Wrapping::Word,
+        )
+    }
+
+    fn publish(&self, shell: &mut Shell<'_, Message>) {
+        shell.publish((self.on_change)(self.value.to_string()));
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for TextEditor<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new())
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let size = self.size.unwrap_or_else(|| renderer.default_size());
+        let padding = self.padding.fit(Size::ZERO, limits.max());
+
+        let limits = limits.pad(padding).width(self.width).height(self.height);
+
+        let paragraph = Paragraph::new(
+            renderer,
+            &self.value,
+            size,
+            &self.font,
+            limits.max().width,
+            text::Wrapping::Word,
+        );
+
+        let intrinsic_width = paragraph
+            .lines
+            .iter()
+            .map(|line| line.width)
+            .fold(0.0, f32::max);
+
+        let mut text = layout::Node::new(
+            limits.resolve(Size::new(intrinsic_width, paragraph.height())),
+        );
+        text.move_to(Point::new(padding.left.into(), padding.top.into()));
+
+        layout::Node::with_children(text.size().pad(padding), vec![text])
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        operation.focusable(state, self.id.as_ref().map(|id| &id.0));
+        operation.text_input(state, self.id.as_ref().map(|id| &id.0));
+        operation.bounds(layout.bounds(), self.id.as_ref().map(|id| &id.0));
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        let bounds = layout.bounds();
+        let text_bounds = layout.children().next().unwrap().bounds();
+        let size = self.size.unwrap_or_else(|| renderer.default_size());
+
+        if let Event::Window(_, window::Event::RedrawRequested(_)) = event {
+            if let Some(action) = state.history.take_scheduled() {
+                if state.history.apply(
+                    action,
+                    &mut self.value,
+                    &mut state.cursor,
+                ) {
+                    self.publish(shell);
+                }
+            }
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let is_clicked = bounds.contains(cursor_position);
+
+                state.is_focused = is_clicked;
+
+                if !is_clicked {
+                    state.preedit = None;
+
+                    return event::Status::Ignored;
+                }
+
+                state.reset_blink();
+                state.history.seal();
+                state.preferred_x = None;
+
+                let paragraph =
+                    self.paragraph(renderer, size, text_bounds.width);
+                let position = paragraph.position_at(Point::new(
+                    cursor_position.x - text_bounds.x,
+                    cursor_position.y - text_bounds.y + state.scroll_offset,
+                ));
+
+                let click =
+                    mouse::Click::new(cursor_position, state.last_click);
+
+                match click.kind() {
+                    click::Kind::Single => {
+                        if state.keyboard_modifiers.shift() {
+                            state.cursor.select_range(
+                                state.cursor.start(&self.value),
+                                position,
+                            );
+                        } else {
+                            state.cursor.move_to(position);
+                        }
+
+                        state.is_dragging = true;
+                    }
+                    click::Kind::Double => {
+                        state.cursor.select_range(
+                            self.value.previous_start_of_word(position),
+                            self.value.next_end_of_word(position),
+                        );
+
+                        state.is_dragging = false;
+                    }
+                    click::Kind::Triple => {
+                        let line = paragraph.lines[paragraph.line_of(position)];
+
+                        state.cursor.select_range(line.start, line.end);
+                        state.is_dragging = false;
+                    }
+                }
+
+                state.last_click = Some(click);
+
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                state.is_dragging = false;
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. })
+                if state.is_dragging =>
+            {
+                let paragraph =
+                    self.paragraph(renderer, size, text_bounds.width);
+                let target = paragraph.position_at(Point::new(
+                    position.x - text_bounds.x,
+                    position.y - text_bounds.y + state.scroll_offset,
+                ));
+
+                state
+                    .cursor
+                    .select_range(state.cursor.start(&self.value), target);
+
+                state.scroll_to_cursor(&paragraph, text_bounds);
+
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if bounds.contains(cursor_position) =>
+            {
+                let paragraph =
+                    self.paragraph(renderer, size, text_bounds.width);
+
+                let delta = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => {
+                        y * paragraph.line_height
+                    }
+                    mouse::ScrollDelta::Pixels { y, .. } => y,
+                };
+
+                let max_offset =
+                    (paragraph.height() - text_bounds.height).max(0.0);
+
+                let offset =
+                    (state.scroll_offset - delta).max(0.0).min(max_offset);
+
+                if offset != state.scroll_offset {
+                    state.scroll_offset = offset;
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Keyboard(keyboard::Event::CharacterReceived(c))
+                if state.is_focused =>
+            {
+                if state.preedit.is_some() {
+                    return event::Status::Captured;
+                }
+
+                if !state.keyboard_modifiers.command() && !c.is_control() {
+                    state.history.record(
+                        Edit::Insert,
+                        &self.value,
+                        state.cursor,
+                    );
+
+                    Editor::new(&mut self.value, &mut state.cursor).insert(c);
+                    self.publish(shell);
+
+                    state.reset_blink();
+                    state.preferred_x = None;
+                    state.scroll_to_cursor(
+                        &self.paragraph(renderer, size, text_bounds.width),
+                        text_bounds,
+                    );
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code, ..
+            }) if state.is_focused => {
+                // The input method editor handles the keys while composing
+                if state.preedit.is_some() {
+                    return event::Status::Captured;
+                }
+
+                let modifiers = state.keyboard_modifiers;
+                state.reset_blink();
+
+                match key_code {
+                    keyboard::KeyCode::Enter
+                    | keyboard::KeyCode::NumpadEnter => {
+                        state.history.record(
+                            Edit::Insert,
+                            &self.value,
+                            state.cursor,
+                        );
+
+                        Editor::new(&mut self.value, &mut state.cursor)
+                            .insert('\n');
+                        self.publish(shell);
+                    }
+                    keyboard::KeyCode::Backspace => {
+                        if platform::is_jump_modifier_pressed(modifiers)
+                            && state.cursor.selection(&self.value).is_none()
+                        {
+                            state.cursor.select_left_by_words(&self.value);
+                        }
+
+                        state.history.record(
+                            Edit::Delete,
+                            &self.value,
+                            state.cursor,
+                        );
+
+                        Editor::new(&mut self.value, &mut state.cursor)
+                            .backspace();
+                        self.publish(shell);
+                    }
+                    keyboard::KeyCode::Delete => {
+                        if platform::is_jump_modifier_pressed(modifiers)
+                            && state.cursor.selection(&self.value).is_none()
+                        {
+                            state.cursor.select_right_by_words(&self.value);
+                        }
+
+                        state.history.record(
+                            Edit::Delete,
+                            &self.value,
+                            state.cursor,
+                        );
+
+                        Editor::new(&mut self.value, &mut state.cursor)
+                            .delete();
+                        self.publish(shell);
+                    }
+                    keyboard::KeyCode::Left => {
+                        if platform::is_jump_modifier_pressed(modifiers) {
+                            if modifiers.shift() {
+                                state.cursor.select_left_by_words(&self.value);
+                            } else {
+                                state.cursor.move_left_by_words(&self.value);
+                            }
+                        } else if modifiers.shift() {
+                            state.cursor.select_left(&self.value)
+                        } else {
+                            state.cursor.move_left(&self.value);
+                        }
+
+                        state.history.seal();
+                    }
+                    keyboard::KeyCode::Right => {
+                        if platform::is_jump_modifier_pressed(modifiers) {
+                            if modifiers.shift() {
+                                state.cursor.select_right_by_words(&self.value);
+                            } else {
+                                state.cursor.move_right_by_words(&self.value);
+                            }
+                        } else if modifiers.shift() {
+                            state.cursor.select_right(&self.value)
+                        } else {
+                            state.cursor.move_right(&self.value);
+                        }
+
+                        state.history.seal();
+                    }
+                    keyboard::KeyCode::Up | keyboard::KeyCode::Down => {
+                        let paragraph =
+                            self.paragraph(renderer, size, text_bounds.width);
+
+                        let position = state.cursor.end(&self.value);
+                        let line = paragraph.line_of(position);
+
+                        let x = *state
+                            .preferred_x
+                            .get_or_insert_with(|| paragraph.x_of(position));
+
+                        let target = if key_code == keyboard::KeyCode::Up {
+                            if line == 0 {
+                                0
+                            } else {
+                                paragraph.position_in(line - 1, x)
+                            }
+                        } else if line + 1 == paragraph.lines.len() {
+                            self.value.len()
+                        } else {
+                            paragraph.position_in(line + 1, x)
+                        };
+
+                        if modifiers.shift() {
+                            state.cursor.select_range(
+                                state.cursor.start(&self.value),
+                                target,
+                            );
+                        } else {
+                            state.cursor.move_to(target);
+                        }
+
+                        state.history.seal();
+                        state.scroll_to_cursor(&paragraph, text_bounds);
+
+                        return event::Status::Captured;
+                    }
+                    keyboard::KeyCode::Home | keyboard::KeyCode::End => {
+                        let paragraph =
+                            self.paragraph(renderer, size, text_bounds.width);
+
+                        let target = if modifiers.command() {
+                            if key_code == keyboard::KeyCode::Home {
+                                0
+                            } else {
+                                self.value.len()
+                            }
+                        } else {
+                            let position = state.cursor.end(&self.value);
+                            let line =
+                                paragraph.lines[paragraph.line_of(position)];
+
+                            if key_code == keyboard::KeyCode::Home {
+                                line.start
+                            } else if line.is_wrapped {
+                                line.end - 1
+                            } else {
+                                line.end
+                            }
+                        };
+
+                        if modifiers.shift() {
+                            state.cursor.select_range(
+                                state.cursor.start(&self.value),
+                                target,
+                            );
+                        } else {
+                            state.cursor.move_to(target);
+                        }
+
+                        state.history.seal();
+                    }
+                    keyboard::KeyCode::A if modifiers.command() => {
+                        state.cursor.select_all(&self.value);
+                    }
+                    keyboard::KeyCode::C if modifiers.command() => {
+                        if let Some((start, end)) =
+                            state.cursor.selection(&self.value)
+                        {
+                            clipboard.write(
+                                self.value.select(start, end).to_string(),
+                            );
+                        }
+                    }
+                    keyboard::KeyCode::X if modifiers.command() => {
+                        if let Some((start, end)) =
+                            state.cursor.selection(&self.value)
+                        {
+                            clipboard.write(
+                                self.value.select(start, end).to_string(),
+                            );
+
+                            state.history.record(
+                                Edit::Delete,
+                                &self.value,
+                                state.cursor,
+                            );
+
+                            Editor::new(&mut self.value, &mut state.cursor)
+                                .delete();
+                            self.publish(shell);
+                        }
+                    }
+                    keyboard::KeyCode::V if modifiers.command() => {
+                        let content: String = clipboard
+                            .read()
+                            .unwrap_or_default()
+                            .replace("\r\n", "\n")
+                            .chars()
+                            .filter(|c| *c == '\n' || !c.is_control())
+                            .collect();
+
+                        state.history.record(
+                            Edit::Paste,
+                            &self.value,
+                            state.cursor,
+                        );
+
+                        Editor::new(&mut self.value, &mut state.cursor)
+                            .paste(Value::new(&content));
+                        self.publish(shell);
+                    }
+                    _ if platform::is_undo(key_code, modifiers) => {
+                        let is_changed = state.history.apply(
+                            history::Action::Undo,
+                            &mut self.value,
+                            &mut state.cursor,
+                        );
+
+                        if is_changed {
+                            self.publish(shell);
+                        }
+                    }
+                    _ if platform::is_redo(key_code, modifiers) => {
+                        let is_changed = state.history.apply(
+                            history::Action::Redo,
+                            &mut self.value,
+                            &mut state.cursor,
+                        );
+
+                        if is_changed {
+                            self.publish(shell);
+                        }
+                    }
+                    keyboard::KeyCode::Escape => {
+                        state.is_focused = false;
+                        state.is_dragging = false;
+
+                        state.keyboard_modifiers =
+                            keyboard::Modifiers::default();
+                    }
+                    keyboard::KeyCode::Tab => {
+                        return event::Status::Ignored;
+                    }
+                    _ => {}
+                }
+
+                state.preferred_x = None;
+                state.scroll_to_cursor(
+                    &self.paragraph(renderer, size, text_bounds.width),
+                    text_bounds,
+                );
+
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.keyboard_modifiers = modifiers;
+            }
+            Event::InputMethod(event) if state.is_focused => {
+                match event {
+                    input_method::Event::Opened => {}
+                    input_method::Event::Preedit(content, _) => {
+                        state.preedit =
+                            (!content.is_empty()).then_some(content);
+                    }
+                    input_method::Event::Commit(content) => {
+                        state.preedit = None;
+
+                        state.history.record(
+                            Edit::Paste,
+                            &self.value,
+                            state.cursor,
+                        );
+
+                        Editor::new(&mut self.value, &mut state.cursor)
+                            .paste(Value::new(&content));
+                        self.publish(shell);
+                    }
+                    input_method::Event::Closed => {
+                        state.preedit = None;
+                    }
+                }
+
+                state.reset_blink();
+                state.scroll_to_cursor(
+                    &self.paragraph(renderer, size, text_bounds.width),
+                    text_bounds,
+                );
+
+                return event::Status::Captured;
+            }
+            Event::Window(_, window::Event::RedrawRequested(now))
+                if state.is_focused =>
+            {
+                state.now = Some(now);
+
+                let updated_at = *state.updated_at.get_or_insert(now);
+                let elapsed = now.duration_since(updated_at).as_millis();
+
+                let millis_until_redraw = CURSOR_BLINK_INTERVAL_MILLIS
+                    - elapsed % CURSOR_BLINK_INTERVAL_MILLIS;
+
+                shell.request_redraw(window::RedrawRequest::At(
+                    now + Duration::from_millis(millis_until_redraw as u64),
+                ));
+
+                let cursor = state.cursor_bounds(
+                    &self.paragraph(renderer, size, text_bounds.width),
+                    text_bounds,
+                    state.cursor.end(&self.value),
+                );
+
+                shell.request_input_method(input_method::Request::Enabled {
+                    cursor,
+                });
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+
+        let bounds = layout.bounds();
+        let text_bounds = layout.children().next().unwrap().bounds();
+        let size = self.size.unwrap_or_else(|| renderer.default_size());
+
+        let appearance = if state.is_focused() {
+            theme.focused(&self.style)
+        } else if bounds.contains(cursor_position) {
+            theme.hovered(&self.style)
+        } else {
+            theme.active(&self.style)
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: appearance.border_radius.into(),
+                border_width: appearance.border_width,
+                border_color: appearance.border_color,
+            },
+            appearance.background,
+        );
+
+        let paragraph = self.paragraph(renderer, size, text_bounds.width);
+
+        let line_height = paragraph.line_height;
+        let offset = state
+            .scroll_offset
+            .min((paragraph.height() - text_bounds.height).max(0.0));
+
+        let first_line = (offset / line_height).floor() as usize;
+        let last_line =
+            ((offset + text_bounds.height) / line_height).ceil() as usize;
+
+        let selection = state
+            .is_focused()
+            .then(|| state.cursor.selection(&self.value))
+            .flatten();
+
+        let lines: Vec<_> = paragraph
+            .lines
+            .iter()
+            .enumerate()
+            .take(last_line)
+            .skip(first_line)
+            .map(|(i, line)| {
+                let y = text_bounds.y + i as f32 * line_height - offset;
+
+                let highlight = selection.and_then(|(start, end)| {
+                    let from = start.max(line.start);
+                    let to = end.min(line.end);
+
+                    let includes_line_break =
+                        !line.is_wrapped && start <= line.end && end > line.end;
+
+                    if from >= to && !includes_line_break {
+                        return None;
+                    }
+
+                    let x = paragraph.measure(line.start, from);
+                    let mut width = paragraph.measure(from, to.max(from));
+
+                    if includes_line_break {
+                        width += renderer.measure_width(
+                            " ",
+                            size,
+                            self.font.clone(),
+                        );
+                    }
+
+                    Some(Rectangle {
+                        x: text_bounds.x + x,
+                        y,
+                        width,
+                        height: line_height,
+                    })
+                });
+
+                (paragraph.text(*line), y, highlight)
+            })
+            .collect();
+
+        let cursor_position = state.cursor.end(&self.value);
+
+        let cursor = (state.is_focused()
+            && selection.is_none()
+            && state.preedit.is_none()
+            && state.is_cursor_visible())
+        .then(|| {
+            let mut bounds =
+                state.cursor_bounds(&paragraph, text_bounds, cursor_position);
+            bounds.y += state.scroll_offset - offset;
+            bounds
+        });
+
+        let preedit = state
+            .preedit
+            .as_ref()
+            .filter(|_| state.is_focused())
+            .map(|preedit| {
+                let mut bounds = state.cursor_bounds(
+                    &paragraph,
+                    text_bounds,
+                    cursor_position,
+                );
+                bounds.y += state.scroll_offset - offset;
+                bounds.width =
+                    renderer.measure_width(preedit, size, self.font.clone());
+
+                (preedit, bounds)
+            });
+
+        let quad = |bounds| renderer::Quad {
+            bounds,
+            border_radius: 0.0.into(),
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+        };
+
+        renderer.with_layer(text_bounds, |renderer| {
+            if self.value.is_empty() && preedit.is_none() {
+                renderer.fill_text(Text {
+                    content: &self.placeholder,
+                    bounds: text_bounds,
+                    size: f32::from(size),
+                    color: theme.placeholder_color(&self.style),
+                    font: self.font.clone(),
+                    horizontal_alignment: alignment::Horizontal::Left,
+                    vertical_alignment: alignment::Vertical::Top,
+                    wrapping: text::Wrapping::default(),
+                });
+            }
+
+            for (content, y, highlight) in &lines {
+                if let Some(highlight) = highlight {
+                    renderer.fill_quad(
+                        quad(*highlight),
+                        theme.selection_color(&self.style),
+                    );
+                }
+
+                renderer.fill_text(Text {
+                    content,
+                    bounds: Rectangle {
+                        y: *y,
+                        width: f32::INFINITY,
+                        height: line_height,
+                        ..text_bounds
+                    },
+                    size: f32::from(size),
+                    color: theme.value_color(&self.style),
+                    font: self.font.clone(),
+                    horizontal_alignment: alignment::Horizontal::Left,
+                    vertical_alignment: alignment::Vertical::Top,
+                    wrapping: text::Wrapping::default(),
+                });
+            }
+
+            if let Some(cursor) = cursor {
+                renderer
+                    .fill_quad(quad(cursor), theme.value_color(&self.style));
+            }
+
+            if let Some((preedit, bounds)) = preedit {
+                renderer.fill_quad(quad(bounds), appearance.background);
+
+                renderer.fill_text(Text {
+                    content: preedit,
+                    bounds: Rectangle {
+                        width: f32::INFINITY,
+                        ..bounds
+                    },
+                    size: f32::from(size),
+                    color: theme.value_color(&self.style),
+                    font: self.font.clone(),
+                    horizontal_alignment: alignment::Horizontal::Left,
+                    vertical_alignment: alignment::Vertical::Top,
+                    wrapping: text::Wrapping::default(),
+                });
+
+                renderer.fill_quad(
+                    quad(Rectangle {
+                        y: bounds.y + bounds.height - 1.0,
+                        height: 1.0,
+                        ..bounds
+                    }),
+                    theme.value_color(&self.style),
+                );
+            }
+        });
+    }
+
+    fn mouse_interaction(
+        &self,
+        _state: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if layout.bounds().contains(cursor_position) {
+            mouse::Interaction::Text
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<TextEditor<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(
+        text_editor: TextEditor<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(text_editor)
+    }
+}
+
+/// The identifier of a [`TextEditor`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Id(widget::Id);
+
+impl Id {
+    /// Creates a custom [`Id`].
+    pub fn new(id: impl Into<std::borrow::Cow<'static, str>>) -> Self {
+        Self(widget::Id::new(id))
+    }
+
+    /// Creates a unique [`Id`].
+    ///
+    /// This function produces a different [`Id`] every time it is called.
+    pub fn unique() -> Self {
+        Self(widget::Id::unique())
+    }
+}
+
+impl From<Id> for widget::Id {
+    fn from(id: Id) -> Self {
+        id.0
+    }
+}
+
+/// Produces a [`Command`] that focuses the [`TextEditor`] with the given [`Id`].
+pub fn focus<Message: 'static>(id: Id) -> Command<Message> {
+    Command::widget(operation::focusable::focus(id.0))
+}
+
+/// The state of a [`TextEditor`].
+#[derive(Debug, Default, Clone)]
+pub struct State {
+    is_focused: bool,
+    is_dragging: bool,
+    last_click: Option<mouse::Click>,
+    cursor: Cursor,
+    keyboard_modifiers: keyboard::Modifiers,
+    history: History,
+    scroll_offset: f32,
+    preferred_x: Option<f32>,
+    preedit: Option<String>,
+    updated_at: Option<Instant>,
+    now: Option<Instant>,
+}
+
+impl State {
+    /// Creates a new [`State`], representing an unfocused [`TextEditor`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns whether the [`TextEditor`] is currently focused or not.
+    pub fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    /// Returns the [`Cursor`] of the [`TextEditor`].
+    pub fn cursor(&self) -> Cursor {
+        self.cursor
+    }
+
+    /// Focuses the [`TextEditor`].
+    pub fn focus(&mut self) {
+        self.is_focused = true;
+        self.reset_blink();
+    }
+
+    /// Unfocuses the [`TextEditor`].
+    pub fn unfocus(&mut self) {
+        self.is_focused = false;
+        self.preedit = None;
+    }
+
+    /// Restarts the blinking of the [`Cursor`], making it visible.
+    fn reset_blink(&mut self) {
+        let now = Instant::now();
+
+        self.updated_at = Some(now);
+        self.now = Some(now);
+    }
+
+    /// Returns whether the [`Cursor`] is visible in the current blinking
+    /// phase.
+    fn is_cursor_visible(&self) -> bool {
+        match (self.updated_at, self.now) {
+            (Some(updated_at), Some(now)) if now > updated_at => {
+                now.duration_since(updated_at).as_millis()
+                    % (2 * CURSOR_BLINK_INTERVAL_MILLIS)
+                    < CURSOR_BLINK_INTERVAL_MILLIS
+            }
+            _ => true,
+        }
+    }
+
+    /// Returns the bounds of the text cursor at the given position.
+    fn cursor_bounds<Renderer: text::Renderer>(
+        &self,
+        paragraph: &Paragraph<'_, Renderer>,
+        text_bounds: Rectangle,
+        position: usize,
+    ) -> Rectangle {
+        let line = paragraph.line_of(position);
+
+        Rectangle {
+            x: text_bounds.x + paragraph.x_of(position),
+            y: text_bounds.y + line as f32 * paragraph.line_height
+                - self.scroll_offset,
+            width: 1.0,
+            height: paragraph.line_height,
+        }
+    }
+
+    /// Scrolls the contents of the [`TextEditor`] until the end of the
+    /// [`Cursor`] is visible.
+    fn scroll_to_cursor<Renderer: text::Renderer>(
+        &mut self,
+        paragraph: &Paragraph<'_, Renderer>,
+        text_bounds: Rectangle,
+    ) {
+        let line = paragraph.line_of(self.cursor.end(paragraph.value));
+
+        let top = line as f32 * paragraph.line_height;
+        let bottom = top + paragraph.line_height;
+
+        let max_offset = (paragraph.height() - text_bounds.height).max(0.0);
+
+        self.scroll_offset = self
+            .scroll_offset
+            .min(top)
+            .max(bottom - text_bounds.height)
+            .max(0.0)
+            .min(max_offset);
+    }
+}
+
+impl operation::Focusable for State {
+    fn is_focused(&self) -> bool {
+        State::is_focused(self)
+    }
+
+    fn focus(&mut self) {
+        State::focus(self)
+    }
+
+    fn unfocus(&mut self) {
+        State::unfocus(self)
+    }
+}
+
+impl operation::TextInput for State {
+    fn move_cursor_to_front(&mut self) {
+        self.cursor.move_to(0);
+    }
+
+    fn move_cursor_to_end(&mut self) {
+        self.cursor.move_to(usize::MAX);
+    }
+
+    fn move_cursor_to(&mut self, position: usize) {
+        self.cursor.move_to(position);
+    }
+
+    fn select_all(&mut self) {
+        self.cursor.select_range(0, usize::MAX);
+    }
+
+    fn undo(&mut self) {
+        self.history.schedule(history::Action::Undo);
+    }
+
+    fn redo(&mut self) {
+        self.history.schedule(history::Action::Redo);
+    }
+}
+
+const CURSOR_BLINK_INTERVAL_MILLIS: u128 = 500;
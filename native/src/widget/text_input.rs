@@ -1,27 +1,34 @@
 //! Display fields that can be filled with text.
 //!
 //! A [`TextInput`] has some local [`State`].
-mod editor;
+pub(crate) mod editor;
+pub(crate) mod history;
+mod mask;
 mod value;
 
 pub mod cursor;
 
 pub use cursor::Cursor;
+pub use mask::Mask;
 pub use value::Value;
 
 use editor::Editor;
+use history::{Edit, History};
 
 use crate::alignment;
 use crate::event::{self, Event};
+use crate::input_method;
 use crate::keyboard;
 use crate::layout;
 use crate::mouse::{self, click};
 use crate::renderer;
 use crate::text::{self, Text};
+use crate::time::{Duration, Instant};
 use crate::touch;
 use crate::widget;
 use crate::widget::operation::{self, Operation};
 use crate::widget::tree::{self, Tree};
+use crate::window;
 use crate::{
     Clipboard, Color, Command, Element, Layout, Length, Padding, Point,
     Rectangle, Shell, Size, Vector, Widget,
@@ -63,6 +70,7 @@ where
     width: Length,
     padding: Padding,
     size: Option<u16>,
+    constraints: Constraints<'a>,
     on_change: Box<dyn Fn(String) -> Message + 'a>,
     on_paste: Option<Box<dyn Fn(String) -> Message + 'a>>,
     on_submit: Option<Message>,
@@ -94,6 +102,7 @@ where
             width: Length::Fill,
             padding: Padding::new(5),
             size: None,
+            constraints: Constraints::default(),
             on_change: Box::new(on_change),
             on_paste: None,
             on_submit: None,
@@ -148,6 +157,25 @@ where
         self
     }
 
+    /// Sets the maximum amount of graphemes of the [`TextInput`].
+    pub fn max_length(mut self, max_length: usize) -> Self {
+        self.constraints.max_length = Some(max_length);
+        self
+    }
+
+    /// Sets the filter of the characters that can be typed or pasted into the
+    /// [`TextInput`].
+    pub fn filter(mut self, filter: impl Fn(char) -> bool + 'a) -> Self {
+        self.constraints.filter = Some(Box::new(filter));
+        self
+    }
+
+    /// Sets the [`Mask`] of the [`TextInput`].
+    pub fn mask(mut self, mask: Mask) -> Self {
+        self.constraints.mask = Some(mask);
+        self
+    }
+
     /// Sets the message that should be produced when the [`TextInput`] is
     /// focused and the enter key is pressed.
     pub fn on_submit(mut self, message: Message) -> Self {
@@ -227,13 +255,14 @@ where
     fn operate(
         &self,
         tree: &mut Tree,
-        _layout: Layout<'_>,
+        layout: Layout<'_>,
         operation: &mut dyn Operation<Message>,
     ) {
         let state = tree.state.downcast_mut::<State>();
 
         operation.focusable(state, self.id.as_ref().map(|id| &id.0));
         operation.text_input(state, self.id.as_ref().map(|id| &id.0));
+        operation.bounds(layout.bounds(), self.id.as_ref().map(|id| &id.0));
     }
 
     fn on_event(
@@ -257,6 +286,7 @@ where
             self.size,
             &self.font,
             self.is_secure,
+            &self.constraints,
             self.on_change.as_ref(),
             self.on_paste.as_deref(),
             &self.on_submit,
@@ -315,6 +345,68 @@ where
     }
 }
 
+/// The constraints on the contents of a [`TextInput`].
+///
+/// They are enforced before any message is produced, so the value of a
+/// [`TextInput`] never violates them because of user input.
+#[allow(missing_debug_implementations)]
+#[derive(Default)]
+pub struct Constraints<'a> {
+    /// The maximum amount of graphemes of the value.
+    pub max_length: Option<usize>,
+
+    /// The filter of the characters that can be typed or pasted.
+    pub filter: Option<Box<dyn Fn(char) -> bool + 'a>>,
+
+    /// The [`Mask`] of the value.
+    pub mask: Option<Mask>,
+}
+
+impl<'a> Constraints<'a> {
+    /// Returns whether the given character can be typed.
+    pub fn allows(&self, c: char) -> bool {
+        match &self.filter {
+            Some(filter) => filter(c),
+            None => true,
+        }
+    }
+
+    /// Drops the characters of the given content that cannot be pasted into
+    /// the [`Value`] at the [`Cursor`], cutting it to the space left.
+    fn sanitize(
+        &self,
+        content: &Value,
+        value: &Value,
+        cursor: Cursor,
+    ) -> Value {
+        let content = if self.filter.is_some() {
+            let filtered: String = content
+                .to_string()
+                .chars()
+                .filter(|c| self.allows(*c))
+                .collect();
+
+            Value::new(&filtered)
+        } else {
+            content.clone()
+        };
+
+        match self.max_length {
+            Some(max_length) => {
+                let selected = cursor
+                    .selection(value)
+                    .map_or(0, |(start, end)| end - start);
+
+                let available =
+                    max_length.saturating_sub(value.len() - selected);
+
+                content.until(available.min(content.len()))
+            }
+            None => content,
+        }
+    }
+}
+
 /// The identifier of a [`TextInput`].
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Id(widget::Id);
@@ -370,6 +462,18 @@ pub fn select_all<Message: 'static>(id: Id) -> Command<Message> {
     Command::widget(operation::text_input::select_all(id.0))
 }
 
+/// Produces a [`Command`] that reverts the last group of edits of the
+/// [`TextInput`] with the given [`Id`].
+pub fn undo<Message: 'static>(id: Id) -> Command<Message> {
+    Command::widget(operation::text_input::undo(id.0))
+}
+
+/// Produces a [`Command`] that reapplies the last group of edits that was
+/// undone in the [`TextInput`] with the given [`Id`].
+pub fn redo<Message: 'static>(id: Id) -> Command<Message> {
+    Command::widget(operation::text_input::redo(id.0))
+}
+
 /// Computes the layout of a [`TextInput`].
 pub fn layout<Renderer>(
     renderer: &Renderer,
@@ -409,6 +513,7 @@ pub fn update<'a, Message, Renderer>(
     size: Option<u16>,
     font: &Renderer::Font,
     is_secure: bool,
+    constraints: &Constraints<'_>,
     on_change: &dyn Fn(String) -> Message,
     on_paste: Option<&dyn Fn(String) -> Message>,
     on_submit: &Option<Message>,
@@ -425,8 +530,15 @@ where
             let is_clicked = layout.bounds().contains(cursor_position);
 
             state.is_focused = is_clicked;
+            state.history.seal();
+
+            if !is_clicked {
+                state.preedit = None;
+            }
 
             if is_clicked {
+                state.reset_blink();
+
                 let text_layout = layout.children().next().unwrap();
                 let target = cursor_position.x - text_layout.bounds().x;
 
@@ -532,17 +644,29 @@ where
         Event::Keyboard(keyboard::Event::CharacterReceived(c)) => {
             let state = state();
 
+            if state.is_focused && state.preedit.is_some() {
+                return event::Status::Captured;
+            }
+
             if state.is_focused
                 && state.is_pasting.is_none()
                 && !state.keyboard_modifiers.command()
                 && !c.is_control()
             {
-                let mut editor = Editor::new(value, &mut state.cursor);
+                let is_edited = constraints.allows(c)
+                    && edit(
+                        value,
+                        state,
+                        constraints,
+                        Edit::Insert,
+                        |editor| editor.insert(c),
+                    );
 
-                editor.insert(c);
+                if is_edited {
+                    shell.publish((on_change)(value.to_string()));
+                }
 
-                let message = (on_change)(editor.contents());
-                shell.publish(message);
+                state.reset_blink();
 
                 return event::Status::Captured;
             }
@@ -550,8 +674,14 @@ where
         Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. }) => {
             let state = state();
 
+            // The input method editor handles the keys while composing
+            if state.is_focused && state.preedit.is_some() {
+                return event::Status::Captured;
+            }
+
             if state.is_focused {
                 let modifiers = state.keyboard_modifiers;
+                state.reset_blink();
 
                 match key_code {
                     keyboard::KeyCode::Enter
@@ -572,11 +702,17 @@ where
                             }
                         }
 
-                        let mut editor = Editor::new(value, &mut state.cursor);
-                        editor.backspace();
+                        let is_edited = edit(
+                            value,
+                            state,
+                            constraints,
+                            Edit::Delete,
+                            |editor| editor.backspace(),
+                        );
 
-                        let message = (on_change)(editor.contents());
-                        shell.publish(message);
+                        if is_edited {
+                            shell.publish((on_change)(value.to_string()));
+                        }
                     }
                     keyboard::KeyCode::Delete => {
                         if platform::is_jump_modifier_pressed(modifiers)
@@ -592,11 +728,17 @@ where
                             }
                         }
 
-                        let mut editor = Editor::new(value, &mut state.cursor);
-                        editor.delete();
+                        let is_edited = edit(
+                            value,
+                            state,
+                            constraints,
+                            Edit::Delete,
+                            |editor| editor.delete(),
+                        );
 
-                        let message = (on_change)(editor.contents());
-                        shell.publish(message);
+                        if is_edited {
+                            shell.publish((on_change)(value.to_string()));
+                        }
                     }
                     keyboard::KeyCode::Left => {
                         if platform::is_jump_modifier_pressed(modifiers)
@@ -612,6 +754,8 @@ where
                         } else {
                             state.cursor.move_left(value);
                         }
+
+                        state.history.seal();
                     }
                     keyboard::KeyCode::Right => {
                         if platform::is_jump_modifier_pressed(modifiers)
@@ -627,6 +771,8 @@ where
                         } else {
                             state.cursor.move_right(value);
                         }
+
+                        state.history.seal();
                     }
                     keyboard::KeyCode::Home => {
                         if modifiers.shift() {
@@ -636,6 +782,8 @@ where
                         } else {
                             state.cursor.move_to(0);
                         }
+
+                        state.history.seal();
                     }
                     keyboard::KeyCode::End => {
                         if modifiers.shift() {
@@ -646,6 +794,8 @@ where
                         } else {
                             state.cursor.move_to(value.len());
                         }
+
+                        state.history.seal();
                     }
                     keyboard::KeyCode::C
                         if state.keyboard_modifiers.command() =>
@@ -667,11 +817,17 @@ where
                                 .write(value.select(start, end).to_string());
                         }
 
-                        let mut editor = Editor::new(value, &mut state.cursor);
-                        editor.delete();
+                        let is_edited = edit(
+                            value,
+                            state,
+                            constraints,
+                            Edit::Delete,
+                            |editor| editor.delete(),
+                        );
 
-                        let message = (on_change)(editor.contents());
-                        shell.publish(message);
+                        if is_edited {
+                            shell.publish((on_change)(value.to_string()));
+                        }
                     }
                     keyboard::KeyCode::V => {
                         if state.keyboard_modifiers.command() {
@@ -689,23 +845,56 @@ where
                                 }
                             };
 
-                            let mut editor =
-                                Editor::new(value, &mut state.cursor);
+                            let pasted = constraints.sanitize(
+                                &content,
+                                value,
+                                state.cursor,
+                            );
 
-                            editor.paste(content.clone());
+                            let is_edited = edit(
+                                value,
+                                state,
+                                constraints,
+                                Edit::Paste,
+                                |editor| editor.paste(pasted),
+                            );
 
-                            let message = if let Some(paste) = &on_paste {
-                                (paste)(editor.contents())
-                            } else {
-                                (on_change)(editor.contents())
-                            };
-                            shell.publish(message);
+                            if is_edited {
+                                let message = if let Some(paste) = &on_paste {
+                                    (paste)(value.to_string())
+                                } else {
+                                    (on_change)(value.to_string())
+                                };
+                                shell.publish(message);
+                            }
 
                             state.is_pasting = Some(content);
                         } else {
                             state.is_pasting = None;
                         }
                     }
+                    _ if platform::is_undo(key_code, modifiers) => {
+                        let is_changed = state.history.apply(
+                            history::Action::Undo,
+                            value,
+                            &mut state.cursor,
+                        );
+
+                        if is_changed {
+                            shell.publish((on_change)(value.to_string()));
+                        }
+                    }
+                    _ if platform::is_redo(key_code, modifiers) => {
+                        let is_changed = state.history.apply(
+                            history::Action::Redo,
+                            value,
+                            &mut state.cursor,
+                        );
+
+                        if is_changed {
+                            shell.publish((on_change)(value.to_string()));
+                        }
+                    }
                     keyboard::KeyCode::A
                         if state.keyboard_modifiers.command() =>
                     {
@@ -713,6 +902,7 @@ where
                     }
                     keyboard::KeyCode::Escape => {
                         state.is_focused = false;
+                        state.preedit = None;
                         state.is_dragging = false;
                         state.is_pasting = None;
 
@@ -756,6 +946,113 @@ where
 
             state.keyboard_modifiers = modifiers;
         }
+        Event::InputMethod(event) => {
+            let state = state();
+
+            if state.is_focused && !is_secure {
+                match event {
+                    input_method::Event::Opened => {}
+                    input_method::Event::Preedit(content, selection) => {
+                        state.preedit = (!content.is_empty())
+                            .then_some(Preedit { content, selection });
+                    }
+                    input_method::Event::Commit(content) => {
+                        state.preedit = None;
+
+                        let committed = constraints.sanitize(
+                            &Value::new(&content),
+                            value,
+                            state.cursor,
+                        );
+
+                        let is_edited = edit(
+                            value,
+                            state,
+                            constraints,
+                            Edit::Paste,
+                            |editor| editor.paste(committed),
+                        );
+
+                        if is_edited {
+                            shell.publish((on_change)(value.to_string()));
+                        }
+                    }
+                    input_method::Event::Closed => {
+                        state.preedit = None;
+                    }
+                }
+
+                state.reset_blink();
+
+                return event::Status::Captured;
+            }
+        }
+        Event::Window(_, window::Event::RedrawRequested(now)) => {
+            let state = state();
+
+            if let Some(action) = state.history.take_scheduled() {
+                if state.history.apply(action, value, &mut state.cursor) {
+                    shell.publish((on_change)(value.to_string()));
+                }
+            }
+
+            if state.is_focused {
+                state.now = Some(now);
+
+                if !is_secure {
+                    let text_bounds =
+                        layout.children().next().unwrap().bounds();
+                    let size = size.unwrap_or_else(|| renderer.default_size());
+
+                    let position = match state.cursor.state(value) {
+                        cursor::State::Index(position) => position,
+                        cursor::State::Selection { start, end } => {
+                            start.min(end)
+                        }
+                    };
+
+                    let (cursor_x, _) = measure_cursor_and_scroll_offset(
+                        renderer,
+                        text_bounds,
+                        value,
+                        size,
+                        position,
+                        font.clone(),
+                    );
+
+                    let offset = offset(
+                        renderer,
+                        text_bounds,
+                        font.clone(),
+                        size,
+                        value,
+                        state,
+                    );
+
+                    shell.request_input_method(
+                        input_method::Request::Enabled {
+                            cursor: Rectangle {
+                                x: text_bounds.x
+                                    + (cursor_x - offset)
+                                        .min(text_bounds.width),
+                                width: 1.0,
+                                ..text_bounds
+                            },
+                        },
+                    );
+                }
+
+                let updated_at = *state.updated_at.get_or_insert(now);
+                let elapsed = now.duration_since(updated_at).as_millis();
+
+                let millis_until_redraw = CURSOR_BLINK_INTERVAL_MILLIS
+                    - elapsed % CURSOR_BLINK_INTERVAL_MILLIS;
+
+                shell.request_redraw(window::RedrawRequest::At(
+                    now + Duration::from_millis(millis_until_redraw as u64),
+                ));
+            }
+        }
         _ => {}
     }
 
@@ -808,11 +1105,81 @@ pub fn draw<Renderer>(
         appearance.background,
     );
 
-    let text = value.to_string();
     let size = size.unwrap_or_else(|| renderer.default_size());
 
-    let (cursor, offset) = if state.is_focused() {
-        match state.cursor.state(value) {
+    let preedit = state
+        .preedit
+        .as_ref()
+        .filter(|_| state.is_focused() && !is_secure);
+
+    let mut underline = None;
+
+    let (text, cursor, offset) = if let Some(preedit) = preedit {
+        let position = match state.cursor.state(value) {
+            cursor::State::Index(position) => position,
+            cursor::State::Selection { start, end } => start.min(end),
+        };
+
+        let before = value.select(0, position).to_string();
+        let after = value.select(position, value.len()).to_string();
+
+        let before_width = renderer.measure_width(&before, size, font.clone());
+        let preedit_width =
+            renderer.measure_width(&preedit.content, size, font.clone());
+
+        let cursor_width = before_width
+            + preedit
+                .selection
+                .as_ref()
+                .map_or(preedit_width, |selection| {
+                    renderer.measure_width(
+                        preedit
+                            .content
+                            .get(..selection.start)
+                            .unwrap_or(&preedit.content),
+                        size,
+                        font.clone(),
+                    )
+                });
+
+        let offset = ((cursor_width + 5.0) - text_bounds.width).max(0.0);
+
+        underline = Some((
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: text_bounds.x + before_width,
+                    y: text_bounds.center_y() + f32::from(size) / 2.0,
+                    width: preedit_width,
+                    height: 1.0,
+                },
+                border_radius: 0.0.into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            theme.value_color(style),
+        ));
+
+        let cursor = (preedit.selection.is_some() && state.is_cursor_visible())
+            .then(|| {
+                (
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: text_bounds.x + cursor_width,
+                            y: text_bounds.y,
+                            width: 1.0,
+                            height: text_bounds.height,
+                        },
+                        border_radius: 0.0.into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    theme.value_color(style),
+                )
+            });
+
+        (before + &preedit.content + &after, cursor, offset)
+    } else if state.is_focused() {
+        let (cursor, offset) = match state.cursor.state(value) {
             cursor::State::Index(position) => {
                 let (text_value_width, offset) =
                     measure_cursor_and_scroll_offset(
@@ -825,20 +1192,22 @@ pub fn draw<Renderer>(
                     );
 
                 (
-                    Some((
-                        renderer::Quad {
-                            bounds: Rectangle {
-                                x: text_bounds.x + text_value_width,
-                                y: text_bounds.y,
-                                width: 1.0,
-                                height: text_bounds.height,
+                    state.is_cursor_visible().then(|| {
+                        (
+                            renderer::Quad {
+                                bounds: Rectangle {
+                                    x: text_bounds.x + text_value_width,
+                                    y: text_bounds.y,
+                                    width: 1.0,
+                                    height: text_bounds.height,
+                                },
+                                border_radius: 0.0.into(),
+                                border_width: 0.0,
+                                border_color: Color::TRANSPARENT,
                             },
-                            border_radius: 0.0.into(),
-                            border_width: 0.0,
-                            border_color: Color::TRANSPARENT,
-                        },
-                        theme.value_color(style),
-                    )),
+                            theme.value_color(style),
+                        )
+                    }),
                     offset,
                 )
             }
@@ -890,9 +1259,11 @@ pub fn draw<Renderer>(
                     },
                 )
             }
-        }
+        };
+
+        (value.to_string(), cursor, offset)
     } else {
-        (None, 0.0)
+        (value.to_string(), None, 0.0)
     };
 
     let text_width = renderer.measure_width(
@@ -906,6 +1277,10 @@ pub fn draw<Renderer>(
             renderer.fill_quad(cursor, color);
         }
 
+        if let Some((underline, color)) = underline {
+            renderer.fill_quad(underline, color);
+        }
+
         renderer.fill_text(Text {
             content: if text.is_empty() { placeholder } else { &text },
             color: if text.is_empty() {
@@ -922,6 +1297,7 @@ pub fn draw<Renderer>(
             size: f32::from(size),
             horizontal_alignment: alignment::Horizontal::Left,
             vertical_alignment: alignment::Vertical::Center,
+            wrapping: text::Wrapping::default(),
         });
     };
 
@@ -955,6 +1331,10 @@ pub struct State {
     last_click: Option<mouse::Click>,
     cursor: Cursor,
     keyboard_modifiers: keyboard::Modifiers,
+    updated_at: Option<Instant>,
+    now: Option<Instant>,
+    preedit: Option<Preedit>,
+    history: History,
     // TODO: Add stateful horizontal scrolling offset
 }
 
@@ -973,6 +1353,10 @@ impl State {
             last_click: None,
             cursor: Cursor::default(),
             keyboard_modifiers: keyboard::Modifiers::default(),
+            updated_at: None,
+            now: None,
+            preedit: None,
+            history: History::default(),
         }
     }
 
@@ -989,12 +1373,14 @@ impl State {
     /// Focuses the [`TextInput`].
     pub fn focus(&mut self) {
         self.is_focused = true;
+        self.reset_blink();
         self.move_cursor_to_end();
     }
 
     /// Unfocuses the [`TextInput`].
     pub fn unfocus(&mut self) {
         self.is_focused = false;
+        self.preedit = None;
     }
 
     /// Moves the [`Cursor`] of the [`TextInput`] to the front of the input text.
@@ -1016,6 +1402,36 @@ impl State {
     pub fn select_all(&mut self) {
         self.cursor.select_range(0, usize::MAX);
     }
+
+    /// Restarts the blinking of the [`Cursor`], making it visible.
+    fn reset_blink(&mut self) {
+        let now = Instant::now();
+
+        self.updated_at = Some(now);
+        self.now = Some(now);
+    }
+
+    /// Returns whether the [`Cursor`] is visible in the current blinking
+    /// phase.
+    fn is_cursor_visible(&self) -> bool {
+        match (self.updated_at, self.now) {
+            (Some(updated_at), Some(now)) if now > updated_at => {
+                now.duration_since(updated_at).as_millis()
+                    % (2 * CURSOR_BLINK_INTERVAL_MILLIS)
+                    < CURSOR_BLINK_INTERVAL_MILLIS
+            }
+            _ => true,
+        }
+    }
+}
+
+const CURSOR_BLINK_INTERVAL_MILLIS: u128 = 500;
+
+/// The text being composed by an input method editor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Preedit {
+    content: String,
+    selection: Option<std::ops::Range<usize>>,
 }
 
 impl operation::Focusable for State {
@@ -1048,9 +1464,17 @@ impl operation::TextInput for State {
     fn select_all(&mut self) {
         State::select_all(self)
     }
+
+    fn undo(&mut self) {
+        self.history.schedule(history::Action::Undo);
+    }
+
+    fn redo(&mut self) {
+        self.history.schedule(history::Action::Redo);
+    }
 }
 
-mod platform {
+pub(crate) mod platform {
     use crate::keyboard;
 
     pub fn is_jump_modifier_pressed(modifiers: keyboard::Modifiers) -> bool {
@@ -1060,6 +1484,74 @@ mod platform {
             modifiers.control()
         }
     }
+
+    pub fn is_undo(
+        key_code: keyboard::KeyCode,
+        modifiers: keyboard::Modifiers,
+    ) -> bool {
+        key_code == keyboard::KeyCode::Z
+            && modifiers.command()
+            && !modifiers.shift()
+    }
+
+    pub fn is_redo(
+        key_code: keyboard::KeyCode,
+        modifiers: keyboard::Modifiers,
+    ) -> bool {
+        let is_shift_z = key_code == keyboard::KeyCode::Z && modifiers.shift();
+
+        if cfg!(target_os = "macos") {
+            modifiers.command() && is_shift_z
+        } else {
+            modifiers.command()
+                && (is_shift_z || key_code == keyboard::KeyCode::Y)
+        }
+    }
+}
+
+/// Applies an edit to the [`Value`] of a [`TextInput`] and records it in the
+/// history, unless the [`Constraints`] reject it or it changes nothing.
+///
+/// Returns whether the edit was applied.
+fn edit(
+    value: &mut Value,
+    state: &mut State,
+    constraints: &Constraints<'_>,
+    kind: Edit,
+    f: impl FnOnce(&mut Editor<'_>),
+) -> bool {
+    let mut new_value = value.clone();
+    let mut cursor = state.cursor;
+
+    f(&mut Editor::new(&mut new_value, &mut cursor));
+
+    if let Some(mask) = &constraints.mask {
+        let is_changed = new_value.graphemes() != value.graphemes();
+
+        mask.apply(value, state.cursor, &mut new_value, &mut cursor);
+
+        if is_changed && new_value.graphemes() == value.graphemes() {
+            return false;
+        }
+    }
+
+    if let Some(max_length) = constraints.max_length {
+        if new_value.len() > max_length && new_value.len() > value.len() {
+            return false;
+        }
+    }
+
+    if new_value.graphemes() == value.graphemes() {
+        return false;
+    }
+
+    state.history.record(kind, value, state.cursor);
+
+    *value = new_value;
+    state.cursor = cursor;
+    state.history.commit(value);
+
+    true
 }
 
 fn offset<Renderer>(
@@ -1147,3 +1639,63 @@ where
         )
         .map(text::Hit::cursor)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn edits_that_change_nothing_keep_the_redo_stack() {
+        let mut value = Value::new("");
+        let mut state = State::focused();
+        let constraints = Constraints::default();
+
+        let is_edited = edit(
+            &mut value,
+            &mut state,
+            &constraints,
+            Edit::Insert,
+            |editor| {
+                editor.insert('a');
+            },
+        );
+        assert!(is_edited);
+
+        let is_changed = state.history.apply(
+            history::Action::Undo,
+            &mut value,
+            &mut state.cursor,
+        );
+        assert!(is_changed);
+
+        let is_edited = edit(
+            &mut value,
+            &mut state,
+            &constraints,
+            Edit::Delete,
+            |editor| {
+                editor.backspace();
+            },
+        );
+        assert!(!is_edited);
+
+        let is_edited = edit(
+            &mut value,
+            &mut state,
+            &constraints,
+            Edit::Delete,
+            |editor| {
+                editor.delete();
+            },
+        );
+        assert!(!is_edited);
+
+        let is_changed = state.history.apply(
+            history::Action::Redo,
+            &mut value,
+            &mut state.cursor,
+        );
+        assert!(is_changed);
+        assert_eq!(value.to_string(), "a");
+    }
+}
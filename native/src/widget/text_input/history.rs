@@ -0,0 +1,237 @@
+use crate::widget::text_input::{Cursor, Value};
+
+/// The undo and redo stacks of a text field.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct History {
+    undo: Vec<Snapshot>,
+    redo: Vec<Snapshot>,
+    last_edit: Option<Edit>,
+    scheduled: Option<Action>,
+    committed: Option<Value>,
+}
+
+/// The contents of a text field at some point in time.
+#[derive(Debug, Clone)]
+struct Snapshot {
+    value: Value,
+    cursor: Cursor,
+}
+
+/// The kind of an edit.
+///
+/// Consecutive edits of the same kind are grouped together, so that undoing
+/// reverts whole words instead of single characters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Edit {
+    Insert,
+    Delete,
+    Paste,
+}
+
+/// An action on a [`History`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Action {
+    Undo,
+    Redo,
+}
+
+/// The maximum amount of snapshots kept in the undo stack.
+const LIMIT: usize = 100;
+
+impl History {
+    /// Records the current contents of a text field before applying an edit
+    /// of the given kind.
+    pub fn record(&mut self, edit: Edit, value: &Value, cursor: Cursor) {
+        self.sync(value);
+
+        if self.last_edit != Some(edit) || edit == Edit::Paste {
+            if self.undo.len() == LIMIT {
+                let _ = self.undo.remove(0);
+            }
+
+            self.undo.push(Snapshot {
+                value: value.clone(),
+                cursor,
+            });
+        }
+
+        self.redo.clear();
+        self.last_edit = Some(edit);
+    }
+
+    /// Ends the current group of edits.
+    pub fn seal(&mut self) {
+        self.last_edit = None;
+    }
+
+    /// Remembers the contents of a text field after an edit, so that the
+    /// [`History`] is dropped if something else replaces them.
+    ///
+    /// Once committed, undoing and redoing commit their result as well. Text
+    /// fields that own their contents do not need to call this.
+    pub fn commit(&mut self, value: &Value) {
+        self.committed = Some(value.clone());
+    }
+
+    /// Applies the given [`Action`] to the contents of a text field.
+    ///
+    /// Returns `true` if the contents changed.
+    pub fn apply(
+        &mut self,
+        action: Action,
+        value: &mut Value,
+        cursor: &mut Cursor,
+    ) -> bool {
+        self.sync(value);
+
+        let snapshot = match action {
+            Action::Undo => self.undo(value, *cursor),
+            Action::Redo => self.redo(value, *cursor),
+        };
+
+        match snapshot {
+            Some(snapshot) => {
+                *value = snapshot.value;
+                *cursor = snapshot.cursor;
+
+                if self.committed.is_some() {
+                    self.commit(value);
+                }
+
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Schedules an [`Action`] to be applied the next time the text field
+    /// is redrawn.
+    ///
+    /// This is used by widget operations, which cannot change the contents
+    /// of a text field directly.
+    pub fn schedule(&mut self, action: Action) {
+        self.scheduled = Some(action);
+    }
+
+    /// Takes the scheduled [`Action`], if any.
+    pub fn take_scheduled(&mut self) -> Option<Action> {
+        self.scheduled.take()
+    }
+
+    /// Drops every snapshot if the contents of a text field are not the ones
+    /// last committed, since undoing would restore contents that were
+    /// replaced on purpose.
+    fn sync(&mut self, value: &Value) {
+        if let Some(committed) = &self.committed {
+            if committed.graphemes() != value.graphemes() {
+                self.undo.clear();
+                self.redo.clear();
+                self.last_edit = None;
+                self.committed = None;
+            }
+        }
+    }
+
+    /// Reverts the last group of edits, given the current contents of a text
+    /// field.
+    fn undo(&mut self, value: &Value, cursor: Cursor) -> Option<Snapshot> {
+        let snapshot = self.undo.pop()?;
+
+        self.redo.push(Snapshot {
+            value: value.clone(),
+            cursor,
+        });
+        self.last_edit = None;
+
+        Some(snapshot)
+    }
+
+    /// Reapplies the last group of edits that was undone, given the current
+    /// contents of a text field.
+    fn redo(&mut self, value: &Value, cursor: Cursor) -> Option<Snapshot> {
+        let snapshot = self.redo.pop()?;
+
+        self.undo.push(Snapshot {
+            value: value.clone(),
+            cursor,
+        });
+        self.last_edit = None;
+
+        Some(snapshot)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn type_text(history: &mut History, value: &mut Value, text: &str) {
+        let mut cursor = Cursor::default();
+        cursor.move_to(value.len());
+
+        for c in text.chars() {
+            history.record(Edit::Insert, value, cursor);
+
+            value.insert(value.len(), c);
+            cursor.move_to(value.len());
+            history.commit(value);
+        }
+    }
+
+    #[test]
+    fn coalesces_consecutive_typing() {
+        let mut history = History::default();
+        let mut value = Value::new("");
+        let mut cursor = Cursor::default();
+
+        type_text(&mut history, &mut value, "hello");
+        history.seal();
+        type_text(&mut history, &mut value, " world");
+
+        assert!(history.apply(Action::Undo, &mut value, &mut cursor));
+        assert_eq!(value.to_string(), "hello");
+
+        assert!(history.apply(Action::Undo, &mut value, &mut cursor));
+        assert_eq!(value.to_string(), "");
+
+        assert!(!history.apply(Action::Undo, &mut value, &mut cursor));
+
+        assert!(history.apply(Action::Redo, &mut value, &mut cursor));
+        assert_eq!(value.to_string(), "hello");
+    }
+
+    #[test]
+    fn new_edits_clear_redo() {
+        let mut history = History::default();
+        let mut value = Value::new("");
+        let mut cursor = Cursor::default();
+
+        type_text(&mut history, &mut value, "hello");
+        assert!(history.apply(Action::Undo, &mut value, &mut cursor));
+
+        type_text(&mut history, &mut value, "bye");
+
+        assert!(!history.apply(Action::Redo, &mut value, &mut cursor));
+        assert_eq!(value.to_string(), "bye");
+    }
+
+    #[test]
+    fn replaced_contents_drop_the_history() {
+        let mut history = History::default();
+        let mut value = Value::new("");
+        let mut cursor = Cursor::default();
+
+        type_text(&mut history, &mut value, "secret");
+
+        let mut value = Value::new("");
+
+        assert!(!history.apply(Action::Undo, &mut value, &mut cursor));
+        assert_eq!(value.to_string(), "");
+
+        type_text(&mut history, &mut value, "hi");
+
+        assert!(history.apply(Action::Undo, &mut value, &mut cursor));
+        assert_eq!(value.to_string(), "");
+        assert!(!history.apply(Action::Undo, &mut value, &mut cursor));
+    }
+}
@@ -0,0 +1,227 @@
+//! Format the value of a text input with a pattern.
+use crate::widget::text_input::{Cursor, Value};
+
+/// An input mask of a [`TextInput`].
+///
+/// A [`Mask`] is described by a pattern, where:
+///
+/// - `#` is a slot for a digit,
+/// - `@` is a slot for a letter,
+/// - `*` is a slot for a letter or a digit, and
+/// - any other character is a literal.
+///
+/// Literals are inserted automatically as the user types, and characters
+/// that appear as literals in the pattern are never considered input.
+///
+/// ```
+/// use iced_native::widget::text_input::Mask;
+///
+/// let phone = Mask::new("(###) ###-####");
+/// let date = Mask::new("##/##/####");
+///
+/// assert_eq!(phone.format("5551234567"), "(555) 123-4567");
+/// assert_eq!(date.format("2412"), "24/12");
+/// ```
+///
+/// [`TextInput`]: crate::widget::TextInput
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mask {
+    pattern: Vec<Slot>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Slot {
+    Digit,
+    Letter,
+    Alphanumeric,
+    Literal(char),
+}
+
+impl Slot {
+    fn accepts(self, grapheme: &str) -> bool {
+        let mut chars = grapheme.chars();
+
+        let c = match (chars.next(), chars.next()) {
+            (Some(c), None) => c,
+            _ => return false,
+        };
+
+        match self {
+            Slot::Digit => c.is_ascii_digit(),
+            Slot::Letter => c.is_alphabetic(),
+            Slot::Alphanumeric => c.is_alphanumeric(),
+            Slot::Literal(_) => false,
+        }
+    }
+}
+
+impl Mask {
+    /// Creates a new [`Mask`] with the given pattern.
+    pub fn new(pattern: &str) -> Self {
+        Self {
+            pattern: pattern
+                .chars()
+                .map(|c| match c {
+                    '#' => Slot::Digit,
+                    '@' => Slot::Letter,
+                    '*' => Slot::Alphanumeric,
+                    _ => Slot::Literal(c),
+                })
+                .collect(),
+        }
+    }
+
+    /// Formats the given text with the [`Mask`].
+    ///
+    /// Literals are dropped from the text and characters that do not fit
+    /// their slot are skipped.
+    pub fn format(&self, text: &str) -> String {
+        let value = Value::new(text);
+        let raw = self.raw(&value);
+
+        self.format_raw(&raw, 0).0
+    }
+
+    /// Applies the [`Mask`] to an edited [`Value`], given its contents before
+    /// the edit, and moves the [`Cursor`] accordingly.
+    pub(crate) fn apply(
+        &self,
+        old_value: &Value,
+        old_cursor: Cursor,
+        value: &mut Value,
+        cursor: &mut Cursor,
+    ) {
+        let position = cursor.end(value);
+
+        let mut raw = self.raw(value);
+        let mut raw_before_cursor = self.raw(&value.until(position)).len();
+
+        // Deleting a literal deletes the closest input instead
+        if value.len() < old_value.len() && raw == self.raw(old_value) {
+            if position < old_cursor.end(old_value) {
+                if raw_before_cursor > 0 {
+                    raw_before_cursor -= 1;
+                    let _ = raw.remove(raw_before_cursor);
+                }
+            } else if raw_before_cursor < raw.len() {
+                let _ = raw.remove(raw_before_cursor);
+            }
+        }
+
+        let (formatted, position) = self.format_raw(&raw, raw_before_cursor);
+
+        *value = Value::new(&formatted);
+        cursor.move_to(position);
+    }
+
+    /// Returns the graphemes of the [`Value`] that are not literals.
+    fn raw<'a>(&self, value: &'a Value) -> Vec<&'a str> {
+        value
+            .graphemes()
+            .iter()
+            .map(String::as_str)
+            .filter(|grapheme| {
+                !self.pattern.iter().any(|slot| match slot {
+                    Slot::Literal(c) => {
+                        grapheme.chars().eq(std::iter::once(*c))
+                    }
+                    _ => false,
+                })
+            })
+            .collect()
+    }
+
+    /// Formats the given input, returning the result and the position right
+    /// after the first `before_cursor` graphemes of input.
+    fn format_raw(
+        &self,
+        raw: &[&str],
+        before_cursor: usize,
+    ) -> (String, usize) {
+        let mut formatted = String::new();
+        let mut length = 0;
+        let mut position = 0;
+
+        let mut raw = raw.iter().enumerate().peekable();
+
+        for slot in &self.pattern {
+            if raw.peek().is_none() {
+                break;
+            }
+
+            match slot {
+                Slot::Literal(c) => {
+                    formatted.push(*c);
+                    length += 1;
+                }
+                _ => {
+                    for (i, grapheme) in raw.by_ref() {
+                        if slot.accepts(grapheme) {
+                            formatted.push_str(grapheme);
+                            length += 1;
+
+                            if i < before_cursor {
+                                position = length;
+                            }
+
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+
+        (formatted, position)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::widget::text_input::editor::Editor;
+
+    fn edit(
+        mask: &Mask,
+        value: &mut Value,
+        cursor: &mut Cursor,
+        f: impl FnOnce(&mut Editor<'_>),
+    ) {
+        let old_value = value.clone();
+        let old_cursor = *cursor;
+
+        f(&mut Editor::new(value, cursor));
+
+        mask.apply(&old_value, old_cursor, value, cursor);
+    }
+
+    #[test]
+    fn inserts_literals_while_typing() {
+        let mask = Mask::new("(###) ###");
+        let mut value = Value::new("");
+        let mut cursor = Cursor::default();
+
+        for c in "5551".chars() {
+            edit(&mask, &mut value, &mut cursor, |editor| editor.insert(c));
+        }
+
+        assert_eq!(value.to_string(), "(555) 1");
+        assert_eq!(cursor.end(&value), 7);
+
+        edit(&mask, &mut value, &mut cursor, |editor| editor.insert('x'));
+
+        assert_eq!(value.to_string(), "(555) 1");
+    }
+
+    #[test]
+    fn deleting_a_literal_deletes_input() {
+        let mask = Mask::new("(###) ###");
+        let mut value = Value::new("(555) 1");
+        let mut cursor = Cursor::default();
+
+        cursor.move_to(6);
+        edit(&mask, &mut value, &mut cursor, |editor| editor.backspace());
+
+        assert_eq!(value.to_string(), "(551");
+        assert_eq!(cursor.end(&value), 3);
+    }
+}
@@ -72,6 +72,11 @@ impl Value {
             .unwrap_or(self.len())
     }
 
+    /// Returns the graphemes of the [`Value`].
+    pub(crate) fn graphemes(&self) -> &[String] {
+        &self.graphemes
+    }
+
     /// Returns a new [`Value`] containing the graphemes from `start` until the
     /// given `end`.
     pub fn select(&self, start: usize, end: usize) -> Self {
@@ -1,11 +1,15 @@
 //! Show toggle controls using togglers.
 use crate::alignment;
+use crate::animation::{Animation, Easing, Motion};
 use crate::event;
 use crate::layout;
 use crate::mouse;
 use crate::renderer;
 use crate::text;
-use crate::widget::{self, Row, Text, Tree};
+use crate::time::{Duration, Instant};
+use crate::widget::tree::{self, Tree};
+use crate::widget::{self, Row, Text};
+use crate::window;
 use crate::{
     Alignment, Clipboard, Element, Event, Layout, Length, Point, Rectangle,
     Shell, Widget,
@@ -43,6 +47,7 @@ where
     text_alignment: alignment::Horizontal,
     spacing: u16,
     font: Renderer::Font,
+    motion: Option<Motion>,
     style: <Renderer::Theme as StyleSheet>::Style,
 }
 
@@ -80,6 +85,7 @@ where
             text_alignment: alignment::Horizontal::Left,
             spacing: 0,
             font: Renderer::Font::default(),
+            motion: None,
             style: Default::default(),
         }
     }
@@ -122,6 +128,15 @@ where
         self
     }
 
+    /// Sets the [`Motion`] used to animate the [`Toggler`] when it is toggled
+    /// or hovered.
+    ///
+    /// By default, or if `None`, the [`Toggler`] changes instantly.
+    pub fn motion(mut self, motion: impl Into<Option<Motion>>) -> Self {
+        self.motion = motion.into();
+        self
+    }
+
     /// Sets the style of the [`Toggler`].
     pub fn style(
         mut self,
@@ -138,6 +153,18 @@ where
     Renderer: text::Renderer,
     Renderer::Theme: StyleSheet + widget::text::StyleSheet,
 {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new(self.is_active, self.motion))
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.state.downcast_mut::<State>().set_motion(self.motion);
+    }
+
     fn width(&self) -> Length {
         self.width
     }
@@ -180,7 +207,7 @@ where
 
     fn on_event(
         &mut self,
-        _state: &mut Tree,
+        tree: &mut Tree,
         event: Event,
         layout: Layout<'_>,
         cursor_position: Point,
@@ -200,6 +227,22 @@ where
                     event::Status::Ignored
                 }
             }
+            Event::Window(_, window::Event::RedrawRequested(now)) => {
+                let state = tree.state.downcast_mut::<State>();
+
+                // Transitions start and are drawn with the time of the redraw
+                let toggler_bounds = layout.children().last().unwrap().bounds();
+                let is_hovered = toggler_bounds.contains(cursor_position);
+
+                state.now = Some(now);
+                state.status.go((self.is_active, is_hovered), now);
+
+                if state.status.is_animating(now) {
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                }
+
+                event::Status::Ignored
+            }
             _ => event::Status::Ignored,
         }
     }
@@ -221,7 +264,7 @@ where
 
     fn draw(
         &self,
-        _state: &Tree,
+        tree: &Tree,
         renderer: &mut Renderer,
         theme: &Renderer::Theme,
         style: &renderer::Style,
@@ -251,18 +294,32 @@ where
                 Default::default(),
                 self.text_alignment,
                 alignment::Vertical::Center,
+                text::Wrapping::default(),
             );
         }
 
         let toggler_layout = children.next().unwrap();
         let bounds = toggler_layout.bounds();
 
-        let is_mouse_over = bounds.contains(cursor_position);
+        let state = tree.state.downcast_ref::<State>();
+        let status = (self.is_active, bounds.contains(cursor_position));
 
-        let style = if is_mouse_over {
-            theme.hovered(&self.style, self.is_active)
-        } else {
-            theme.active(&self.style, self.is_active)
+        let appearance = |(is_active, is_hovered)| {
+            if is_hovered {
+                theme.hovered(&self.style, is_active)
+            } else {
+                theme.active(&self.style, is_active)
+            }
+        };
+
+        let position = |(is_active, _)| if is_active { 1.0 } else { 0.0 };
+
+        let (style, position) = match state.now {
+            Some(now) if state.status.state() == status => (
+                state.status.interpolate_with(appearance, now),
+                state.status.interpolate_with(position, now),
+            ),
+            _ => (appearance(status), position(status)),
         };
 
         let border_radius = bounds.height / BORDER_RADIUS_RATIO;
@@ -289,11 +346,11 @@ where
 
         let toggler_foreground_bounds = Rectangle {
             x: bounds.x
-                + if self.is_active {
-                    bounds.width - 2.0 * space - (bounds.height - (4.0 * space))
-                } else {
-                    2.0 * space
-                },
+                + 2.0 * space
+                + position
+                    * (bounds.width
+                        - 4.0 * space
+                        - (bounds.height - (4.0 * space))),
             y: bounds.y + (2.0 * space),
             width: bounds.height - (4.0 * space),
             height: bounds.height - (4.0 * space),
@@ -326,3 +383,29 @@ where
         Element::new(toggler)
     }
 }
+
+/// The local state of a [`Toggler`].
+#[derive(Debug, Clone, Copy)]
+struct State {
+    status: Animation<(bool, bool)>,
+    now: Option<Instant>,
+}
+
+impl State {
+    fn new(is_active: bool, motion: Option<Motion>) -> Self {
+        let mut state = Self {
+            status: Animation::new((is_active, false)),
+            now: None,
+        };
+
+        state.set_motion(motion);
+        state
+    }
+
+    fn set_motion(&mut self, motion: Option<Motion>) {
+        self.status.set_motion(motion.unwrap_or(Motion::Tween {
+            duration: Duration::ZERO,
+            easing: Easing::Linear,
+        }));
+    }
+}
@@ -0,0 +1,1120 @@
+//! Display a hierarchy of nodes that can be expanded and collapsed.
+//!
+//! A [`TreeView`] has some local [`State`].
+use crate::event::{self, Event};
+use crate::keyboard;
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::renderer;
+use crate::touch;
+use crate::widget::operation::{self, Operation};
+use crate::widget::tree::{self, Tree};
+use crate::{
+    Clipboard, Color, Element, Layout, Length, Point, Rectangle, Shell, Size,
+    Widget,
+};
+
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+pub use iced_style::tree_view::{Appearance, StyleSheet};
+
+/// The size of the disclosure arrow of a node.
+const ARROW_SIZE: f32 = 8.0;
+
+/// An indented hierarchy of nodes with disclosure arrows to expand and
+/// collapse them.
+///
+/// Nodes are identified by a user id. The set of expanded nodes is kept in
+/// the local [`State`] of the [`TreeView`], keyed by those ids, so it
+/// survives `view` rebuilds, as does the state of the content of each node.
+/// The children of a node are only requested, and their content only built,
+/// while the node is expanded; which allows them to be loaded lazily after an
+/// `on_toggle` message. Nodes that are no longer visible, including the
+/// descendants of a collapsed node, are not kept expanded.
+///
+/// Once clicked, a [`TreeView`] can be navigated with the arrow keys: up and
+/// down move the selection, while right and left expand and collapse nodes.
+///
+/// # Example
+/// ```
+/// # use iced_native::widget::{text, tree_view};
+/// # use iced_native::renderer::Null;
+/// #
+/// # type TreeView<'a, Id, Message> = tree_view::TreeView<'a, Id, Message, Null>;
+/// use tree_view::Node;
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Toggled(u32, bool),
+///     Selected(u32),
+/// }
+///
+/// let tree_view = TreeView::new(
+///     vec![Node::branch(0), Node::leaf(1)],
+///     |id| match id {
+///         0 => vec![Node::leaf(2), Node::leaf(3)],
+///         _ => vec![],
+///     },
+///     |id| text(format!("Node {id}")).into(),
+/// )
+/// .on_toggle(Message::Toggled)
+/// .on_select(Some(2), Message::Selected);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct TreeView<'a, Id, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    roots: Vec<Node<Id>>,
+    children: Box<dyn Fn(&Id) -> Vec<Node<Id>> + 'a>,
+    view: Box<dyn Fn(&Id) -> Element<'a, Message, Renderer> + 'a>,
+    content: RefCell<Content<'a, Id, Message, Renderer>>,
+    width: Length,
+    indent: u16,
+    padding: u16,
+    spacing: u16,
+    selected: Option<Id>,
+    on_select: Option<Box<dyn Fn(Id) -> Message + 'a>>,
+    on_toggle: Option<Box<dyn Fn(Id, bool) -> Message + 'a>>,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+/// A node of a [`TreeView`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Node<Id> {
+    /// The id of the [`Node`].
+    pub id: Id,
+
+    /// Whether the [`Node`] can be expanded to show some children.
+    pub is_branch: bool,
+}
+
+impl<Id> Node<Id> {
+    /// Creates a new [`Node`] without children.
+    pub fn leaf(id: Id) -> Self {
+        Self {
+            id,
+            is_branch: false,
+        }
+    }
+
+    /// Creates a new [`Node`] that can be expanded.
+    ///
+    /// Its children are only requested while it is expanded, and it may
+    /// have none.
+    pub fn branch(id: Id) -> Self {
+        Self {
+            id,
+            is_branch: true,
+        }
+    }
+}
+
+impl<'a, Id, Message, Renderer> TreeView<'a, Id, Message, Renderer>
+where
+    Id: 'static + Clone + Eq + Hash,
+    Renderer: crate::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`TreeView`].
+    ///
+    /// It expects:
+    ///   * the root nodes of the [`TreeView`]
+    ///   * a function that produces the children of an expanded node
+    ///   * a function that produces the content of a node
+    pub fn new(
+        roots: Vec<Node<Id>>,
+        children: impl Fn(&Id) -> Vec<Node<Id>> + 'a,
+        view: impl Fn(&Id) -> Element<'a, Message, Renderer> + 'a,
+    ) -> Self {
+        TreeView {
+            roots,
+            children: Box::new(children),
+            view: Box::new(view),
+            content: RefCell::new(Content {
+                rows: Vec::new(),
+                elements: Vec::new(),
+            }),
+            width: Length::Fill,
+            indent: 16,
+            padding: 2,
+            spacing: 0,
+            selected: None,
+            on_select: None,
+            on_toggle: None,
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the width of the [`TreeView`].
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the horizontal indentation of each level of the [`TreeView`].
+    pub fn indent(mut self, indent: u16) -> Self {
+        self.indent = indent;
+        self
+    }
+
+    /// Sets the padding of each node of the [`TreeView`].
+    pub fn padding(mut self, padding: u16) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the vertical spacing between the nodes of the [`TreeView`].
+    pub fn spacing(mut self, spacing: u16) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Enables the selection of nodes in the [`TreeView`].
+    ///
+    /// It expects:
+    ///   * the id of the selected node, if any
+    ///   * a function that will be called when a node is selected, either
+    ///     by clicking it or with the arrow keys
+    pub fn on_select<F>(mut self, selected: Option<Id>, f: F) -> Self
+    where
+        F: 'a + Fn(Id) -> Message,
+    {
+        self.selected = selected;
+        self.on_select = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the message that will be produced when a node is expanded or
+    /// collapsed.
+    ///
+    /// It receives the id of the node and whether it is now expanded, which
+    /// can be used to start loading its children.
+    pub fn on_toggle<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Fn(Id, bool) -> Message,
+    {
+        self.on_toggle = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the style of the [`TreeView`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Rebuilds the visible rows of the [`TreeView`], and their content,
+    /// with the given expanded nodes.
+    ///
+    /// Expanded nodes that are no longer visible are removed from the set.
+    fn rebuild(
+        &self,
+        content: &mut Content<'a, Id, Message, Renderer>,
+        expanded: &mut HashSet<Id>,
+    ) {
+        content.rows = flatten(&self.roots, &self.children, expanded);
+
+        *expanded = content
+            .rows
+            .iter()
+            .filter(|row| row.is_expanded)
+            .map(|row| row.id.clone())
+            .collect();
+
+        content.elements = content
+            .rows
+            .iter()
+            .map(|row| (self.view)(&row.id))
+            .collect();
+    }
+
+    /// Expands or collapses the node of the given row and rebuilds the
+    /// visible rows accordingly.
+    fn toggle(
+        &self,
+        tree: &mut Tree,
+        index: usize,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State<Id>>();
+        let mut content = self.content.borrow_mut();
+
+        let row = &content.rows[index];
+        let id = row.id.clone();
+        let is_expanded = !row.is_expanded;
+
+        if is_expanded {
+            state.expand(id.clone());
+        } else {
+            state.collapse(&id);
+        }
+
+        self.rebuild(&mut content, &mut state.expanded);
+
+        diff_rows(tree, &content);
+        shell.invalidate_layout();
+
+        if let Some(on_toggle) = &self.on_toggle {
+            shell.publish(on_toggle(id, is_expanded));
+        }
+    }
+
+    /// Selects the node of the given row, if selection is enabled.
+    fn select(&self, index: usize, shell: &mut Shell<'_, Message>) {
+        if let Some(on_select) = &self.on_select {
+            let id = &self.content.borrow().rows[index].id;
+
+            if self.selected.as_ref() != Some(id) {
+                shell.publish(on_select(id.clone()));
+            }
+        }
+    }
+}
+
+/// The visible rows of a [`TreeView`], with the content of their nodes.
+struct Content<'a, Id, Message, Renderer> {
+    rows: Vec<Row<Id>>,
+    elements: Vec<Element<'a, Message, Renderer>>,
+}
+
+/// A visible node of a [`TreeView`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Row<Id> {
+    id: Id,
+    depth: usize,
+    is_branch: bool,
+    is_expanded: bool,
+}
+
+/// The local state of a [`TreeView`].
+#[derive(Debug, Clone)]
+pub struct State<Id> {
+    expanded: HashSet<Id>,
+    is_focused: bool,
+    rows: Vec<Id>,
+}
+
+impl<Id> State<Id>
+where
+    Id: Eq + Hash,
+{
+    /// Creates a new [`State`] with every node collapsed.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns whether the node with the given id is expanded.
+    pub fn is_expanded(&self, id: &Id) -> bool {
+        self.expanded.contains(id)
+    }
+
+    /// Expands the node with the given id.
+    pub fn expand(&mut self, id: Id) {
+        let _ = self.expanded.insert(id);
+    }
+
+    /// Collapses the node with the given id.
+    pub fn collapse(&mut self, id: &Id) {
+        let _ = self.expanded.remove(id);
+    }
+}
+
+impl<Id> Default for State<Id> {
+    fn default() -> Self {
+        Self {
+            expanded: HashSet::new(),
+            is_focused: false,
+            rows: Vec::new(),
+        }
+    }
+}
+
+impl<Id> operation::Focusable for State<Id> {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
+impl<'a, Id, Message, Renderer> Widget<Message, Renderer>
+    for TreeView<'a, Id, Message, Renderer>
+where
+    Id: 'static + Clone + Eq + Hash,
+    Renderer: crate::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Id>>()
+    }
+
+    fn state(&self) -> tree::State {
+        // Every node starts collapsed, so only the roots are visible
+        tree::State::new(State::<Id> {
+            rows: self.roots.iter().map(|node| node.id.clone()).collect(),
+            ..State::new()
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        let mut content = self.content.borrow_mut();
+
+        self.rebuild(&mut content, &mut HashSet::new());
+
+        content.elements.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let mut content = self.content.borrow_mut();
+        let state = tree.state.downcast_mut::<State<Id>>();
+
+        self.rebuild(&mut content, &mut state.expanded);
+
+        diff_rows(tree, &content);
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let content = self.content.borrow();
+        let limits = limits.width(self.width).height(Length::Shrink);
+
+        let padding = f32::from(self.padding);
+        let indent = f32::from(self.indent);
+        let width = limits.max().width;
+        let mut y = 0.0;
+
+        let rows = content
+            .rows
+            .iter()
+            .zip(&content.elements)
+            .enumerate()
+            .map(|(index, (row, element))| {
+                if index > 0 {
+                    y += f32::from(self.spacing);
+                }
+
+                let x = row.depth as f32 * indent + ARROW_SIZE + padding * 2.0;
+
+                let limits = layout::Limits::new(
+                    Size::ZERO,
+                    Size::new((width - x - padding).max(0.0), f32::INFINITY),
+                );
+
+                let mut node = element.as_widget().layout(renderer, &limits);
+                let height = node.size().height.max(ARROW_SIZE);
+
+                node.move_to(Point::new(
+                    x,
+                    padding + (height - node.size().height) / 2.0,
+                ));
+
+                let mut row = layout::Node::with_children(
+                    Size::new(width, height + padding * 2.0),
+                    vec![node],
+                );
+
+                row.move_to(Point::new(0.0, y));
+                y += row.size().height;
+
+                row
+            })
+            .collect();
+
+        let size = limits.resolve(Size::new(width, y));
+
+        layout::Node::with_children(size, rows)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State<Id>>();
+        let content = self.content.borrow();
+
+        operation.focusable(state, None);
+
+        operation.container(None, &mut |operation| {
+            content
+                .elements
+                .iter()
+                .zip(&mut tree.children)
+                .zip(layout.children().filter_map(|row| row.children().next()))
+                .for_each(|((element, tree), layout)| {
+                    element.as_widget().operate(tree, layout, operation);
+                });
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let status = {
+            let content = self.content.get_mut();
+
+            content
+                .elements
+                .iter_mut()
+                .zip(&mut tree.children)
+                .zip(layout.children().filter_map(|row| row.children().next()))
+                .map(|((element, tree), layout)| {
+                    element.as_widget_mut().on_event(
+                        tree,
+                        event.clone(),
+                        layout,
+                        cursor_position,
+                        renderer,
+                        clipboard,
+                        shell,
+                    )
+                })
+                .fold(event::Status::Ignored, event::Status::merge)
+        };
+
+        if status == event::Status::Captured {
+            return status;
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let state = tree.state.downcast_mut::<State<Id>>();
+                state.is_focused = layout.bounds().contains(cursor_position);
+
+                let indent = f32::from(self.indent);
+                let padding = f32::from(self.padding);
+
+                let hovered = layout
+                    .children()
+                    .position(|row| row.bounds().contains(cursor_position));
+
+                if let Some(index) = hovered {
+                    let is_over_arrow = {
+                        let row = &self.content.get_mut().rows[index];
+
+                        row.is_branch
+                            && cursor_position.x
+                                < layout.bounds().x
+                                    + row.depth as f32 * indent
+                                    + ARROW_SIZE
+                                    + padding * 2.0
+                    };
+
+                    if is_over_arrow {
+                        self.toggle(tree, index, shell);
+                    } else {
+                        self.select(index, shell);
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code, ..
+            }) => {
+                let state = tree.state.downcast_mut::<State<Id>>();
+
+                if !state.is_focused {
+                    return event::Status::Ignored;
+                }
+
+                let action = {
+                    let rows = &self.content.get_mut().rows;
+
+                    let current = self.selected.as_ref().and_then(|selected| {
+                        rows.iter().position(|row| &row.id == selected)
+                    });
+
+                    navigate(rows, current, key_code)
+                };
+
+                match action {
+                    Some(Action::Select(index)) if self.on_select.is_some() => {
+                        self.select(index, shell);
+                    }
+                    Some(Action::Toggle(index)) => {
+                        self.toggle(tree, index, shell);
+                    }
+                    _ => return event::Status::Ignored,
+                }
+
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State<Id>>();
+        let content = self.content.borrow();
+
+        let appearance = if state.is_focused {
+            theme.focused(&self.style)
+        } else {
+            theme.active(&self.style)
+        };
+
+        let indent = f32::from(self.indent);
+        let padding = f32::from(self.padding);
+
+        for ((row, element), (tree, row_layout)) in content
+            .rows
+            .iter()
+            .zip(&content.elements)
+            .zip(tree.children.iter().zip(layout.children()))
+        {
+            let bounds = row_layout.bounds();
+
+            if bounds.intersection(viewport).is_none() {
+                continue;
+            }
+
+            let is_selected = self.selected.as_ref() == Some(&row.id);
+
+            let background = if is_selected {
+                Some(appearance.selected_background)
+            } else if self.on_select.is_some()
+                && bounds.contains(cursor_position)
+            {
+                appearance.hovered_background
+            } else {
+                None
+            };
+
+            if let Some(background) = background {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border_radius: appearance.border_radius.into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    background,
+                );
+            }
+
+            if row.is_branch {
+                draw_arrow(
+                    renderer,
+                    Point::new(
+                        bounds.x
+                            + row.depth as f32 * indent
+                            + padding
+                            + ARROW_SIZE / 2.0,
+                        bounds.center_y(),
+                    ),
+                    row.is_expanded,
+                    if is_selected {
+                        appearance.selected_text_color
+                    } else {
+                        appearance.arrow_color
+                    },
+                );
+            }
+
+            let style = if is_selected {
+                renderer::Style {
+                    text_color: appearance.selected_text_color,
+                }
+            } else {
+                *style
+            };
+
+            if let Some(layout) = row_layout.children().next() {
+                element.as_widget().draw(
+                    tree,
+                    renderer,
+                    theme,
+                    &style,
+                    layout,
+                    cursor_position,
+                    viewport,
+                );
+            }
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let content = self.content.borrow();
+
+        let interaction = content
+            .elements
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children().filter_map(|row| row.children().next()))
+            .map(|((element, tree), layout)| {
+                element.as_widget().mouse_interaction(
+                    tree,
+                    layout,
+                    cursor_position,
+                    viewport,
+                    renderer,
+                )
+            })
+            .max()
+            .unwrap_or_default();
+
+        if interaction != mouse::Interaction::default() {
+            return interaction;
+        }
+
+        let is_clickable =
+            content
+                .rows
+                .iter()
+                .zip(layout.children())
+                .any(|(row, layout)| {
+                    layout.bounds().contains(cursor_position)
+                        && (row.is_branch || self.on_select.is_some())
+                });
+
+        if is_clickable {
+            mouse::Interaction::Pointer
+        } else {
+            interaction
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        self.content
+            .get_mut()
+            .elements
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children().filter_map(|row| row.children().next()))
+            .find_map(|((element, tree), layout)| {
+                element.as_widget_mut().overlay(tree, layout, renderer)
+            })
+    }
+}
+
+impl<'a, Id, Message, Renderer> From<TreeView<'a, Id, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Id: 'static + Clone + Eq + Hash,
+    Message: 'a,
+    Renderer: 'a + crate::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(tree_view: TreeView<'a, Id, Message, Renderer>) -> Self {
+        Element::new(tree_view)
+    }
+}
+
+/// Reconciliates the children of the [`Tree`] of a [`TreeView`] with the
+/// content of its visible rows, keyed by the ids of their nodes.
+fn diff_rows<Id, Message, Renderer>(
+    tree: &mut Tree,
+    content: &Content<'_, Id, Message, Renderer>,
+) where
+    Id: 'static + Clone + Eq + Hash,
+    Renderer: crate::Renderer,
+{
+    let state = tree.state.downcast_mut::<State<Id>>();
+
+    let mut previous: HashMap<Id, Tree> =
+        state.rows.drain(..).zip(tree.children.drain(..)).collect();
+
+    tree.children = content
+        .rows
+        .iter()
+        .zip(&content.elements)
+        .map(|(row, element)| match previous.remove(&row.id) {
+            Some(mut child) => {
+                child.diff(element);
+                child
+            }
+            None => Tree::new(element),
+        })
+        .collect();
+
+    state.rows = content.rows.iter().map(|row| row.id.clone()).collect();
+}
+
+/// An action performed with the keyboard on a [`TreeView`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Action {
+    Select(usize),
+    Toggle(usize),
+}
+
+/// Produces the [`Action`] of a key press on the rows of a [`TreeView`],
+/// given the index of the selected row.
+fn navigate<Id>(
+    rows: &[Row<Id>],
+    current: Option<usize>,
+    key_code: keyboard::KeyCode,
+) -> Option<Action> {
+    let last = rows.len().checked_sub(1)?;
+
+    match key_code {
+        keyboard::KeyCode::Up => Some(Action::Select(
+            current.map(|index| index.saturating_sub(1)).unwrap_or(last),
+        )),
+        keyboard::KeyCode::Down => Some(Action::Select(
+            current.map(|index| (index + 1).min(last)).unwrap_or(0),
+        )),
+        keyboard::KeyCode::Home => Some(Action::Select(0)),
+        keyboard::KeyCode::End => Some(Action::Select(last)),
+        keyboard::KeyCode::Right => {
+            let index = current?;
+            let row = &rows[index];
+
+            if row.is_branch && !row.is_expanded {
+                Some(Action::Toggle(index))
+            } else {
+                rows.get(index + 1)
+                    .filter(|next| next.depth > row.depth)
+                    .map(|_| Action::Select(index + 1))
+            }
+        }
+        keyboard::KeyCode::Left => {
+            let index = current?;
+            let row = &rows[index];
+
+            if row.is_expanded {
+                Some(Action::Toggle(index))
+            } else {
+                rows[..index]
+                    .iter()
+                    .rposition(|parent| parent.depth < row.depth)
+                    .map(Action::Select)
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Flattens the nodes of a [`TreeView`] into its visible rows, only
+/// requesting the children of the expanded nodes.
+fn flatten<Id>(
+    roots: &[Node<Id>],
+    children: impl Fn(&Id) -> Vec<Node<Id>>,
+    expanded: &HashSet<Id>,
+) -> Vec<Row<Id>>
+where
+    Id: Clone + Eq + Hash,
+{
+    let mut rows = Vec::new();
+    let mut stack: Vec<_> =
+        roots.iter().rev().map(|node| (node.clone(), 0)).collect();
+
+    while let Some((node, depth)) = stack.pop() {
+        let is_expanded = node.is_branch && expanded.contains(&node.id);
+
+        if is_expanded {
+            stack.extend(
+                children(&node.id)
+                    .into_iter()
+                    .rev()
+                    .map(|child| (child, depth + 1)),
+            );
+        }
+
+        rows.push(Row {
+            id: node.id,
+            depth,
+            is_branch: node.is_branch,
+            is_expanded,
+        });
+    }
+
+    rows
+}
+
+/// Draws the disclosure arrow of a node, pointing right when collapsed and
+/// down when expanded.
+fn draw_arrow<Renderer>(
+    renderer: &mut Renderer,
+    center: Point,
+    is_expanded: bool,
+    color: Color,
+) where
+    Renderer: crate::Renderer,
+{
+    let steps = (ARROW_SIZE / 2.0) as usize;
+
+    for step in 0..steps {
+        let length = (steps - step) as f32 * 2.0;
+        let offset = step as f32 - steps as f32 / 2.0;
+
+        let bounds = if is_expanded {
+            Rectangle {
+                x: center.x - length / 2.0,
+                y: center.y + offset,
+                width: length,
+                height: 1.0,
+            }
+        } else {
+            Rectangle {
+                x: center.x + offset,
+                y: center.y - length / 2.0,
+                width: 1.0,
+                height: length,
+            }
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: 0.0.into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            color,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::renderer::Null;
+    use crate::widget::Space;
+
+    use std::cell::Cell;
+
+    fn children(id: &u32) -> Vec<Node<u32>> {
+        match id {
+            0 => vec![Node::branch(2), Node::leaf(3)],
+            2 => vec![Node::leaf(4)],
+            _ => vec![],
+        }
+    }
+
+    fn ids(rows: &[Row<u32>]) -> Vec<(u32, usize)> {
+        rows.iter().map(|row| (row.id, row.depth)).collect()
+    }
+
+    #[test]
+    fn flatten_collapsed() {
+        let roots = [Node::branch(0), Node::leaf(1)];
+        let rows = flatten(&roots, children, &HashSet::new());
+
+        assert_eq!(ids(&rows), vec![(0, 0), (1, 0)]);
+    }
+
+    #[test]
+    fn flatten_expanded() {
+        let roots = [Node::branch(0), Node::leaf(1)];
+        let rows = flatten(&roots, children, &HashSet::from([0, 2]));
+
+        assert_eq!(ids(&rows), vec![(0, 0), (2, 1), (4, 2), (3, 1), (1, 0)]);
+        assert!(rows[1].is_expanded);
+        assert!(!rows[3].is_expanded);
+    }
+
+    #[test]
+    fn flatten_only_requests_expanded_children() {
+        let roots = [Node::branch(0), Node::branch(1)];
+        let requests = Cell::new(0);
+
+        let rows = flatten(
+            &roots,
+            |id| {
+                requests.set(requests.get() + 1);
+                children(id)
+            },
+            &HashSet::from([0, 4]),
+        );
+
+        assert_eq!(ids(&rows), vec![(0, 0), (2, 1), (3, 1), (1, 0)]);
+        assert_eq!(requests.get(), 1);
+    }
+
+    #[test]
+    fn navigate_with_arrows() {
+        use keyboard::KeyCode;
+
+        let roots = [Node::branch(0), Node::leaf(1)];
+        let rows = flatten(&roots, children, &HashSet::from([0]));
+
+        assert_eq!(
+            navigate(&rows, None, KeyCode::Down),
+            Some(Action::Select(0))
+        );
+        assert_eq!(
+            navigate(&rows, Some(3), KeyCode::Down),
+            Some(Action::Select(3))
+        );
+        assert_eq!(
+            navigate(&rows, Some(0), KeyCode::Right),
+            Some(Action::Select(1))
+        );
+        assert_eq!(
+            navigate(&rows, Some(1), KeyCode::Right),
+            Some(Action::Toggle(1))
+        );
+        assert_eq!(
+            navigate(&rows, Some(2), KeyCode::Left),
+            Some(Action::Select(0))
+        );
+        assert_eq!(
+            navigate(&rows, Some(0), KeyCode::Left),
+            Some(Action::Toggle(0))
+        );
+        assert_eq!(navigate(&rows, Some(3), KeyCode::Right), None);
+    }
+
+    /// A [`TreeView`] with nested, empty tree views as the content of its
+    /// nodes, since their state can be told apart.
+    fn tree_view(roots: Vec<Node<u32>>) -> TreeView<'static, u32, u32, Null> {
+        TreeView::new(roots, children, |_| {
+            TreeView::new(
+                Vec::new(),
+                |_: &u32| Vec::new(),
+                |_| Space::new(Length::Shrink, Length::Shrink).into(),
+            )
+            .into()
+        })
+    }
+
+    fn is_focused(tree: &Tree) -> bool {
+        tree.state.downcast_ref::<State<u32>>().is_focused
+    }
+
+    #[test]
+    fn content_state_follows_node_ids() {
+        let roots = || vec![Node::branch(0), Node::leaf(1)];
+        let mut tree = Tree::new(&tree_view(roots()) as &dyn Widget<u32, Null>);
+
+        tree.children[1]
+            .state
+            .downcast_mut::<State<u32>>()
+            .is_focused = true;
+        tree.state.downcast_mut::<State<u32>>().expand(0);
+
+        tree.diff(&tree_view(roots()) as &dyn Widget<u32, Null>);
+
+        assert_eq!(
+            tree.children.iter().map(is_focused).collect::<Vec<_>>(),
+            vec![false, false, false, true]
+        );
+    }
+
+    #[test]
+    fn removed_nodes_are_not_kept_expanded() {
+        let mut tree = Tree::new(
+            &tree_view(vec![Node::branch(0)]) as &dyn Widget<u32, Null>
+        );
+
+        let state = tree.state.downcast_mut::<State<u32>>();
+        state.expand(0);
+        state.expand(2);
+        state.expand(5);
+
+        tree.diff(&tree_view(vec![Node::branch(0)]) as &dyn Widget<u32, Null>);
+
+        let state = tree.state.downcast_ref::<State<u32>>();
+        assert!(state.is_expanded(&0));
+        assert!(state.is_expanded(&2));
+        assert!(!state.is_expanded(&5));
+
+        tree.diff(&tree_view(vec![Node::leaf(1)]) as &dyn Widget<u32, Null>);
+
+        let state = tree.state.downcast_ref::<State<u32>>();
+        assert!(!state.is_expanded(&0));
+        assert!(!state.is_expanded(&2));
+    }
+
+    #[test]
+    fn arrows_are_only_captured_with_selection() {
+        let press = |tree_view: &mut TreeView<'static, u32, u32, Null>| {
+            let mut tree = Tree::new(&*tree_view as &dyn Widget<u32, Null>);
+            tree.state.downcast_mut::<State<u32>>().is_focused = true;
+
+            let node = Widget::<u32, Null>::layout(
+                tree_view,
+                &Null,
+                &layout::Limits::new(Size::ZERO, Size::new(100.0, 100.0)),
+            );
+
+            let mut messages = Vec::new();
+            let mut shell = Shell::new(&mut messages);
+
+            let status = tree_view.on_event(
+                &mut tree,
+                Event::Keyboard(keyboard::Event::KeyPressed {
+                    key_code: keyboard::KeyCode::Down,
+                    modifiers: keyboard::Modifiers::default(),
+                }),
+                Layout::new(&node),
+                Point::ORIGIN,
+                &Null,
+                &mut crate::clipboard::Null,
+                &mut shell,
+            );
+
+            (status, messages)
+        };
+
+        let roots = || vec![Node::branch(0), Node::leaf(1)];
+
+        assert_eq!(
+            press(&mut tree_view(roots())),
+            (event::Status::Ignored, vec![])
+        );
+        assert_eq!(
+            press(&mut tree_view(roots()).on_select(None, |id| id)),
+            (event::Status::Captured, vec![0])
+        );
+    }
+}
@@ -1,8 +1,16 @@
 //! Build window-based GUI applications.
 mod action;
 mod event;
+mod id;
 mod mode;
+mod redraw_request;
+mod screenshot;
+mod settings;
 
 pub use action::Action;
 pub use event::Event;
+pub use id::Id;
 pub use mode::Mode;
+pub use redraw_request::RedrawRequest;
+pub use screenshot::Screenshot;
+pub use settings::Settings;
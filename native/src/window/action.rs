@@ -1,52 +1,91 @@
-use crate::window::Mode;
+use crate::window::{Id, Mode, Screenshot, Settings};
 
 use iced_futures::MaybeSend;
 use std::fmt;
 
 /// An operation to be performed on some window.
+///
+/// Every [`Action`] targets the window with a given [`Id`]. Single-window
+/// applications only have the [`Id::MAIN`] window.
 pub enum Action<T> {
-    /// Closes the current window and exits the application.
-    Close,
+    /// Closes the window with the given [`Id`].
+    ///
+    /// Closing the [`Id::MAIN`] window exits the application.
+    Close(Id),
+    /// Spawns a new window with the given [`Id`] and [`Settings`].
+    ///
+    /// Only supported by multi-window applications.
+    Spawn {
+        /// The [`Id`] of the new window
+        id: Id,
+        /// The [`Settings`] of the new window
+        settings: Settings,
+    },
     /// Moves the window with the left mouse button until the button is
     /// released.
     ///
     /// There’s no guarantee that this will work unless the left mouse
     /// button was pressed immediately before this function is called.
-    Drag,
+    Drag(Id),
     /// Resize the window.
     Resize {
+        /// The [`Id`] of the window
+        id: Id,
         /// The new logical width of the window
         width: u32,
         /// The new logical height of the window
         height: u32,
     },
     /// Sets the window to maximized or back
-    Maximize(bool),
+    Maximize(Id, bool),
     /// Set the window to minimized or back
-    Minimize(bool),
+    Minimize(Id, bool),
     /// Move the window.
     ///
     /// Unsupported on Wayland.
     Move {
+        /// The [`Id`] of the window
+        id: Id,
         /// The new logical x location of the window
         x: i32,
         /// The new logical y location of the window
         y: i32,
     },
     /// Set the [`Mode`] of the window.
-    SetMode(Mode),
+    SetMode(Id, Mode),
     /// Sets the window to maximized or back
-    ToggleMaximize,
+    ToggleMaximize(Id),
     /// Toggles whether window has decorations
     /// ## Platform-specific
     /// - **X11:** Not implemented.
     /// - **Web:** Unsupported.
-    ToggleDecorations,
+    ToggleDecorations(Id),
     /// Fetch the current [`Mode`] of the window.
-    FetchMode(Box<dyn FnOnce(Mode) -> T + 'static>),
+    FetchMode(Id, Box<dyn FnOnce(Mode) -> T + 'static>),
+    /// Captures a [`Screenshot`] of the window after the next frame is
+    /// presented.
+    Screenshot(Id, Box<dyn FnOnce(Screenshot) -> T + 'static>),
 }
 
 impl<T> Action<T> {
+    /// Returns the [`Id`] of the window targeted by the [`Action`].
+    pub fn id(&self) -> Id {
+        match self {
+            Self::Close(id)
+            | Self::Spawn { id, .. }
+            | Self::Drag(id)
+            | Self::Resize { id, .. }
+            | Self::Maximize(id, _)
+            | Self::Minimize(id, _)
+            | Self::Move { id, .. }
+            | Self::SetMode(id, _)
+            | Self::ToggleMaximize(id)
+            | Self::ToggleDecorations(id)
+            | Self::FetchMode(id, _)
+            | Self::Screenshot(id, _) => *id,
+        }
+    }
+
     /// Maps the output of a window [`Action`] using the provided closure.
     pub fn map<A>(
         self,
@@ -56,16 +95,24 @@ impl<T> Action<T> {
         T: 'static,
     {
         match self {
-            Self::Close => Action::Close,
-            Self::Drag => Action::Drag,
-            Self::Resize { width, height } => Action::Resize { width, height },
-            Self::Maximize(bool) => Action::Maximize(bool),
-            Self::Minimize(bool) => Action::Minimize(bool),
-            Self::Move { x, y } => Action::Move { x, y },
-            Self::SetMode(mode) => Action::SetMode(mode),
-            Self::ToggleMaximize => Action::ToggleMaximize,
-            Self::ToggleDecorations => Action::ToggleDecorations,
-            Self::FetchMode(o) => Action::FetchMode(Box::new(move |s| f(o(s)))),
+            Self::Close(id) => Action::Close(id),
+            Self::Spawn { id, settings } => Action::Spawn { id, settings },
+            Self::Drag(id) => Action::Drag(id),
+            Self::Resize { id, width, height } => {
+                Action::Resize { id, width, height }
+            }
+            Self::Maximize(id, bool) => Action::Maximize(id, bool),
+            Self::Minimize(id, bool) => Action::Minimize(id, bool),
+            Self::Move { id, x, y } => Action::Move { id, x, y },
+            Self::SetMode(id, mode) => Action::SetMode(id, mode),
+            Self::ToggleMaximize(id) => Action::ToggleMaximize(id),
+            Self::ToggleDecorations(id) => Action::ToggleDecorations(id),
+            Self::FetchMode(id, o) => {
+                Action::FetchMode(id, Box::new(move |s| f(o(s))))
+            }
+            Self::Screenshot(id, o) => {
+                Action::Screenshot(id, Box::new(move |s| f(o(s))))
+            }
         }
     }
 }
@@ -73,22 +120,40 @@ impl<T> Action<T> {
 impl<T> fmt::Debug for Action<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            Self::Close => write!(f, "Action::Close"),
-            Self::Drag => write!(f, "Action::Drag"),
-            Self::Resize { width, height } => write!(
+            Self::Close(id) => write!(f, "Action::Close({:?})", id),
+            Self::Spawn { id, settings } => write!(
+                f,
+                "Action::Spawn {{ id: {:?}, settings: {:?} }}",
+                id, settings
+            ),
+            Self::Drag(id) => write!(f, "Action::Drag({:?})", id),
+            Self::Resize { id, width, height } => write!(
                 f,
-                "Action::Resize {{ widget: {}, height: {} }}",
-                width, height
+                "Action::Resize {{ id: {:?}, width: {}, height: {} }}",
+                id, width, height
             ),
-            Self::Maximize(value) => write!(f, "Action::Maximize({})", value),
-            Self::Minimize(value) => write!(f, "Action::Minimize({}", value),
-            Self::Move { x, y } => {
-                write!(f, "Action::Move {{ x: {}, y: {} }}", x, y)
+            Self::Maximize(id, value) => {
+                write!(f, "Action::Maximize({:?}, {})", id, value)
+            }
+            Self::Minimize(id, value) => {
+                write!(f, "Action::Minimize({:?}, {})", id, value)
+            }
+            Self::Move { id, x, y } => {
+                write!(f, "Action::Move {{ id: {:?}, x: {}, y: {} }}", id, x, y)
+            }
+            Self::SetMode(id, mode) => {
+                write!(f, "Action::SetMode({:?}, {:?})", id, mode)
+            }
+            Self::ToggleMaximize(id) => {
+                write!(f, "Action::ToggleMaximize({:?})", id)
+            }
+            Self::ToggleDecorations(id) => {
+                write!(f, "Action::ToggleDecorations({:?})", id)
+            }
+            Self::FetchMode(id, _) => write!(f, "Action::FetchMode({:?})", id),
+            Self::Screenshot(id, _) => {
+                write!(f, "Action::Screenshot({:?})", id)
             }
-            Self::SetMode(mode) => write!(f, "Action::SetMode({:?})", mode),
-            Self::ToggleMaximize => write!(f, "Action::ToggleMaximize"),
-            Self::ToggleDecorations => write!(f, "Action::ToggleDecorations"),
-            Self::FetchMode(_) => write!(f, "Action::FetchMode"),
         }
     }
 }
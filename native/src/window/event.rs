@@ -1,3 +1,5 @@
+use crate::time::Instant;
+
 use std::path::PathBuf;
 
 /// A window-related event.
@@ -19,12 +21,26 @@ pub enum Event {
         height: u32,
     },
 
+    /// A window is about to be redrawn.
+    ///
+    /// Widgets can use the provided [`Instant`] to advance their animations
+    /// and request another redraw through the [`Shell`].
+    ///
+    /// [`Shell`]: crate::Shell
+    RedrawRequested(Instant),
+
     /// The user has requested for the window to close.
     ///
     /// Usually, you will want to terminate the execution whenever this event
     /// occurs.
     CloseRequested,
 
+    /// A window was closed.
+    ///
+    /// It is produced by multi-window applications once a window other than
+    /// the main one has been closed, right before it is removed.
+    Closed,
+
     /// A window was focused.
     Focused,
 
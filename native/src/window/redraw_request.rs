@@ -0,0 +1,14 @@
+use crate::time::Instant;
+
+/// A request to redraw a window.
+///
+/// Requests are ordered by urgency: [`RedrawRequest::NextFrame`] comes first,
+/// followed by [`RedrawRequest::At`] in chronological order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum RedrawRequest {
+    /// Redraw the next frame.
+    NextFrame,
+
+    /// Redraw at the given time.
+    At(Instant),
+}
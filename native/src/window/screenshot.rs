@@ -0,0 +1,32 @@
+use crate::Size;
+
+use std::fmt;
+
+/// The pixels of a frame presented to a window.
+#[derive(Clone)]
+pub struct Screenshot {
+    /// The RGBA bytes of the [`Screenshot`], row by row, starting at the top
+    /// left corner.
+    pub bytes: Vec<u8>,
+    /// The physical size of the [`Screenshot`].
+    pub size: Size<u32>,
+}
+
+impl Screenshot {
+    /// Creates a new [`Screenshot`].
+    pub fn new(bytes: Vec<u8>, size: Size<u32>) -> Self {
+        Self { bytes, size }
+    }
+}
+
+impl fmt::Debug for Screenshot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Screenshot {{ bytes: {} bytes, size: {}x{} }}",
+            self.bytes.len(),
+            self.size.width,
+            self.size.height
+        )
+    }
+}
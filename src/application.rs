@@ -203,6 +203,8 @@ pub trait Application: Sized {
         #[allow(clippy::needless_update)]
         let renderer_settings = crate::renderer::Settings {
             default_font: settings.default_font,
+            fallback_fonts: settings.fallback_fonts,
+            fonts: settings.fonts,
             default_text_size: settings.default_text_size,
             text_multithreading: settings.text_multithreading,
             antialiasing: if settings.antialiasing {
@@ -0,0 +1,2 @@
+//! Listen and react to input method events.
+pub use crate::runtime::input_method::Event;
@@ -172,8 +172,15 @@ mod sandbox;
 pub mod application;
 pub mod clipboard;
 pub mod executor;
+pub mod input_method;
 pub mod keyboard;
 pub mod mouse;
+#[cfg(all(
+    not(feature = "glow"),
+    feature = "wgpu",
+    not(target_arch = "wasm32")
+))]
+pub mod multi_window;
 pub mod overlay;
 pub mod settings;
 pub mod time;
@@ -210,6 +217,8 @@ pub use subscription::Subscription;
 pub use theme::Theme;
 
 pub use runtime::alignment;
+pub use runtime::animation;
+pub use runtime::font;
 pub use runtime::futures;
 pub use runtime::{
     color, Alignment, Background, Color, Command, ContentFit, Font, Length,
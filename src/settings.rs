@@ -26,6 +26,21 @@ pub struct Settings<Flags> {
     // TODO: Add `name` for web compatibility
     pub default_font: Option<&'static [u8]>,
 
+    /// The bytes of the fonts that will be used, in order, for the characters
+    /// missing in the default font.
+    ///
+    /// With the `default_system_font` feature, the fallback fonts installed
+    /// in the system will be tried afterwards.
+    pub fallback_fonts: &'static [&'static [u8]],
+
+    /// The bytes of the fonts that will be loaded on boot, which can be
+    /// selected by the family and properties of a [`Font`].
+    ///
+    /// By default, it is empty.
+    ///
+    /// [`Font`]: crate::Font
+    pub fonts: &'static [&'static [u8]],
+
     /// The text size that will be used by default.
     ///
     /// The default value is 20.
@@ -78,6 +93,8 @@ impl<Flags> Settings<Flags> {
             id: default_settings.id,
             window: default_settings.window,
             default_font: default_settings.default_font,
+            fallback_fonts: default_settings.fallback_fonts,
+            fonts: default_settings.fonts,
             default_text_size: default_settings.default_text_size,
             text_multithreading: default_settings.text_multithreading,
             antialiasing: default_settings.antialiasing,
@@ -97,6 +114,8 @@ where
             window: Default::default(),
             flags: Default::default(),
             default_font: Default::default(),
+            fallback_fonts: &[],
+            fonts: &[],
             default_text_size: 20,
             text_multithreading: false,
             antialiasing: false,
@@ -13,7 +13,7 @@ pub type Row<'a, Message, Renderer = crate::Renderer> =
 
 pub mod text {
     //! Write some text for your users to read.
-    pub use iced_native::widget::text::{Appearance, StyleSheet};
+    pub use iced_native::widget::text::{Appearance, StyleSheet, Wrapping};
 
     /// A paragraph of text.
     pub type Text<'a, Renderer = crate::Renderer> =
@@ -22,7 +22,7 @@ pub mod text {
 
 pub mod button {
     //! Allow your users to perform actions by pressing a button.
-    pub use iced_native::widget::button::{Appearance, StyleSheet};
+    pub use iced_native::widget::button::{Appearance, Id, StyleSheet};
 
     /// A widget that produces a message when clicked.
     pub type Button<'a, Message, Renderer = crate::Renderer> =
@@ -40,13 +40,46 @@ pub mod checkbox {
 
 pub mod container {
     //! Decorate content and apply alignment.
-    pub use iced_native::widget::container::{Appearance, StyleSheet};
+    pub use iced_native::widget::container::{Appearance, Id, StyleSheet};
 
     /// An element decorating some content.
     pub type Container<'a, Message, Renderer = crate::Renderer> =
         iced_native::widget::Container<'a, Message, Renderer>;
 }
 
+pub mod combo_box {
+    //! Search and pick a value from a long list of options.
+    pub use iced_native::widget::combo_box::{
+        fuzzy, substring, Appearance, Match, State, StyleSheet,
+    };
+
+    /// A text input that filters a list of options as the user types.
+    pub type ComboBox<'a, T, Message, Renderer = crate::Renderer> =
+        iced_native::widget::ComboBox<'a, T, Message, Renderer>;
+}
+
+pub mod context_menu {
+    //! Show a menu when some content is right-clicked.
+    pub use iced_native::widget::context_menu::{
+        Appearance, Item, State, StyleSheet,
+    };
+
+    /// Some content that shows a menu when it is right-clicked.
+    pub type ContextMenu<'a, Message, Renderer = crate::Renderer> =
+        iced_native::widget::ContextMenu<'a, Message, Renderer>;
+}
+
+pub mod menu_bar {
+    //! Show the menus of an application in a bar.
+    pub use iced_native::widget::menu_bar::{
+        Appearance, Item, State, StyleSheet,
+    };
+
+    /// A bar of menus.
+    pub type MenuBar<Message, Renderer = crate::Renderer> =
+        iced_native::widget::MenuBar<Message, Renderer>;
+}
+
 pub mod pane_grid {
     //! Let your users split regions of your application and organize layout dynamically.
     //!
@@ -78,6 +111,29 @@ pub mod pane_grid {
         iced_native::widget::pane_grid::TitleBar<'a, Message, Renderer>;
 }
 
+pub mod number_input {
+    //! Display fields that can only be filled with numbers.
+    pub use iced_native::widget::number_input::{Appearance, StyleSheet};
+
+    /// A field that can only be filled with numbers.
+    pub type NumberInput<'a, T, Message, Renderer = crate::Renderer> =
+        iced_native::widget::NumberInput<'a, T, Message, Renderer>;
+}
+
+pub mod rich_text {
+    //! Write paragraphs that mix different styles and links.
+    pub use iced_native::widget::rich_text::{Appearance, StyleSheet};
+
+    /// A paragraph made of spans with their own size, color and font.
+    pub type RichText<'a, Message, Renderer = crate::Renderer> =
+        iced_native::widget::RichText<'a, Message, Renderer>;
+
+    /// A run of text of a [`RichText`] with its own style, which can be a
+    /// link.
+    pub type Span<'a, Message, Font = crate::Font> =
+        iced_native::widget::rich_text::Span<'a, Message, Font>;
+}
+
 pub mod pick_list {
     //! Display a dropdown list of selectable values.
     pub use iced_native::widget::pick_list::{Appearance, StyleSheet};
@@ -99,15 +155,44 @@ pub mod radio {
 pub mod scrollable {
     //! Navigate an endless amount of content with a scrollbar.
     pub use iced_native::widget::scrollable::{
-        snap_to, style::Scrollbar, style::Scroller, Id, StyleSheet,
+        snap_to, style::Scrollbar, style::Scroller, Direction, Id,
+        RelativeOffset, StyleSheet,
     };
 
-    /// A widget that can vertically display an infinite amount of content
-    /// with a scrollbar.
+    /// A widget that can display an infinite amount of content with
+    /// vertical and horizontal scrollbars.
     pub type Scrollable<'a, Message, Renderer = crate::Renderer> =
         iced_native::widget::Scrollable<'a, Message, Renderer>;
 }
 
+pub mod table {
+    //! Display data in rows and columns under a sticky header.
+    pub use iced_native::widget::table::{
+        Appearance, Order, ResizeEvent, Selection, StyleSheet,
+    };
+
+    /// A grid of rows of some data with a sticky header.
+    pub type Table<'a, T, Message, Renderer = crate::Renderer> =
+        iced_native::widget::Table<'a, T, Message, Renderer>;
+
+    /// A column of a [`Table`].
+    pub type Column<'a, T, Message, Renderer = crate::Renderer> =
+        iced_native::widget::table::Column<'a, T, Message, Renderer>;
+}
+
+pub mod tabs {
+    //! Switch between pages of content with a bar of tabs.
+    pub use iced_native::widget::tabs::{Appearance, Bar, StyleSheet};
+
+    /// A bar of tabs on top of the page of the active one.
+    pub type Tabs<'a, Message, Renderer = crate::Renderer> =
+        iced_native::widget::Tabs<'a, Message, Renderer>;
+
+    /// The label of a tab.
+    pub type Label<'a, Message, Renderer = crate::Renderer> =
+        iced_native::widget::tabs::Label<'a, Message, Renderer>;
+}
+
 pub mod toggler {
     //! Show toggle controls using togglers.
     pub use iced_native::widget::toggler::{Appearance, StyleSheet};
@@ -120,8 +205,8 @@ pub mod toggler {
 pub mod text_input {
     //! Display fields that can be filled with text.
     pub use iced_native::widget::text_input::{
-        focus, move_cursor_to, move_cursor_to_end, move_cursor_to_front,
-        select_all, Appearance, Id, StyleSheet,
+        focus, move_cursor_to, move_cursor_to_end, move_cursor_to_front, redo,
+        select_all, undo, Appearance, Constraints, Id, Mask, StyleSheet,
     };
 
     /// A field that can be filled with text.
@@ -129,6 +214,17 @@ pub mod text_input {
         iced_native::widget::TextInput<'a, Message, Renderer>;
 }
 
+pub mod text_editor {
+    //! Display a multi-line text field that can be edited.
+    pub use iced_native::widget::text_editor::{
+        focus, Appearance, Id, StyleSheet,
+    };
+
+    /// A multi-line field that can be filled with text.
+    pub type TextEditor<'a, Message, Renderer = crate::Renderer> =
+        iced_native::widget::TextEditor<'a, Message, Renderer>;
+}
+
 pub mod tooltip {
     //! Display a widget over another.
     pub use iced_native::widget::tooltip::Position;
@@ -138,6 +234,17 @@ pub mod tooltip {
         iced_native::widget::Tooltip<'a, Message, Renderer>;
 }
 
+pub mod tree_view {
+    //! Display a hierarchy of nodes that can be expanded and collapsed.
+    pub use iced_native::widget::tree_view::{
+        Appearance, Node, State, StyleSheet,
+    };
+
+    /// An indented hierarchy of nodes that can be expanded and collapsed.
+    pub type TreeView<'a, Id, Message, Renderer = crate::Renderer> =
+        iced_native::widget::TreeView<'a, Id, Message, Renderer>;
+}
+
 pub use iced_native::widget::progress_bar;
 pub use iced_native::widget::rule;
 pub use iced_native::widget::slider;
@@ -146,18 +253,27 @@ pub use iced_native::widget::Space;
 
 pub use button::Button;
 pub use checkbox::Checkbox;
+pub use combo_box::ComboBox;
 pub use container::Container;
+pub use context_menu::ContextMenu;
+pub use menu_bar::MenuBar;
+pub use number_input::NumberInput;
 pub use pane_grid::PaneGrid;
 pub use pick_list::PickList;
 pub use progress_bar::ProgressBar;
 pub use radio::Radio;
+pub use rich_text::RichText;
 pub use rule::Rule;
 pub use scrollable::Scrollable;
 pub use slider::Slider;
+pub use table::Table;
+pub use tabs::Tabs;
 pub use text::Text;
+pub use text_editor::TextEditor;
 pub use text_input::TextInput;
 pub use toggler::Toggler;
 pub use tooltip::Tooltip;
+pub use tree_view::TreeView;
 pub use vertical_slider::VerticalSlider;
 
 #[cfg(feature = "canvas")]
@@ -68,3 +68,22 @@ impl From<Settings> for iced_winit::settings::Window {
         }
     }
 }
+
+impl From<Settings> for iced_native::window::Settings {
+    fn from(settings: Settings) -> Self {
+        Self {
+            size: settings.size,
+            position: match settings.position {
+                Position::Specific(x, y) => Some((x, y)),
+                Position::Default | Position::Centered => None,
+            },
+            min_size: settings.min_size,
+            max_size: settings.max_size,
+            visible: settings.visible,
+            resizable: settings.resizable,
+            decorations: settings.decorations,
+            transparent: settings.transparent,
+            always_on_top: settings.always_on_top,
+        }
+    }
+}
@@ -1,4 +1,5 @@
 //! Change the apperance of a button.
+use iced_core::animation::Interpolate;
 use iced_core::{Background, Color, Vector};
 
 /// The appearance of a button.
@@ -31,6 +32,23 @@ impl std::default::Default for Appearance {
     }
 }
 
+impl Interpolate for Appearance {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        Self {
+            shadow_offset: self
+                .shadow_offset
+                .interpolate(&other.shadow_offset, t),
+            background: self.background.interpolate(&other.background, t),
+            border_radius: self
+                .border_radius
+                .interpolate(&other.border_radius, t),
+            border_width: self.border_width.interpolate(&other.border_width, t),
+            border_color: self.border_color.interpolate(&other.border_color, t),
+            text_color: self.text_color.interpolate(&other.text_color, t),
+        }
+    }
+}
+
 /// A set of rules that dictate the style of a button.
 pub trait StyleSheet {
     /// The supported style of the [`StyleSheet`].
@@ -25,6 +25,7 @@ pub mod button;
 pub mod checkbox;
 pub mod container;
 pub mod menu;
+pub mod number_input;
 pub mod pane_grid;
 pub mod pick_list;
 pub mod progress_bar;
@@ -33,9 +34,12 @@ pub mod rule;
 pub mod scrollable;
 pub mod slider;
 pub mod svg;
+pub mod table;
+pub mod tabs;
 pub mod text;
 pub mod text_input;
 pub mod theme;
 pub mod toggler;
+pub mod tree_view;
 
 pub use theme::Theme;
@@ -18,6 +18,14 @@ pub struct Appearance {
     pub selected_text_color: Color,
     /// The background [`Color`] of a selected option in the menu.
     pub selected_background: Background,
+    /// The [`Color`] of the separators between items of the menu.
+    pub separator_color: Color,
+    /// The text [`Color`] of a disabled item in the menu.
+    pub disabled_text_color: Color,
+    /// The text [`Color`] of the keyboard accelerators of the menu.
+    pub accelerator_text_color: Color,
+    /// The text [`Color`] of the highlighted parts of an option in the menu.
+    pub highlighted_text_color: Color,
 }
 
 /// The style sheet of a menu.
@@ -0,0 +1,45 @@
+//! Change the appearance of a number input.
+use iced_core::{Background, Color};
+
+/// The appearance of the buttons of a number input.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The [`Background`] of the buttons.
+    pub button_background: Option<Background>,
+    /// The [`Color`] of the icons of the buttons.
+    pub icon_color: Color,
+    /// The border radius of the buttons.
+    pub border_radius: f32,
+}
+
+/// A set of rules that dictate the style of a number input.
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Produces the active [`Appearance`] of a button of a number input.
+    fn active(&self, style: &Self::Style) -> Appearance;
+
+    /// Produces the hovered [`Appearance`] of a button of a number input.
+    fn hovered(&self, style: &Self::Style) -> Appearance;
+
+    /// Produces the pressed [`Appearance`] of a button of a number input.
+    fn pressed(&self, style: &Self::Style) -> Appearance {
+        self.hovered(style)
+    }
+
+    /// Produces the disabled [`Appearance`] of a button of a number input.
+    ///
+    /// A button is disabled when the value has reached its bound.
+    fn disabled(&self, style: &Self::Style) -> Appearance {
+        let active = self.active(style);
+
+        Appearance {
+            icon_color: Color {
+                a: active.icon_color.a * 0.5,
+                ..active.icon_color
+            },
+            ..active
+        }
+    }
+}
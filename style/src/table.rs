@@ -0,0 +1,34 @@
+//! Change the appearance of a table.
+use iced_core::{Background, Color};
+
+/// The appearance of a table.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The [`Background`] of the header.
+    pub header_background: Background,
+    /// The [`Color`] of the text of the header.
+    pub header_text_color: Color,
+    /// The [`Background`] of the rows.
+    pub row_background: Option<Background>,
+    /// The [`Background`] of every other row, if they are striped.
+    pub alternate_row_background: Option<Background>,
+    /// The [`Background`] of the row under the cursor.
+    pub hovered_row_background: Option<Background>,
+    /// The [`Background`] of the selected rows.
+    pub selected_row_background: Background,
+    /// The [`Color`] of the text of the selected rows.
+    pub selected_text_color: Color,
+    /// The [`Color`] of the lines dividing the columns and the header.
+    pub divider_color: Color,
+    /// The [`Color`] of a column divider that is hovered or being dragged.
+    pub hovered_divider_color: Color,
+}
+
+/// A set of rules that dictate the style of a table.
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Produces the [`Appearance`] of a table.
+    fn appearance(&self, style: &Self::Style) -> Appearance;
+}
@@ -0,0 +1,47 @@
+//! Change the appearance of tabs.
+use iced_core::{Background, Color};
+
+/// The appearance of a tab.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The [`Background`] of the tab.
+    pub background: Option<Background>,
+    /// The [`Color`] of the label of the tab.
+    pub text_color: Color,
+    /// The [`Color`] of the line under the tab, if any.
+    pub indicator_color: Option<Color>,
+    /// The [`Color`] of the close button of the tab.
+    pub close_color: Color,
+    /// The border radius of the top corners of the tab.
+    pub border_radius: f32,
+}
+
+/// The appearance of the bar containing the tabs.
+#[derive(Debug, Clone, Copy)]
+pub struct Bar {
+    /// The [`Background`] of the bar.
+    pub background: Option<Background>,
+    /// The [`Color`] of the line under the bar.
+    pub border_color: Color,
+    /// The width of the line under the bar.
+    pub border_width: f32,
+}
+
+/// A set of rules that dictate the style of tabs.
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Produces the [`Bar`] appearance of the tabs.
+    fn bar(&self, style: &Self::Style) -> Bar;
+
+    /// Produces the [`Appearance`] of a tab, given whether it is the active
+    /// one.
+    fn active(&self, style: &Self::Style, is_active: bool) -> Appearance;
+
+    /// Produces the [`Appearance`] of a hovered tab, given whether it is the
+    /// active one.
+    fn hovered(&self, style: &Self::Style, is_active: bool) -> Appearance {
+        self.active(style, is_active)
+    }
+}
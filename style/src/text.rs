@@ -8,6 +8,9 @@ pub trait StyleSheet {
 
     /// Produces the [`Appearance`] of some text.
     fn appearance(&self, style: Self::Style) -> Appearance;
+
+    /// Produces the [`Color`] of the selection of some selectable text.
+    fn selection_color(&self, style: Self::Style) -> Color;
 }
 
 /// The apperance of some text.
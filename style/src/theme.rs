@@ -9,6 +9,7 @@ use crate::button;
 use crate::checkbox;
 use crate::container;
 use crate::menu;
+use crate::number_input;
 use crate::pane_grid;
 use crate::pick_list;
 use crate::progress_bar;
@@ -17,9 +18,12 @@ use crate::rule;
 use crate::scrollable;
 use crate::slider;
 use crate::svg;
+use crate::table;
+use crate::tabs;
 use crate::text;
 use crate::text_input;
 use crate::toggler;
+use crate::tree_view;
 
 use iced_core::{Background, Color, Vector};
 
@@ -494,6 +498,10 @@ impl menu::StyleSheet for Theme {
                     border_color: palette.background.strong.color,
                     selected_text_color: palette.primary.strong.text,
                     selected_background: palette.primary.strong.color.into(),
+                    separator_color: palette.background.strong.color,
+                    disabled_text_color: palette.background.strong.color,
+                    accelerator_text_color: palette.secondary.strong.color,
+                    highlighted_text_color: palette.primary.base.color,
                 }
             }
             Menu::Custom(custom) => custom.appearance(self),
@@ -952,6 +960,10 @@ impl text::StyleSheet for Theme {
             Text::Color(c) => text::Appearance { color: Some(c) },
         }
     }
+
+    fn selection_color(&self, _style: Self::Style) -> Color {
+        self.extended_palette().primary.weak.color
+    }
 }
 
 /// The style of a text input.
@@ -1042,3 +1054,231 @@ impl text_input::StyleSheet for Theme {
         palette.primary.weak.color
     }
 }
+
+/// The style of a number input.
+#[derive(Default)]
+pub enum NumberInput {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn number_input::StyleSheet<Style = Theme>>),
+}
+
+impl number_input::StyleSheet for Theme {
+    type Style = NumberInput;
+
+    fn active(&self, style: &Self::Style) -> number_input::Appearance {
+        if let NumberInput::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        number_input::Appearance {
+            button_background: None,
+            icon_color: palette.background.base.text,
+            border_radius: 2.0,
+        }
+    }
+
+    fn hovered(&self, style: &Self::Style) -> number_input::Appearance {
+        if let NumberInput::Custom(custom) = style {
+            return custom.hovered(self);
+        }
+
+        let palette = self.extended_palette();
+
+        number_input::Appearance {
+            button_background: Some(palette.background.weak.color.into()),
+            ..self.active(style)
+        }
+    }
+
+    fn pressed(&self, style: &Self::Style) -> number_input::Appearance {
+        if let NumberInput::Custom(custom) = style {
+            return custom.pressed(self);
+        }
+
+        let palette = self.extended_palette();
+
+        number_input::Appearance {
+            button_background: Some(palette.background.strong.color.into()),
+            ..self.active(style)
+        }
+    }
+
+    fn disabled(&self, style: &Self::Style) -> number_input::Appearance {
+        if let NumberInput::Custom(custom) = style {
+            return custom.disabled(self);
+        }
+
+        let palette = self.extended_palette();
+
+        number_input::Appearance {
+            icon_color: palette.background.strong.color,
+            ..self.active(style)
+        }
+    }
+}
+
+/// The style of a table.
+#[derive(Default)]
+pub enum Table {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn table::StyleSheet<Style = Theme>>),
+}
+
+impl table::StyleSheet for Theme {
+    type Style = Table;
+
+    fn appearance(&self, style: &Self::Style) -> table::Appearance {
+        if let Table::Custom(custom) = style {
+            return custom.appearance(self);
+        }
+
+        let palette = self.extended_palette();
+
+        table::Appearance {
+            header_background: palette.background.weak.color.into(),
+            header_text_color: palette.background.weak.text,
+            row_background: None,
+            alternate_row_background: Some(
+                Color {
+                    a: 0.5,
+                    ..palette.background.weak.color
+                }
+                .into(),
+            ),
+            hovered_row_background: Some(palette.background.weak.color.into()),
+            selected_row_background: palette.primary.weak.color.into(),
+            selected_text_color: palette.primary.weak.text,
+            divider_color: palette.background.strong.color,
+            hovered_divider_color: palette.primary.strong.color,
+        }
+    }
+}
+
+/// The style of a tree view.
+#[derive(Default)]
+pub enum TreeView {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn tree_view::StyleSheet<Style = Theme>>),
+}
+
+impl tree_view::StyleSheet for Theme {
+    type Style = TreeView;
+
+    fn active(&self, style: &Self::Style) -> tree_view::Appearance {
+        if let TreeView::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        tree_view::Appearance {
+            arrow_color: palette.background.strong.text,
+            hovered_background: Some(palette.background.weak.color.into()),
+            selected_background: palette.background.strong.color.into(),
+            selected_text_color: palette.background.strong.text,
+            border_radius: 2.0,
+        }
+    }
+
+    fn focused(&self, style: &Self::Style) -> tree_view::Appearance {
+        if let TreeView::Custom(custom) = style {
+            return custom.focused(self);
+        }
+
+        let palette = self.extended_palette();
+
+        tree_view::Appearance {
+            selected_background: palette.primary.weak.color.into(),
+            selected_text_color: palette.primary.weak.text,
+            ..self.active(style)
+        }
+    }
+}
+
+/// The style of tabs.
+#[derive(Default)]
+pub enum Tabs {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn tabs::StyleSheet<Style = Theme>>),
+}
+
+impl tabs::StyleSheet for Theme {
+    type Style = Tabs;
+
+    fn bar(&self, style: &Self::Style) -> tabs::Bar {
+        if let Tabs::Custom(custom) = style {
+            return custom.bar(self);
+        }
+
+        let palette = self.extended_palette();
+
+        tabs::Bar {
+            background: None,
+            border_color: palette.background.strong.color,
+            border_width: 1.0,
+        }
+    }
+
+    fn active(&self, style: &Self::Style, is_active: bool) -> tabs::Appearance {
+        if let Tabs::Custom(custom) = style {
+            return custom.active(self, is_active);
+        }
+
+        let palette = self.extended_palette();
+
+        if is_active {
+            tabs::Appearance {
+                background: Some(palette.background.weak.color.into()),
+                text_color: palette.background.weak.text,
+                indicator_color: Some(palette.primary.strong.color),
+                close_color: palette.background.weak.text,
+                border_radius: 2.0,
+            }
+        } else {
+            tabs::Appearance {
+                background: None,
+                text_color: palette.background.strong.color,
+                indicator_color: None,
+                close_color: palette.background.strong.color,
+                border_radius: 2.0,
+            }
+        }
+    }
+
+    fn hovered(
+        &self,
+        style: &Self::Style,
+        is_active: bool,
+    ) -> tabs::Appearance {
+        if let Tabs::Custom(custom) = style {
+            return custom.hovered(self, is_active);
+        }
+
+        let palette = self.extended_palette();
+
+        if is_active {
+            self.active(style, is_active)
+        } else {
+            tabs::Appearance {
+                background: Some(palette.background.weak.color.into()),
+                text_color: palette.background.base.text,
+                close_color: palette.background.base.text,
+                ..self.active(style, is_active)
+            }
+        }
+    }
+}
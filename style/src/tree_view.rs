@@ -0,0 +1,31 @@
+//! Change the appearance of a tree view.
+use iced_core::{Background, Color};
+
+/// The appearance of a tree view.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The [`Color`] of the disclosure arrows.
+    pub arrow_color: Color,
+    /// The [`Background`] of the node under the cursor.
+    pub hovered_background: Option<Background>,
+    /// The [`Background`] of the selected node.
+    pub selected_background: Background,
+    /// The [`Color`] of the text of the selected node.
+    pub selected_text_color: Color,
+    /// The border radius of the background of a node.
+    pub border_radius: f32,
+}
+
+/// A set of rules that dictate the style of a tree view.
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Produces the active [`Appearance`] of a tree view.
+    fn active(&self, style: &Self::Style) -> Appearance;
+
+    /// Produces the [`Appearance`] of a tree view that has keyboard focus.
+    fn focused(&self, style: &Self::Style) -> Appearance {
+        self.active(style)
+    }
+}
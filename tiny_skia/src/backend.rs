@@ -0,0 +1,642 @@
+#[cfg(any(feature = "image", feature = "svg"))]
+use crate::image;
+use crate::text;
+use crate::{Settings, Viewport};
+
+use iced_graphics::backend;
+use iced_graphics::font;
+use iced_graphics::gradient::Gradient;
+use iced_graphics::layer::{self, Layer, Mesh};
+use iced_graphics::Primitive;
+use iced_native::{Color, Font, Rectangle, Size};
+
+/// A [`tiny-skia`] graphics backend for [`iced`].
+///
+/// [`tiny-skia`]: https://github.com/RazrFalcon/tiny-skia
+/// [`iced`]: https://github.com/iced-rs/iced
+#[derive(Debug)]
+pub struct Backend {
+    #[cfg(any(feature = "image", feature = "svg"))]
+    image_pipeline: image::Pipeline,
+    text_pipeline: text::Pipeline,
+    default_text_size: u16,
+}
+
+impl Backend {
+    /// Creates a new [`Backend`].
+    pub fn new(settings: Settings) -> Self {
+        Self {
+            #[cfg(any(feature = "image", feature = "svg"))]
+            image_pipeline: image::Pipeline::new(),
+            text_pipeline: text::Pipeline::new(
+                settings.default_font,
+                settings.fallback_fonts,
+                settings.fonts,
+            ),
+            default_text_size: settings.default_text_size,
+        }
+    }
+
+    /// Draws the provided primitives in the given pixel buffer.
+    ///
+    /// The pixel buffer is cleared with the provided background [`Color`]
+    /// before drawing, and its dimensions should match the physical size of
+    /// the [`Viewport`].
+    ///
+    /// The text provided as overlay will be rendered on top of the primitives.
+    /// This is useful for rendering debug information.
+    pub fn draw<T: AsRef<str>>(
+        &mut self,
+        pixels: &mut tiny_skia::PixmapMut<'_>,
+        primitives: &[Primitive],
+        viewport: &Viewport,
+        background_color: Color,
+        overlay_text: &[T],
+    ) {
+        let scale_factor = viewport.scale_factor() as f32;
+
+        pixels.fill(from_color(background_color));
+
+        let mut layers = Layer::generate(primitives, viewport);
+        layers.push(Layer::overlay(overlay_text, viewport));
+
+        let mut clip_mask = tiny_skia::ClipMask::new();
+
+        for layer in layers {
+            self.flush(pixels, &mut clip_mask, scale_factor, &layer);
+        }
+
+        #[cfg(any(feature = "image", feature = "svg"))]
+        self.image_pipeline.trim_cache();
+    }
+
+    fn flush(
+        &mut self,
+        pixels: &mut tiny_skia::PixmapMut<'_>,
+        clip_mask: &mut tiny_skia::ClipMask,
+        scale_factor: f32,
+        layer: &Layer<'_>,
+    ) {
+        let target = Rectangle::new(
+            iced_native::Point::ORIGIN,
+            Size::new(pixels.width() as f32, pixels.height() as f32),
+        );
+
+        let bounds = match (layer.bounds * scale_factor).intersection(&target) {
+            Some(bounds) => bounds,
+            None => return,
+        };
+
+        let snapped = bounds.snap();
+
+        if snapped.width < 1 || snapped.height < 1 {
+            return;
+        }
+
+        let clip_mask = if bounds == target {
+            None
+        } else if set_clip_mask(
+            clip_mask,
+            pixels.width(),
+            pixels.height(),
+            snapped.into(),
+        )
+        .is_some()
+        {
+            Some(&*clip_mask)
+        } else {
+            return;
+        };
+
+        for quad in &layer.quads {
+            draw_quad(pixels, clip_mask, scale_factor, quad);
+        }
+
+        if !layer.meshes.is_empty() {
+            let mut mesh_mask = tiny_skia::ClipMask::new();
+
+            for mesh in &layer.meshes {
+                let clip_bounds = mesh.clip_bounds() * scale_factor;
+
+                if set_clip_mask(
+                    &mut mesh_mask,
+                    pixels.width(),
+                    pixels.height(),
+                    clip_bounds,
+                )
+                .is_some()
+                {
+                    draw_mesh(pixels, &mesh_mask, scale_factor, mesh);
+                }
+            }
+        }
+
+        #[cfg(any(feature = "image", feature = "svg"))]
+        if !layer.images.is_empty() {
+            self.image_pipeline.draw(
+                pixels,
+                clip_mask,
+                scale_factor,
+                &layer.images,
+            );
+        }
+
+        for text in &layer.text {
+            self.text_pipeline.draw(pixels, snapped, scale_factor, text);
+        }
+
+        for text in &layer.rich_text {
+            self.text_pipeline
+                .draw_rich(pixels, snapped, scale_factor, text);
+        }
+    }
+}
+
+fn draw_quad(
+    pixels: &mut tiny_skia::PixmapMut<'_>,
+    clip_mask: Option<&tiny_skia::ClipMask>,
+    scale_factor: f32,
+    quad: &layer::Quad,
+) {
+    let [x, y] = quad.position;
+    let [width, height] = quad.size;
+
+    let bounds = Rectangle {
+        x: x * scale_factor,
+        y: y * scale_factor,
+        width: width * scale_factor,
+        height: height * scale_factor,
+    };
+
+    // Border radii can never exceed half of the smallest side of the quad
+    let max_radius = bounds.width.min(bounds.height) / 2.0;
+    let border_radius = quad
+        .border_radius
+        .map(|radius| (radius * scale_factor).min(max_radius).max(0.0));
+
+    let mut builder = tiny_skia::PathBuilder::new();
+    push_rounded_rectangle(&mut builder, bounds, border_radius);
+
+    let path = if let Some(path) = builder.clone().finish() {
+        path
+    } else {
+        return;
+    };
+
+    let _ = pixels.fill_path(
+        &path,
+        &paint(into_color(quad.color)),
+        tiny_skia::FillRule::EvenOdd,
+        tiny_skia::Transform::identity(),
+        clip_mask,
+    );
+
+    let border_width =
+        (quad.border_width * scale_factor).min(max_radius.max(0.0));
+
+    if border_width > 0.0 {
+        let inner_bounds = Rectangle {
+            x: bounds.x + border_width,
+            y: bounds.y + border_width,
+            width: bounds.width - border_width * 2.0,
+            height: bounds.height - border_width * 2.0,
+        };
+
+        let inner_radius =
+            border_radius.map(|radius| (radius - border_width).max(0.0));
+
+        // The border is the region between the outer and the inner
+        // rectangles, which we fill using the even-odd rule
+        push_rounded_rectangle(&mut builder, inner_bounds, inner_radius);
+
+        if let Some(border) = builder.finish() {
+            let _ = pixels.fill_path(
+                &border,
+                &paint(into_color(quad.border_color)),
+                tiny_skia::FillRule::EvenOdd,
+                tiny_skia::Transform::identity(),
+                clip_mask,
+            );
+        }
+    }
+}
+
+fn draw_mesh(
+    pixels: &mut tiny_skia::PixmapMut<'_>,
+    clip_mask: &tiny_skia::ClipMask,
+    scale_factor: f32,
+    mesh: &Mesh<'_>,
+) {
+    let origin = mesh.origin();
+
+    let transform = tiny_skia::Transform::from_row(
+        scale_factor,
+        0.0,
+        0.0,
+        scale_factor,
+        origin.x * scale_factor,
+        origin.y * scale_factor,
+    );
+
+    match mesh {
+        Mesh::Solid { buffers, .. } => {
+            // Triangles do not have colored vertices in `tiny-skia`. We group
+            // contiguous triangles of the same color in a single path, which
+            // also avoids seams between adjacent triangles.
+            let mut triangles = buffers.indices.chunks_exact(3).peekable();
+
+            while let Some(triangle) = triangles.next() {
+                let color = buffers.vertices[triangle[0] as usize].color;
+                let mut builder = tiny_skia::PathBuilder::new();
+
+                push_triangle(&mut builder, triangle, |index| {
+                    buffers.vertices[index].position
+                });
+
+                while let Some(triangle) = triangles.next_if(|triangle| {
+                    buffers.vertices[triangle[0] as usize].color == color
+                }) {
+                    push_triangle(&mut builder, triangle, |index| {
+                        buffers.vertices[index].position
+                    });
+                }
+
+                if let Some(path) = builder.finish() {
+                    let _ = pixels.fill_path(
+                        &path,
+                        &paint(into_color(color)),
+                        tiny_skia::FillRule::Winding,
+                        transform,
+                        Some(clip_mask),
+                    );
+                }
+            }
+        }
+        Mesh::Gradient {
+            buffers, gradient, ..
+        } => {
+            let mut builder = tiny_skia::PathBuilder::new();
+
+            for triangle in buffers.indices.chunks_exact(3) {
+                push_triangle(&mut builder, triangle, |index| {
+                    buffers.vertices[index].position
+                });
+            }
+
+            let shader = match gradient {
+                Gradient::Linear(linear) => tiny_skia::LinearGradient::new(
+                    tiny_skia::Point::from_xy(linear.start.x, linear.start.y),
+                    tiny_skia::Point::from_xy(linear.end.x, linear.end.y),
+                    linear
+                        .color_stops
+                        .iter()
+                        .map(|stop| {
+                            tiny_skia::GradientStop::new(
+                                stop.offset,
+                                from_color(stop.color),
+                            )
+                        })
+                        .collect(),
+                    tiny_skia::SpreadMode::Pad,
+                    tiny_skia::Transform::identity(),
+                ),
+            };
+
+            if let (Some(path), Some(shader)) = (builder.finish(), shader) {
+                let _ = pixels.fill_path(
+                    &path,
+                    &tiny_skia::Paint {
+                        shader,
+                        anti_alias: true,
+                        ..tiny_skia::Paint::default()
+                    },
+                    tiny_skia::FillRule::Winding,
+                    transform,
+                    Some(clip_mask),
+                );
+            }
+        }
+    }
+}
+
+fn push_triangle(
+    builder: &mut tiny_skia::PathBuilder,
+    triangle: &[u32],
+    position: impl Fn(usize) -> [f32; 2],
+) {
+    let [a, b, c] = [0, 1, 2].map(|i| position(triangle[i] as usize));
+
+    builder.move_to(a[0], a[1]);
+    builder.line_to(b[0], b[1]);
+    builder.line_to(c[0], c[1]);
+    builder.close();
+}
+
+fn push_rounded_rectangle(
+    builder: &mut tiny_skia::PathBuilder,
+    bounds: Rectangle,
+    [top_left, top_right, bottom_right, bottom_left]: [f32; 4],
+) {
+    // The distance of the control points of a cubic Bézier curve that
+    // approximates a quarter of a circle
+    const KAPPA: f32 = 0.552_284_8;
+
+    let Rectangle {
+        x,
+        y,
+        width,
+        height,
+    } = bounds;
+
+    if width <= 0.0 || height <= 0.0 {
+        return;
+    }
+
+    let right = x + width;
+    let bottom = y + height;
+
+    builder.move_to(x + top_left, y);
+    builder.line_to(right - top_right, y);
+    builder.cubic_to(
+        right - top_right * (1.0 - KAPPA),
+        y,
+        right,
+        y + top_right * (1.0 - KAPPA),
+        right,
+        y + top_right,
+    );
+    builder.line_to(right, bottom - bottom_right);
+    builder.cubic_to(
+        right,
+        bottom - bottom_right * (1.0 - KAPPA),
+        right - bottom_right * (1.0 - KAPPA),
+        bottom,
+        right - bottom_right,
+        bottom,
+    );
+    builder.line_to(x + bottom_left, bottom);
+    builder.cubic_to(
+        x + bottom_left * (1.0 - KAPPA),
+        bottom,
+        x,
+        bottom - bottom_left * (1.0 - KAPPA),
+        x,
+        bottom - bottom_left,
+    );
+    builder.line_to(x, y + top_left);
+    builder.cubic_to(
+        x,
+        y + top_left * (1.0 - KAPPA),
+        x + top_left * (1.0 - KAPPA),
+        y,
+        x + top_left,
+        y,
+    );
+    builder.close();
+}
+
+fn set_clip_mask(
+    clip_mask: &mut tiny_skia::ClipMask,
+    width: u32,
+    height: u32,
+    bounds: Rectangle,
+) -> Option<()> {
+    let path = tiny_skia::PathBuilder::from_rect(tiny_skia::Rect::from_xywh(
+        bounds.x,
+        bounds.y,
+        bounds.width,
+        bounds.height,
+    )?);
+
+    clip_mask.set_path(
+        width,
+        height,
+        &path,
+        tiny_skia::FillRule::Winding,
+        false,
+    )
+}
+
+fn paint(color: tiny_skia::Color) -> tiny_skia::Paint<'static> {
+    tiny_skia::Paint {
+        shader: tiny_skia::Shader::SolidColor(color),
+        anti_alias: true,
+        ..tiny_skia::Paint::default()
+    }
+}
+
+/// Converts a [`Color`] into a [`tiny_skia::Color`].
+///
+/// Out of range components are clamped, and invalid colors become
+/// transparent.
+fn from_color(color: Color) -> tiny_skia::Color {
+    tiny_skia::Color::from_rgba(
+        color.r.clamp(0.0, 1.0),
+        color.g.clamp(0.0, 1.0),
+        color.b.clamp(0.0, 1.0),
+        color.a.clamp(0.0, 1.0),
+    )
+    .unwrap_or(tiny_skia::Color::TRANSPARENT)
+}
+
+/// Converts a color in __linear RGB__ into a [`tiny_skia::Color`].
+fn into_color(linear: [f32; 4]) -> tiny_skia::Color {
+    let [r, g, b, a] = into_srgb(linear);
+
+    tiny_skia::Color::from_rgba(r, g, b, a)
+        .unwrap_or(tiny_skia::Color::TRANSPARENT)
+}
+
+/// Converts a color in __linear RGB__ into __sRGB__.
+///
+/// The primitives of a [`Layer`] use linear colors, since GPUs blend in linear
+/// space. `tiny-skia` expects colors in sRGB.
+pub(crate) fn into_srgb([r, g, b, a]: [f32; 4]) -> [f32; 4] {
+    fn gamma_component(u: f32) -> f32 {
+        if u <= 0.003_130_8 {
+            12.92 * u
+        } else {
+            1.055 * u.powf(1.0 / 2.4) - 0.055
+        }
+        .clamp(0.0, 1.0)
+    }
+
+    [
+        gamma_component(r),
+        gamma_component(g),
+        gamma_component(b),
+        a.clamp(0.0, 1.0),
+    ]
+}
+
+impl iced_graphics::Backend for Backend {
+    fn trim_measurements(&mut self) {
+        self.text_pipeline.trim_measurement_cache()
+    }
+}
+
+impl backend::Text for Backend {
+    const ICON_FONT: Font = font::ICONS;
+    const CHECKMARK_ICON: char = font::CHECKMARK_ICON;
+    const ARROW_DOWN_ICON: char = font::ARROW_DOWN_ICON;
+
+    fn default_size(&self) -> u16 {
+        self.default_text_size
+    }
+
+    fn measure(
+        &self,
+        contents: &str,
+        size: f32,
+        font: Font,
+        bounds: Size,
+        wrapping: text::Wrapping,
+    ) -> (f32, f32) {
+        self.text_pipeline
+            .measure(contents, size, font, bounds, wrapping)
+    }
+
+    fn hit_test(
+        &self,
+        contents: &str,
+        size: f32,
+        font: Font,
+        bounds: Size,
+        point: iced_native::Point,
+        nearest_only: bool,
+    ) -> Option<text::Hit> {
+        self.text_pipeline.hit_test(
+            contents,
+            size,
+            font,
+            bounds,
+            point,
+            nearest_only,
+        )
+    }
+
+    fn measure_spans(
+        &self,
+        spans: &[text::Span<'_, Font>],
+        bounds: Size,
+    ) -> (f32, f32) {
+        self.text_pipeline.measure_spans(spans, bounds)
+    }
+
+    fn hit_span(
+        &self,
+        spans: &[text::Span<'_, Font>],
+        bounds: Size,
+        point: iced_native::Point,
+    ) -> Option<usize> {
+        self.text_pipeline.hit_span(spans, bounds, point)
+    }
+
+    fn load_font(
+        &mut self,
+        bytes: Vec<u8>,
+    ) -> Result<(), iced_native::font::Error> {
+        self.text_pipeline.load_font(bytes)
+    }
+}
+
+#[cfg(feature = "image")]
+impl backend::Image for Backend {
+    fn dimensions(&self, handle: &iced_native::image::Handle) -> Size<u32> {
+        self.image_pipeline.dimensions(handle)
+    }
+}
+
+#[cfg(feature = "svg")]
+impl backend::Svg for Backend {
+    fn viewport_dimensions(
+        &self,
+        handle: &iced_native::svg::Handle,
+    ) -> Size<u32> {
+        self.image_pipeline.viewport_dimensions(handle)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use iced_graphics::triangle::{Mesh2D, Vertex2D};
+    use iced_native::{Background, Point};
+
+    fn render(primitives: &[Primitive]) -> tiny_skia::Pixmap {
+        let mut backend = Backend::new(Settings::default());
+        let mut pixmap = tiny_skia::Pixmap::new(20, 20).unwrap();
+
+        backend.draw::<&str>(
+            &mut pixmap.as_mut(),
+            primitives,
+            &Viewport::with_physical_size(Size::new(20, 20), 1.0),
+            Color::WHITE,
+            &[],
+        );
+
+        pixmap
+    }
+
+    fn pixel(pixmap: &tiny_skia::Pixmap, x: u32, y: u32) -> [u8; 4] {
+        let pixel = pixmap.pixel(x, y).unwrap().demultiply();
+
+        [pixel.red(), pixel.green(), pixel.blue(), pixel.alpha()]
+    }
+
+    #[test]
+    fn draws_quads() {
+        let pixmap = render(&[Primitive::Quad {
+            bounds: Rectangle::new(Point::new(5.0, 5.0), Size::new(10.0, 10.0)),
+            background: Background::Color(Color::BLACK),
+            border_radius: [0.0; 4],
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+        }]);
+
+        assert_eq!(pixel(&pixmap, 10, 10), [0, 0, 0, 255]);
+        assert_eq!(pixel(&pixmap, 2, 2), [255, 255, 255, 255]);
+    }
+
+    #[test]
+    fn clamps_out_of_range_gradient_colors() {
+        let color = Color {
+            r: 2.0,
+            g: -1.0,
+            b: 0.0,
+            a: 1.0,
+        };
+
+        let gradient =
+            Gradient::linear((Point::new(0.0, 0.0), Point::new(20.0, 0.0)))
+                .add_stop(0.0, color)
+                .add_stop(1.0, color)
+                .build()
+                .unwrap();
+
+        let pixmap = render(&[Primitive::GradientMesh {
+            buffers: Mesh2D {
+                vertices: vec![
+                    Vertex2D {
+                        position: [0.0, 0.0],
+                    },
+                    Vertex2D {
+                        position: [20.0, 0.0],
+                    },
+                    Vertex2D {
+                        position: [20.0, 20.0],
+                    },
+                    Vertex2D {
+                        position: [0.0, 20.0],
+                    },
+                ],
+                indices: vec![0, 1, 2, 0, 2, 3],
+            },
+            size: Size::new(20.0, 20.0),
+            gradient,
+        }]);
+
+        assert_eq!(pixel(&pixmap, 10, 10), [255, 0, 0, 255]);
+    }
+}
@@ -0,0 +1,45 @@
+use iced_graphics::image;
+use iced_graphics::Size;
+
+#[derive(Debug, Default)]
+pub struct Storage;
+
+impl image::Storage for Storage {
+    type Entry = Entry;
+    type State<'a> = ();
+
+    fn upload(
+        &mut self,
+        width: u32,
+        height: u32,
+        data: &[u8],
+        _state: &mut (),
+    ) -> Option<Self::Entry> {
+        let mut pixmap = tiny_skia::Pixmap::new(width, height)?;
+
+        // `tiny-skia` works with premultiplied alpha
+        for (pixel, rgba) in
+            pixmap.pixels_mut().iter_mut().zip(data.chunks_exact(4))
+        {
+            *pixel = tiny_skia::ColorU8::from_rgba(
+                rgba[0], rgba[1], rgba[2], rgba[3],
+            )
+            .premultiply();
+        }
+
+        Some(Entry { pixmap })
+    }
+
+    fn remove(&mut self, _entry: &Entry, _state: &mut ()) {}
+}
+
+#[derive(Debug)]
+pub struct Entry {
+    pub(super) pixmap: tiny_skia::Pixmap,
+}
+
+impl image::storage::Entry for Entry {
+    fn size(&self) -> Size<u32> {
+        Size::new(self.pixmap.width(), self.pixmap.height())
+    }
+}
@@ -0,0 +1,63 @@
+//! Configure a renderer.
+
+/// The settings of a [`Backend`].
+///
+/// [`Backend`]: crate::Backend
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Settings {
+    /// The bytes of the font that will be used by default.
+    ///
+    /// If `None` is provided, a default system font will be chosen.
+    pub default_font: Option<&'static [u8]>,
+
+    /// The bytes of the fonts that will be used, in order, for the characters
+    /// missing in the default font.
+    ///
+    /// By default, it is empty.
+    pub fallback_fonts: &'static [&'static [u8]],
+
+    /// The bytes of the fonts that will be loaded on boot, which can be
+    /// selected by the family and properties of a [`Font`].
+    ///
+    /// By default, it is empty.
+    ///
+    /// [`Font`]: iced_native::Font
+    pub fonts: &'static [&'static [u8]],
+
+    /// The default size of text.
+    ///
+    /// By default, it will be set to 20.
+    pub default_text_size: u16,
+}
+
+impl Default for Settings {
+    fn default() -> Settings {
+        Settings {
+            default_font: None,
+            fallback_fonts: &[],
+            fonts: &[],
+            default_text_size: 20,
+        }
+    }
+}
+
+impl std::fmt::Debug for Settings {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Settings")
+            // Instead of printing the font bytes, we simply show a `bool` indicating if using a default font or not.
+            .field("default_font", &self.default_font.is_none())
+            .field("fallback_fonts", &self.fallback_fonts.len())
+            .field("fonts", &self.fonts.len())
+            .field("default_text_size", &self.default_text_size)
+            .finish()
+    }
+}
+
+impl Settings {
+    /// Creates new [`Settings`] using environment configuration.
+    ///
+    /// Currently, this is equivalent to calling [`Settings::default`].
+    pub fn from_env() -> Self {
+        Self::default()
+    }
+}
@@ -0,0 +1,439 @@
+use iced_graphics::font;
+use iced_graphics::layer;
+use iced_graphics::text::{Database, Layout};
+use iced_native::alignment;
+use iced_native::Rectangle;
+
+use glyph_brush::ab_glyph;
+use std::cell::RefCell;
+
+pub use iced_native::text::{Hit, Span, Wrapping};
+
+#[derive(Debug)]
+pub struct Pipeline {
+    brush: RefCell<glyph_brush::GlyphBrush<()>>,
+    database: RefCell<Database>,
+    fallbacks: usize,
+}
+
+impl Pipeline {
+    pub fn new(
+        default_font: Option<&[u8]>,
+        fallback_fonts: &[&'static [u8]],
+        fonts: &[&'static [u8]],
+    ) -> Self {
+        let default_font = default_font.map(|slice| slice.to_vec());
+
+        // TODO: Font customization
+        #[cfg(feature = "default_system_font")]
+        let default_font = {
+            default_font.or_else(|| {
+                font::Source::new()
+                    .load(&[font::Family::SansSerif, font::Family::Serif])
+                    .ok()
+            })
+        };
+
+        let default_font =
+            default_font.unwrap_or_else(|| font::FALLBACK.to_vec());
+
+        let font = ab_glyph::FontArc::try_from_vec(default_font)
+            .unwrap_or_else(|_| {
+                log::warn!(
+                    "System font failed to load. Falling back to \
+                    embedded font..."
+                );
+
+                ab_glyph::FontArc::try_from_slice(font::FALLBACK)
+                    .expect("Load fallback font")
+            });
+
+        let mut loaded: Vec<_> = std::iter::once(font)
+            .chain(iced_graphics::text::load_fallbacks(fallback_fonts))
+            .collect();
+
+        let fallbacks = loaded.len() - 1;
+
+        loaded.extend(fonts.iter().filter_map(|bytes| {
+            ab_glyph::FontArc::try_from_slice(bytes)
+                .map_err(|_| log::warn!("Font failed to load"))
+                .ok()
+        }));
+
+        let mut database = Database::new(&loaded);
+
+        loaded.push(
+            ab_glyph::FontArc::try_from_slice(font::ICONS_BYTES)
+                .expect("Load icons font"),
+        );
+
+        database.alias(font::ICONS, glyph_brush::FontId(loaded.len() - 1));
+
+        let brush = glyph_brush::GlyphBrushBuilder::using_fonts(loaded).build();
+
+        Pipeline {
+            brush: RefCell::new(brush),
+            database: RefCell::new(database),
+            fallbacks,
+        }
+    }
+
+    pub fn draw(
+        &mut self,
+        pixels: &mut tiny_skia::PixmapMut<'_>,
+        clip_bounds: Rectangle<u32>,
+        scale_factor: f32,
+        text: &layer::Text<'_>,
+    ) {
+        let font_id = self.find_font(text.font);
+
+        // Target physical coordinates directly to avoid blurry text
+        let section = glyph_brush::Section {
+            // TODO: We `round` here to avoid rerasterizing text when
+            // its position changes slightly. This can make text feel a
+            // bit "jumpy". We may be able to do better once we improve
+            // our text rendering/caching pipeline.
+            screen_position: (
+                (text.bounds.x * scale_factor).round(),
+                (text.bounds.y * scale_factor).round(),
+            ),
+            bounds: (
+                (text.bounds.width * scale_factor).ceil(),
+                (text.bounds.height * scale_factor).ceil(),
+            ),
+            text: vec![glyph_brush::Text {
+                text: text.content,
+                scale: (text.size * scale_factor).into(),
+                font_id,
+                extra: glyph_brush::Extra {
+                    color: text.color,
+                    z: 0.0,
+                },
+            }],
+            ..Default::default()
+        };
+
+        let layout = Layout::new(
+            text.horizontal_alignment,
+            text.vertical_alignment,
+            self.fallbacks,
+        )
+        .wrapping(text.wrapping);
+
+        self.draw_section(pixels, clip_bounds, &section, layout);
+    }
+
+    pub fn draw_rich(
+        &mut self,
+        pixels: &mut tiny_skia::PixmapMut<'_>,
+        clip_bounds: Rectangle<u32>,
+        scale_factor: f32,
+        text: &layer::RichText<'_>,
+    ) {
+        let section = glyph_brush::Section {
+            screen_position: (
+                (text.bounds.x * scale_factor).round(),
+                (text.bounds.y * scale_factor).round(),
+            ),
+            bounds: (
+                (text.bounds.width * scale_factor).ceil(),
+                (text.bounds.height * scale_factor).ceil(),
+            ),
+            text: text
+                .spans
+                .iter()
+                .map(|span| glyph_brush::Text {
+                    text: &span.content,
+                    scale: (span.size * scale_factor).into(),
+                    font_id: self.find_font(span.font),
+                    extra: glyph_brush::Extra {
+                        color: span.color.into_linear(),
+                        z: 0.0,
+                    },
+                })
+                .collect(),
+            ..Default::default()
+        };
+
+        let layout = Layout::new(
+            text.horizontal_alignment,
+            text.vertical_alignment,
+            self.fallbacks,
+        );
+
+        self.draw_section(pixels, clip_bounds, &section, layout);
+    }
+
+    fn draw_section(
+        &mut self,
+        pixels: &mut tiny_skia::PixmapMut<'_>,
+        clip_bounds: Rectangle<u32>,
+        section: &glyph_brush::Section<'_>,
+        layout: Layout,
+    ) {
+        use glyph_brush::GlyphCruncher;
+
+        let mut brush = self.brush.borrow_mut();
+        let fonts = brush.fonts().to_vec();
+
+        for section_glyph in brush.glyphs_custom_layout(section, &layout) {
+            use ab_glyph::Font;
+
+            let font = &fonts[section_glyph.font_id.0];
+
+            let outline = if let Some(outline) =
+                font.outline_glyph(section_glyph.glyph.clone())
+            {
+                outline
+            } else {
+                continue;
+            };
+
+            let glyph_bounds = outline.px_bounds();
+
+            let [r, g, b, a] = crate::backend::into_srgb(
+                section.text[section_glyph.section_index].extra.color,
+            );
+
+            outline.draw(|x, y, coverage| {
+                let x = glyph_bounds.min.x as i32 + x as i32;
+                let y = glyph_bounds.min.y as i32 + y as i32;
+
+                if x < clip_bounds.x as i32
+                    || y < clip_bounds.y as i32
+                    || x >= (clip_bounds.x + clip_bounds.width) as i32
+                    || y >= (clip_bounds.y + clip_bounds.height) as i32
+                {
+                    return;
+                }
+
+                blend(pixels, x as u32, y as u32, [r, g, b, a * coverage]);
+            });
+        }
+    }
+
+    pub fn measure(
+        &self,
+        content: &str,
+        size: f32,
+        font: iced_native::Font,
+        bounds: iced_native::Size,
+        wrapping: Wrapping,
+    ) -> (f32, f32) {
+        use glyph_brush::GlyphCruncher;
+
+        let section = glyph_brush::Section {
+            bounds: (bounds.width, bounds.height),
+            text: vec![glyph_brush::Text {
+                text: content,
+                scale: size.into(),
+                font_id: self.find_font(font),
+                extra: glyph_brush::Extra::default(),
+            }],
+            ..Default::default()
+        };
+
+        let layout = Layout::new(
+            alignment::Horizontal::Left,
+            alignment::Vertical::Top,
+            self.fallbacks,
+        )
+        .wrapping(wrapping);
+
+        if let Some(bounds) = self
+            .brush
+            .borrow_mut()
+            .glyph_bounds_custom_layout(section, &layout)
+        {
+            (bounds.width().ceil(), bounds.height().ceil())
+        } else {
+            (0.0, 0.0)
+        }
+    }
+
+    pub fn hit_test(
+        &self,
+        content: &str,
+        size: f32,
+        font: iced_native::Font,
+        bounds: iced_native::Size,
+        point: iced_native::Point,
+        nearest_only: bool,
+    ) -> Option<Hit> {
+        use glyph_brush::GlyphCruncher;
+
+        let glyph_brush::FontId(font_id) = self.find_font(font);
+
+        let section = glyph_brush::Section {
+            bounds: (bounds.width, bounds.height),
+            text: vec![glyph_brush::Text {
+                text: content,
+                scale: size.into(),
+                font_id: glyph_brush::FontId(font_id),
+                extra: glyph_brush::Extra::default(),
+            }],
+            ..Default::default()
+        };
+
+        let layout = Layout::new(
+            alignment::Horizontal::Left,
+            alignment::Vertical::Top,
+            self.fallbacks,
+        );
+
+        let mut brush = self.brush.borrow_mut();
+
+        // The underlying type is FontArc, so clones are cheap.
+        let fonts = brush.fonts().to_vec();
+
+        iced_graphics::text::hit_test(
+            &fonts,
+            brush.glyphs_custom_layout(section, &layout),
+            content,
+            point,
+            nearest_only,
+        )
+    }
+
+    pub fn measure_spans(
+        &self,
+        spans: &[Span<'_, iced_native::Font>],
+        bounds: iced_native::Size,
+    ) -> (f32, f32) {
+        use glyph_brush::GlyphCruncher;
+
+        let section = self.span_section(spans, bounds);
+        let layout = Layout::new(
+            alignment::Horizontal::Left,
+            alignment::Vertical::Top,
+            self.fallbacks,
+        );
+
+        if let Some(bounds) = self
+            .brush
+            .borrow_mut()
+            .glyph_bounds_custom_layout(section, &layout)
+        {
+            (bounds.width().ceil(), bounds.height().ceil())
+        } else {
+            (0.0, 0.0)
+        }
+    }
+
+    pub fn hit_span(
+        &self,
+        spans: &[Span<'_, iced_native::Font>],
+        bounds: iced_native::Size,
+        point: iced_native::Point,
+    ) -> Option<usize> {
+        use glyph_brush::GlyphCruncher;
+
+        let section = self.span_section(spans, bounds);
+        let layout = Layout::new(
+            alignment::Horizontal::Left,
+            alignment::Vertical::Top,
+            self.fallbacks,
+        );
+
+        let mut brush = self.brush.borrow_mut();
+
+        // The underlying type is FontArc, so clones are cheap.
+        let fonts = brush.fonts().to_vec();
+
+        iced_graphics::text::hit_section(
+            &fonts,
+            brush.glyphs_custom_layout(section, &layout),
+            point,
+        )
+    }
+
+    fn span_section<'a>(
+        &self,
+        spans: &[Span<'a, iced_native::Font>],
+        bounds: iced_native::Size,
+    ) -> glyph_brush::Section<'a> {
+        glyph_brush::Section {
+            bounds: (bounds.width, bounds.height),
+            text: spans
+                .iter()
+                .map(|span| glyph_brush::Text {
+                    text: span.content,
+                    scale: span.size.into(),
+                    font_id: self.find_font(span.font),
+                    extra: glyph_brush::Extra::default(),
+                })
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    pub fn trim_measurement_cache(&mut self) {
+        // TODO: We should probably use a `GlyphCalculator` for this. However,
+        // it uses a lifetimed `GlyphCalculatorGuard` with side-effects on drop.
+        // This makes stuff quite inconvenient. A manual method for trimming the
+        // cache would make our lives easier.
+        loop {
+            let action =
+                self.brush.borrow_mut().process_queued(|_, _| {}, |_| {});
+
+            match action {
+                Ok(_) => break,
+                Err(glyph_brush::BrushError::TextureTooSmall { suggested }) => {
+                    let (width, height) = suggested;
+
+                    self.brush.borrow_mut().resize_texture(width, height);
+                }
+            }
+        }
+    }
+
+    pub fn load_font(
+        &mut self,
+        bytes: Vec<u8>,
+    ) -> Result<(), iced_native::font::Error> {
+        let font = ab_glyph::FontArc::try_from_vec(bytes)
+            .map_err(|_| iced_native::font::Error::Invalid)?;
+
+        let id = self.brush.get_mut().add_font(font.clone());
+
+        self.database.get_mut().insert(id, &font);
+
+        // Text measured before may now resolve to the new font
+        let brush = self.brush.get_mut();
+        *brush = brush.to_builder().build();
+
+        Ok(())
+    }
+
+    pub fn find_font(&self, font: iced_native::Font) -> glyph_brush::FontId {
+        self.database
+            .borrow_mut()
+            .resolve(font, |font| self.brush.borrow_mut().add_font(font))
+    }
+}
+
+/// Blends a straight alpha color on top of the pixel at the given position,
+/// using the _source over_ operation.
+fn blend(
+    pixels: &mut tiny_skia::PixmapMut<'_>,
+    x: u32,
+    y: u32,
+    [r, g, b, a]: [f32; 4],
+) {
+    let index = (y * pixels.width() + x) as usize;
+    let pixel = &mut pixels.pixels_mut()[index];
+
+    let blend = |source: f32, destination: u8| {
+        let destination = f32::from(destination) / 255.0;
+
+        ((source * a + destination * (1.0 - a)) * 255.0).round() as u8
+    };
+
+    *pixel = tiny_skia::PremultipliedColorU8::from_rgba(
+        blend(r, pixel.red()),
+        blend(g, pixel.green()),
+        blend(b, pixel.blue()),
+        blend(1.0, pixel.alpha()),
+    )
+    .unwrap_or(*pixel);
+}
@@ -0,0 +1,4 @@
+//! Render to in-memory surfaces.
+mod compositor;
+
+pub use compositor::{Compositor, Surface};
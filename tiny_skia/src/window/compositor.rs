@@ -0,0 +1,136 @@
+use crate::{Backend, Color, Error, Renderer, Settings, Viewport};
+
+use iced_graphics::{compositor, Size};
+use raw_window_handle::{HasRawDisplayHandle, HasRawWindowHandle};
+
+use std::marker::PhantomData;
+
+/// A compositor that rasterizes with [`tiny-skia`] on the CPU.
+///
+/// Presenting to native windows is not supported yet. Instead, every
+/// [`Surface`] keeps the pixels of its last frame in memory. This makes the
+/// [`Compositor`] mostly useful for offscreen rendering.
+///
+/// [`tiny-skia`]: https://github.com/RazrFalcon/tiny-skia
+#[allow(missing_debug_implementations)]
+pub struct Compositor<Theme> {
+    theme: PhantomData<Theme>,
+}
+
+/// An in-memory surface of a [`Compositor`].
+#[derive(Debug)]
+pub struct Surface {
+    pixels: Option<tiny_skia::Pixmap>,
+}
+
+impl Surface {
+    /// Returns the pixels of the last frame presented to the [`Surface`], if
+    /// any.
+    pub fn pixels(&self) -> Option<&tiny_skia::Pixmap> {
+        self.pixels.as_ref()
+    }
+}
+
+impl<Theme> iced_graphics::window::Compositor for Compositor<Theme> {
+    type Settings = Settings;
+    type Renderer = Renderer<Theme>;
+    type Surface = Surface;
+
+    fn new<W: HasRawWindowHandle + HasRawDisplayHandle>(
+        settings: Self::Settings,
+        _compatible_window: Option<&W>,
+    ) -> Result<(Self, Self::Renderer), Error> {
+        log::info!("{:#?}", settings);
+
+        Ok((
+            Self { theme: PhantomData },
+            Renderer::new(Backend::new(settings)),
+        ))
+    }
+
+    fn create_surface<W: HasRawWindowHandle + HasRawDisplayHandle>(
+        &mut self,
+        _window: &W,
+    ) -> Surface {
+        Surface { pixels: None }
+    }
+
+    fn configure_surface(
+        &mut self,
+        surface: &mut Surface,
+        width: u32,
+        height: u32,
+    ) {
+        surface.pixels = tiny_skia::Pixmap::new(width, height);
+    }
+
+    fn fetch_information(&self) -> compositor::Information {
+        compositor::Information {
+            adapter: String::from("CPU"),
+            backend: String::from("tiny-skia"),
+        }
+    }
+
+    fn present<T: AsRef<str>>(
+        &mut self,
+        renderer: &mut Self::Renderer,
+        surface: &mut Surface,
+        viewport: &Viewport,
+        background_color: Color,
+        overlay: &[T],
+    ) -> Result<(), compositor::SurfaceError> {
+        let pixels = surface
+            .pixels
+            .as_mut()
+            .ok_or(compositor::SurfaceError::Outdated)?;
+
+        renderer.with_primitives(|backend, primitives| {
+            backend.draw(
+                &mut pixels.as_mut(),
+                primitives,
+                viewport,
+                background_color,
+                overlay,
+            );
+        });
+
+        Ok(())
+    }
+
+    fn screenshot<T: AsRef<str>>(
+        &mut self,
+        renderer: &mut Self::Renderer,
+        viewport: &Viewport,
+        background_color: Color,
+        overlay: &[T],
+    ) -> Result<Vec<u8>, compositor::SurfaceError> {
+        let Size { width, height } = viewport.physical_size();
+
+        let mut pixels =
+            if let Some(pixels) = tiny_skia::Pixmap::new(width, height) {
+                pixels
+            } else {
+                return Ok(Vec::new());
+            };
+
+        renderer.with_primitives(|backend, primitives| {
+            backend.draw(
+                &mut pixels.as_mut(),
+                primitives,
+                viewport,
+                background_color,
+                overlay,
+            );
+        });
+
+        Ok(pixels
+            .pixels()
+            .iter()
+            .flat_map(|pixel| {
+                let color = pixel.demultiply();
+
+                [color.red(), color.green(), color.blue(), color.alpha()]
+            })
+            .collect())
+    }
+}
@@ -7,7 +7,6 @@ use iced_graphics::backend;
 use iced_graphics::font;
 use iced_graphics::layer::Layer;
 use iced_graphics::{Primitive, Viewport};
-use iced_native::alignment;
 use iced_native::{Font, Size};
 
 #[cfg(any(feature = "image", feature = "svg"))]
@@ -40,6 +39,8 @@ impl Backend {
             device,
             format,
             settings.default_font,
+            settings.fallback_fonts,
+            settings.fonts,
             settings.text_multithreading,
         );
 
@@ -167,10 +168,10 @@ impl Backend {
             }
         }
 
-        if !layer.text.is_empty() {
+        if !layer.text.is_empty() || !layer.rich_text.is_empty() {
             for text in layer.text.iter() {
                 // Target physical coordinates directly to avoid blurry text
-                let text = wgpu_glyph::Section {
+                let section = wgpu_glyph::Section {
                     // TODO: We `round` here to avoid rerasterizing text when
                     // its position changes slightly. This can make text feel a
                     // bit "jumpy". We may be able to do better once we improve
@@ -204,32 +205,51 @@ impl Backend {
                             z: 0.0,
                         },
                     }],
-                    layout: wgpu_glyph::Layout::default()
-                        .h_align(match text.horizontal_alignment {
-                            alignment::Horizontal::Left => {
-                                wgpu_glyph::HorizontalAlign::Left
-                            }
-                            alignment::Horizontal::Center => {
-                                wgpu_glyph::HorizontalAlign::Center
-                            }
-                            alignment::Horizontal::Right => {
-                                wgpu_glyph::HorizontalAlign::Right
-                            }
+                    ..Default::default()
+                };
+
+                let layout = self
+                    .text_pipeline
+                    .layout(text.horizontal_alignment, text.vertical_alignment)
+                    .wrapping(text.wrapping);
+
+                self.text_pipeline.queue(section, layout);
+            }
+
+            for text in layer.rich_text.iter() {
+                let section = wgpu_glyph::Section {
+                    screen_position: (
+                        (text.bounds.x * scale_factor).round(),
+                        (text.bounds.y * scale_factor).round(),
+                    ),
+                    bounds: (
+                        (text.bounds.width * scale_factor).ceil(),
+                        (text.bounds.height * scale_factor).ceil(),
+                    ),
+                    text: text
+                        .spans
+                        .iter()
+                        .map(|span| wgpu_glyph::Text {
+                            text: &span.content,
+                            scale: wgpu_glyph::ab_glyph::PxScale {
+                                x: span.size * scale_factor,
+                                y: span.size * scale_factor,
+                            },
+                            font_id: self.text_pipeline.find_font(span.font),
+                            extra: wgpu_glyph::Extra {
+                                color: span.color.into_linear(),
+                                z: 0.0,
+                            },
                         })
-                        .v_align(match text.vertical_alignment {
-                            alignment::Vertical::Top => {
-                                wgpu_glyph::VerticalAlign::Top
-                            }
-                            alignment::Vertical::Center => {
-                                wgpu_glyph::VerticalAlign::Center
-                            }
-                            alignment::Vertical::Bottom => {
-                                wgpu_glyph::VerticalAlign::Bottom
-                            }
-                        }),
+                        .collect(),
+                    ..Default::default()
                 };
 
-                self.text_pipeline.queue(text);
+                let layout = self
+                    .text_pipeline
+                    .layout(text.horizontal_alignment, text.vertical_alignment);
+
+                self.text_pipeline.queue(section, layout);
             }
 
             self.text_pipeline.draw_queued(
@@ -270,8 +290,10 @@ impl backend::Text for Backend {
         size: f32,
         font: Font,
         bounds: Size,
+        wrapping: text::Wrapping,
     ) -> (f32, f32) {
-        self.text_pipeline.measure(contents, size, font, bounds)
+        self.text_pipeline
+            .measure(contents, size, font, bounds, wrapping)
     }
 
     fn hit_test(
@@ -292,6 +314,30 @@ impl backend::Text for Backend {
             nearest_only,
         )
     }
+
+    fn measure_spans(
+        &self,
+        spans: &[text::Span<'_, Font>],
+        bounds: Size,
+    ) -> (f32, f32) {
+        self.text_pipeline.measure_spans(spans, bounds)
+    }
+
+    fn hit_span(
+        &self,
+        spans: &[text::Span<'_, Font>],
+        bounds: Size,
+        point: iced_native::Point,
+    ) -> Option<usize> {
+        self.text_pipeline.hit_span(spans, bounds, point)
+    }
+
+    fn load_font(
+        &mut self,
+        bytes: Vec<u8>,
+    ) -> Result<(), iced_native::font::Error> {
+        self.text_pipeline.load_font(bytes)
+    }
 }
 
 #[cfg(feature = "image")]
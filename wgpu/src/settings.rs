@@ -19,6 +19,20 @@ pub struct Settings {
     /// If `None` is provided, a default system font will be chosen.
     pub default_font: Option<&'static [u8]>,
 
+    /// The bytes of the fonts that will be used, in order, for the characters
+    /// missing in the default font.
+    ///
+    /// By default, it is empty.
+    pub fallback_fonts: &'static [&'static [u8]],
+
+    /// The bytes of the fonts that will be loaded on boot, which can be
+    /// selected by the family and properties of a [`Font`].
+    ///
+    /// By default, it is empty.
+    ///
+    /// [`Font`]: iced_native::Font
+    pub fonts: &'static [&'static [u8]],
+
     /// The default size of text.
     ///
     /// By default, it will be set to 20.
@@ -66,6 +80,8 @@ impl Default for Settings {
             present_mode: wgpu::PresentMode::AutoVsync,
             internal_backend: wgpu::Backends::all(),
             default_font: None,
+            fallback_fonts: &[],
+            fonts: &[],
             default_text_size: 20,
             text_multithreading: false,
             antialiasing: None,
@@ -2,11 +2,12 @@ use crate::{Backend, Color, Error, Renderer, Settings, Viewport};
 
 use futures::stream::{self, StreamExt};
 
-use iced_graphics::compositor;
+use iced_graphics::{compositor, Size};
 use iced_native::futures;
 use raw_window_handle::{HasRawDisplayHandle, HasRawWindowHandle};
 
 use std::marker::PhantomData;
+use std::num::NonZeroU32;
 
 /// A window graphics backend for iced powered by `wgpu`.
 #[allow(missing_debug_implementations)]
@@ -62,9 +63,13 @@ impl<Theme> Compositor<Theme> {
 
         log::info!("Selected: {:#?}", adapter.get_info());
 
-        let format = compatible_surface.as_ref().and_then(|surface| {
-            surface.get_supported_formats(&adapter).first().copied()
-        })?;
+        let format = match compatible_surface.as_ref() {
+            Some(surface) => {
+                surface.get_supported_formats(&adapter).first().copied()?
+            }
+            // Offscreen rendering only
+            None => wgpu::TextureFormat::Rgba8UnormSrgb,
+        };
 
         log::info!("Selected format: {:?}", format);
 
@@ -116,6 +121,64 @@ impl<Theme> Compositor<Theme> {
     pub fn create_backend(&self) -> Backend {
         Backend::new(&self.device, self.settings, self.format)
     }
+
+    /// Renders the primitives of the [`Renderer`] to the given texture view
+    /// and submits the work to the queue.
+    fn render<T: AsRef<str>>(
+        &mut self,
+        renderer: &mut Renderer<Theme>,
+        view: &wgpu::TextureView,
+        viewport: &Viewport,
+        background_color: Color,
+        overlay: &[T],
+    ) {
+        let mut encoder = self.device.create_command_encoder(
+            &wgpu::CommandEncoderDescriptor {
+                label: Some("iced_wgpu encoder"),
+            },
+        );
+
+        let _ = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some("iced_wgpu::window::Compositor render pass"),
+            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                view,
+                resolve_target: None,
+                ops: wgpu::Operations {
+                    load: wgpu::LoadOp::Clear({
+                        let [r, g, b, a] = background_color.into_linear();
+
+                        wgpu::Color {
+                            r: f64::from(r),
+                            g: f64::from(g),
+                            b: f64::from(b),
+                            a: f64::from(a),
+                        }
+                    }),
+                    store: true,
+                },
+            })],
+            depth_stencil_attachment: None,
+        });
+
+        renderer.with_primitives(|backend, primitives| {
+            backend.present(
+                &self.device,
+                &mut self.staging_belt,
+                &mut encoder,
+                view,
+                primitives,
+                viewport,
+                overlay,
+            );
+        });
+
+        // Submit work
+        self.staging_belt.finish();
+        let _submission = self.queue.submit(Some(encoder.finish()));
+
+        // Recall staging buffers
+        self.staging_belt.recall();
+    }
 }
 
 impl<Theme> iced_graphics::window::Compositor for Compositor<Theme> {
@@ -186,63 +249,19 @@ impl<Theme> iced_graphics::window::Compositor for Compositor<Theme> {
     ) -> Result<(), compositor::SurfaceError> {
         match surface.get_current_texture() {
             Ok(frame) => {
-                let mut encoder = self.device.create_command_encoder(
-                    &wgpu::CommandEncoderDescriptor {
-                        label: Some("iced_wgpu encoder"),
-                    },
-                );
-
                 let view = &frame
                     .texture
                     .create_view(&wgpu::TextureViewDescriptor::default());
 
-                let _ =
-                    encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-                        label: Some(
-                            "iced_wgpu::window::Compositor render pass",
-                        ),
-                        color_attachments: &[Some(
-                            wgpu::RenderPassColorAttachment {
-                                view,
-                                resolve_target: None,
-                                ops: wgpu::Operations {
-                                    load: wgpu::LoadOp::Clear({
-                                        let [r, g, b, a] =
-                                            background_color.into_linear();
-
-                                        wgpu::Color {
-                                            r: f64::from(r),
-                                            g: f64::from(g),
-                                            b: f64::from(b),
-                                            a: f64::from(a),
-                                        }
-                                    }),
-                                    store: true,
-                                },
-                            },
-                        )],
-                        depth_stencil_attachment: None,
-                    });
-
-                renderer.with_primitives(|backend, primitives| {
-                    backend.present(
-                        &self.device,
-                        &mut self.staging_belt,
-                        &mut encoder,
-                        view,
-                        primitives,
-                        viewport,
-                        overlay,
-                    );
-                });
-
-                // Submit work
-                self.staging_belt.finish();
-                let _submission = self.queue.submit(Some(encoder.finish()));
-                frame.present();
+                self.render(
+                    renderer,
+                    view,
+                    viewport,
+                    background_color,
+                    overlay,
+                );
 
-                // Recall staging buffers
-                self.staging_belt.recall();
+                frame.present();
 
                 Ok(())
             }
@@ -260,4 +279,117 @@ impl<Theme> iced_graphics::window::Compositor for Compositor<Theme> {
             },
         }
     }
+
+    fn screenshot<T: AsRef<str>>(
+        &mut self,
+        renderer: &mut Self::Renderer,
+        viewport: &Viewport,
+        background_color: Color,
+        overlay: &[T],
+    ) -> Result<Vec<u8>, compositor::SurfaceError> {
+        let Size { width, height } = viewport.physical_size();
+
+        if width == 0 || height == 0 {
+            return Ok(Vec::new());
+        }
+
+        let extent = wgpu::Extent3d {
+            width,
+            height,
+            depth_or_array_layers: 1,
+        };
+
+        let texture = self.device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("iced_wgpu::window::Compositor screenshot texture"),
+            size: extent,
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: self.format,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT
+                | wgpu::TextureUsages::COPY_SRC,
+        });
+
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+        self.render(renderer, &view, viewport, background_color, overlay);
+
+        // Rows copied to a buffer must be aligned
+        let row_length = width as usize * 4;
+        let padded_row_length = {
+            let alignment = wgpu::COPY_BYTES_PER_ROW_ALIGNMENT as usize;
+
+            (row_length + alignment - 1) / alignment * alignment
+        };
+
+        let buffer = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("iced_wgpu::window::Compositor screenshot buffer"),
+            size: (padded_row_length * height as usize) as u64,
+            usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+            mapped_at_creation: false,
+        });
+
+        let mut encoder = self.device.create_command_encoder(
+            &wgpu::CommandEncoderDescriptor {
+                label: Some("iced_wgpu screenshot encoder"),
+            },
+        );
+
+        encoder.copy_texture_to_buffer(
+            wgpu::ImageCopyTexture {
+                texture: &texture,
+                mip_level: 0,
+                origin: wgpu::Origin3d::ZERO,
+                aspect: wgpu::TextureAspect::All,
+            },
+            wgpu::ImageCopyBuffer {
+                buffer: &buffer,
+                layout: wgpu::ImageDataLayout {
+                    offset: 0,
+                    bytes_per_row: NonZeroU32::new(padded_row_length as u32),
+                    rows_per_image: None,
+                },
+            },
+            extent,
+        );
+
+        let _submission = self.queue.submit(Some(encoder.finish()));
+
+        let slice = buffer.slice(..);
+        let (sender, receiver) = std::sync::mpsc::channel();
+
+        slice.map_async(wgpu::MapMode::Read, move |result| {
+            let _ = sender.send(result);
+        });
+        let _ = self.device.poll(wgpu::Maintain::Wait);
+
+        // Mapping fails if the device is lost while reading back
+        if !matches!(receiver.try_recv(), Ok(Ok(()))) {
+            return Err(compositor::SurfaceError::Lost);
+        }
+
+        let mut pixels = Vec::with_capacity(row_length * height as usize);
+
+        {
+            let padded_pixels = slice.get_mapped_range();
+
+            for row in padded_pixels.chunks_exact(padded_row_length) {
+                pixels.extend_from_slice(&row[..row_length]);
+            }
+        }
+
+        buffer.unmap();
+
+        if matches!(
+            self.format,
+            wgpu::TextureFormat::Bgra8Unorm
+                | wgpu::TextureFormat::Bgra8UnormSrgb
+        ) {
+            for pixel in pixels.chunks_exact_mut(4) {
+                pixel.swap(0, 2);
+            }
+        }
+
+        Ok(pixels)
+    }
 }
@@ -19,6 +19,7 @@ use iced_graphics::compositor;
 use iced_graphics::window;
 use iced_native::program::Program;
 use iced_native::user_interface::{self, UserInterface};
+use iced_native::window::Screenshot;
 
 pub use iced_native::application::{Appearance, StyleSheet};
 
@@ -106,6 +107,7 @@ where
     E: Executor + 'static,
     C: window::Compositor<Renderer = A::Renderer> + 'static,
     <A::Renderer as crate::Renderer>::Theme: StyleSheet,
+    A::Renderer: crate::text::Renderer,
 {
     use futures::task;
     use futures::Future;
@@ -174,6 +176,7 @@ where
     let (compositor, renderer) = C::new(compositor_settings, Some(&window))?;
 
     let (mut sender, receiver) = mpsc::unbounded();
+    let (control_sender, control_receiver) = std::sync::mpsc::channel();
 
     let mut instance = Box::pin(run_instance::<A, E, C>(
         application,
@@ -183,12 +186,14 @@ where
         proxy,
         debug,
         receiver,
+        control_sender,
         init_command,
         window,
         settings.exit_on_close_request,
     ));
 
     let mut context = task::Context::from_waker(task::noop_waker_ref());
+    let mut requested_flow = winit::event_loop::ControlFlow::Wait;
 
     platform::run(event_loop, move |event, _, control_flow| {
         use winit::event_loop::ControlFlow;
@@ -218,7 +223,13 @@ where
             let poll = instance.as_mut().poll(&mut context);
 
             *control_flow = match poll {
-                task::Poll::Pending => ControlFlow::Wait,
+                task::Poll::Pending => {
+                    if let Some(flow) = control_receiver.try_iter().last() {
+                        requested_flow = flow;
+                    }
+
+                    requested_flow
+                }
                 task::Poll::Ready(_) => ControlFlow::Exit,
             };
         }
@@ -233,6 +244,7 @@ async fn run_instance<A, E, C>(
     mut proxy: winit::event_loop::EventLoopProxy<A::Message>,
     mut debug: Debug,
     mut receiver: mpsc::UnboundedReceiver<winit::event::Event<'_, A::Message>>,
+    control_sender: std::sync::mpsc::Sender<winit::event_loop::ControlFlow>,
     init_command: Command<A::Message>,
     window: winit::window::Window,
     exit_on_close_request: bool,
@@ -241,6 +253,7 @@ async fn run_instance<A, E, C>(
     E: Executor + 'static,
     C: window::Compositor<Renderer = A::Renderer> + 'static,
     <A::Renderer as crate::Renderer>::Theme: StyleSheet,
+    A::Renderer: crate::text::Renderer,
 {
     use iced_futures::futures::stream::StreamExt;
     use winit::event;
@@ -249,6 +262,7 @@ async fn run_instance<A, E, C>(
     let mut cache = user_interface::Cache::default();
     let mut surface = compositor.create_surface(&window);
     let mut should_exit = false;
+    let mut screenshots = Vec::new();
 
     let mut state = State::new(&application, &window);
     let mut viewport_version = state.viewport_version();
@@ -272,6 +286,7 @@ async fn run_instance<A, E, C>(
         &mut should_exit,
         &mut proxy,
         &mut debug,
+        &mut screenshots,
         &window,
         || compositor.fetch_information(),
     );
@@ -288,13 +303,22 @@ async fn run_instance<A, E, C>(
     let mut mouse_interaction = mouse::Interaction::default();
     let mut events = Vec::new();
     let mut messages = Vec::new();
+    let mut redraw_pending = false;
 
     debug.startup_finished();
 
     while let Some(event) = receiver.next().await {
         match event {
+            event::Event::NewEvents(start_cause) => {
+                redraw_pending = matches!(
+                    start_cause,
+                    event::StartCause::Init
+                        | event::StartCause::Poll
+                        | event::StartCause::ResumeTimeReached { .. }
+                );
+            }
             event::Event::MainEventsCleared => {
-                if events.is_empty() && messages.is_empty() {
+                if !redraw_pending && events.is_empty() && messages.is_empty() {
                     continue;
                 }
 
@@ -314,6 +338,14 @@ async fn run_instance<A, E, C>(
                     runtime.broadcast(event);
                 }
 
+                // Widgets may request a redraw while handling the events too
+                let events_redraw_request = match interface_state {
+                    user_interface::State::Updated {
+                        redraw_request, ..
+                    } => redraw_request,
+                    user_interface::State::Outdated => None,
+                };
+
                 if !messages.is_empty()
                     || matches!(
                         interface_state,
@@ -335,6 +367,7 @@ async fn run_instance<A, E, C>(
                         &mut proxy,
                         &mut debug,
                         &mut messages,
+                        &mut screenshots,
                         &window,
                         || compositor.fetch_information(),
                     );
@@ -355,6 +388,67 @@ async fn run_instance<A, E, C>(
                     }
                 }
 
+                // Let widgets advance their animations and request the
+                // next redraw
+                let redraw_event = iced_native::Event::Window(
+                    iced_native::window::Id::MAIN,
+                    iced_native::window::Event::RedrawRequested(
+                        crate::time::Instant::now(),
+                    ),
+                );
+
+                let (interface_state, _) = user_interface.update(
+                    std::slice::from_ref(&redraw_event),
+                    state.cursor_position(),
+                    &mut renderer,
+                    &mut clipboard,
+                    &mut messages,
+                );
+
+                runtime
+                    .broadcast((redraw_event, crate::event::Status::Ignored));
+
+                let redraw_request = match interface_state {
+                    user_interface::State::Updated {
+                        redraw_request,
+                        input_method,
+                    } => {
+                        state.update_input_method(&window, input_method);
+
+                        events_redraw_request
+                            .into_iter()
+                            .chain(redraw_request)
+                            .min()
+                    }
+                    user_interface::State::Outdated => {
+                        // The widgets changed while redrawing, so the
+                        // interface is rebuilt before drawing it
+                        let cache = ManuallyDrop::into_inner(user_interface)
+                            .into_cache();
+
+                        user_interface =
+                            ManuallyDrop::new(build_user_interface(
+                                &application,
+                                cache,
+                                &mut renderer,
+                                state.logical_size(),
+                                &mut debug,
+                            ));
+
+                        Some(iced_native::window::RedrawRequest::NextFrame)
+                    }
+                };
+
+                let _ = control_sender.send(if messages.is_empty() {
+                    conversion::control_flow(redraw_request)
+                } else {
+                    // Process any messages produced by the redraw as soon as
+                    // possible
+                    winit::event_loop::ControlFlow::Poll
+                });
+
+                redraw_pending = false;
+
                 debug.draw_started();
                 let new_mouse_interaction = user_interface.draw(
                     &mut renderer,
@@ -448,8 +542,36 @@ async fn run_instance<A, E, C>(
                     Ok(()) => {
                         debug.render_finished();
 
-                        // TODO: Handle animations!
-                        // Maybe we can use `ControlFlow::WaitUntil` for this.
+                        if !screenshots.is_empty() {
+                            match compositor.screenshot(
+                                &mut renderer,
+                                state.viewport(),
+                                state.background_color(),
+                                &debug.overlay(),
+                            ) {
+                                Ok(pixels) => {
+                                    let screenshot =
+                                        Screenshot::new(pixels, physical_size);
+
+                                    for tag in screenshots.drain(..) {
+                                        proxy
+                                            .send_event(tag(screenshot.clone()))
+                                            .expect(
+                                                "Send message to event loop",
+                                            );
+                                    }
+                                }
+                                Err(error) => {
+                                    log::warn!(
+                                        "Failed to take a screenshot: {}",
+                                        error
+                                    );
+
+                                    // Try again next frame.
+                                    window.request_redraw();
+                                }
+                            }
+                        }
                     }
                     Err(error) => match error {
                         // This is an unrecoverable error.
@@ -478,6 +600,7 @@ async fn run_instance<A, E, C>(
                 state.update(&window, &window_event, &mut debug);
 
                 if let Some(event) = conversion::window_event(
+                    iced_native::window::Id::MAIN,
                     &window_event,
                     state.scale_factor(),
                     state.modifiers(),
@@ -553,10 +676,12 @@ pub fn update<A: Application, E: Executor>(
     proxy: &mut winit::event_loop::EventLoopProxy<A::Message>,
     debug: &mut Debug,
     messages: &mut Vec<A::Message>,
+    screenshots: &mut Vec<Box<dyn FnOnce(Screenshot) -> A::Message>>,
     window: &winit::window::Window,
     graphics_info: impl FnOnce() -> compositor::Information + Copy,
 ) where
     <A::Renderer as crate::Renderer>::Theme: StyleSheet,
+    A::Renderer: crate::text::Renderer,
 {
     for message in messages.drain(..) {
         debug.log_message(&message);
@@ -576,6 +701,7 @@ pub fn update<A: Application, E: Executor>(
             should_exit,
             proxy,
             debug,
+            screenshots,
             window,
             graphics_info,
         );
@@ -597,12 +723,14 @@ pub fn run_command<A, E>(
     should_exit: &mut bool,
     proxy: &mut winit::event_loop::EventLoopProxy<A::Message>,
     debug: &mut Debug,
+    screenshots: &mut Vec<Box<dyn FnOnce(Screenshot) -> A::Message>>,
     window: &winit::window::Window,
     _graphics_info: impl FnOnce() -> compositor::Information + Copy,
 ) where
     A: Application,
     E: Executor,
     <A::Renderer as crate::Renderer>::Theme: StyleSheet,
+    A::Renderer: crate::text::Renderer,
 {
     use iced_native::command;
     use iced_native::system;
@@ -625,45 +753,57 @@ pub fn run_command<A, E>(
                     clipboard.write(contents);
                 }
             },
+            command::Action::Window(window::Action::Spawn { .. }) => {
+                log::warn!(
+                    "Spawning windows is only supported by multi-window \
+                    applications"
+                );
+            }
+            command::Action::Window(action)
+                if action.id() != window::Id::MAIN =>
+            {
+                log::warn!("Window {:?} does not exist", action.id());
+            }
             command::Action::Window(action) => match action {
-                window::Action::Close => {
+                window::Action::Close(_) => {
                     *should_exit = true;
                 }
-                window::Action::Drag => {
+                window::Action::Spawn { .. } => {}
+                window::Action::Drag(_) => {
                     let _res = window.drag_window();
                 }
-                window::Action::Resize { width, height } => {
+                window::Action::Resize { width, height, .. } => {
                     window.set_inner_size(winit::dpi::LogicalSize {
                         width,
                         height,
                     });
                 }
-                window::Action::Maximize(value) => {
+                window::Action::Maximize(_, value) => {
                     window.set_maximized(value);
                 }
-                window::Action::Minimize(value) => {
+                window::Action::Minimize(_, value) => {
                     window.set_minimized(value);
                 }
-                window::Action::Move { x, y } => {
+                window::Action::Move { x, y, .. } => {
                     window.set_outer_position(winit::dpi::LogicalPosition {
                         x,
                         y,
                     });
                 }
-                window::Action::SetMode(mode) => {
+                window::Action::SetMode(_, mode) => {
                     window.set_visible(conversion::visible(mode));
                     window.set_fullscreen(conversion::fullscreen(
                         window.primary_monitor(),
                         mode,
                     ));
                 }
-                window::Action::ToggleMaximize => {
+                window::Action::ToggleMaximize(_) => {
                     window.set_maximized(!window.is_maximized())
                 }
-                window::Action::ToggleDecorations => {
+                window::Action::ToggleDecorations(_) => {
                     window.set_decorations(!window.is_decorated())
                 }
-                window::Action::FetchMode(tag) => {
+                window::Action::FetchMode(_, tag) => {
                     let mode = if window.is_visible().unwrap_or(true) {
                         conversion::mode(window.fullscreen())
                     } else {
@@ -674,6 +814,11 @@ pub fn run_command<A, E>(
                         .send_event(tag(mode))
                         .expect("Send message to event loop");
                 }
+                window::Action::Screenshot(_, tag) => {
+                    screenshots.push(tag);
+
+                    window.request_redraw();
+                }
             },
             command::Action::System(action) => match action {
                 system::Action::QueryInformation(_tag) => {
@@ -726,12 +871,36 @@ pub fn run_command<A, E>(
                 current_cache = user_interface.into_cache();
                 *cache = current_cache;
             }
+            command::Action::LoadFont { bytes, tagger } => {
+                use crate::text::Renderer;
+
+                let result = renderer.load_font(bytes);
+
+                if result.is_ok() {
+                    // Lay out the user interface again with the new font
+                    let user_interface = build_user_interface(
+                        application,
+                        std::mem::take(cache),
+                        renderer,
+                        state.logical_size(),
+                        debug,
+                    );
+
+                    *cache = user_interface.into_cache();
+
+                    window.request_redraw();
+                }
+
+                proxy
+                    .send_event(tagger(result))
+                    .expect("Send message to event loop");
+            }
         }
     }
 }
 
 #[cfg(not(target_arch = "wasm32"))]
-mod platform {
+pub(crate) mod platform {
     pub fn run<T, F>(
         mut event_loop: winit::event_loop::EventLoop<T>,
         event_handler: F,
@@ -753,7 +922,7 @@ mod platform {
 }
 
 #[cfg(target_arch = "wasm32")]
-mod platform {
+pub(crate) mod platform {
     pub fn run<T, F>(
         event_loop: winit::event_loop::EventLoop<T>,
         event_handler: F,
@@ -1,5 +1,6 @@
 use crate::application::{self, StyleSheet as _};
 use crate::conversion;
+use crate::input_method;
 use crate::{Application, Color, Debug, Point, Size, Viewport};
 
 use std::marker::PhantomData;
@@ -20,6 +21,7 @@ where
     modifiers: winit::event::ModifiersState,
     theme: <A::Renderer as crate::Renderer>::Theme,
     appearance: application::Appearance,
+    input_method: input_method::Request,
     application: PhantomData<A>,
 }
 
@@ -53,6 +55,7 @@ where
             modifiers: winit::event::ModifiersState::default(),
             theme,
             appearance,
+            input_method: input_method::Request::Disabled,
             application: PhantomData,
         }
     }
@@ -173,6 +176,36 @@ where
         }
     }
 
+    /// Synchronizes the input method editor of the given window with the
+    /// provided [`input_method::Request`].
+    pub fn update_input_method(
+        &mut self,
+        window: &Window,
+        request: input_method::Request,
+    ) {
+        if self.input_method == request {
+            return;
+        }
+
+        match request {
+            input_method::Request::Enabled { cursor } => {
+                if self.input_method == input_method::Request::Disabled {
+                    window.set_ime_allowed(true);
+                }
+
+                window.set_ime_position(conversion::input_method_position(
+                    cursor,
+                    self.scale_factor(),
+                ));
+            }
+            input_method::Request::Disabled => {
+                window.set_ime_allowed(false);
+            }
+        }
+
+        self.input_method = request;
+    }
+
     /// Synchronizes the [`State`] with its [`Application`] and its respective
     /// window.
     ///
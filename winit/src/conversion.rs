@@ -2,14 +2,17 @@
 //!
 //! [`winit`]: https://github.com/rust-windowing/winit
 //! [`iced_native`]: https://github.com/iced-rs/iced/tree/0.6/native
+use crate::input_method;
 use crate::keyboard;
 use crate::mouse;
 use crate::touch;
 use crate::window;
-use crate::{Event, Point, Position};
+use crate::{Event, Point, Position, Rectangle};
 
-/// Converts a winit window event into an iced event.
+/// Converts a winit window event into an iced event, tagging window events
+/// with the given [`window::Id`].
 pub fn window_event(
+    id: window::Id,
     event: &winit::event::WindowEvent<'_>,
     scale_factor: f64,
     modifiers: winit::event::ModifiersState,
@@ -20,21 +23,27 @@ pub fn window_event(
         WindowEvent::Resized(new_size) => {
             let logical_size = new_size.to_logical(scale_factor);
 
-            Some(Event::Window(window::Event::Resized {
-                width: logical_size.width,
-                height: logical_size.height,
-            }))
+            Some(Event::Window(
+                id,
+                window::Event::Resized {
+                    width: logical_size.width,
+                    height: logical_size.height,
+                },
+            ))
         }
         WindowEvent::ScaleFactorChanged { new_inner_size, .. } => {
             let logical_size = new_inner_size.to_logical(scale_factor);
 
-            Some(Event::Window(window::Event::Resized {
-                width: logical_size.width,
-                height: logical_size.height,
-            }))
+            Some(Event::Window(
+                id,
+                window::Event::Resized {
+                    width: logical_size.width,
+                    height: logical_size.height,
+                },
+            ))
         }
         WindowEvent::CloseRequested => {
-            Some(Event::Window(window::Event::CloseRequested))
+            Some(Event::Window(id, window::Event::CloseRequested))
         }
         WindowEvent::CursorMoved { position, .. } => {
             let position = position.to_logical::<f64>(scale_factor);
@@ -112,19 +121,22 @@ pub fn window_event(
         WindowEvent::ModifiersChanged(new_modifiers) => Some(Event::Keyboard(
             keyboard::Event::ModifiersChanged(self::modifiers(*new_modifiers)),
         )),
-        WindowEvent::Focused(focused) => Some(Event::Window(if *focused {
-            window::Event::Focused
-        } else {
-            window::Event::Unfocused
-        })),
+        WindowEvent::Focused(focused) => Some(Event::Window(
+            id,
+            if *focused {
+                window::Event::Focused
+            } else {
+                window::Event::Unfocused
+            },
+        )),
         WindowEvent::HoveredFile(path) => {
-            Some(Event::Window(window::Event::FileHovered(path.clone())))
+            Some(Event::Window(id, window::Event::FileHovered(path.clone())))
         }
         WindowEvent::DroppedFile(path) => {
-            Some(Event::Window(window::Event::FileDropped(path.clone())))
+            Some(Event::Window(id, window::Event::FileDropped(path.clone())))
         }
         WindowEvent::HoveredFileCancelled => {
-            Some(Event::Window(window::Event::FilesHoveredLeft))
+            Some(Event::Window(id, window::Event::FilesHoveredLeft))
         }
         WindowEvent::Touch(touch) => {
             Some(Event::Touch(touch_event(*touch, scale_factor)))
@@ -133,12 +145,50 @@ pub fn window_event(
             let winit::dpi::LogicalPosition { x, y } =
                 position.to_logical(scale_factor);
 
-            Some(Event::Window(window::Event::Moved { x, y }))
+            Some(Event::Window(id, window::Event::Moved { x, y }))
+        }
+        WindowEvent::Ime(ime) => {
+            Some(Event::InputMethod(input_method_event(ime.clone())))
         }
         _ => None,
     }
 }
 
+/// Converts a [`winit`] IME event into an [`input_method::Event`].
+///
+/// [`winit`]: https://github.com/rust-windowing/winit
+pub fn input_method_event(ime: winit::event::Ime) -> input_method::Event {
+    use winit::event::Ime;
+
+    match ime {
+        Ime::Enabled => input_method::Event::Opened,
+        Ime::Preedit(content, cursor) => input_method::Event::Preedit(
+            content,
+            cursor.map(|(start, end)| start..end),
+        ),
+        Ime::Commit(content) => input_method::Event::Commit(content),
+        Ime::Disabled => input_method::Event::Closed,
+    }
+}
+
+/// Converts the bounds of a text cursor into the [`winit`] position of the
+/// candidate window of an input method editor.
+///
+/// The candidate window is placed right below the cursor.
+///
+/// [`winit`]: https://github.com/rust-windowing/winit
+pub fn input_method_position(
+    cursor: Rectangle,
+    scale_factor: f64,
+) -> winit::dpi::Position {
+    let position = winit::dpi::LogicalPosition {
+        x: f64::from(cursor.x),
+        y: f64::from(cursor.y + cursor.height),
+    };
+
+    winit::dpi::Position::Physical(position.to_physical(scale_factor))
+}
+
 /// Converts a [`Position`] to a [`winit`] logical position for a given monitor.
 ///
 /// [`winit`]: https://github.com/rust-windowing/winit
@@ -215,6 +265,26 @@ pub fn mode(mode: Option<winit::window::Fullscreen>) -> window::Mode {
     }
 }
 
+/// Converts an optional [`window::RedrawRequest`] to a [`winit`] control
+/// flow.
+///
+/// [`winit`]: https://github.com/rust-windowing/winit
+pub fn control_flow(
+    redraw_request: Option<window::RedrawRequest>,
+) -> winit::event_loop::ControlFlow {
+    use winit::event_loop::ControlFlow;
+
+    match redraw_request {
+        None => ControlFlow::Wait,
+        Some(window::RedrawRequest::NextFrame) => ControlFlow::Poll,
+        #[cfg(not(target_arch = "wasm32"))]
+        Some(window::RedrawRequest::At(at)) => ControlFlow::WaitUntil(at),
+        // The instants of the web runtime are not compatible with `winit`
+        #[cfg(target_arch = "wasm32")]
+        Some(window::RedrawRequest::At(_)) => ControlFlow::Poll,
+    }
+}
+
 /// Converts a `MouseCursor` from [`iced_native`] to a [`winit`] cursor icon.
 ///
 /// [`winit`]: https://github.com/rust-windowing/winit
@@ -0,0 +1,17 @@
+//! Load and use fonts.
+pub use iced_native::font::*;
+
+use crate::command::{self, Command};
+
+/// Load a font from its bytes.
+///
+/// Once loaded, the font can be used by its family name like any other
+/// [`Font`]. The cached text measurements are discarded and the user
+/// interface is laid out again, so text that resolves to the new font
+/// picks it up right away.
+pub fn load(bytes: Vec<u8>) -> Command<Result<(), Error>> {
+    Command::single(command::Action::LoadFont {
+        bytes,
+        tagger: Box::new(std::convert::identity),
+    })
+}
@@ -39,6 +39,9 @@ pub use winit;
 pub mod application;
 pub mod clipboard;
 pub mod conversion;
+pub mod font;
+#[cfg(all(feature = "application", not(target_arch = "wasm32")))]
+pub mod multi_window;
 pub mod settings;
 pub mod window;
 
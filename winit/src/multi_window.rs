@@ -0,0 +1,1170 @@
+//! Create interactive, native cross-platform applications with multiple
+//! windows.
+mod state;
+
+pub use state::State;
+
+use crate::clipboard::{self, Clipboard};
+use crate::conversion;
+use crate::mouse;
+use crate::renderer;
+use crate::settings;
+use crate::widget::operation;
+use crate::window;
+use crate::{
+    Command, Debug, Element, Error, Executor, Proxy, Runtime, Settings,
+    Subscription,
+};
+
+use iced_futures::futures;
+use iced_futures::futures::channel::mpsc;
+use iced_graphics::compositor;
+use iced_native::user_interface::{self, UserInterface};
+
+pub use iced_native::application::{Appearance, StyleSheet};
+
+use std::collections::HashMap;
+use std::mem::ManuallyDrop;
+
+/// An interactive, native cross-platform application with multiple windows.
+///
+/// The application starts with a single window, identified by
+/// [`window::Id::MAIN`]. Additional windows can be opened with
+/// [`window::spawn`] and closed with [`window::close_window`].
+///
+/// Every window is drawn with the same renderer on its own surface of the
+/// compositor. Window events are tagged with the [`window::Id`] of the window
+/// that produced them.
+///
+/// When using an [`Application`] with the `debug` feature enabled, a debug view
+/// can be toggled by pressing `F12`.
+pub trait Application: Sized
+where
+    <Self::Renderer as crate::Renderer>::Theme: StyleSheet,
+{
+    /// The graphics backend to use to draw the windows of the
+    /// [`Application`].
+    type Renderer: crate::Renderer;
+
+    /// The type of __messages__ your [`Application`] will produce.
+    type Message: std::fmt::Debug + Send;
+
+    /// The data needed to initialize your [`Application`].
+    type Flags;
+
+    /// Initializes the [`Application`] with the flags provided to
+    /// [`run`] as part of the [`Settings`].
+    ///
+    /// Here is where you should return the initial state of your app.
+    ///
+    /// Additionally, you can return a [`Command`] if you need to perform some
+    /// async action in the background on startup.
+    fn new(flags: Self::Flags) -> (Self, Command<Self::Message>);
+
+    /// Returns the current title of the window with the given
+    /// [`window::Id`].
+    ///
+    /// This title can be dynamic! The runtime will automatically update the
+    /// title of the window when necessary.
+    fn title(&self, window: window::Id) -> String;
+
+    /// Handles a __message__ and updates the state of the [`Application`].
+    ///
+    /// Any [`Command`] returned will be executed immediately in the background.
+    fn update(&mut self, message: Self::Message) -> Command<Self::Message>;
+
+    /// Returns the widgets to display in the window with the given
+    /// [`window::Id`].
+    fn view(
+        &self,
+        window: window::Id,
+    ) -> Element<'_, Self::Message, Self::Renderer>;
+
+    /// Returns the current `Theme` of the window with the given
+    /// [`window::Id`].
+    fn theme(
+        &self,
+        window: window::Id,
+    ) -> <Self::Renderer as crate::Renderer>::Theme;
+
+    /// Returns the `Style` variation of the `Theme`.
+    fn style(
+        &self,
+    ) -> <<Self::Renderer as crate::Renderer>::Theme as StyleSheet>::Style {
+        Default::default()
+    }
+
+    /// Returns the event `Subscription` for the current state of the
+    /// application.
+    ///
+    /// By default, it returns an empty subscription.
+    fn subscription(&self) -> Subscription<Self::Message> {
+        Subscription::none()
+    }
+
+    /// Returns the scale factor of the window with the given [`window::Id`].
+    ///
+    /// By default, it returns `1.0`.
+    fn scale_factor(&self, _window: window::Id) -> f64 {
+        1.0
+    }
+}
+
+/// An event processed by the instance of a multi-window [`Application`].
+enum Event<Message: 'static> {
+    Winit(winit::event::Event<'static, Message>),
+    WindowCreated(window::Id, winit::window::Window),
+}
+
+/// A request from the instance of a multi-window [`Application`] to the event
+/// loop.
+enum Control {
+    CreateWindow {
+        id: window::Id,
+        settings: settings::Window,
+        title: String,
+    },
+    ChangeFlow(winit::event_loop::ControlFlow),
+}
+
+/// Runs a multi-window [`Application`] with an executor, compositor, and the
+/// provided settings.
+///
+/// The window settings of the provided [`Settings`] are used for the
+/// [`window::Id::MAIN`] window.
+pub fn run<A, E, C>(
+    settings: Settings<A::Flags>,
+    compositor_settings: C::Settings,
+) -> Result<(), Error>
+where
+    A: Application + 'static,
+    E: Executor + 'static,
+    C: iced_graphics::window::Compositor<Renderer = A::Renderer> + 'static,
+    <A::Renderer as crate::Renderer>::Theme: StyleSheet,
+    A::Renderer: crate::text::Renderer,
+{
+    use futures::task;
+    use futures::Future;
+    use winit::event_loop::EventLoopBuilder;
+
+    let mut debug = Debug::new();
+    debug.startup_started();
+
+    let event_loop = EventLoopBuilder::with_user_event().build();
+    let proxy = event_loop.create_proxy();
+
+    let runtime = {
+        let proxy = Proxy::new(event_loop.create_proxy());
+        let executor = E::new().map_err(Error::ExecutorCreationFailed)?;
+
+        Runtime::new(executor, proxy)
+    };
+
+    let (application, init_command) = {
+        let flags = settings.flags;
+
+        runtime.enter(|| A::new(flags))
+    };
+
+    let builder = settings.window.into_builder(
+        &application.title(window::Id::MAIN),
+        event_loop.primary_monitor(),
+        settings.id,
+    );
+
+    log::info!("Window builder: {:#?}", builder);
+
+    let window = builder
+        .build(&event_loop)
+        .map_err(Error::WindowCreationFailed)?;
+
+    let (compositor, renderer) = C::new(compositor_settings, Some(&window))?;
+
+    let (mut sender, receiver) = mpsc::unbounded();
+    let (control_sender, control_receiver) = std::sync::mpsc::channel();
+
+    let mut instance = Box::pin(run_instance::<A, E, C>(
+        application,
+        compositor,
+        renderer,
+        runtime,
+        proxy,
+        debug,
+        receiver,
+        control_sender,
+        init_command,
+        window,
+        settings.exit_on_close_request,
+    ));
+
+    let mut context = task::Context::from_waker(task::noop_waker_ref());
+    let mut requested_flow = winit::event_loop::ControlFlow::Wait;
+
+    crate::application::platform::run(
+        event_loop,
+        move |event, window_target, control_flow| {
+            use winit::event_loop::ControlFlow;
+
+            if let ControlFlow::ExitWithCode(_) = control_flow {
+                return;
+            }
+
+            let event = match event {
+                winit::event::Event::WindowEvent {
+                    event:
+                        winit::event::WindowEvent::ScaleFactorChanged {
+                            new_inner_size,
+                            ..
+                        },
+                    window_id,
+                } => Some(winit::event::Event::WindowEvent {
+                    event: winit::event::WindowEvent::Resized(*new_inner_size),
+                    window_id,
+                }),
+                _ => event.to_static(),
+            };
+
+            if let Some(event) = event {
+                sender.start_send(Event::Winit(event)).expect("Send event");
+
+                let mut poll = instance.as_mut().poll(&mut context);
+
+                // Handle the requests of the instance
+                while let (task::Poll::Pending, Ok(control)) =
+                    (&poll, control_receiver.try_recv())
+                {
+                    match control {
+                        Control::CreateWindow {
+                            id,
+                            settings,
+                            title,
+                        } => {
+                            let builder = settings.into_builder(
+                                &title,
+                                window_target.primary_monitor(),
+                                None,
+                            );
+
+                            match builder.build(window_target) {
+                                Ok(window) => {
+                                    sender
+                                        .start_send(Event::WindowCreated(
+                                            id, window,
+                                        ))
+                                        .expect("Send event");
+
+                                    poll = instance.as_mut().poll(&mut context);
+                                }
+                                Err(error) => {
+                                    log::error!(
+                                        "Failed to create window {:?}: {}",
+                                        id,
+                                        error
+                                    );
+                                }
+                            }
+                        }
+                        Control::ChangeFlow(flow) => {
+                            requested_flow = flow;
+                        }
+                    }
+                }
+
+                *control_flow = match poll {
+                    task::Poll::Pending => requested_flow,
+                    task::Poll::Ready(_) => ControlFlow::Exit,
+                };
+            }
+        },
+    )
+}
+
+/// The windows of a multi-window [`Application`] and their surfaces.
+struct Windows<A, C>
+where
+    A: Application,
+    C: iced_graphics::window::Compositor<Renderer = A::Renderer>,
+    <A::Renderer as crate::Renderer>::Theme: StyleSheet,
+{
+    raw: HashMap<window::Id, winit::window::Window>,
+    ids: HashMap<winit::window::WindowId, window::Id>,
+    states: HashMap<window::Id, State<A>>,
+    surfaces: HashMap<window::Id, C::Surface>,
+    viewport_versions: HashMap<window::Id, usize>,
+    mouse_interactions: HashMap<window::Id, mouse::Interaction>,
+    redraw_requests: HashMap<window::Id, window::RedrawRequest>,
+    screenshots: HashMap<window::Id, Vec<Screenshot<A::Message>>>,
+}
+
+/// A function that produces a message with the [`window::Screenshot`] of a
+/// window.
+type Screenshot<Message> = Box<dyn FnOnce(window::Screenshot) -> Message>;
+
+impl<A, C> Windows<A, C>
+where
+    A: Application,
+    C: iced_graphics::window::Compositor<Renderer = A::Renderer>,
+    <A::Renderer as crate::Renderer>::Theme: StyleSheet,
+{
+    fn new() -> Self {
+        Self {
+            raw: HashMap::new(),
+            ids: HashMap::new(),
+            states: HashMap::new(),
+            surfaces: HashMap::new(),
+            viewport_versions: HashMap::new(),
+            mouse_interactions: HashMap::new(),
+            redraw_requests: HashMap::new(),
+            screenshots: HashMap::new(),
+        }
+    }
+
+    fn insert(
+        &mut self,
+        id: window::Id,
+        window: winit::window::Window,
+        application: &A,
+        compositor: &mut C,
+    ) {
+        let state = State::new(application, id, &window);
+        let physical_size = state.physical_size();
+
+        let mut surface = compositor.create_surface(&window);
+
+        compositor.configure_surface(
+            &mut surface,
+            physical_size.width,
+            physical_size.height,
+        );
+
+        let _ = self.ids.insert(window.id(), id);
+        let _ = self.viewport_versions.insert(id, state.viewport_version());
+        let _ = self.mouse_interactions.insert(id, mouse::Interaction::Idle);
+        let _ = self.states.insert(id, state);
+        let _ = self.surfaces.insert(id, surface);
+        let _ = self.raw.insert(id, window);
+    }
+
+    fn remove(&mut self, id: window::Id) {
+        if let Some(window) = self.raw.remove(&id) {
+            let _ = self.ids.remove(&window.id());
+        }
+
+        let _ = self.states.remove(&id);
+        let _ = self.surfaces.remove(&id);
+        let _ = self.viewport_versions.remove(&id);
+        let _ = self.mouse_interactions.remove(&id);
+        let _ = self.redraw_requests.remove(&id);
+        let _ = self.screenshots.remove(&id);
+    }
+}
+
+async fn run_instance<A, E, C>(
+    mut application: A,
+    mut compositor: C,
+    mut renderer: A::Renderer,
+    mut runtime: Runtime<E, Proxy<A::Message>, A::Message>,
+    mut proxy: winit::event_loop::EventLoopProxy<A::Message>,
+    mut debug: Debug,
+    mut receiver: mpsc::UnboundedReceiver<Event<A::Message>>,
+    control_sender: std::sync::mpsc::Sender<Control>,
+    init_command: Command<A::Message>,
+    window: winit::window::Window,
+    exit_on_close_request: bool,
+) where
+    A: Application + 'static,
+    E: Executor + 'static,
+    C: iced_graphics::window::Compositor<Renderer = A::Renderer> + 'static,
+    <A::Renderer as crate::Renderer>::Theme: StyleSheet,
+    A::Renderer: crate::text::Renderer,
+{
+    use iced_futures::futures::stream::StreamExt;
+    use winit::event;
+
+    let mut clipboard = Clipboard::connect(&window);
+    let mut caches = HashMap::new();
+    let mut should_exit = false;
+
+    let mut windows = Windows::<A, C>::new();
+    windows.insert(window::Id::MAIN, window, &application, &mut compositor);
+
+    let _ = caches.insert(window::Id::MAIN, user_interface::Cache::default());
+
+    run_command(
+        &application,
+        &mut caches,
+        &mut windows,
+        &mut renderer,
+        init_command,
+        &mut runtime,
+        &mut clipboard,
+        &mut should_exit,
+        &mut proxy,
+        &mut debug,
+        &control_sender,
+        || compositor.fetch_information(),
+    );
+    runtime.track(application.subscription());
+
+    let mut user_interfaces = ManuallyDrop::new(build_user_interfaces(
+        &application,
+        caches,
+        &windows,
+        &mut renderer,
+        &mut debug,
+    ));
+
+    let mut events: Vec<(window::Id, iced_native::Event)> = Vec::new();
+    let mut messages = Vec::new();
+    let mut redraw_pending = false;
+    let mut events_redraw_requests: HashMap<window::Id, window::RedrawRequest> =
+        HashMap::new();
+
+    debug.startup_finished();
+
+    while let Some(event) = receiver.next().await {
+        match event {
+            Event::Winit(event::Event::NewEvents(start_cause)) => {
+                redraw_pending = matches!(
+                    start_cause,
+                    event::StartCause::Init
+                        | event::StartCause::Poll
+                        | event::StartCause::ResumeTimeReached { .. }
+                );
+            }
+            Event::WindowCreated(id, window) => {
+                windows.insert(id, window, &application, &mut compositor);
+
+                let state = &windows.states[&id];
+
+                let _ = user_interfaces.insert(
+                    id,
+                    build_user_interface(
+                        &application,
+                        id,
+                        user_interface::Cache::default(),
+                        &mut renderer,
+                        state.logical_size(),
+                        &mut debug,
+                    ),
+                );
+
+                windows.raw[&id].request_redraw();
+            }
+            Event::Winit(event::Event::MainEventsCleared) => {
+                if !redraw_pending && events.is_empty() && messages.is_empty() {
+                    continue;
+                }
+
+                debug.event_processing_started();
+
+                let mut is_outdated = false;
+
+                for (id, user_interface) in user_interfaces.iter_mut() {
+                    let state = &windows.states[id];
+
+                    let window_events: Vec<_> = events
+                        .iter()
+                        .filter(|(window, _)| window == id)
+                        .map(|(_, event)| event.clone())
+                        .collect();
+
+                    let (interface_state, statuses) = user_interface.update(
+                        &window_events,
+                        state.cursor_position(),
+                        &mut renderer,
+                        &mut clipboard,
+                        &mut messages,
+                    );
+
+                    for event in window_events.into_iter().zip(statuses) {
+                        runtime.broadcast(event);
+                    }
+
+                    // Widgets may request a redraw while handling the events
+                    // too
+                    if let user_interface::State::Updated {
+                        redraw_request: Some(redraw_request),
+                        ..
+                    } = interface_state
+                    {
+                        let _ =
+                            events_redraw_requests.insert(*id, redraw_request);
+                    }
+
+                    is_outdated = is_outdated
+                        || matches!(
+                            interface_state,
+                            user_interface::State::Outdated
+                        );
+                }
+
+                events.clear();
+
+                debug.event_processing_finished();
+
+                if !messages.is_empty() || is_outdated {
+                    let mut caches: HashMap<_, _> =
+                        ManuallyDrop::into_inner(user_interfaces)
+                            .into_iter()
+                            .map(|(id, user_interface)| {
+                                (id, user_interface.into_cache())
+                            })
+                            .collect();
+
+                    // Update application
+                    update(
+                        &mut application,
+                        &mut caches,
+                        &mut windows,
+                        &mut renderer,
+                        &mut runtime,
+                        &mut clipboard,
+                        &mut should_exit,
+                        &mut proxy,
+                        &mut debug,
+                        &mut messages,
+                        &control_sender,
+                        || compositor.fetch_information(),
+                    );
+
+                    // Update windows
+                    for (id, state) in windows.states.iter_mut() {
+                        state.synchronize(&application, &windows.raw[id]);
+                    }
+
+                    user_interfaces = ManuallyDrop::new(build_user_interfaces(
+                        &application,
+                        caches,
+                        &windows,
+                        &mut renderer,
+                        &mut debug,
+                    ));
+
+                    if should_exit {
+                        break;
+                    }
+                }
+
+                for window in windows.raw.values() {
+                    window.request_redraw();
+                }
+
+                redraw_pending = false;
+            }
+            Event::Winit(event::Event::PlatformSpecific(
+                event::PlatformSpecific::MacOS(event::MacOS::ReceivedUrl(url)),
+            )) => {
+                use iced_native::event;
+
+                events.push((
+                    window::Id::MAIN,
+                    iced_native::Event::PlatformSpecific(
+                        event::PlatformSpecific::MacOS(
+                            event::MacOS::ReceivedUrl(url),
+                        ),
+                    ),
+                ));
+            }
+            Event::Winit(event::Event::UserEvent(message)) => {
+                messages.push(message);
+            }
+            Event::Winit(event::Event::RedrawRequested(window_id)) => {
+                let id = match windows.ids.get(&window_id) {
+                    Some(id) => *id,
+                    None => continue,
+                };
+
+                let state =
+                    windows.states.get_mut(&id).expect("Get window state");
+                let window = &windows.raw[&id];
+                let physical_size = state.physical_size();
+
+                if physical_size.width == 0 || physical_size.height == 0 {
+                    continue;
+                }
+
+                debug.render_started();
+
+                let current_viewport_version = state.viewport_version();
+                let viewport_version = windows
+                    .viewport_versions
+                    .get_mut(&id)
+                    .expect("Get viewport version");
+
+                let surface =
+                    windows.surfaces.get_mut(&id).expect("Get window surface");
+
+                if *viewport_version != current_viewport_version {
+                    if let Some(user_interface) = user_interfaces.remove(&id) {
+                        debug.layout_started();
+                        let _ = user_interfaces.insert(
+                            id,
+                            user_interface
+                                .relayout(state.logical_size(), &mut renderer),
+                        );
+                        debug.layout_finished();
+                    }
+
+                    compositor.configure_surface(
+                        surface,
+                        physical_size.width,
+                        physical_size.height,
+                    );
+
+                    *viewport_version = current_viewport_version;
+                }
+
+                // Every window shares the same renderer, so we need to draw
+                // right before presenting.
+                if let Some(mut user_interface) = user_interfaces.remove(&id) {
+                    // Let widgets advance their animations and request the
+                    // next redraw
+                    let redraw_event = iced_native::Event::Window(
+                        id,
+                        window::Event::RedrawRequested(
+                            crate::time::Instant::now(),
+                        ),
+                    );
+
+                    let (interface_state, _) = user_interface.update(
+                        std::slice::from_ref(&redraw_event),
+                        state.cursor_position(),
+                        &mut renderer,
+                        &mut clipboard,
+                        &mut messages,
+                    );
+
+                    runtime.broadcast((
+                        redraw_event,
+                        crate::event::Status::Ignored,
+                    ));
+
+                    let events_redraw_request =
+                        events_redraw_requests.remove(&id);
+
+                    let redraw_request = match interface_state {
+                        user_interface::State::Updated {
+                            redraw_request,
+                            input_method,
+                        } => {
+                            state.update_input_method(window, input_method);
+
+                            events_redraw_request
+                                .into_iter()
+                                .chain(redraw_request)
+                                .min()
+                        }
+                        user_interface::State::Outdated => {
+                            // The widgets changed while redrawing, so the
+                            // interface is rebuilt before drawing it
+                            user_interface = build_user_interface(
+                                &application,
+                                id,
+                                user_interface.into_cache(),
+                                &mut renderer,
+                                state.logical_size(),
+                                &mut debug,
+                            );
+
+                            Some(window::RedrawRequest::NextFrame)
+                        }
+                    };
+
+                    if let Some(redraw_request) = redraw_request {
+                        let _ =
+                            windows.redraw_requests.insert(id, redraw_request);
+                    } else {
+                        let _ = windows.redraw_requests.remove(&id);
+                    }
+
+                    let _ = control_sender.send(Control::ChangeFlow(
+                        if messages.is_empty() {
+                            conversion::control_flow(
+                                windows.redraw_requests.values().min().copied(),
+                            )
+                        } else {
+                            // Process any messages produced by the redraw as
+                            // soon as possible
+                            winit::event_loop::ControlFlow::Poll
+                        },
+                    ));
+
+                    debug.draw_started();
+                    let new_mouse_interaction = user_interface.draw(
+                        &mut renderer,
+                        state.theme(),
+                        &renderer::Style {
+                            text_color: state.text_color(),
+                        },
+                        state.cursor_position(),
+                    );
+                    debug.draw_finished();
+
+                    let mouse_interaction = windows
+                        .mouse_interactions
+                        .get_mut(&id)
+                        .expect("Get mouse interaction");
+
+                    if new_mouse_interaction != *mouse_interaction {
+                        window.set_cursor_icon(conversion::mouse_interaction(
+                            new_mouse_interaction,
+                        ));
+
+                        *mouse_interaction = new_mouse_interaction;
+                    }
+
+                    let _ = user_interfaces.insert(id, user_interface);
+                }
+
+                match compositor.present(
+                    &mut renderer,
+                    surface,
+                    state.viewport(),
+                    state.background_color(),
+                    &debug.overlay(),
+                ) {
+                    Ok(()) => {
+                        debug.render_finished();
+
+                        if let Some(tags) = windows.screenshots.remove(&id) {
+                            match compositor.screenshot(
+                                &mut renderer,
+                                state.viewport(),
+                                state.background_color(),
+                                &debug.overlay(),
+                            ) {
+                                Ok(pixels) => {
+                                    let screenshot = window::Screenshot::new(
+                                        pixels,
+                                        physical_size,
+                                    );
+
+                                    for tag in tags {
+                                        proxy
+                                            .send_event(tag(screenshot.clone()))
+                                            .expect(
+                                                "Send message to event loop",
+                                            );
+                                    }
+                                }
+                                Err(error) => {
+                                    log::warn!(
+                                        "Failed to take a screenshot: {}",
+                                        error
+                                    );
+
+                                    // Try again next frame.
+                                    let _ =
+                                        windows.screenshots.insert(id, tags);
+                                    window.request_redraw();
+                                }
+                            }
+                        }
+                    }
+                    Err(error) => match error {
+                        // This is an unrecoverable error.
+                        compositor::SurfaceError::OutOfMemory => {
+                            panic!("{:?}", error);
+                        }
+                        _ => {
+                            debug.render_finished();
+
+                            // Try rendering again next frame.
+                            window.request_redraw();
+                        }
+                    },
+                }
+            }
+            Event::Winit(event::Event::WindowEvent {
+                event: window_event,
+                window_id,
+            }) => {
+                let id = match windows.ids.get(&window_id) {
+                    Some(id) => *id,
+                    None => continue,
+                };
+
+                if crate::application::requests_exit(
+                    &window_event,
+                    windows.states[&id].modifiers(),
+                ) && exit_on_close_request
+                {
+                    if id == window::Id::MAIN {
+                        break;
+                    }
+
+                    runtime.broadcast((
+                        iced_native::Event::Window(id, window::Event::Closed),
+                        crate::event::Status::Ignored,
+                    ));
+
+                    let _ = user_interfaces.remove(&id);
+                    let _ = events_redraw_requests.remove(&id);
+                    let _ = events_redraw_requests.remove(&id);
+                    windows.remove(id);
+
+                    continue;
+                }
+
+                let state =
+                    windows.states.get_mut(&id).expect("Get window state");
+
+                state.update(&windows.raw[&id], &window_event, &mut debug);
+
+                if let Some(event) = conversion::window_event(
+                    id,
+                    &window_event,
+                    state.scale_factor(),
+                    state.modifiers(),
+                ) {
+                    events.push((id, event));
+                }
+            }
+            Event::Winit(_) => {}
+        }
+
+        // Close any windows that are no longer needed
+        let closed: Vec<_> = user_interfaces
+            .keys()
+            .filter(|id| !windows.raw.contains_key(id))
+            .copied()
+            .collect();
+
+        for id in closed {
+            let _ = user_interfaces.remove(&id);
+            let _ = events_redraw_requests.remove(&id);
+        }
+    }
+
+    // Manually drop the user interfaces
+    drop(ManuallyDrop::into_inner(user_interfaces));
+}
+
+/// Builds a [`UserInterface`] for the window with the given [`window::Id`]
+/// of the provided [`Application`], logging [`struct@Debug`] information
+/// accordingly.
+pub fn build_user_interface<'a, A: Application>(
+    application: &'a A,
+    id: window::Id,
+    cache: user_interface::Cache,
+    renderer: &mut A::Renderer,
+    size: crate::Size,
+    debug: &mut Debug,
+) -> UserInterface<'a, A::Message, A::Renderer>
+where
+    <A::Renderer as crate::Renderer>::Theme: StyleSheet,
+{
+    debug.view_started();
+    let view = application.view(id);
+    debug.view_finished();
+
+    debug.layout_started();
+    let user_interface = UserInterface::build(view, size, cache, renderer);
+    debug.layout_finished();
+
+    user_interface
+}
+
+fn build_user_interfaces<'a, A, C>(
+    application: &'a A,
+    mut caches: HashMap<window::Id, user_interface::Cache>,
+    windows: &Windows<A, C>,
+    renderer: &mut A::Renderer,
+    debug: &mut Debug,
+) -> HashMap<window::Id, UserInterface<'a, A::Message, A::Renderer>>
+where
+    A: Application,
+    C: iced_graphics::window::Compositor<Renderer = A::Renderer>,
+    <A::Renderer as crate::Renderer>::Theme: StyleSheet,
+{
+    windows
+        .states
+        .iter()
+        .map(|(id, state)| {
+            let cache = caches.remove(id).unwrap_or_default();
+
+            (
+                *id,
+                build_user_interface(
+                    application,
+                    *id,
+                    cache,
+                    renderer,
+                    state.logical_size(),
+                    debug,
+                ),
+            )
+        })
+        .collect()
+}
+
+/// Updates an [`Application`] by feeding it the provided messages, spawning any
+/// resulting [`Command`], and tracking its [`Subscription`].
+fn update<A, E, C>(
+    application: &mut A,
+    caches: &mut HashMap<window::Id, user_interface::Cache>,
+    windows: &mut Windows<A, C>,
+    renderer: &mut A::Renderer,
+    runtime: &mut Runtime<E, Proxy<A::Message>, A::Message>,
+    clipboard: &mut Clipboard,
+    should_exit: &mut bool,
+    proxy: &mut winit::event_loop::EventLoopProxy<A::Message>,
+    debug: &mut Debug,
+    messages: &mut Vec<A::Message>,
+    control_sender: &std::sync::mpsc::Sender<Control>,
+    graphics_info: impl FnOnce() -> compositor::Information + Copy,
+) where
+    A: Application,
+    E: Executor,
+    C: iced_graphics::window::Compositor<Renderer = A::Renderer>,
+    <A::Renderer as crate::Renderer>::Theme: StyleSheet,
+    A::Renderer: crate::text::Renderer,
+{
+    for message in messages.drain(..) {
+        debug.log_message(&message);
+
+        debug.update_started();
+        let command = runtime.enter(|| application.update(message));
+        debug.update_finished();
+
+        run_command(
+            application,
+            caches,
+            windows,
+            renderer,
+            command,
+            runtime,
+            clipboard,
+            should_exit,
+            proxy,
+            debug,
+            control_sender,
+            graphics_info,
+        );
+    }
+
+    let subscription = application.subscription();
+    runtime.track(subscription);
+}
+
+/// Runs the actions of a [`Command`].
+fn run_command<A, E, C>(
+    application: &A,
+    caches: &mut HashMap<window::Id, user_interface::Cache>,
+    windows: &mut Windows<A, C>,
+    renderer: &mut A::Renderer,
+    command: Command<A::Message>,
+    runtime: &mut Runtime<E, Proxy<A::Message>, A::Message>,
+    clipboard: &mut Clipboard,
+    should_exit: &mut bool,
+    proxy: &mut winit::event_loop::EventLoopProxy<A::Message>,
+    debug: &mut Debug,
+    control_sender: &std::sync::mpsc::Sender<Control>,
+    _graphics_info: impl FnOnce() -> compositor::Information + Copy,
+) where
+    A: Application,
+    E: Executor,
+    C: iced_graphics::window::Compositor<Renderer = A::Renderer>,
+    <A::Renderer as crate::Renderer>::Theme: StyleSheet,
+    A::Renderer: crate::text::Renderer,
+{
+    use iced_native::command;
+    use iced_native::system;
+    use iced_native::window::Action;
+
+    for action in command.actions() {
+        match action {
+            command::Action::Future(future) => {
+                runtime.spawn(future);
+            }
+            command::Action::Clipboard(action) => match action {
+                clipboard::Action::Read(tag) => {
+                    let message = tag(clipboard.read());
+
+                    proxy
+                        .send_event(message)
+                        .expect("Send message to event loop");
+                }
+                clipboard::Action::Write(contents) => {
+                    clipboard.write(contents);
+                }
+            },
+            command::Action::Window(Action::Close(id)) => {
+                if id == window::Id::MAIN {
+                    *should_exit = true;
+                } else if windows.raw.contains_key(&id) {
+                    runtime.broadcast((
+                        iced_native::Event::Window(id, window::Event::Closed),
+                        crate::event::Status::Ignored,
+                    ));
+
+                    windows.remove(id);
+                }
+            }
+            command::Action::Window(Action::Spawn { id, settings }) => {
+                if windows.raw.contains_key(&id) {
+                    log::warn!("Window {:?} already exists", id);
+                    continue;
+                }
+
+                control_sender
+                    .send(Control::CreateWindow {
+                        id,
+                        settings: settings.into(),
+                        title: application.title(id),
+                    })
+                    .expect("Send control action");
+            }
+            command::Action::Window(action) => {
+                let id = action.id();
+
+                let window = match windows.raw.get(&id) {
+                    Some(window) => window,
+                    None => {
+                        log::warn!("Window {:?} does not exist", id);
+                        continue;
+                    }
+                };
+
+                match action {
+                    Action::Close(_) | Action::Spawn { .. } => {}
+                    Action::Drag(_) => {
+                        let _res = window.drag_window();
+                    }
+                    Action::Resize { width, height, .. } => {
+                        window.set_inner_size(winit::dpi::LogicalSize {
+                            width,
+                            height,
+                        });
+                    }
+                    Action::Maximize(_, value) => {
+                        window.set_maximized(value);
+                    }
+                    Action::Minimize(_, value) => {
+                        window.set_minimized(value);
+                    }
+                    Action::Move { x, y, .. } => {
+                        window.set_outer_position(
+                            winit::dpi::LogicalPosition { x, y },
+                        );
+                    }
+                    Action::SetMode(_, mode) => {
+                        window.set_visible(conversion::visible(mode));
+                        window.set_fullscreen(conversion::fullscreen(
+                            window.primary_monitor(),
+                            mode,
+                        ));
+                    }
+                    Action::ToggleMaximize(_) => {
+                        window.set_maximized(!window.is_maximized())
+                    }
+                    Action::ToggleDecorations(_) => {
+                        window.set_decorations(!window.is_decorated())
+                    }
+                    Action::FetchMode(_, tag) => {
+                        let mode = if window.is_visible().unwrap_or(true) {
+                            conversion::mode(window.fullscreen())
+                        } else {
+                            window::Mode::Hidden
+                        };
+
+                        proxy
+                            .send_event(tag(mode))
+                            .expect("Send message to event loop");
+                    }
+                    Action::Screenshot(_, tag) => {
+                        window.request_redraw();
+
+                        windows.screenshots.entry(id).or_default().push(tag);
+                    }
+                }
+            }
+            command::Action::System(action) => match action {
+                system::Action::QueryInformation(_tag) => {
+                    #[cfg(feature = "system")]
+                    {
+                        let graphics_info = _graphics_info();
+                        let proxy = proxy.clone();
+
+                        let _ = std::thread::spawn(move || {
+                            let information =
+                                crate::system::information(graphics_info);
+
+                            let message = _tag(information);
+
+                            proxy
+                                .send_event(message)
+                                .expect("Send message to event loop")
+                        });
+                    }
+                }
+            },
+            command::Action::Widget(action) => {
+                let mut current_operation = Some(action.into_operation());
+
+                let mut user_interfaces = build_user_interfaces(
+                    application,
+                    std::mem::take(caches),
+                    windows,
+                    renderer,
+                    debug,
+                );
+
+                while let Some(mut operation) = current_operation.take() {
+                    for user_interface in user_interfaces.values_mut() {
+                        user_interface.operate(renderer, operation.as_mut());
+                    }
+
+                    match operation.finish() {
+                        operation::Outcome::None => {}
+                        operation::Outcome::Some(message) => {
+                            proxy
+                                .send_event(message)
+                                .expect("Send message to event loop");
+                        }
+                        operation::Outcome::Chain(next) => {
+                            current_operation = Some(next);
+                        }
+                    }
+                }
+
+                *caches = user_interfaces
+                    .into_iter()
+                    .map(|(id, user_interface)| {
+                        (id, user_interface.into_cache())
+                    })
+                    .collect();
+            }
+            command::Action::LoadFont { bytes, tagger } => {
+                use crate::text::Renderer;
+
+                let result = renderer.load_font(bytes);
+
+                if result.is_ok() {
+                    // Lay out the user interfaces again with the new font
+                    *caches = build_user_interfaces(
+                        application,
+                        std::mem::take(caches),
+                        windows,
+                        renderer,
+                        debug,
+                    )
+                    .into_iter()
+                    .map(|(id, user_interface)| {
+                        (id, user_interface.into_cache())
+                    })
+                    .collect();
+
+                    for window in windows.raw.values() {
+                        window.request_redraw();
+                    }
+                }
+
+                proxy
+                    .send_event(tagger(result))
+                    .expect("Send message to event loop");
+            }
+        }
+    }
+}
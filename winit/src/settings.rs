@@ -197,3 +197,24 @@ impl Default for Window {
         }
     }
 }
+
+impl From<iced_native::window::Settings> for Window {
+    fn from(settings: iced_native::window::Settings) -> Self {
+        Self {
+            size: settings.size,
+            position: settings
+                .position
+                .map(|(x, y)| Position::Specific(x, y))
+                .unwrap_or_default(),
+            min_size: settings.min_size,
+            max_size: settings.max_size,
+            visible: settings.visible,
+            resizable: settings.resizable,
+            decorations: settings.decorations,
+            transparent: settings.transparent,
+            always_on_top: settings.always_on_top,
+            icon: None,
+            platform_specific: Default::default(),
+        }
+    }
+}
@@ -1,57 +1,114 @@
 //! Interact with the window of your application.
 use crate::command::{self, Command};
+use crate::subscription::{self, Subscription};
+use crate::time::Instant;
 use iced_native::window;
 
-pub use window::{Event, Mode};
+pub use window::{Event, Id, Mode, RedrawRequest, Screenshot, Settings};
 
-/// Closes the current window and exits the application.
+/// Subscribes to the frames of the windows of the running application.
+///
+/// The resulting [`Subscription`] produces a message every time a window is
+/// about to be redrawn, with the [`Instant`] of the redraw. Redraws can be
+/// scheduled by widgets through the [`Shell`], which makes this useful to
+/// drive animations.
+///
+/// [`Shell`]: crate::Shell
+pub fn frames() -> Subscription<Instant> {
+    subscription::raw_events(|event, _status| match event {
+        iced_native::Event::Window(_, Event::RedrawRequested(at)) => Some(at),
+        _ => None,
+    })
+}
+
+/// Closes the main window and exits the application.
 pub fn close<Message>() -> Command<Message> {
-    Command::single(command::Action::Window(window::Action::Close))
+    close_window(Id::MAIN)
+}
+
+/// Closes the window with the given [`Id`].
+///
+/// Closing the [`Id::MAIN`] window exits the application.
+pub fn close_window<Message>(id: Id) -> Command<Message> {
+    Command::single(command::Action::Window(window::Action::Close(id)))
+}
+
+/// Spawns a new window with the given [`Id`] and settings.
+///
+/// Only supported by multi-window applications.
+pub fn spawn<Message>(
+    id: Id,
+    settings: impl Into<window::Settings>,
+) -> Command<Message> {
+    Command::single(command::Action::Window(window::Action::Spawn {
+        id,
+        settings: settings.into(),
+    }))
 }
 
-/// Begins dragging the window while the left mouse button is held.
-pub fn drag<Message>() -> Command<Message> {
-    Command::single(command::Action::Window(window::Action::Drag))
+/// Begins dragging the window with the given [`Id`] while the left mouse
+/// button is held.
+pub fn drag<Message>(id: Id) -> Command<Message> {
+    Command::single(command::Action::Window(window::Action::Drag(id)))
 }
 
-/// Resizes the window to the given logical dimensions.
-pub fn resize<Message>(width: u32, height: u32) -> Command<Message> {
+/// Resizes the window with the given [`Id`] to the given logical dimensions.
+pub fn resize<Message>(id: Id, width: u32, height: u32) -> Command<Message> {
     Command::single(command::Action::Window(window::Action::Resize {
+        id,
         width,
         height,
     }))
 }
 
-/// Sets the window to maximized or back.
-pub fn maximize<Message>(value: bool) -> Command<Message> {
-    Command::single(command::Action::Window(window::Action::Maximize(value)))
+/// Sets the window with the given [`Id`] to maximized or back.
+pub fn maximize<Message>(id: Id, value: bool) -> Command<Message> {
+    Command::single(command::Action::Window(window::Action::Maximize(
+        id, value,
+    )))
 }
 
-/// Set the window to minimized or back.
-pub fn minimize<Message>(value: bool) -> Command<Message> {
-    Command::single(command::Action::Window(window::Action::Minimize(value)))
+/// Set the window with the given [`Id`] to minimized or back.
+pub fn minimize<Message>(id: Id, value: bool) -> Command<Message> {
+    Command::single(command::Action::Window(window::Action::Minimize(
+        id, value,
+    )))
 }
 
-/// Moves a window to the given logical coordinates.
-pub fn move_to<Message>(x: i32, y: i32) -> Command<Message> {
-    Command::single(command::Action::Window(window::Action::Move { x, y }))
+/// Moves the window with the given [`Id`] to the given logical coordinates.
+pub fn move_to<Message>(id: Id, x: i32, y: i32) -> Command<Message> {
+    Command::single(command::Action::Window(window::Action::Move { id, x, y }))
 }
 
-/// Sets the [`Mode`] of the window.
-pub fn set_mode<Message>(mode: Mode) -> Command<Message> {
-    Command::single(command::Action::Window(window::Action::SetMode(mode)))
+/// Sets the [`Mode`] of the window with the given [`Id`].
+pub fn set_mode<Message>(id: Id, mode: Mode) -> Command<Message> {
+    Command::single(command::Action::Window(window::Action::SetMode(id, mode)))
 }
 
-/// Sets the window to maximized or back.
-pub fn toggle_maximize<Message>() -> Command<Message> {
-    Command::single(command::Action::Window(window::Action::ToggleMaximize))
+/// Sets the window with the given [`Id`] to maximized or back.
+pub fn toggle_maximize<Message>(id: Id) -> Command<Message> {
+    Command::single(command::Action::Window(window::Action::ToggleMaximize(id)))
 }
 
-/// Fetches the current [`Mode`] of the window.
+/// Fetches the current [`Mode`] of the window with the given [`Id`].
 pub fn fetch_mode<Message>(
+    id: Id,
     f: impl FnOnce(Mode) -> Message + 'static,
 ) -> Command<Message> {
     Command::single(command::Action::Window(window::Action::FetchMode(
+        id,
+        Box::new(f),
+    )))
+}
+
+/// Captures a [`Screenshot`] of the window with the given [`Id`] after its
+/// next frame is presented.
+pub fn screenshot<Message>(
+    id: Id,
+    f: impl FnOnce(Screenshot) -> Message + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::Window(window::Action::Screenshot(
+        id,
         Box::new(f),
     )))
 }